@@ -228,11 +228,12 @@ fn test_clean_for_polars_nomodify() {
 
 #[test]
 fn test_clean_for_polars_modify() {
-    use re_log_types::{DataCell, Pinhole, Transform};
+    use re_log_types::{component_types::LensDistortion, DataCell, Pinhole, Transform};
     // transforms are a nice pathological type with both Unions and FixedSizeLists
     let transforms = vec![Transform::Pinhole(Pinhole {
         image_from_cam: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into(),
         resolution: None,
+        distortion: LensDistortion::None,
     })];
 
     let cell: DataCell = transforms.try_into().unwrap();
@@ -282,8 +283,94 @@ fn test_clean_for_polars_modify() {
                             ),
                             true,
                         ),
+                        Field::new(
+                            "distortion",
+                            DataType::Union(
+                                vec![
+                                    Field::new("None", DataType::Boolean, false),
+                                    Field::new(
+                                        "BrownConrady",
+                                        DataType::Struct(vec![
+                                            Field::new("k1", DataType::Float32, false),
+                                            Field::new("k2", DataType::Float32, false),
+                                            Field::new("p1", DataType::Float32, false),
+                                            Field::new("p2", DataType::Float32, false),
+                                            Field::new("k3", DataType::Float32, false),
+                                        ]),
+                                        false
+                                    ),
+                                    Field::new(
+                                        "KannalaBrandt",
+                                        DataType::Struct(vec![
+                                            Field::new("k1", DataType::Float32, false),
+                                            Field::new("k2", DataType::Float32, false),
+                                            Field::new("k3", DataType::Float32, false),
+                                            Field::new("k4", DataType::Float32, false),
+                                        ]),
+                                        false
+                                    ),
+                                    Field::new("Equirectangular", DataType::Boolean, false),
+                                ],
+                                None,
+                                UnionMode::Dense
+                            ),
+                            false,
+                        ),
                     ]),
                     false
+                ),
+                Field::new(
+                    "Scale",
+                    DataType::Union(
+                        vec![
+                            Field::new("Uniform", DataType::Float32, false),
+                            Field::new(
+                                "ThreeD",
+                                DataType::FixedSizeList(
+                                    Box::new(Field::new("item", DataType::Float32, false)),
+                                    3
+                                ),
+                                false
+                            ),
+                        ],
+                        None,
+                        UnionMode::Dense
+                    ),
+                    false
+                ),
+                Field::new(
+                    "Affine3",
+                    DataType::Struct(vec![
+                        Field::new(
+                            "linear",
+                            DataType::FixedSizeList(
+                                Box::new(Field::new("item", DataType::Float32, false)),
+                                9
+                            ),
+                            false
+                        ),
+                        Field::new(
+                            "translation",
+                            DataType::FixedSizeList(
+                                Box::new(Field::new("item", DataType::Float32, false)),
+                                3
+                            ),
+                            false
+                        )
+                    ]),
+                    false
+                ),
+                Field::new(
+                    "Homography2D",
+                    DataType::Struct(vec![Field::new(
+                        "parent_from_child",
+                        DataType::FixedSizeList(
+                            Box::new(Field::new("item", DataType::Float32, false)),
+                            9
+                        ),
+                        false
+                    )]),
+                    false
                 )
             ],
             None,
@@ -326,8 +413,74 @@ fn test_clean_for_polars_modify() {
                         DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
                         true,
                     ),
+                    Field::new(
+                        "distortion",
+                        DataType::Struct(vec![
+                            Field::new("None", DataType::Boolean, false),
+                            Field::new(
+                                "BrownConrady",
+                                DataType::Struct(vec![
+                                    Field::new("k1", DataType::Float32, false),
+                                    Field::new("k2", DataType::Float32, false),
+                                    Field::new("p1", DataType::Float32, false),
+                                    Field::new("p2", DataType::Float32, false),
+                                    Field::new("k3", DataType::Float32, false),
+                                ]),
+                                false
+                            ),
+                            Field::new(
+                                "KannalaBrandt",
+                                DataType::Struct(vec![
+                                    Field::new("k1", DataType::Float32, false),
+                                    Field::new("k2", DataType::Float32, false),
+                                    Field::new("k3", DataType::Float32, false),
+                                    Field::new("k4", DataType::Float32, false),
+                                ]),
+                                false
+                            ),
+                            Field::new("Equirectangular", DataType::Boolean, false),
+                        ]),
+                        false,
+                    ),
                 ]),
                 false
+            ),
+            Field::new(
+                "Scale",
+                DataType::Struct(vec![
+                    Field::new("Uniform", DataType::Float32, false),
+                    Field::new(
+                        "ThreeD",
+                        DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
+                        false
+                    ),
+                ]),
+                false
+            ),
+            Field::new(
+                "Affine3",
+                DataType::Struct(vec![
+                    Field::new(
+                        "linear",
+                        DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
+                        false
+                    ),
+                    Field::new(
+                        "translation",
+                        DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
+                        false
+                    )
+                ]),
+                false
+            ),
+            Field::new(
+                "Homography2D",
+                DataType::Struct(vec![Field::new(
+                    "parent_from_child",
+                    DataType::List(Box::new(Field::new("item", DataType::Float32, false))),
+                    false
+                )]),
+                false
             )
         ],),
     );
@@ -9,6 +9,7 @@
 //! * See [`DataStore::latest_at`] and [`DataStore::range`] for the documentation of the public
 //!   read APIs.
 //! * See [`DataStore::insert_row`] for the documentation of the public write APIs.
+//! * See [`DataStore::delete_range`] and [`DataStore::tombstone_instances`] for deleting data.
 //!
 //! ## Feature flags
 #![doc = document_features::document_features!()]
@@ -16,6 +17,7 @@
 
 mod arrow_util;
 mod store;
+mod store_delete;
 mod store_format;
 mod store_gc;
 mod store_read;
@@ -36,9 +38,10 @@ pub use self::arrow_util::ArrayExt;
 pub use self::store::{
     DataStore, DataStoreConfig, IndexBucket, IndexRowNr, IndexTable, RowIndex, RowIndexKind,
 };
+pub use self::store_delete::DeletedRow;
 pub use self::store_gc::GarbageCollectionTarget;
 pub use self::store_read::{LatestAtQuery, RangeQuery};
-pub use self::store_stats::DataStoreStats;
+pub use self::store_stats::{ComponentVolume, DataStoreStats};
 pub use self::store_write::{WriteError, WriteResult};
 
 pub(crate) use self::store::{
@@ -8,7 +8,8 @@ use arrow2::datatypes::{DataType, TimeUnit};
 use nohash_hasher::{IntMap, IntSet};
 use parking_lot::RwLock;
 use re_log_types::{
-    ComponentName, EntityPath, EntityPathHash, MsgId, TimeInt, TimePoint, TimeRange, Timeline,
+    component_types::InstanceKey, ComponentName, EntityPath, EntityPathHash, MsgId, TimeInt,
+    TimePoint, TimeRange, Timeline, TimelineRelation,
 };
 
 // --- Indices & offsets ---
@@ -255,6 +256,22 @@ pub struct DataStore {
 
     /// Monotonically increasing ID for GCs.
     pub(crate) gc_id: u64,
+
+    /// Monotonically increasing ID for deletions of time ranges.
+    pub(crate) delete_id: u64,
+
+    /// Bumped whenever anything changes the results of queries, see [`Self::generation`].
+    pub(crate) generation: u64,
+
+    /// The instances that were tombstoned, per entity per timeline, and since when.
+    ///
+    /// See [`Self::tombstone_instances`].
+    pub(crate) tombstones: HashMap<(Timeline, EntityPathHash), BTreeMap<TimeInt, Vec<InstanceKey>>>,
+
+    /// How the times of the timelines relate to each other.
+    ///
+    /// See [`Self::set_timeline_relations`].
+    pub(crate) timeline_relations: Vec<TimelineRelation>,
 }
 
 impl DataStore {
@@ -272,6 +289,10 @@ impl DataStore {
             insert_id: 0,
             query_id: AtomicU64::new(0),
             gc_id: 0,
+            delete_id: 0,
+            generation: 0,
+            tombstones: Default::default(),
+            timeline_relations: Default::default(),
         }
     }
 
@@ -294,6 +315,35 @@ impl DataStore {
     pub fn lookup_data_type(&self, component: &ComponentName) -> Option<&DataType> {
         self.components.get(component).map(|c| &c.datatype)
     }
+
+    /// Declares how the times of the timelines relate to each other.
+    ///
+    /// Queries for an entity that has no data on the queried timeline then fall back to the data
+    /// it has on a related timeline, at the equivalent time.
+    /// Invalid relations (see [`TimelineRelation::is_valid`]) are ignored.
+    pub fn set_timeline_relations(&mut self, relations: &[TimelineRelation]) {
+        let valid_relations = relations.iter().filter(|relation| relation.is_valid());
+        if self.timeline_relations.iter().eq(valid_relations) {
+            return;
+        }
+        self.generation += 1;
+        self.timeline_relations.clear();
+        self.timeline_relations
+            .extend(relations.iter().filter(|relation| relation.is_valid()));
+    }
+
+    /// See [`Self::set_timeline_relations`].
+    pub fn timeline_relations(&self) -> &[TimelineRelation] {
+        &self.timeline_relations
+    }
+
+    /// Changes whenever the results of queries may have changed, i.e. on insertions, garbage
+    /// collections, deletions, tombstones and new timeline relations.
+    ///
+    /// Use it to know when to invalidate caches of query results.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 // --- Persistent Indices ---
@@ -0,0 +1,217 @@
+use std::collections::BTreeSet;
+
+use nohash_hasher::IntMap;
+use re_log_types::{
+    component_types::InstanceKey, ComponentName, EntityPath, TimeInt, TimePoint, TimeRange,
+    Timeline,
+};
+
+use crate::{DataStore, IndexBucketIndices, RowIndex};
+
+// ---
+
+/// A row removed by [`DataStore::delete_range`], as seen on one of the timelines it was logged on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeletedRow {
+    pub timeline: Timeline,
+    pub component: ComponentName,
+    pub time: TimeInt,
+}
+
+impl DataStore {
+    /// Deletes the rows of `ent_path` that were logged within `time_range` on `timeline`, as if
+    /// they had never been inserted.
+    ///
+    /// The rows are deleted on all timelines, not only on `timeline`.
+    /// If `components` is set, only these components are deleted and the rest of the rows stays
+    /// as-is.
+    /// Timeless data is never deleted.
+    ///
+    /// Just like the garbage collector, this only affects the indices: the data itself stays in
+    /// the component tables until it gets garbage collected.
+    ///
+    /// Returns the deleted rows, once per component and timeline they were logged on, so that
+    /// callers can keep their own book-keeping (e.g. time histograms) in sync.
+    pub fn delete_range(
+        &mut self,
+        timeline: Timeline,
+        ent_path: &EntityPath,
+        time_range: TimeRange,
+        components: Option<&[ComponentName]>,
+    ) -> Vec<DeletedRow> {
+        crate::profile_function!();
+
+        let ent_path_hash = ent_path.hash();
+        let Some(table) = self.indices.get(&(timeline, ent_path_hash)) else {
+            return Vec::new();
+        };
+
+        self.delete_id += 1;
+        self.generation += 1;
+
+        let cluster_key = self.cluster_key;
+        let should_delete = |component: &ComponentName| {
+            // The cluster index must stay dense, and insert IDs aren't actual row indices.
+            *component != cluster_key
+                && *component != Self::insert_id_key()
+                && components.map_or(true, |components| components.contains(component))
+        };
+
+        // Delete the rows on the given timeline first, keeping track of which ones they were…
+        let mut deleted: IntMap<ComponentName, BTreeSet<RowIndex>> = Default::default();
+        let mut deleted_rows = Vec::new();
+        let (bucket_time, _) = table.find_bucket(time_range.min);
+        for (_, bucket) in table.range_buckets(bucket_time..=time_range.max) {
+            bucket.sort_indices_if_needed();
+
+            let mut guard = bucket.indices.write();
+            let IndexBucketIndices {
+                is_sorted: _,
+                time_range: _,
+                times,
+                indices,
+            } = &mut *guard;
+
+            let start = times.partition_point(|t| *t < time_range.min.as_i64());
+            let end = times.partition_point(|t| *t <= time_range.max.as_i64());
+            if start == end {
+                continue;
+            }
+
+            for (component, index) in indices.iter_mut() {
+                if !should_delete(component) {
+                    continue;
+                }
+                let deleted = deleted.entry(*component).or_default();
+                for (time, row_idx) in times[start..end].iter().zip(&mut index[start..end]) {
+                    if let Some(row_idx) = row_idx.take() {
+                        deleted.insert(row_idx);
+                        deleted_rows.push(DeletedRow {
+                            timeline,
+                            component: *component,
+                            time: (*time).into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let num_deleted = deleted_rows.len();
+
+        // …so that we can then delete the very same rows on all other timelines.
+        // A row is indexed at most once per timeline, so we can stop looking as soon as all
+        // of them have been found.
+        if num_deleted > 0 {
+            for ((other_timeline, other_ent_path_hash), table) in &self.indices {
+                if *other_timeline == timeline || *other_ent_path_hash != ent_path_hash {
+                    continue;
+                }
+                let mut num_found = 0;
+                for bucket in table.buckets.values() {
+                    if num_found == num_deleted {
+                        break;
+                    }
+                    let mut guard = bucket.indices.write();
+                    let IndexBucketIndices {
+                        is_sorted: _,
+                        time_range: _,
+                        times,
+                        indices,
+                    } = &mut *guard;
+                    for (component, index) in indices.iter_mut() {
+                        let Some(deleted) = deleted.get(component) else {
+                            continue;
+                        };
+                        for (time, row_idx) in times.iter().zip(index.iter_mut()) {
+                            if row_idx.map_or(false, |row_idx| deleted.contains(&row_idx)) {
+                                *row_idx = None;
+                                num_found += 1;
+                                deleted_rows.push(DeletedRow {
+                                    timeline: *other_timeline,
+                                    component: *component,
+                                    time: (*time).into(),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        re_log::debug!(
+            kind = "delete",
+            id = self.delete_id,
+            timeline = %timeline.name(),
+            time_range = timeline.typ().format_range(time_range),
+            entity = %ent_path,
+            ?components,
+            num_deleted,
+            "deleted time range"
+        );
+
+        deleted_rows
+    }
+
+    /// Marks `instance_keys` of `ent_path` as deleted from `timepoint` onward.
+    ///
+    /// The queries of the store deal in rows, not instances, so they ignore tombstones:
+    /// it is up to the caller to filter out the [`Self::tombstoned_instances`] (`re_query`
+    /// does it for you).
+    /// Timeless tombstones are ignored.
+    ///
+    /// Only the earliest tombstone of each instance is kept, so the tombstones of an entity never
+    /// outgrow the number of distinct instances that were removed from it.
+    pub fn tombstone_instances(
+        &mut self,
+        timepoint: &TimePoint,
+        ent_path: &EntityPath,
+        instance_keys: &[InstanceKey],
+    ) {
+        self.generation += 1;
+
+        for (timeline, time) in timepoint.iter() {
+            let tombstones = self
+                .tombstones
+                .entry((*timeline, ent_path.hash()))
+                .or_default();
+
+            for instance_key in instance_keys {
+                let already_tombstoned = tombstones
+                    .range(..=*time)
+                    .any(|(_, instance_keys)| instance_keys.contains(instance_key));
+                if already_tombstoned {
+                    continue;
+                }
+
+                // Supersede later tombstones of the same instance.
+                for instance_keys in tombstones.range_mut(*time..).map(|(_, keys)| keys) {
+                    instance_keys.retain(|key| key != instance_key);
+                }
+                tombstones.entry(*time).or_default().push(*instance_key);
+            }
+            tombstones.retain(|_, instance_keys| !instance_keys.is_empty());
+        }
+    }
+
+    /// All the instances of `ent_path` that were tombstoned at or before `time`, sorted.
+    ///
+    /// See [`Self::tombstone_instances`].
+    pub fn tombstoned_instances(
+        &self,
+        timeline: Timeline,
+        ent_path: &EntityPath,
+        time: TimeInt,
+    ) -> Vec<InstanceKey> {
+        let Some(tombstones) = self.tombstones.get(&(timeline, ent_path.hash())) else {
+            return Vec::new();
+        };
+
+        let mut instance_keys = tombstones
+            .range(..=time)
+            .flat_map(|(_, instance_keys)| instance_keys.iter().copied())
+            .collect::<Vec<_>>();
+        instance_keys.sort();
+        instance_keys.dedup();
+        instance_keys
+    }
+}
@@ -40,6 +40,10 @@ impl std::fmt::Display for DataStore {
             insert_id: _,
             query_id: _,
             gc_id: _,
+            delete_id: _,
+            generation: _,
+            tombstones: _,
+            timeline_relations: _,
         } = self;
 
         f.write_str("DataStore {\n")?;
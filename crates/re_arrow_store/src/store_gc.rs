@@ -49,6 +49,7 @@ impl DataStore {
         crate::profile_function!();
 
         self.gc_id += 1;
+        self.generation += 1;
 
         let initial_nb_rows = self.total_temporal_component_rows();
         let initial_size_bytes = self.total_temporal_component_size_bytes() as f64;
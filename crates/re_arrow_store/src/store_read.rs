@@ -4,7 +4,10 @@ use arrow2::array::{Array, ListArray};
 
 use itertools::Itertools;
 use re_log::trace;
-use re_log_types::{ComponentName, EntityPath, MsgId, TimeInt, TimePoint, TimeRange, Timeline};
+use re_log_types::{
+    ComponentName, EntityPath, EntityPathHash, MsgId, TimeInt, TimePoint, TimeRange, Timeline,
+    TimelineRelation,
+};
 
 use crate::{
     ComponentBucket, ComponentTable, DataStore, IndexBucket, IndexBucketIndices, IndexRowNr,
@@ -109,9 +112,8 @@ impl DataStore {
             .map(|index| &index.all_components);
 
         let temporal = self
-            .indices
-            .get(&(*timeline, ent_path_hash))
-            .map(|index| &index.all_components);
+            .index_table_or_related(*timeline, ent_path_hash)
+            .map(|(index, _)| &index.all_components);
 
         let components = match (timeless, temporal) {
             (None, Some(temporal)) => temporal.iter().cloned().collect_vec(),
@@ -216,10 +218,10 @@ impl DataStore {
         );
 
         let row_indices = self
-            .indices
-            .get(&(query.timeline, ent_path_hash))
-            .and_then(|index| {
-                let row_indices = index.latest_at(query.at, primary, components);
+            .index_table_or_related(query.timeline, ent_path_hash)
+            .and_then(|(index, relation)| {
+                let at = relation.map_or(query.at, |relation| relation.map_int(query.at));
+                let row_indices = index.latest_at(at, primary, components);
                 trace!(
                     kind = "latest_at",
                     query = ?query,
@@ -228,6 +230,7 @@ impl DataStore {
                     ?components,
                     ?row_indices,
                     timeless = false,
+                    related_timeline = ?relation.map(|relation| relation.to),
                     "row indices fetched"
                 );
                 row_indices
@@ -409,9 +412,19 @@ impl DataStore {
         );
 
         let temporal = self
-            .indices
-            .get(&(query.timeline, ent_path_hash))
-            .map(|index| index.range(query.range, components))
+            .index_table_or_related(query.timeline, ent_path_hash)
+            .map(|(index, relation)| {
+                let range =
+                    relation.map_or(query.range, |relation| relation.map_range(query.range));
+                index
+                    .range(range, components)
+                    .map(move |(time, idx_row_nr, row_indices)| {
+                        // Report the times on the queried timeline.
+                        let time =
+                            relation.map_or(time, |relation| relation.inverse().map_int(time));
+                        (time, idx_row_nr, row_indices)
+                    })
+            })
             .into_iter()
             .flatten()
             .map(|(time, idx_row_nr, row_indices)| (Some(time), idx_row_nr, row_indices));
@@ -433,6 +446,32 @@ impl DataStore {
         }
     }
 
+    /// The index table of an entity on `timeline` or, if it has no data there, on a related
+    /// timeline (see [`Self::set_timeline_relations`]).
+    ///
+    /// Also returns how to map `timeline` onto the related timeline, if any.
+    fn index_table_or_related(
+        &self,
+        timeline: Timeline,
+        ent_path_hash: EntityPathHash,
+    ) -> Option<(&IndexTable, Option<TimelineRelation>)> {
+        if let Some(index) = self.indices.get(&(timeline, ent_path_hash)) {
+            return Some((index, None));
+        }
+
+        self.timeline_relations.iter().find_map(|relation| {
+            let relation = if relation.from == timeline {
+                *relation
+            } else if relation.to == timeline {
+                relation.inverse()
+            } else {
+                return None;
+            };
+            let index = self.indices.get(&(relation.to, ent_path_hash))?;
+            Some((index, Some(relation)))
+        })
+    }
+
     /// Retrieves the data associated with a list of `components` at the specified `indices`.
     ///
     /// If the associated data is found, it will be written into the returned array at the
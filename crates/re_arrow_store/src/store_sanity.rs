@@ -20,8 +20,8 @@ impl DataStore {
     pub fn sanity_check(&self) -> anyhow::Result<()> {
         crate::profile_function!();
 
-        // Row indices should be continuous across all index tables.
-        if self.gc_id == 0 {
+        // Row indices should be continuous across all index tables, unless some were deleted.
+        if self.gc_id == 0 && self.delete_id == 0 {
             let mut row_indices: IntMap<_, Vec<u64>> = IntMap::default();
             for table in self.indices.values() {
                 for bucket in table.buckets.values() {
@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+
+use arrow2::compute::aggregate::estimated_bytes_size;
+use re_log_types::{ComponentName, EntityPath, Timeline};
+
 use crate::{
     ComponentBucket, ComponentTable, DataStore, DataStoreConfig, IndexBucket, IndexBucketIndices,
     IndexTable, PersistentComponentTable, PersistentIndexTable,
@@ -74,9 +79,63 @@ impl DataStoreStats {
     }
 }
 
+/// How much temporal data a single component of a single entity holds.
+///
+/// See [`DataStore::component_volumes`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentVolume {
+    /// Number of rows in which the component is present.
+    pub num_rows: u64,
+
+    /// Estimated size of the component data across these rows, in bytes.
+    pub size_bytes: u64,
+}
+
 // --- Data store ---
 
 impl DataStore {
+    /// Returns how much data every component of every entity holds on the given `timeline`.
+    ///
+    /// Deleted and garbage collected rows are not accounted for, and neither is timeless data.
+    ///
+    /// This is expensive: it looks up the size of every single row, so cache the results.
+    pub fn component_volumes(
+        &self,
+        timeline: Timeline,
+    ) -> BTreeMap<EntityPath, BTreeMap<ComponentName, ComponentVolume>> {
+        crate::profile_function!();
+
+        let mut volumes: BTreeMap<EntityPath, BTreeMap<ComponentName, ComponentVolume>> =
+            Default::default();
+
+        for ((table_timeline, _), table) in &self.indices {
+            if *table_timeline != timeline {
+                continue;
+            }
+
+            let entity_volumes = volumes.entry(table.ent_path.clone()).or_default();
+            for bucket in table.buckets.values() {
+                let guard = bucket.indices.read();
+                for (component, index) in &guard.indices {
+                    if *component == Self::insert_id_key() {
+                        continue; // Not actual data.
+                    }
+
+                    let component_table = self.components.get(component);
+                    let volume = entity_volumes.entry(*component).or_default();
+                    for row_idx in index.iter().flatten() {
+                        volume.num_rows += 1;
+                        if let Some(row) = component_table.and_then(|table| table.get(*row_idx)) {
+                            volume.size_bytes += estimated_bytes_size(&*row) as u64;
+                        }
+                    }
+                }
+            }
+        }
+
+        volumes
+    }
+
     /// Returns the number of timeless index rows stored across this entire store, i.e. the sum of
     /// the number of rows across all of its timeless index tables.
     pub fn total_timeless_index_rows(&self) -> u64 {
@@ -68,6 +68,7 @@ impl DataStore {
     pub fn insert_row(&mut self, row: &DataRow) -> WriteResult<()> {
         // TODO(cmc): kind & insert_id need to somehow propagate through the span system.
         self.insert_id += 1;
+        self.generation += 1;
 
         if row.num_cells() == 0 {
             return Ok(());
@@ -370,6 +370,170 @@ fn gc_correct() {
     assert_eq!(2, store.total_temporal_component_rows());
 }
 
+#[test]
+fn delete_range() {
+    init_logs();
+
+    for config in re_arrow_store::test_util::all_configs() {
+        let mut store = DataStore::new(InstanceKey::name(), config.clone());
+        delete_range_impl(&mut store);
+    }
+}
+
+fn delete_range_impl(store: &mut DataStore) {
+    use re_log_types::component_types::Point2D;
+
+    let ent_path = EntityPath::from("this/that");
+    let now = Time::now();
+    let timeline_frame_nr = Timeline::new("frame_nr", TimeType::Sequence);
+    let timeline_log_time = Timeline::log_time();
+
+    for frame_nr in 1i64..=5 {
+        let log_time = now + Duration::from_secs(frame_nr as f32);
+        store
+            .insert_row(&test_row!(ent_path @ [
+                    build_frame_nr(frame_nr.into()), build_log_time(log_time),
+                ] => 2; [build_some_point2d(2)]))
+            .unwrap();
+    }
+
+    let deleted = store.delete_range(
+        timeline_frame_nr,
+        &ent_path,
+        re_arrow_store::TimeRange::new(3.into(), 4.into()),
+        None,
+    );
+    let num_deleted_on = |timeline: Timeline| {
+        deleted
+            .iter()
+            .filter(|row| row.timeline == timeline && row.component == Point2D::name())
+            .count()
+    };
+    assert_eq!(2, num_deleted_on(timeline_frame_nr));
+    assert_eq!(2, num_deleted_on(timeline_log_time));
+
+    if let err @ Err(_) = store.sanity_check() {
+        store.sort_indices_if_needed();
+        eprintln!("{store}");
+        err.unwrap();
+    }
+
+    let latest_point = |store: &DataStore, query: LatestAtQuery| {
+        store
+            .latest_at(&query, &ent_path, Point2D::name(), &[Point2D::name()])
+            .map(|[row_idx]| row_idx)
+    };
+
+    // The deleted frames show the latest data from before the deleted range.
+    let frame2 = latest_point(store, LatestAtQuery::new(timeline_frame_nr, 2.into()));
+    let frame4 = latest_point(store, LatestAtQuery::new(timeline_frame_nr, 4.into()));
+    let frame5 = latest_point(store, LatestAtQuery::new(timeline_frame_nr, 5.into()));
+    assert!(frame2.is_some());
+    assert_eq!(frame2, frame4);
+    assert_ne!(frame2, frame5);
+
+    // The rows are gone from the other timelines too.
+    let log_time4 = (now + Duration::from_secs(4.0)).nanos_since_epoch().into();
+    let log_time4 = latest_point(store, LatestAtQuery::new(timeline_log_time, log_time4));
+    assert_eq!(frame2, log_time4);
+
+    check_still_readable(store);
+}
+
+// ---
+
+#[test]
+fn latest_at_related_timeline() {
+    init_logs();
+
+    for config in re_arrow_store::test_util::all_configs() {
+        let mut store = DataStore::new(InstanceKey::name(), config.clone());
+        latest_at_related_timeline_impl(&mut store);
+    }
+}
+
+fn latest_at_related_timeline_impl(store: &mut DataStore) {
+    use re_log_types::{component_types::Point2D, TimelineRelation};
+
+    let ent_path = EntityPath::from("this/that");
+    let timeline_frame_nr = Timeline::new("frame_nr", TimeType::Sequence);
+    let timeline_sensor_nr = Timeline::new("sensor_nr", TimeType::Sequence);
+
+    // Only logged on `frame_nr`.
+    store
+        .insert_row(
+            &test_row!(ent_path @ [build_frame_nr(10.into())] => 2; [build_some_point2d(2)]),
+        )
+        .unwrap();
+
+    let latest_point = |store: &DataStore, query: LatestAtQuery| {
+        store
+            .latest_at(&query, &ent_path, Point2D::name(), &[Point2D::name()])
+            .map(|[row_idx]| row_idx)
+    };
+
+    assert!(latest_point(store, LatestAtQuery::new(timeline_sensor_nr, 100.into())).is_none());
+
+    // frame_nr = sensor_nr - 80
+    store.set_timeline_relations(&[TimelineRelation::offset(
+        timeline_sensor_nr,
+        timeline_frame_nr,
+        -80.0,
+    )]);
+
+    let frame10 = latest_point(store, LatestAtQuery::new(timeline_frame_nr, 10.into()));
+    assert!(frame10.is_some());
+    assert!(latest_point(store, LatestAtQuery::new(timeline_sensor_nr, 89.into())).is_none());
+    assert_eq!(
+        frame10,
+        latest_point(store, LatestAtQuery::new(timeline_sensor_nr, 90.into()))
+    );
+}
+
+#[test]
+fn component_volumes() {
+    init_logs();
+
+    for config in re_arrow_store::test_util::all_configs() {
+        let mut store = DataStore::new(InstanceKey::name(), config.clone());
+        component_volumes_impl(&mut store);
+    }
+}
+
+fn component_volumes_impl(store: &mut DataStore) {
+    use re_log_types::component_types::{ColorRGBA, Point2D};
+
+    let ent_path = EntityPath::from("this/that");
+    let timeline_frame_nr = Timeline::new("frame_nr", TimeType::Sequence);
+
+    for frame_nr in 1i64..=3 {
+        store
+            .insert_row(
+                &test_row!(ent_path @ [build_frame_nr(frame_nr.into())] => 2; [
+                    build_some_point2d(2),
+                ]),
+            )
+            .unwrap();
+    }
+    store
+        .insert_row(&test_row!(ent_path @ [build_frame_nr(4.into())] => 2; [
+            build_some_colors(2),
+        ]))
+        .unwrap();
+
+    let volumes = store.component_volumes(timeline_frame_nr);
+    let volumes = &volumes[&ent_path];
+    assert_eq!(3, volumes[&Point2D::name()].num_rows);
+    assert_eq!(1, volumes[&ColorRGBA::name()].num_rows);
+    assert!(volumes[&Point2D::name()].size_bytes > volumes[&ColorRGBA::name()].size_bytes);
+
+    assert!(store
+        .component_volumes(Timeline::new("other", TimeType::Sequence))
+        .is_empty());
+}
+
+// ---
+
 fn check_still_readable(_store: &DataStore) {
     #[cfg(feature = "polars")]
     {
@@ -69,6 +69,21 @@ pub struct EntityProperties {
 
     /// Used to scale the radii of the points in the resulting point cloud.
     pub backproject_radius_scale: EditableAutoValue<f32>,
+
+    /// How meshes are shaded.
+    ///
+    /// Only applies to meshes in a spatial view, using 3D navigation.
+    pub mesh_shading: EditableAutoValue<MeshShading>,
+
+    /// Should the edges of all mesh triangles be drawn on top of the mesh?
+    ///
+    /// Only applies to meshes in a spatial view, using 3D navigation.
+    pub mesh_wireframe: EditableAutoValue<bool>,
+
+    /// Should rigid transforms be interpolated between the logged samples around the current time?
+    ///
+    /// Otherwise the latest transform at the current time is used as-is.
+    pub interpolate_transforms: EditableAutoValue<bool>,
 }
 
 #[cfg(feature = "serde")]
@@ -96,6 +111,14 @@ impl EntityProperties {
                 .backproject_radius_scale
                 .or(&child.backproject_radius_scale)
                 .clone(),
+
+            mesh_shading: self.mesh_shading.or(&child.mesh_shading).clone(),
+            mesh_wireframe: self.mesh_wireframe.or(&child.mesh_wireframe).clone(),
+
+            interpolate_transforms: self
+                .interpolate_transforms
+                .or(&child.interpolate_transforms)
+                .clone(),
         }
     }
 }
@@ -112,6 +135,9 @@ impl Default for EntityProperties {
             backproject_depth: EditableAutoValue::Auto(true),
             depth_from_world_scale: EditableAutoValue::default(),
             backproject_radius_scale: EditableAutoValue::Auto(1.0),
+            mesh_shading: EditableAutoValue::default(),
+            mesh_wireframe: EditableAutoValue::Auto(false),
+            interpolate_transforms: EditableAutoValue::Auto(false),
         }
     }
 }
@@ -192,6 +218,32 @@ impl Default for ColorMapper {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MeshShading {
+    /// Lighting with the mesh's vertex normals.
+    #[default]
+    Smooth,
+
+    /// Lighting with a single normal per triangle.
+    Flat,
+
+    /// No lighting.
+    Unlit,
+}
+
+impl std::fmt::Display for MeshShading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MeshShading::Smooth => "Smooth",
+            MeshShading::Flat => "Flat",
+            MeshShading::Unlit => "Unlit",
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Get the latest value for a given [`re_log_types::Component`].
 ///
 /// This assumes that the row we get from the store only contains a single instance for this
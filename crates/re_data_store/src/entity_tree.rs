@@ -108,6 +108,9 @@ pub struct EntityTree {
     /// Book-keeping around whether we should clear recursively when data is added
     pub recursive_clears: BTreeMap<MsgId, TimePoint>,
 
+    /// Book-keeping around whether we should clear some specific fields when they are added
+    pub component_clears: BTreeMap<MsgId, (TimePoint, Vec<ComponentName>)>,
+
     /// Data logged at this entity path.
     pub components: BTreeMap<ComponentName, ComponentStats>,
 }
@@ -125,6 +128,7 @@ impl EntityTree {
             num_timeless_messages: 0,
             nonrecursive_clears: recursive_clears.clone(),
             recursive_clears,
+            component_clears: Default::default(),
             components: Default::default(),
         }
     }
@@ -161,7 +165,19 @@ impl EntityTree {
             .or_insert_with(|| {
                 // If we needed to create a new leaf to hold this data, we also want to
                 // insert all of the historical pending clear operations
-                pending_clears = leaf.nonrecursive_clears.clone().into_iter().collect_vec();
+                let component_clears = leaf.component_clears.iter().filter_map(
+                    |(msg_id, (time_point, component_names))| {
+                        component_names
+                            .contains(&component_path.component_name)
+                            .then(|| (*msg_id, time_point.clone()))
+                    },
+                );
+                pending_clears = leaf
+                    .nonrecursive_clears
+                    .clone()
+                    .into_iter()
+                    .chain(component_clears)
+                    .collect_vec();
 
                 Default::default()
             });
@@ -176,6 +192,9 @@ impl EntityTree {
     /// Returns a collection of paths to clear as a result of the operation
     /// Additional pending clear operations will be stored in the tree for future
     /// insertion.
+    ///
+    /// [`PathOp::ClearTimeRange`] and [`PathOp::TombstoneInstances`] never return any paths:
+    /// they don't clear anything at the time of the operation.
     pub fn add_path_op(
         &mut self,
         msg_id: MsgId,
@@ -232,6 +251,60 @@ impl EntityTree {
                 }
                 results
             }
+            PathOp::ClearSomeComponents(entity_path, component_names) => {
+                // Track that these fields need a Null at the right time-point
+                // if they are added later.
+                leaf.component_clears
+                    .entry(msg_id)
+                    .or_insert_with(|| (time_point.clone(), component_names.clone()));
+
+                // For every existing field of these return a clear event
+                component_names
+                    .iter()
+                    .filter(|component_name| leaf.components.contains_key(component_name))
+                    .map(|component_name| ComponentPath::new(entity_path.clone(), *component_name))
+                    .collect_vec()
+            }
+            // These don't clear anything at the time of the path op, and the store lets
+            // us know about the deleted rows, see `Self::on_rows_deleted`.
+            PathOp::ClearTimeRange { .. } => vec![],
+            PathOp::TombstoneInstances(_, _) => vec![],
+        }
+    }
+
+    /// Forget about the given rows of `entity_path` that were deleted from the store,
+    /// on all of the timelines they were logged on.
+    ///
+    /// The inverse of [`Self::add_data_msg`].
+    pub fn on_rows_deleted(
+        &mut self,
+        entity_path: &EntityPath,
+        deleted_rows: &[re_arrow_store::DeletedRow],
+    ) {
+        let mut tree = self;
+        let mut depth = 0;
+        loop {
+            for row in deleted_rows {
+                if let Some(histogram) = tree.prefix_times.0.get_mut(&row.timeline) {
+                    histogram.decrement(row.time.as_i64(), 1);
+                }
+            }
+            let Some(part) = entity_path.as_slice().get(depth) else {
+                break;
+            };
+            let Some(child) = tree.children.get_mut(part) else {
+                return;
+            };
+            tree = child;
+            depth += 1;
+        }
+
+        for row in deleted_rows {
+            if let Some(stats) = tree.components.get_mut(&row.component) {
+                if let Some(histogram) = stats.times.0.get_mut(&row.timeline) {
+                    histogram.decrement(row.time.as_i64(), 1);
+                }
+            }
         }
     }
 
@@ -295,6 +368,7 @@ impl EntityTree {
             num_timeless_messages: _,
             nonrecursive_clears,
             recursive_clears,
+            component_clears,
             components: fields,
         } = self;
 
@@ -312,6 +386,10 @@ impl EntityTree {
             crate::profile_scope!("recursive_clears");
             recursive_clears.retain(|msg_id, _| !drop_msg_ids.contains(msg_id));
         }
+        {
+            crate::profile_scope!("component_clears");
+            component_clears.retain(|msg_id, _| !drop_msg_ids.contains(msg_id));
+        }
 
         {
             crate::profile_scope!("fields");
@@ -1,3 +1,4 @@
+use itertools::Itertools as _;
 use nohash_hasher::IntMap;
 
 use re_arrow_store::{DataStoreConfig, GarbageCollectionTarget, TimeInt};
@@ -128,27 +129,77 @@ impl EntityDb {
     fn add_path_op(&mut self, msg_id: MsgId, time_point: &TimePoint, path_op: &PathOp) {
         let cleared_paths = self.tree.add_path_op(msg_id, time_point, path_op);
 
-        for component_path in cleared_paths {
-            if let Some(data_type) = self
-                .data_store
-                .lookup_data_type(&component_path.component_name)
-            {
-                // Create and insert an empty component into the arrow store
-                // TODO(jleibs): Faster empty-array creation
-                let cell =
-                    DataCell::from_arrow_empty(component_path.component_name, data_type.clone());
-                let row = DataRow::from_cells1(
-                    msg_id,
-                    component_path.entity_path.clone(),
-                    time_point.clone(),
-                    cell.num_instances(),
-                    cell,
-                );
-                self.data_store.insert_row(&row).ok();
-                // Also update the tree with the clear-event
+        // Clear each entity with a single row holding an empty cell per cleared component.
+        for (entity_path, component_paths) in &cleared_paths
+            .iter()
+            .group_by(|component_path| &component_path.entity_path)
+        {
+            let cells = component_paths
+                .filter_map(|component_path| {
+                    let data_type = self
+                        .data_store
+                        .lookup_data_type(&component_path.component_name)?;
+                    // TODO(jleibs): Faster empty-array creation
+                    Some(DataCell::from_arrow_empty(
+                        component_path.component_name,
+                        data_type.clone(),
+                    ))
+                })
+                .collect_vec();
+            if cells.is_empty() {
+                continue;
+            }
+
+            let row =
+                DataRow::from_cells(msg_id, time_point.clone(), entity_path.clone(), 0, cells);
+            self.data_store.insert_row(&row).ok();
+
+            // Also update the tree with the clear-events
+            for cell in row.cells().iter() {
+                let component_path = ComponentPath::new(entity_path.clone(), cell.component_name());
                 self.tree.add_data_msg(time_point, &component_path);
             }
         }
+
+        match path_op {
+            PathOp::ClearComponents(_)
+            | PathOp::ClearRecursive(_)
+            | PathOp::ClearSomeComponents(_, _) => {}
+            PathOp::ClearTimeRange {
+                entity_path,
+                timeline,
+                range,
+                components,
+                recursive,
+            } => {
+                let mut entity_paths = vec![];
+                if *recursive {
+                    if let Some(tree) = self.tree.subtree(entity_path) {
+                        tree.visit_children_recursively(&mut |entity_path| {
+                            entity_paths.push(entity_path.clone());
+                        });
+                    }
+                } else {
+                    entity_paths.push(entity_path.clone());
+                }
+
+                for entity_path in &entity_paths {
+                    let mut deleted_rows = self.data_store.delete_range(
+                        *timeline,
+                        entity_path,
+                        *range,
+                        components.as_deref(),
+                    );
+                    // The tree doesn't keep track of `MsgId`s, see `Self::try_add_data_row`.
+                    deleted_rows.retain(|row| row.component != MsgId::name());
+                    self.tree.on_rows_deleted(entity_path, &deleted_rows);
+                }
+            }
+            PathOp::TombstoneInstances(entity_path, instance_keys) => {
+                self.data_store
+                    .tombstone_instances(time_point, entity_path, instance_keys);
+            }
+        }
     }
 
     pub fn purge(
@@ -0,0 +1,171 @@
+//! Tests of the [`PathOp`]s that clear or delete data, as seen through a [`LogDb`].
+
+use re_arrow_store::LatestAtQuery;
+use re_data_store::LogDb;
+use re_log_types::{
+    component_types::{ColorRGBA, InstanceKey, Point2D, Radius},
+    ArrowMsg, Component as _, ComponentName, DataCell, DataRow, DataTable, EntityPath,
+    EntityPathOpMsg, LogMsg, MsgId, PathOp, TimeInt, TimePoint, Timeline,
+};
+
+fn frame_nr() -> Timeline {
+    Timeline::new_sequence("frame_nr")
+}
+
+/// A second timeline, at ten times the frame number.
+fn other() -> Timeline {
+    Timeline::new_sequence("other")
+}
+
+fn timepoint(frame: i64) -> TimePoint {
+    TimePoint::from([
+        (frame_nr(), TimeInt::from(frame)),
+        (other(), TimeInt::from(10 * frame)),
+    ])
+}
+
+fn log_row(db: &mut LogDb, ent_path: &EntityPath, frame: i64, cells: Vec<DataCell>) {
+    let row = DataRow::from_cells(
+        MsgId::random(),
+        timepoint(frame),
+        ent_path.clone(),
+        2,
+        cells,
+    );
+    let table = DataTable::from_rows(MsgId::random(), [row]);
+    db.add(LogMsg::ArrowMsg(ArrowMsg::try_from(&table).unwrap()))
+        .unwrap();
+}
+
+fn log_points_and_colors(db: &mut LogDb, ent_path: &EntityPath, frame: i64) {
+    let points = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+    let colors = [ColorRGBA(0xFF0000FF), ColorRGBA(0x00FF00FF)];
+    log_row(
+        db,
+        ent_path,
+        frame,
+        vec![
+            DataCell::from_native(points.iter()),
+            DataCell::from_native(colors.iter()),
+        ],
+    );
+}
+
+fn log_path_op(db: &mut LogDb, frame: i64, path_op: PathOp) {
+    db.add(LogMsg::EntityPathOpMsg(EntityPathOpMsg {
+        msg_id: MsgId::random(),
+        time_point: timepoint(frame),
+        path_op,
+    }))
+    .unwrap();
+}
+
+/// How many instances of `component` there are at `frame`, if any were ever logged.
+fn num_instances(
+    db: &LogDb,
+    ent_path: &EntityPath,
+    component: ComponentName,
+    frame: i64,
+) -> Option<usize> {
+    let store = &db.entity_db.data_store;
+    let query = LatestAtQuery::new(frame_nr(), frame.into());
+    let [row_idx] = store.latest_at(&query, ent_path, component, &[component])?;
+    let [array] = store.get(&[component], &[row_idx]);
+    array.map(|array| array.len())
+}
+
+#[test]
+fn clear_some_components() {
+    let mut db = LogDb::default();
+    let ent_path = EntityPath::from("points");
+
+    log_points_and_colors(&mut db, &ent_path, 1);
+    log_path_op(
+        &mut db,
+        2,
+        PathOp::ClearSomeComponents(ent_path.clone(), vec![ColorRGBA::name(), Radius::name()]),
+    );
+
+    // Only the given components are cleared, and only from the time of the clear onward.
+    assert_eq!(num_instances(&db, &ent_path, ColorRGBA::name(), 1), Some(2));
+    assert_eq!(num_instances(&db, &ent_path, ColorRGBA::name(), 2), Some(0));
+    assert_eq!(num_instances(&db, &ent_path, Point2D::name(), 2), Some(2));
+
+    // Components logged for the first time after the clear still get cleared.
+    let radii = [Radius(1.0), Radius(2.0)];
+    log_row(
+        &mut db,
+        &ent_path,
+        1,
+        vec![DataCell::from_native(radii.iter())],
+    );
+    assert_eq!(num_instances(&db, &ent_path, Radius::name(), 1), Some(2));
+    assert_eq!(num_instances(&db, &ent_path, Radius::name(), 2), Some(0));
+}
+
+#[test]
+fn tombstone_instances() {
+    let mut db = LogDb::default();
+    let ent_path = EntityPath::from("points");
+
+    log_points_and_colors(&mut db, &ent_path, 1);
+    log_path_op(
+        &mut db,
+        3,
+        PathOp::TombstoneInstances(ent_path.clone(), vec![InstanceKey(1)]),
+    );
+
+    let tombstoned = |db: &LogDb, timeline: Timeline, time: i64| {
+        db.entity_db
+            .data_store
+            .tombstoned_instances(timeline, &ent_path, time.into())
+    };
+    assert!(tombstoned(&db, frame_nr(), 2).is_empty());
+    assert_eq!(tombstoned(&db, frame_nr(), 3), vec![InstanceKey(1)]);
+    assert_eq!(tombstoned(&db, other(), 30), vec![InstanceKey(1)]);
+
+    // Tombstoning the same instance again, earlier, moves the tombstone.
+    log_path_op(
+        &mut db,
+        2,
+        PathOp::TombstoneInstances(ent_path.clone(), vec![InstanceKey(1)]),
+    );
+    assert!(tombstoned(&db, frame_nr(), 1).is_empty());
+    assert_eq!(tombstoned(&db, frame_nr(), 2), vec![InstanceKey(1)]);
+    assert_eq!(tombstoned(&db, frame_nr(), 3), vec![InstanceKey(1)]);
+}
+
+#[test]
+fn clear_time_range_updates_all_timelines() {
+    let mut db = LogDb::default();
+    let ent_path = EntityPath::from("points");
+
+    for frame in 1..=3 {
+        log_points_and_colors(&mut db, &ent_path, frame);
+    }
+    log_path_op(
+        &mut db,
+        4,
+        PathOp::ClearTimeRange {
+            entity_path: ent_path.clone(),
+            timeline: frame_nr(),
+            range: re_log_types::TimeRange::new(2.into(), 2.into()),
+            components: None,
+            recursive: false,
+        },
+    );
+
+    // The deleted frame shows the data from before it.
+    assert_eq!(num_instances(&db, &ent_path, Point2D::name(), 2), Some(2));
+
+    let tree = db.entity_db.tree.subtree(&ent_path).unwrap();
+    let points = &tree.components[&Point2D::name()];
+    for (timeline, time) in [(frame_nr(), 2), (other(), 20)] {
+        let histogram = points.times.get(&timeline).unwrap();
+        assert_eq!(histogram.range_count(time..=time), 0, "{timeline:?}");
+        assert_eq!(histogram.total_count(), 2, "{timeline:?}");
+
+        let prefix_times = db.entity_db.tree.prefix_times.get(&timeline).unwrap();
+        assert_eq!(prefix_times.range_count(time..=time), 0, "{timeline:?}");
+    }
+}
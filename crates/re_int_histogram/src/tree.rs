@@ -132,6 +132,21 @@ impl Int64Histogram {
             .increment(ROOT_LEVEL, u64_key_from_i64_key(key), inc);
     }
 
+    /// Decrement the count for the given key, without going below zero.
+    ///
+    /// This is the inverse of [`Self::increment`], e.g. for when an element was removed again.
+    /// Returns how much count was removed.
+    pub fn decrement(&mut self, key: i64, dec: u64) -> u64 {
+        let count = self.remove(key..=key);
+        let mut remaining = count.saturating_sub(dec);
+        while remaining > 0 {
+            let inc = remaining.min(u32::MAX as u64) as u32;
+            self.increment(key, inc);
+            remaining -= inc as u64;
+        }
+        count.min(dec)
+    }
+
     /// Is the total count zero?
     ///
     /// Note that incrementing a key with zero is a no-op and
@@ -738,6 +753,22 @@ mod tests {
         assert_eq!(set.range(..10, 1).count(), 10);
     }
 
+    #[test]
+    fn test_decrement() {
+        let mut set = Int64Histogram::default();
+        set.increment(-5, 3);
+        set.increment(7, 1);
+
+        assert_eq!(set.decrement(-5, 2), 2);
+        assert_eq!(set.range_count(-5..=-5), 1);
+        assert_eq!(set.decrement(-5, 2), 1);
+        assert_eq!(set.range_count(-5..=-5), 0);
+        assert_eq!(set.decrement(42, 1), 0);
+
+        assert_eq!(set.total_count(), 1);
+        assert_eq!(set.min_key(), Some(7));
+    }
+
     #[test]
     fn test_sparse() {
         let inc = 2;
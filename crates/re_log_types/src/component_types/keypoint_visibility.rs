@@ -0,0 +1,66 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// Whether a keypoint (see [`super::KeypointId`]) can be seen in the data it was annotated in.
+///
+/// Follows the convention of the COCO keypoint format.
+///
+/// ```
+/// use re_log_types::component_types::KeypointVisibility;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(KeypointVisibility::data_type(), DataType::UInt8);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    ArrowField,
+    ArrowSerialize,
+    ArrowDeserialize,
+)]
+#[arrow_field(transparent)]
+pub struct KeypointVisibility(pub u8);
+
+impl KeypointVisibility {
+    /// The keypoint was not annotated, its position is meaningless.
+    pub const NOT_LABELED: Self = Self(0);
+
+    /// The keypoint was annotated, but is hidden, e.g. behind another object.
+    pub const OCCLUDED: Self = Self(1);
+
+    /// The keypoint was annotated and can be seen.
+    pub const VISIBLE: Self = Self(2);
+
+    #[inline]
+    pub fn is_labeled(&self) -> bool {
+        *self != Self::NOT_LABELED
+    }
+
+    #[inline]
+    pub fn is_occluded(&self) -> bool {
+        *self == Self::OCCLUDED
+    }
+}
+
+impl Default for KeypointVisibility {
+    #[inline]
+    fn default() -> Self {
+        Self::VISIBLE
+    }
+}
+
+impl Component for KeypointVisibility {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.keypoint_visibility".into()
+    }
+}
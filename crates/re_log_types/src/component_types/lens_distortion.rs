@@ -0,0 +1,298 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+/// Lens distortion of a [`super::Pinhole`] camera.
+///
+/// All models operate on normalized image coordinates,
+/// i.e. the coordinates before applying [`super::Pinhole::image_from_cam`].
+///
+/// ```
+/// use re_log_types::component_types::LensDistortion;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+///
+/// assert_eq!(
+///     LensDistortion::data_type(),
+///     DataType::Union(
+///         vec![
+///             Field::new("None", DataType::Boolean, false),
+///             Field::new(
+///                 "BrownConrady",
+///                 DataType::Struct(vec![
+///                     Field::new("k1", DataType::Float32, false),
+///                     Field::new("k2", DataType::Float32, false),
+///                     Field::new("p1", DataType::Float32, false),
+///                     Field::new("p2", DataType::Float32, false),
+///                     Field::new("k3", DataType::Float32, false),
+///                 ]),
+///                 false
+///             ),
+///             Field::new(
+///                 "KannalaBrandt",
+///                 DataType::Struct(vec![
+///                     Field::new("k1", DataType::Float32, false),
+///                     Field::new("k2", DataType::Float32, false),
+///                     Field::new("k3", DataType::Float32, false),
+///                     Field::new("k4", DataType::Float32, false),
+///                 ]),
+///                 false
+///             ),
+///             Field::new("Equirectangular", DataType::Boolean, false),
+///         ],
+///         None,
+///         UnionMode::Dense
+///     )
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[arrow_field(type = "dense")]
+pub enum LensDistortion {
+    /// An ideal pinhole camera without any distortion.
+    None,
+
+    /// Radial & tangential distortion as used by `OpenCV`.
+    BrownConrady(BrownConrady),
+
+    /// Fisheye distortion as used by `OpenCV`'s `fisheye` module.
+    KannalaBrandt(KannalaBrandt),
+
+    /// Equirectangular (a.k.a. spherical) projection.
+    ///
+    /// Normalized image coordinates are longitude & latitude in radians,
+    /// with longitude being the angle around the camera's y axis
+    /// and latitude the angle to the camera's xz plane.
+    Equirectangular,
+}
+
+impl Default for LensDistortion {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Coefficients of the Brown-Conrady distortion model.
+///
+/// Same order & meaning as `OpenCV`'s `distCoeffs` with five elements.
+#[derive(Copy, Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BrownConrady {
+    pub k1: f32,
+    pub k2: f32,
+    pub p1: f32,
+    pub p2: f32,
+    pub k3: f32,
+}
+
+/// Coefficients of the Kannala-Brandt fisheye distortion model.
+///
+/// Same order & meaning as `OpenCV`'s `fisheye::distortPoints`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KannalaBrandt {
+    pub k1: f32,
+    pub k2: f32,
+    pub k3: f32,
+    pub k4: f32,
+}
+
+impl LensDistortion {
+    /// Whether this is the ideal pinhole camera, i.e. projection is a linear operation.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl std::fmt::Display for LensDistortion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => "None".fmt(f),
+            Self::BrownConrady(_) => "Brown-Conrady".fmt(f),
+            Self::KannalaBrandt(_) => "Kannala-Brandt".fmt(f),
+            Self::Equirectangular => "Equirectangular".fmt(f),
+        }
+    }
+}
+
+/// Number of iterations used for numerically inverting the distortion models.
+#[cfg(feature = "glam")]
+const UNDISTORT_ITERATIONS: usize = 10;
+
+#[cfg(feature = "glam")]
+impl LensDistortion {
+    /// Projects a point in camera space onto the normalized image plane, applying the distortion.
+    ///
+    /// Returns `None` if the point can't be seen by the camera, e.g. because it is behind a camera with a
+    /// perspective projection.
+    pub fn distort(&self, point_in_cam: glam::Vec3) -> Option<glam::Vec2> {
+        match self {
+            Self::None => (point_in_cam.z > 0.0).then(|| point_in_cam.truncate() / point_in_cam.z),
+            Self::BrownConrady(coefficients) => (point_in_cam.z > 0.0)
+                .then(|| coefficients.distort(point_in_cam.truncate() / point_in_cam.z)),
+            Self::KannalaBrandt(coefficients) => {
+                let r = point_in_cam.truncate().length();
+                if r == 0.0 {
+                    return (point_in_cam.z > 0.0).then_some(glam::Vec2::ZERO);
+                }
+                let theta = r.atan2(point_in_cam.z);
+                Some(point_in_cam.truncate() * (coefficients.theta_d(theta) / r))
+            }
+            Self::Equirectangular => {
+                if point_in_cam == glam::Vec3::ZERO {
+                    return None;
+                }
+                let longitude = point_in_cam.x.atan2(point_in_cam.z);
+                let latitude = point_in_cam
+                    .y
+                    .atan2(glam::vec2(point_in_cam.x, point_in_cam.z).length());
+                Some(glam::vec2(longitude, latitude))
+            }
+        }
+    }
+
+    /// Inverse of [`Self::distort`]: computes the direction of the ray going through the given point on
+    /// the normalized image plane.
+    ///
+    /// For [`Self::None`] and [`Self::BrownConrady`] the ray is scaled to have a z of one,
+    /// for all other models it has unit length since they can express rays with z <= 0.
+    pub fn undistort(&self, point_on_image_plane: glam::Vec2) -> glam::Vec3 {
+        match self {
+            Self::None => point_on_image_plane.extend(1.0),
+            Self::BrownConrady(coefficients) => {
+                coefficients.undistort(point_on_image_plane).extend(1.0)
+            }
+            Self::KannalaBrandt(coefficients) => {
+                let theta_d = point_on_image_plane.length();
+                if theta_d == 0.0 {
+                    return glam::Vec3::Z;
+                }
+                let theta = coefficients.theta(theta_d);
+                (point_on_image_plane * (theta.sin() / theta_d)).extend(theta.cos())
+            }
+            Self::Equirectangular => {
+                let (sin_longitude, cos_longitude) = point_on_image_plane.x.sin_cos();
+                let (sin_latitude, cos_latitude) = point_on_image_plane.y.sin_cos();
+                glam::vec3(
+                    cos_latitude * sin_longitude,
+                    sin_latitude,
+                    cos_latitude * cos_longitude,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl BrownConrady {
+    /// Distorts a point on the (undistorted) normalized image plane.
+    pub fn distort(&self, p: glam::Vec2) -> glam::Vec2 {
+        let Self { k1, k2, k3, .. } = *self;
+        let r2 = p.length_squared();
+        let radial = 1.0 + r2 * (k1 + r2 * (k2 + r2 * k3));
+        p * radial + self.tangential(p, r2)
+    }
+
+    /// Inverse of [`Self::distort`], found by fixed point iteration.
+    ///
+    /// This is the same approach `OpenCV` takes in `undistortPoints`
+    /// and works well for the moderate distortions that can be calibrated with this model.
+    pub fn undistort(&self, distorted: glam::Vec2) -> glam::Vec2 {
+        let Self { k1, k2, k3, .. } = *self;
+        let mut p = distorted;
+        for _ in 0..UNDISTORT_ITERATIONS {
+            let r2 = p.length_squared();
+            let radial = 1.0 + r2 * (k1 + r2 * (k2 + r2 * k3));
+            p = (distorted - self.tangential(p, r2)) / radial;
+        }
+        p
+    }
+
+    fn tangential(&self, p: glam::Vec2, r2: f32) -> glam::Vec2 {
+        let Self { p1, p2, .. } = *self;
+        glam::vec2(
+            2.0 * p1 * p.x * p.y + p2 * (r2 + 2.0 * p.x * p.x),
+            p1 * (r2 + 2.0 * p.y * p.y) + 2.0 * p2 * p.x * p.y,
+        )
+    }
+}
+
+#[cfg(feature = "glam")]
+impl KannalaBrandt {
+    /// Distorted angle for a given angle `theta` between a ray and the optical axis.
+    pub fn theta_d(&self, theta: f32) -> f32 {
+        let Self { k1, k2, k3, k4 } = *self;
+        let theta2 = theta * theta;
+        theta * (1.0 + theta2 * (k1 + theta2 * (k2 + theta2 * (k3 + theta2 * k4))))
+    }
+
+    /// Inverse of [`Self::theta_d`], found with Newton's method.
+    pub fn theta(&self, theta_d: f32) -> f32 {
+        let Self { k1, k2, k3, k4 } = *self;
+        let mut theta = theta_d;
+        for _ in 0..UNDISTORT_ITERATIONS {
+            let theta2 = theta * theta;
+            let derivative = 1.0
+                + theta2
+                    * (3.0 * k1 + theta2 * (5.0 * k2 + theta2 * (7.0 * k3 + theta2 * 9.0 * k4)));
+            if derivative.abs() < f32::EPSILON {
+                break;
+            }
+            theta -= (self.theta_d(theta) - theta_d) / derivative;
+        }
+        theta
+    }
+}
+
+#[cfg(all(test, feature = "glam"))]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrip(distortion: LensDistortion, point_in_cam: glam::Vec3) {
+        let on_image_plane = distortion.distort(point_in_cam).unwrap();
+        let ray = distortion.undistort(on_image_plane);
+        let angle = ray.angle_between(point_in_cam);
+        assert!(
+            angle < 1.0e-4,
+            "{distortion:?}: {point_in_cam:?} came back as {ray:?}"
+        );
+    }
+
+    #[test]
+    fn distortion_roundtrip() {
+        let distortions = [
+            LensDistortion::None,
+            LensDistortion::BrownConrady(BrownConrady {
+                k1: -0.28,
+                k2: 0.07,
+                p1: 0.0002,
+                p2: -0.0001,
+                k3: 0.0,
+            }),
+            LensDistortion::KannalaBrandt(KannalaBrandt {
+                k1: -0.013,
+                k2: -0.0012,
+                k3: 0.0005,
+                k4: -0.0001,
+            }),
+            LensDistortion::Equirectangular,
+        ];
+        let points = [
+            glam::vec3(0.0, 0.0, 1.0),
+            glam::vec3(0.1, -0.2, 1.0),
+            glam::vec3(-0.4, 0.3, 2.0),
+        ];
+
+        for distortion in distortions {
+            for point in points {
+                assert_roundtrip(distortion, point);
+            }
+        }
+
+        // Fisheye & spherical cameras see points behind them.
+        assert_roundtrip(LensDistortion::Equirectangular, glam::vec3(1.0, 0.5, -1.0));
+        assert!(LensDistortion::None
+            .distort(glam::vec3(1.0, 0.5, -1.0))
+            .is_none());
+    }
+}
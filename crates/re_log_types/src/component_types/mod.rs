@@ -25,7 +25,9 @@ pub mod context;
 pub mod coordinates;
 mod instance_key;
 mod keypoint_id;
+mod keypoint_visibility;
 mod label;
+mod lens_distortion;
 mod linestrip;
 mod mat;
 mod mesh3d;
@@ -38,6 +40,7 @@ mod scalar;
 mod size;
 mod tensor;
 mod text_entry;
+mod time_marker;
 mod transform;
 mod vec;
 
@@ -49,7 +52,9 @@ pub use context::{AnnotationContext, AnnotationInfo, ClassDescription};
 pub use coordinates::ViewCoordinates;
 pub use instance_key::InstanceKey;
 pub use keypoint_id::KeypointId;
+pub use keypoint_visibility::KeypointVisibility;
 pub use label::Label;
+pub use lens_distortion::{BrownConrady, KannalaBrandt, LensDistortion};
 pub use linestrip::{LineStrip2D, LineStrip3D};
 pub use mat::Mat3x3;
 pub use mesh3d::{EncodedMesh3D, Mesh3D, MeshFormat, MeshId, RawMesh3D};
@@ -65,13 +70,14 @@ pub use tensor::TensorImageError;
 pub use tensor::{
     Tensor, TensorCastError, TensorData, TensorDataMeaning, TensorDimension, TensorId, TensorTrait,
 };
-pub use text_entry::TextEntry;
-pub use transform::{Pinhole, Rigid3, Transform};
+pub use text_entry::{TextEntry, TextEntryField, TextEntryFields};
+pub use time_marker::TimeMarker;
+pub use transform::{Affine3, Homography2D, Pinhole, Rigid3, Scale3D, Transform};
 pub use vec::{Vec2D, Vec3D, Vec4D};
 
 lazy_static! {
     //TODO(john): use a run-time type registry
-    static ref FIELDS: [Field; 26] = [
+    static ref FIELDS: [Field; 29] = [
         <AnnotationContext as Component>::field(),
         <Arrow3D as Component>::field(),
         <Box3D as Component>::field(),
@@ -79,6 +85,7 @@ lazy_static! {
         <ColorRGBA as Component>::field(),
         <InstanceKey as Component>::field(),
         <KeypointId as Component>::field(),
+        <KeypointVisibility as Component>::field(),
         <Label as Component>::field(),
         <LineStrip2D as Component>::field(),
         <LineStrip3D as Component>::field(),
@@ -94,6 +101,8 @@ lazy_static! {
         <Size3D as Component>::field(),
         <Tensor as Component>::field(),
         <TextEntry as Component>::field(),
+        <TextEntryFields as Component>::field(),
+        <TimeMarker as Component>::field(),
         <Transform as Component>::field(),
         <Vec2D as Component>::field(),
         <Vec3D as Component>::field(),
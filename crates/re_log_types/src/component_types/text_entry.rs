@@ -4,6 +4,8 @@ use crate::Component;
 
 /// A text entry component, comprised of a text body and its log level.
 ///
+/// Structured key/value pairs go into the separate [`TextEntryFields`] component.
+///
 /// ```
 /// use re_log_types::component_types::TextEntry;
 /// use arrow2_convert::field::ArrowField;
@@ -32,6 +34,10 @@ pub struct TextEntry {
 }
 
 impl TextEntry {
+    /// The recommended log levels, ordered from least to most severe.
+    pub const RECOMMENDED_LEVELS: [&'static str; 6] =
+        ["TRACE", "DEBUG", "INFO", "WARN", "ERROR", "CRITICAL"];
+
     #[inline]
     pub fn new(body: impl Into<String>, level: Option<String>) -> Self {
         Self {
@@ -47,6 +53,14 @@ impl TextEntry {
             level: None,
         }
     }
+
+    /// Severity of the given level if it is one of [`Self::RECOMMENDED_LEVELS`], higher is more severe.
+    #[inline]
+    pub fn severity(level: &str) -> Option<usize> {
+        Self::RECOMMENDED_LEVELS
+            .iter()
+            .position(|recommended| *recommended == level)
+    }
 }
 
 impl Component for TextEntry {
@@ -55,3 +69,90 @@ impl Component for TextEntry {
         "rerun.text_entry".into()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Structured key/value pairs attached to a [`TextEntry`], e.g. the fields of a `tracing` event.
+///
+/// ```
+/// use re_log_types::component_types::TextEntryFields;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     TextEntryFields::data_type(),
+///     DataType::List(Box::new(Field::new(
+///         "item",
+///         DataType::Struct(vec![
+///             Field::new("key", DataType::Utf8, false),
+///             Field::new("value", DataType::Utf8, false),
+///         ]),
+///         false
+///     )))
+/// );
+/// ```
+#[derive(Clone, Debug, Default, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq, Eq)]
+#[arrow_field(transparent)]
+pub struct TextEntryFields(pub Vec<TextEntryField>);
+
+/// A single structured key/value pair of [`TextEntryFields`].
+#[derive(Clone, Debug, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq, Eq)]
+pub struct TextEntryField {
+    pub key: String,
+    pub value: String,
+}
+
+impl TextEntryFields {
+    #[inline]
+    pub fn new(fields: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>) -> Self {
+        Self(
+            fields
+                .into_iter()
+                .map(|(key, value)| TextEntryField {
+                    key: key.into(),
+                    value: value.into(),
+                })
+                .collect(),
+        )
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Component for TextEntryFields {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.text_entry_fields".into()
+    }
+}
+
+#[test]
+fn test_text_entry_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let entries_in = vec![
+        TextEntry::new("plain", None),
+        TextEntry::new("with level", Some("WARN".into())),
+    ];
+    let array: Box<dyn Array> = entries_in.try_into_arrow().unwrap();
+    let entries_out: Vec<TextEntry> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(entries_in, entries_out);
+}
+
+#[test]
+fn test_text_entry_fields_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let fields_in = vec![
+        TextEntryFields::default(),
+        TextEntryFields::new([("speed", "4.2"), ("gear", "3")]),
+    ];
+    let array: Box<dyn Array> = fields_in.try_into_arrow().unwrap();
+    let fields_out: Vec<TextEntryFields> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(fields_in, fields_out);
+}
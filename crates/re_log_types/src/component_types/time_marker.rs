@@ -0,0 +1,67 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+use crate::Component;
+
+/// A named marker on the time axis, e.g. to flag an incident, shown as a flag in the time panel.
+///
+/// The marker sits at the time it was logged at, on every timeline it was logged on.
+///
+/// ```
+/// use re_log_types::component_types::TimeMarker;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     TimeMarker::data_type(),
+///     DataType::Struct(vec![
+///         Field::new("label", DataType::Utf8, false),
+///         Field::new("duration", DataType::Int64, true),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq, Eq)]
+pub struct TimeMarker {
+    pub label: String,
+
+    /// If set, the marker spans a range of this length, starting where it was logged.
+    ///
+    /// In the units of each timeline: nanoseconds for time timelines, steps for sequence timelines.
+    pub duration: Option<i64>,
+}
+
+impl TimeMarker {
+    #[inline]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            duration: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_duration(mut self, duration: i64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+impl Component for TimeMarker {
+    #[inline]
+    fn name() -> crate::ComponentName {
+        "rerun.time_marker".into()
+    }
+}
+
+#[test]
+fn test_time_marker_roundtrip() {
+    use arrow2::array::Array;
+    use arrow2_convert::{deserialize::TryIntoCollection, serialize::TryIntoArrow};
+
+    let markers_in = vec![
+        TimeMarker::new("crash"),
+        TimeMarker::new("brownout").with_duration(1_000_000_000),
+    ];
+    let array: Box<dyn Array> = markers_in.try_into_arrow().unwrap();
+    let markers_out: Vec<TimeMarker> = TryIntoCollection::try_into_collection(array).unwrap();
+    assert_eq!(markers_in, markers_out);
+}
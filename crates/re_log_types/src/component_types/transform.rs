@@ -2,7 +2,7 @@ use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
 
 use crate::Component;
 
-use super::{mat::Mat3x3, Quaternion, Vec2D, Vec3D};
+use super::{mat::Mat3x3, LensDistortion, Quaternion, Vec2D, Vec3D};
 
 /// A proper rigid 3D transform, i.e. a rotation and a translation.
 ///
@@ -85,13 +85,25 @@ impl Rigid3 {
     pub fn child_from_parent(&self) -> macaw::IsoTransform {
         self.parent_from_child().inverse()
     }
+
+    /// Interpolates between `self` at `t == 0` and `other` at `t == 1`.
+    ///
+    /// Linearly interpolates the translation and spherically interpolates the rotation.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let start = self.parent_from_child();
+        let end = other.parent_from_child();
+        Self::new_parent_from_child(macaw::IsoTransform::from_rotation_translation(
+            start.rotation().slerp(end.rotation(), t),
+            start.translation().lerp(end.translation(), t),
+        ))
+    }
 }
 
 /// Camera perspective projection (a.k.a. intrinsics).
 ///
 ///
 /// ```
-/// use re_log_types::component_types::Pinhole;
+/// use re_log_types::component_types::{LensDistortion, Pinhole};
 /// use arrow2_convert::field::ArrowField;
 /// use arrow2::datatypes::{DataType, Field};
 ///
@@ -114,6 +126,7 @@ impl Rigid3 {
 ///             ),
 ///             true,
 ///         ),
+///         Field::new("distortion", LensDistortion::data_type(), false),
 ///     ]),
 /// );
 /// ```
@@ -142,6 +155,9 @@ pub struct Pinhole {
     ///
     /// [`Self::image_from_cam`] project onto the space spanned by `(0,0)` and `resolution - 1`.
     pub resolution: Option<Vec2D>,
+
+    /// Lens distortion, applied between camera space and [`Self::image_from_cam`].
+    pub distortion: LensDistortion,
 }
 
 impl Pinhole {
@@ -186,6 +202,188 @@ impl Pinhole {
     pub fn aspect_ratio(&self) -> Option<f32> {
         self.resolution.map(|r| r[0] / r[1])
     }
+
+    /// Does this camera have any lens distortion?
+    #[inline]
+    pub fn has_distortion(&self) -> bool {
+        !self.distortion.is_none()
+    }
+
+    /// Projects a point from camera space to pixel coordinates, taking [`Self::distortion`] into account.
+    ///
+    /// Returns `None` if the point can't be seen by the camera.
+    #[cfg(feature = "glam")]
+    pub fn project(&self, point_in_cam: glam::Vec3) -> Option<glam::Vec2> {
+        let on_image_plane = self.distortion.distort(point_in_cam)?;
+        Some((glam::Mat3::from(self.image_from_cam) * on_image_plane.extend(1.0)).truncate())
+    }
+
+    /// Inverse of [`Self::project`]: moves a pixel back into camera space.
+    ///
+    /// For [`LensDistortion::None`] and [`LensDistortion::BrownConrady`], `depth` is the distance along the
+    /// camera's z axis, as is customary for depth images of perspective cameras.
+    /// All other models can see behind the camera, so `depth` is the distance to the camera's origin instead.
+    #[cfg(feature = "glam")]
+    pub fn unproject(&self, pixel: glam::Vec2, depth: f32) -> glam::Vec3 {
+        let on_image_plane = glam::Mat3::from(self.image_from_cam).inverse() * pixel.extend(1.0);
+        self.distortion.undistort(on_image_plane.truncate()) * depth
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Scale of a child space relative to its parent, e.g. between millimeters and meters.
+///
+/// ```
+/// use re_log_types::component_types::Scale3D;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+///
+/// assert_eq!(
+///     Scale3D::data_type(),
+///     DataType::Union(
+///         vec![
+///             Field::new("Uniform", DataType::Float32, false),
+///             Field::new(
+///                 "ThreeD",
+///                 DataType::FixedSizeList(
+///                     Box::new(Field::new("item", DataType::Float32, false)),
+///                     3
+///                 ),
+///                 false
+///             ),
+///         ],
+///         None,
+///         UnionMode::Dense
+///     )
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[arrow_field(type = "dense")]
+pub enum Scale3D {
+    /// The same scale along all axes.
+    Uniform(f32),
+
+    /// Individual scale along each axis.
+    ThreeD(Vec3D),
+}
+
+#[cfg(feature = "glam")]
+impl Scale3D {
+    /// Scale to parent from child along each axis.
+    #[inline]
+    pub fn parent_from_child(&self) -> glam::Vec3 {
+        match self {
+            Self::Uniform(scale) => glam::Vec3::splat(*scale),
+            Self::ThreeD(scale) => (*scale).into(),
+        }
+    }
+}
+
+/// A 3D affine transform, i.e. a linear transform (rotation, scale, shear) followed by a translation.
+///
+/// ```
+/// use re_log_types::component_types::Affine3;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     Affine3::data_type(),
+///     DataType::Struct(vec![
+///         Field::new(
+///             "linear",
+///             DataType::FixedSizeList(
+///                 Box::new(Field::new("item", DataType::Float32, false)),
+///                 9
+///             ),
+///             false
+///         ),
+///         Field::new(
+///             "translation",
+///             DataType::FixedSizeList(
+///                 Box::new(Field::new("item", DataType::Float32, false)),
+///                 3
+///             ),
+///             false
+///         )
+///     ]),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Affine3 {
+    /// Column-major linear part of the transform to parent from child.
+    pub linear: Mat3x3,
+
+    /// Translation to parent from child, applied after [`Self::linear`].
+    pub translation: Vec3D,
+}
+
+#[cfg(feature = "glam")]
+impl Affine3 {
+    #[inline]
+    pub fn new_parent_from_child(parent_from_child: glam::Affine3A) -> Self {
+        Self {
+            linear: glam::Mat3::from(parent_from_child.matrix3).into(),
+            translation: glam::Vec3::from(parent_from_child.translation).into(),
+        }
+    }
+
+    #[inline]
+    pub fn parent_from_child(&self) -> glam::Affine3A {
+        glam::Affine3A::from_mat3_translation(self.linear.into(), self.translation.into())
+    }
+}
+
+/// A projective transform between two 2D spaces, e.g. to warp one camera's image into another's.
+///
+/// ```
+/// use re_log_types::component_types::Homography2D;
+/// use arrow2_convert::field::ArrowField;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// assert_eq!(
+///     Homography2D::data_type(),
+///     DataType::Struct(vec![Field::new(
+///         "parent_from_child",
+///         DataType::FixedSizeList(
+///             Box::new(Field::new("item", DataType::Float32, false)),
+///             9
+///         ),
+///         false
+///     )]),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Homography2D {
+    /// Column-major matrix mapping homogeneous child coordinates `(x, y, 1)`
+    /// to homogeneous parent coordinates.
+    pub parent_from_child: Mat3x3,
+}
+
+#[cfg(feature = "glam")]
+impl Homography2D {
+    #[inline]
+    pub fn parent_from_child(&self) -> glam::Mat3 {
+        self.parent_from_child.into()
+    }
+
+    /// The homography as a 3D transform acting on the x/y plane.
+    ///
+    /// The z coordinate is passed through unchanged before the perspective divide,
+    /// so it is only preserved exactly for z == 0.
+    #[inline]
+    pub fn parent_from_child_mat4(&self) -> glam::Mat4 {
+        let h = self.parent_from_child();
+        glam::Mat4::from_cols(
+            glam::vec4(h.x_axis.x, h.x_axis.y, 0.0, h.x_axis.z),
+            glam::vec4(h.y_axis.x, h.y_axis.y, 0.0, h.y_axis.z),
+            glam::Vec4::Z,
+            glam::vec4(h.z_axis.x, h.z_axis.y, 0.0, h.z_axis.z),
+        )
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -193,7 +391,7 @@ impl Pinhole {
 /// A transform between two spaces.
 ///
 /// ```
-/// use re_log_types::component_types::{Transform, Rigid3, Pinhole};
+/// use re_log_types::component_types::{Affine3, Homography2D, Pinhole, Rigid3, Scale3D, Transform};
 /// use arrow2_convert::field::ArrowField;
 /// use arrow2::datatypes::{DataType, Field, UnionMode};
 ///
@@ -211,6 +409,21 @@ impl Pinhole {
 ///                "Pinhole",
 ///                Pinhole::data_type(),
 ///                false
+///            ),
+///            Field::new(
+///                "Scale",
+///                Scale3D::data_type(),
+///                false
+///            ),
+///            Field::new(
+///                "Affine3",
+///                Affine3::data_type(),
+///                false
+///            ),
+///            Field::new(
+///                "Homography2D",
+///                Homography2D::data_type(),
+///                false
 ///            )
 ///        ],
 ///        None,
@@ -231,6 +444,15 @@ pub enum Transform {
 
     /// The parent is some local camera space, the child an image space.
     Pinhole(Pinhole),
+
+    /// The child is a scaled version of the parent space, e.g. millimeters in a meter based world.
+    Scale(Scale3D),
+
+    /// Any 3D affine transform, including non-uniform scale and shear.
+    Affine3(Affine3),
+
+    /// Both the parent and the child are 2D spaces, related by a homography.
+    Homography2D(Homography2D),
 }
 
 impl Component for Transform {
@@ -249,6 +471,7 @@ fn test_transform_roundtrip() {
         Transform::Pinhole(Pinhole {
             image_from_cam: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into(),
             resolution: None,
+            distortion: LensDistortion::None,
         }),
         Transform::Rigid3(Rigid3 {
             rotation: Quaternion {
@@ -262,9 +485,46 @@ fn test_transform_roundtrip() {
         Transform::Pinhole(Pinhole {
             image_from_cam: [[21.0, 22.0, 23.0], [24.0, 25.0, 26.0], [27.0, 28.0, 29.0]].into(),
             resolution: Some([123.0, 456.0].into()),
+            distortion: LensDistortion::KannalaBrandt(super::KannalaBrandt {
+                k1: 0.1,
+                k2: 0.2,
+                k3: 0.3,
+                k4: 0.4,
+            }),
+        }),
+        Transform::Scale(Scale3D::Uniform(1000.0)),
+        Transform::Scale(Scale3D::ThreeD([1.0, 2.0, 3.0].into())),
+        Transform::Affine3(Affine3 {
+            linear: [[31.0, 32.0, 33.0], [34.0, 35.0, 36.0], [37.0, 38.0, 39.0]].into(),
+            translation: [40.0, 41.0, 42.0].into(),
+        }),
+        Transform::Homography2D(Homography2D {
+            parent_from_child: [[51.0, 52.0, 53.0], [54.0, 55.0, 56.0], [57.0, 58.0, 59.0]].into(),
         }),
     ];
     let array: Box<dyn Array> = transforms_in.try_into_arrow().unwrap();
     let transforms_out: Vec<Transform> = TryIntoCollection::try_into_collection(array).unwrap();
     assert_eq!(transforms_in, transforms_out);
 }
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_rigid3_interpolate() {
+    let start = Rigid3::new_parent_from_child(macaw::IsoTransform::from_rotation_translation(
+        glam::Quat::IDENTITY,
+        glam::Vec3::ZERO,
+    ));
+    let end = Rigid3::new_parent_from_child(macaw::IsoTransform::from_rotation_translation(
+        glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        glam::vec3(2.0, 4.0, 0.0),
+    ));
+
+    let halfway = start.interpolate(&end, 0.5).parent_from_child();
+    assert!(halfway
+        .translation()
+        .abs_diff_eq(glam::vec3(1.0, 2.0, 0.0), 1.0e-6));
+    assert!(halfway.rotation().abs_diff_eq(
+        glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+        1.0e-6
+    ));
+}
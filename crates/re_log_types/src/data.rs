@@ -1,6 +1,8 @@
 use half::f16;
 
-pub use crate::component_types::{Arrow3D, Pinhole, Rigid3, Transform};
+pub use crate::component_types::{
+    Affine3, Arrow3D, Homography2D, Pinhole, Rigid3, Scale3D, Transform,
+};
 
 // ----------------------------------------------------------------------------
 
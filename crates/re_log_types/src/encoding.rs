@@ -158,6 +158,9 @@ pub enum DecodeError {
 
     #[error("MsgPack error: {0}")]
     MsgPack(#[from] rmp_serde::decode::Error),
+
+    #[error("Message of {0} bytes is too large - the stream is probably corrupt")]
+    MessageTooLarge(u64),
 }
 
 // ----------------------------------------------------------------------------
@@ -285,6 +288,223 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
 }
 
 // ----------------------------------------------------------------------------
+// stream decode:
+
+/// Decodes an `.rrd` file incrementally, as its bytes arrive, e.g. over the network.
+///
+/// Unlike [`Decoder`], this never blocks waiting for more data:
+/// feed it bytes with [`Self::push_chunk`], and take out the messages decoded so far with [`Self::try_read`].
+#[cfg(feature = "load")]
+pub struct StreamDecoder {
+    /// The first bytes of the file, until we have the whole header.
+    header: Vec<u8>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    zdecoder: zstd::stream::raw::Decoder<'static>,
+
+    /// Compressed bytes that don't make up a whole block yet.
+    #[cfg(target_arch = "wasm32")]
+    compressed: Vec<u8>,
+
+    #[cfg(target_arch = "wasm32")]
+    zdecoder: ruzstd::FrameDecoder,
+
+    /// Decompressed bytes, starting at [`Self::read_pos`] for the next message.
+    decompressed: Vec<u8>,
+    read_pos: usize,
+
+    /// Reused for decompressing.
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "load")]
+impl StreamDecoder {
+    /// Magic bytes and version.
+    const HEADER_SIZE: usize = 8;
+
+    /// Compressed frames start with a header of at most this size.
+    #[cfg(target_arch = "wasm32")]
+    const MAX_FRAME_HEADER_SIZE: usize = 18;
+
+    /// Longer messages are treated as corrupt, so we don't wait forever for bytes that never arrive.
+    pub const MAX_MESSAGE_SIZE: u64 = 1024 * 1024 * 1024;
+
+    pub fn new() -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: Vec::with_capacity(Self::HEADER_SIZE),
+            #[cfg(not(target_arch = "wasm32"))]
+            zdecoder: zstd::stream::raw::Decoder::new().map_err(DecodeError::Zstd)?,
+            #[cfg(target_arch = "wasm32")]
+            compressed: vec![],
+            #[cfg(target_arch = "wasm32")]
+            zdecoder: ruzstd::FrameDecoder::new(),
+            decompressed: vec![],
+            read_pos: 0,
+            scratch: vec![0; 64 * 1024],
+        })
+    }
+
+    /// Feed the next bytes of the file.
+    pub fn push_chunk(&mut self, mut chunk: &[u8]) -> Result<(), DecodeError> {
+        crate::profile_function!();
+
+        if self.header.len() < Self::HEADER_SIZE {
+            let num_header_bytes = chunk.len().min(Self::HEADER_SIZE - self.header.len());
+            self.header.extend_from_slice(&chunk[..num_header_bytes]);
+            chunk = &chunk[num_header_bytes..];
+
+            if self.header.len() < Self::HEADER_SIZE {
+                return Ok(());
+            }
+            if &self.header[..4] != b"RRF0" {
+                return Err(DecodeError::NotAnRrd);
+            }
+            warn_on_version_mismatch([
+                self.header[4],
+                self.header[5],
+                self.header[6],
+                self.header[7],
+            ]);
+        }
+
+        self.decompress(chunk)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn decompress(&mut self, chunk: &[u8]) -> Result<(), DecodeError> {
+        use zstd::stream::raw::{InBuffer, Operation as _, OutBuffer};
+
+        let mut in_buffer = InBuffer::around(chunk);
+        loop {
+            let mut out_buffer = OutBuffer::around(self.scratch.as_mut_slice());
+            self.zdecoder
+                .run(&mut in_buffer, &mut out_buffer)
+                .map_err(DecodeError::Zstd)?;
+            let num_decompressed = out_buffer.pos();
+            self.decompressed
+                .extend_from_slice(&self.scratch[..num_decompressed]);
+
+            // A full output buffer means there may be more to flush, even without more input.
+            if in_buffer.pos == chunk.len() && num_decompressed < self.scratch.len() {
+                return Ok(());
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn decompress(&mut self, chunk: &[u8]) -> Result<(), DecodeError> {
+        self.compressed.extend_from_slice(chunk);
+        if self.compressed.len() < Self::MAX_FRAME_HEADER_SIZE {
+            return Ok(()); // Not enough to start the frame yet.
+        }
+
+        loop {
+            // Only consumes whole blocks, so we keep the rest around until more arrives.
+            let (num_read, num_decompressed) = self
+                .zdecoder
+                .decode_from_to(&self.compressed, &mut self.scratch)
+                .map_err(DecodeError::RuzstdInit)?;
+            self.compressed.drain(..num_read);
+            self.decompressed
+                .extend_from_slice(&self.scratch[..num_decompressed]);
+
+            if num_read == 0 && num_decompressed == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The next message, if all its bytes have arrived.
+    ///
+    /// After a [`DecodeError::MessageTooLarge`] the rest of the stream can't be read.
+    pub fn try_read(&mut self) -> Option<Result<LogMsg, DecodeError>> {
+        crate::profile_function!();
+
+        let len_bytes = self.decompressed.get(self.read_pos..self.read_pos + 8)?;
+        let len = u64::from_le_bytes(len_bytes.try_into().ok()?);
+        let start = self.read_pos + 8;
+        let end = match usize::try_from(len)
+            .ok()
+            .filter(|_| len <= Self::MAX_MESSAGE_SIZE)
+            .and_then(|len| start.checked_add(len))
+        {
+            Some(end) => end,
+            None => return Some(Err(DecodeError::MessageTooLarge(len))),
+        };
+        let mut msg_bytes = self.decompressed.get(start..end)?;
+        self.read_pos = end;
+
+        let msg = {
+            crate::profile_scope!("MsgPack deser");
+            rmp_serde::from_read(&mut msg_bytes).map_err(DecodeError::from)
+        };
+
+        // Forget about the decoded messages now and then:
+        if self.read_pos > self.decompressed.len() / 2 {
+            self.decompressed.drain(..self.read_pos);
+            self.read_pos = 0;
+        }
+
+        Some(msg)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(all(feature = "load", feature = "save"))]
+#[test]
+fn test_stream_decode() {
+    use crate::{BeginRecordingMsg, LogMsg, MsgId, Time};
+
+    let messages = (0..10)
+        .map(|_| {
+            LogMsg::BeginRecordingMsg(BeginRecordingMsg {
+                msg_id: MsgId::random(),
+                info: crate::RecordingInfo {
+                    application_id: crate::ApplicationId("test".to_owned()),
+                    recording_id: crate::RecordingId::random(),
+                    is_official_example: true,
+                    started: Time::now(),
+                    recording_source: crate::RecordingSource::RustSdk {
+                        rustc_version: String::new(),
+                        llvm_version: String::new(),
+                    },
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut file = vec![];
+    encode(messages.iter(), &mut file).unwrap();
+
+    // Feed it in small chunks, splitting the header, frame and messages:
+    let mut decoder = StreamDecoder::new().unwrap();
+    let mut decoded_messages = vec![];
+    for chunk in file.chunks(7) {
+        decoder.push_chunk(chunk).unwrap();
+        while let Some(msg) = decoder.try_read() {
+            decoded_messages.push(msg.unwrap());
+        }
+    }
+
+    assert_eq!(messages, decoded_messages);
+}
+
+#[cfg(all(feature = "load", feature = "save"))]
+#[test]
+fn test_stream_decode_corrupt_length() {
+    let rerun_version = re_build_info::CrateVersion::parse(env!("CARGO_PKG_VERSION"));
+    let mut file = b"RRF0".to_vec();
+    file.extend(rerun_version.to_bytes());
+    file.extend(zstd::encode_all(&u64::MAX.to_le_bytes()[..], 3).unwrap());
+
+    let mut decoder = StreamDecoder::new().unwrap();
+    decoder.push_chunk(&file).unwrap();
+    assert!(matches!(
+        decoder.try_read(),
+        Some(Err(DecodeError::MessageTooLarge(u64::MAX)))
+    ));
+}
 
 #[cfg(all(feature = "load", feature = "save"))]
 #[test]
@@ -24,6 +24,8 @@ mod time;
 pub mod time_point;
 mod time_range;
 mod time_real;
+mod viewer_command;
+mod viewer_event;
 
 pub mod external {
     pub use arrow2;
@@ -52,9 +54,16 @@ pub use self::data_table::{DataTable, DataTableError, DataTableResult};
 pub use self::index::*;
 pub use self::path::*;
 pub use self::time::{Duration, Time};
-pub use self::time_point::{TimeInt, TimePoint, TimeType, Timeline, TimelineName};
+pub use self::time_point::{
+    TimeInt, TimePoint, TimeType, Timeline, TimelineName, TimelineRelation,
+};
 pub use self::time_range::{TimeRange, TimeRangeF};
 pub use self::time_real::TimeReal;
+pub use self::viewer_command::{ViewerCommand, ViewerCommandReply, ViewerCommandResponse};
+pub use self::viewer_event::ViewerEvent;
+
+#[cfg(feature = "serde")]
+pub use self::viewer_event::{decode_viewer_event, encode_viewer_event, ViewerEventDecodeError};
 
 #[macro_export]
 macro_rules! impl_into_enum {
@@ -320,6 +329,37 @@ pub enum PathOp {
 
     /// Clear all the components of an `[EntityPath]` and any descendants.
     ClearRecursive(EntityPath),
+
+    /// Clear only the given components stored at an [`EntityPath`].
+    ClearSomeComponents(EntityPath, Vec<ComponentName>),
+
+    /// Retroactively delete the data logged within a time range, e.g. because it was bad.
+    ///
+    /// Unlike the other clears, this doesn't happen at the time of the [`EntityPathOpMsg`]:
+    /// the deleted data is gone at all times, on all timelines.
+    /// Timeless data is never deleted.
+    ClearTimeRange {
+        entity_path: EntityPath,
+
+        /// The timeline that `range` refers to.
+        timeline: Timeline,
+
+        /// Inclusive.
+        range: TimeRange,
+
+        /// Only delete these components. `None` means all of them.
+        components: Option<Vec<ComponentName>>,
+
+        /// Also delete the data of all descendants.
+        recursive: bool,
+    },
+
+    /// Remove some instances of an [`EntityPath`], from the time of the [`EntityPathOpMsg`]
+    /// onward.
+    ///
+    /// The instances stay removed even if they are logged again later, so use new
+    /// [`component_types::InstanceKey`]s for new instances.
+    TombstoneInstances(EntityPath, Vec<component_types::InstanceKey>),
 }
 
 impl PathOp {
@@ -333,7 +373,13 @@ impl PathOp {
 
     pub fn entity_path(&self) -> &EntityPath {
         match &self {
-            PathOp::ClearComponents(path) | PathOp::ClearRecursive(path) => path,
+            PathOp::ClearComponents(path)
+            | PathOp::ClearRecursive(path)
+            | PathOp::ClearSomeComponents(path, _)
+            | PathOp::ClearTimeRange {
+                entity_path: path, ..
+            }
+            | PathOp::TombstoneInstances(path, _) => path,
         }
     }
 }
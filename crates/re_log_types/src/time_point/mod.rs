@@ -2,12 +2,14 @@ use std::collections::{btree_map, BTreeMap};
 
 mod time_int;
 mod timeline;
+mod timeline_relation;
 
 use crate::{time::Time, TimeRange};
 
 // Re-exports
 pub use time_int::TimeInt;
 pub use timeline::{Timeline, TimelineName};
+pub use timeline_relation::TimelineRelation;
 
 /// A point in time on any number of [`Timeline`]s.
 ///
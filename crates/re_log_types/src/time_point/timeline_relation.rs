@@ -0,0 +1,157 @@
+use crate::{TimeInt, TimeRange, TimeReal, Timeline};
+
+/// How the times of one [`Timeline`] map onto the times of another, e.g. when a sensor logs
+/// both `log_time` and its own `sensor_time`, which has a known offset and drift.
+///
+/// The relation is linear: `to = from * scale + offset`, in the native units of each timeline
+/// (nanoseconds for time timelines, sequence numbers for sequence timelines).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TimelineRelation {
+    pub from: Timeline,
+    pub to: Timeline,
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl TimelineRelation {
+    /// `to = from + offset`
+    pub fn offset(from: Timeline, to: Timeline, offset: f64) -> Self {
+        Self {
+            from,
+            to,
+            scale: 1.0,
+            offset,
+        }
+    }
+
+    /// Relations between a timeline and itself, or that reverse or collapse time, make no sense.
+    pub fn is_valid(&self) -> bool {
+        self.from != self.to
+            && self.scale.is_finite()
+            && self.scale > 0.0
+            && self.offset.is_finite()
+    }
+
+    /// The valid relation from `from` to `to` among `relations`, whichever way it was declared.
+    pub fn find(relations: &[Self], from: Timeline, to: Timeline) -> Option<Self> {
+        relations.iter().find_map(|relation| {
+            if !relation.is_valid() {
+                None
+            } else if relation.from == from && relation.to == to {
+                Some(*relation)
+            } else if relation.from == to && relation.to == from {
+                Some(relation.inverse())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The same relation, in the other direction.
+    pub fn inverse(&self) -> Self {
+        Self {
+            from: self.to,
+            to: self.from,
+            scale: 1.0 / self.scale,
+            offset: -self.offset / self.scale,
+        }
+    }
+
+    /// Maps a time on [`Self::from`] to the equivalent time on [`Self::to`].
+    ///
+    /// Computed in fixed point, so that nanosecond timestamps keep their precision,
+    /// and saturating, so that extreme relations can't overflow.
+    pub fn map(&self, time: TimeReal) -> TimeReal {
+        time * self.scale + TimeReal::saturating_from_f64(self.offset)
+    }
+
+    /// Like [`Self::map`], keeping the beginning and end of time as-is.
+    pub fn map_int(&self, time: TimeInt) -> TimeInt {
+        if time <= TimeInt::BEGINNING || time == TimeInt::MAX {
+            time
+        } else {
+            self.map(time.into()).round()
+        }
+    }
+
+    pub fn map_range(&self, range: TimeRange) -> TimeRange {
+        TimeRange::new(self.map_int(range.min), self.map_int(range.max))
+    }
+}
+
+#[test]
+fn test_timeline_relation() {
+    let log_time = Timeline::log_time();
+    let sensor_time = Timeline::new("sensor_time", crate::TimeType::Time);
+
+    let relation = TimelineRelation {
+        from: sensor_time,
+        to: log_time,
+        scale: 2.0,
+        offset: 100.0,
+    };
+    assert!(relation.is_valid());
+    assert_eq!(relation.map_int(10.into()), 120.into());
+    assert_eq!(relation.inverse().map_int(120.into()), 10.into());
+    assert_eq!(relation.map_int(TimeInt::MIN), TimeInt::MIN);
+    assert_eq!(relation.map_int(TimeInt::MAX), TimeInt::MAX);
+
+    let found = TimelineRelation::find(&[relation], log_time, sensor_time).unwrap();
+    assert_eq!(found.map_int(120.into()), 10.into());
+
+    assert!(!TimelineRelation::offset(log_time, log_time, 1.0).is_valid());
+}
+
+#[test]
+fn test_timeline_relation_precision() {
+    let log_time = Timeline::log_time();
+    let sensor_time = Timeline::new("sensor_time", crate::TimeType::Time);
+
+    // Beyond 2^53, where f64 can no longer represent every nanosecond.
+    let time = TimeInt::from(1_700_000_000_123_456_789);
+
+    let relation = TimelineRelation::offset(sensor_time, log_time, 1.0);
+    assert_eq!(
+        relation.map_int(time),
+        TimeInt::from(1_700_000_000_123_456_790)
+    );
+    assert_eq!(relation.inverse().map_int(relation.map_int(time)), time);
+
+    let relation = TimelineRelation {
+        from: sensor_time,
+        to: log_time,
+        scale: 0.5,
+        offset: 0.0,
+    };
+    assert_eq!(
+        relation.map_int(TimeInt::from(1_700_000_000_123_456_788)),
+        TimeInt::from(850_000_000_061_728_394)
+    );
+}
+
+#[test]
+fn test_timeline_relation_extremes() {
+    let log_time = Timeline::log_time();
+    let sensor_time = Timeline::new("sensor_time", crate::TimeType::Time);
+    let time = TimeInt::from(1_700_000_000_123_456_789);
+
+    let relation = |scale, offset| TimelineRelation {
+        from: sensor_time,
+        to: log_time,
+        scale,
+        offset,
+    };
+
+    // Saturates instead of overflowing:
+    assert_eq!(relation(1e30, 0.0).map_int(time), TimeInt::MAX);
+    assert_eq!(relation(f64::MAX, 0.0).map_int(time), TimeInt::MAX);
+    assert_eq!(relation(1.0, f64::INFINITY).map_int(time), TimeInt::MAX);
+    assert_eq!(relation(1.0, -1e30).map_int(time), TimeInt::MIN);
+    assert_eq!(relation(1e-30, 0.0).map_int(time), TimeInt::from(0));
+
+    // Invalid relations are never found, but mapping with them must not panic either:
+    assert!(!relation(f64::NAN, 0.0).is_valid());
+    relation(f64::NAN, f64::NAN).map_int(time);
+    relation(0.0, 0.0).inverse().map_int(time);
+}
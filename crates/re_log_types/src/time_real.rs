@@ -52,6 +52,21 @@ impl TimeReal {
     pub fn abs(self) -> Self {
         Self(self.0.saturating_abs())
     }
+
+    /// Like `From<f64>`, but saturates instead of panicking when out of range, and maps NaN to zero.
+    #[inline]
+    pub fn saturating_from_f64(value: f64) -> Self {
+        Self(saturating_fixed_from_f64(value))
+    }
+}
+
+#[inline]
+fn saturating_fixed_from_f64(value: f64) -> FixedI128<typenum::U64> {
+    if value.is_nan() {
+        FixedI128::ZERO
+    } else {
+        FixedI128::saturating_from_num(value)
+    }
 }
 
 // ---------------
@@ -146,7 +161,7 @@ impl std::ops::Mul<f64> for TimeReal {
 
     #[inline]
     fn mul(self, rhs: f64) -> Self::Output {
-        Self(self.0.saturating_mul(FixedI128::from_num(rhs)))
+        Self(self.0.saturating_mul(saturating_fixed_from_f64(rhs)))
     }
 }
 
@@ -0,0 +1,102 @@
+use crate::{
+    component_types::InstanceKey, ApplicationId, EntityPath, RecordingId, TimeInt, Timeline,
+};
+
+/// A request to remote-control a running viewer, e.g. from a test harness or a notebook.
+///
+/// This is the reverse of [`crate::ViewerEvent`]: instead of the viewer telling the outside
+/// world what the user did, the outside world tells the viewer what to do.
+/// Every command is answered with a [`ViewerCommandResponse`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerCommand {
+    /// Run one of the commands of the command palette, e.g. `"ToggleTimePanel"`.
+    ///
+    /// The name is the name of the `re_ui::Command` variant.
+    RunCommand { command: String },
+
+    /// Select a timeline and, if given, move the time cursor on it.
+    ///
+    /// Moving the time cursor pauses playback.
+    SetTime {
+        timeline: Timeline,
+        time: Option<TimeInt>,
+    },
+
+    /// Replace the selection.
+    ///
+    /// An instance key of [`InstanceKey::SPLAT`] selects the whole entity,
+    /// just like in [`crate::ViewerEvent::SelectionChanged`].
+    SetSelection {
+        selection: Vec<(EntityPath, InstanceKey)>,
+    },
+
+    /// Expand or collapse the panels of the blueprint of the selected recording.
+    ///
+    /// Panels that are `None` are left as-is.
+    SetPanels {
+        blueprint_panel: Option<bool>,
+        selection_panel: Option<bool>,
+        time_panel: Option<bool>,
+    },
+
+    /// Maximize the space view with this name, or restore all space views if `None`.
+    MaximizeSpaceView { space_view: Option<String> },
+
+    /// Load an `.rrd` file (or an image, mesh, …) from the file system of the viewer.
+    LoadRecording { path: String },
+
+    /// List the open recordings, see [`ViewerCommandResponse::Recordings`].
+    ListRecordings,
+
+    /// Show this recording.
+    SelectRecording { recording_id: RecordingId },
+
+    /// Close this recording, or the shown one if `None`.
+    CloseRecording { recording_id: Option<RecordingId> },
+
+    /// Take a screenshot of the 2D or 3D space view with this name.
+    ///
+    /// The space view must be visible. Just like the screenshots from the context menu of a
+    /// space view, it does not contain the labels.
+    Screenshot { space_view: String },
+}
+
+/// The answer to a [`ViewerCommand`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerCommandResponse {
+    /// The command was carried out.
+    Ok,
+
+    /// Answer to [`ViewerCommand::ListRecordings`].
+    Recordings {
+        recordings: Vec<(RecordingId, ApplicationId)>,
+
+        /// The shown recording, if any.
+        selected: Option<RecordingId>,
+    },
+
+    /// Answer to [`ViewerCommand::Screenshot`].
+    Screenshot {
+        width: u32,
+        height: u32,
+
+        /// The encoded PNG.
+        png: Vec<u8>,
+    },
+
+    /// The command could not be carried out.
+    Error { message: String },
+}
+
+impl ViewerCommandResponse {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Error {
+            message: message.into(),
+        }
+    }
+}
+
+/// Called by the viewer with the response to a [`ViewerCommand`], once it has been carried out.
+pub type ViewerCommandReply = Box<dyn FnOnce(ViewerCommandResponse) + Send>;
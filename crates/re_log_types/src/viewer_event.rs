@@ -0,0 +1,88 @@
+use crate::{component_types::InstanceKey, EntityPath, TimeInt, Timeline};
+
+/// An interaction in the viewer, sent back to the logging SDK over the same connection
+/// that the log data arrives on.
+///
+/// This is the reverse direction of [`crate::LogMsg`]: it lets the logging process react to
+/// what the user is doing in the viewer, e.g. to fetch more data for the selected entity.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerEvent {
+    /// The selection changed.
+    ///
+    /// Only contains the selected entities and instances, not e.g. selected space views.
+    /// An instance key of [`InstanceKey::SPLAT`] means the whole entity is selected.
+    SelectionChanged {
+        selection: Vec<(EntityPath, InstanceKey)>,
+    },
+
+    /// The time cursor was moved, or a different timeline was selected.
+    TimeChanged {
+        timeline: Timeline,
+
+        /// `None` if there is no data on the timeline yet.
+        time: Option<TimeInt>,
+    },
+
+    /// An entity was clicked in a spatial view.
+    EntityClicked {
+        entity_path: EntityPath,
+        instance_key: InstanceKey,
+
+        /// Where the entity was clicked, in the coordinates of the space view.
+        ///
+        /// In 2D views, `z` is zero.
+        position: Option<[f32; 3]>,
+    },
+}
+
+// ----------------------------------------------------------------------------
+
+/// Prefix of the packets going the other way, from the viewer to the SDK.
+#[cfg(feature = "serde")]
+const VIEWER_EVENT_PREFIX: [u8; 4] = *b"RV00";
+
+/// On failure to decode a [`ViewerEvent`].
+#[cfg(feature = "serde")]
+#[derive(thiserror::Error, Debug)]
+pub enum ViewerEventDecodeError {
+    #[error("Viewer event didn't start with the correct prefix")]
+    BadPrefix,
+
+    #[error("bincode: {0}")]
+    Bincode(#[from] bincode::Error),
+}
+
+/// Encode a [`ViewerEvent`] for sending it over the wire, see [`decode_viewer_event`].
+#[cfg(feature = "serde")]
+pub fn encode_viewer_event(event: &ViewerEvent) -> Vec<u8> {
+    use bincode::Options as _;
+    let mut bytes = VIEWER_EVENT_PREFIX.to_vec();
+    bincode::DefaultOptions::new()
+        .serialize_into(&mut bytes, event)
+        .unwrap();
+    bytes
+}
+
+/// Decode a [`ViewerEvent`] encoded with [`encode_viewer_event`].
+#[cfg(feature = "serde")]
+pub fn decode_viewer_event(data: &[u8]) -> Result<ViewerEvent, ViewerEventDecodeError> {
+    use bincode::Options as _;
+    let payload = data
+        .strip_prefix(&VIEWER_EVENT_PREFIX)
+        .ok_or(ViewerEventDecodeError::BadPrefix)?;
+    Ok(bincode::DefaultOptions::new().deserialize(payload)?)
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_viewer_event_roundtrip() {
+    let event = ViewerEvent::EntityClicked {
+        entity_path: EntityPath::from("world/points"),
+        instance_key: InstanceKey(42),
+        position: Some([1.0, 2.0, 3.0]),
+    };
+    let decoded = decode_viewer_event(&encode_viewer_event(&event)).unwrap();
+    assert_eq!(event, decoded);
+    assert!(decode_viewer_event(b"RR00").is_err());
+}
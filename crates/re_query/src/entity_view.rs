@@ -148,6 +148,63 @@ impl ComponentWithInstances {
             values,
         })
     }
+
+    /// Remove the given instances, e.g. because they were tombstoned.
+    ///
+    /// `instance_keys` must be sorted. Splats are left as-is.
+    pub(crate) fn without_instances(self, instance_keys: &[InstanceKey]) -> crate::Result<Self> {
+        if instance_keys.is_empty() {
+            return Ok(self);
+        }
+
+        let keys = self.iter_instance_keys()?.collect::<Vec<_>>();
+        if keys == [InstanceKey::SPLAT] {
+            return Ok(self);
+        }
+
+        let keep = keys
+            .iter()
+            .map(|key| instance_keys.binary_search(key).is_err())
+            .collect::<Vec<_>>();
+        if keep.iter().all(|keep| *keep) {
+            return Ok(self);
+        }
+
+        // Slice out all the runs of instances we keep, and stitch them back together.
+        let mut slices = Vec::new();
+        let mut run_start = None;
+        for (i, keep) in keep.iter().chain(std::iter::once(&false)).enumerate() {
+            match (run_start, *keep) {
+                (None, true) => run_start = Some(i),
+                (Some(start), false) => {
+                    slices.push(self.values.slice(start, i - start));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        let values = if slices.is_empty() {
+            arrow2::array::new_empty_array(self.values.data_type().clone())
+        } else {
+            let slices = slices
+                .iter()
+                .map(|slice| slice.as_ref())
+                .collect::<Vec<_>>();
+            arrow2::compute::concatenate::concatenate(&slices)?
+        };
+
+        let kept_keys = keys
+            .iter()
+            .zip(&keep)
+            .filter_map(|(key, keep)| keep.then_some(key.0))
+            .collect::<Vec<_>>();
+
+        Ok(ComponentWithInstances {
+            name: self.name,
+            instance_keys: Some(PrimitiveArray::from_vec(kept_keys).boxed()),
+            values,
+        })
+    }
 }
 
 /// Iterator over a single component joined onto a primary component
@@ -468,3 +525,44 @@ fn lookup_splat() {
     let value = component.lookup::<Point2D>(&InstanceKey(99)).unwrap();
     assert_eq!(points[0], value);
 }
+
+#[test]
+fn without_instances() {
+    use re_log_types::component_types::{InstanceKey, Point2D};
+    let points = vec![
+        Point2D { x: 1.0, y: 2.0 }, //
+        Point2D { x: 3.0, y: 4.0 },
+        Point2D { x: 5.0, y: 6.0 },
+        Point2D { x: 7.0, y: 8.0 },
+        Point2D { x: 9.0, y: 10.0 },
+    ];
+
+    let component = ComponentWithInstances::from_native(None, &points).unwrap();
+    let component = component
+        .without_instances(&[InstanceKey(0), InstanceKey(2), InstanceKey(3)])
+        .unwrap();
+
+    let instance_keys = component.iter_instance_keys().unwrap().collect::<Vec<_>>();
+    assert_eq!(instance_keys, vec![InstanceKey(1), InstanceKey(4)]);
+
+    let values = component
+        .iter_values::<Point2D>()
+        .unwrap()
+        .flatten()
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![points[1].clone(), points[4].clone()]);
+
+    // Removing everything leaves nothing.
+    let component = component
+        .without_instances(&[InstanceKey(1), InstanceKey(4)])
+        .unwrap();
+    assert!(component.is_empty());
+
+    // Splats are not instances.
+    let splat =
+        ComponentWithInstances::from_native(Some(&vec![InstanceKey::SPLAT]), &points[..1].to_vec())
+            .unwrap()
+            .without_instances(&[InstanceKey(0)])
+            .unwrap();
+    assert_eq!(splat.len(), 1);
+}
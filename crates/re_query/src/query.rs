@@ -55,11 +55,12 @@ pub fn get_component_with_instances(
 
     let mut results = store.get(&components, &row_indices);
 
-    Ok(ComponentWithInstances {
+    let cwi = ComponentWithInstances {
         name: component,
         instance_keys: results[0].take(),
         values: results[1].take().ok_or(QueryError::PrimaryNotFound)?,
-    })
+    };
+    cwi.without_instances(&store.tombstoned_instances(query.timeline, ent_path, query.at))
 }
 
 /// Retrieve an `EntityView` from the `DataStore`
@@ -86,6 +86,8 @@ pub fn range_entity_with_primary<'a, Primary: Component + 'a, const N: usize>(
         }
     }
 
+    let timeline = query.timeline;
+
     // send the latest-at state before anything else
     cwis_latest
         .into_iter()
@@ -96,16 +98,19 @@ pub fn range_entity_with_primary<'a, Primary: Component + 'a, const N: usize>(
                 .map(move |(time, _, row_indices)| {
                     let results = store.get(&components, &row_indices);
                     let instance_keys = results[cluster_col].clone(); // shallow
+                    let tombstoned = store.tombstoned_instances(timeline, ent_path, time);
                     let cwis = results
                         .into_iter()
                         .enumerate()
                         .map(|(i, res)| {
-                            res.map(|res| {
+                            res.and_then(|res| {
                                 ComponentWithInstances {
                                     name: components[i],
                                     instance_keys: instance_keys.clone(), // shallow
                                     values: res.clone(),                  // shallow
                                 }
+                                .without_instances(&tombstoned)
+                                .ok()
                             })
                         })
                         .collect::<Vec<_>>();
@@ -43,6 +43,7 @@ impl framework::Example for Render2D {
                 format: wgpu::TextureFormat::Rgba8UnormSrgb,
                 width: rerun_logo.width(),
                 height: rerun_logo.height(),
+                generate_mip_maps: true,
             },
         );
         Render2D {
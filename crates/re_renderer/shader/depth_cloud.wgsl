@@ -21,8 +21,6 @@ struct DepthCloudInfo {
     world_from_obj: Mat4,
 
     /// The intrinsics of the camera used for the projection.
-    ///
-    /// Only supports pinhole cameras at the moment.
     depth_camera_intrinsics: Mat3,
 
     /// Outline mask id for the outline mask pass.
@@ -42,8 +40,22 @@ struct DepthCloudInfo {
 
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
+
+    /// One of the `DISTORTION_*` constants.
+    distortion_model: u32,
+
+    /// Coefficients of the distortion model, see `DepthCloudDistortion` in `depth_cloud.rs`.
+    distortion_coefficients: array<Vec4, 2>,
 };
 
+const DISTORTION_NONE: u32 = 0u;
+const DISTORTION_BROWN_CONRADY: u32 = 1u;
+const DISTORTION_KANNALA_BRANDT: u32 = 2u;
+const DISTORTION_EQUIRECTANGULAR: u32 = 3u;
+
+// Number of iterations used for numerically inverting the distortion models.
+const UNDISTORT_ITERATIONS: i32 = 10;
+
 @group(1) @binding(0)
 var<uniform> depth_cloud_info: DepthCloudInfo;
 
@@ -66,7 +78,54 @@ struct PointData {
     color: Vec4
 }
 
-// Backprojects the depth texture using the intrinsics passed in the uniform buffer.
+// Computes the direction of the ray through a point on the normalized image plane.
+//
+// Keep in sync with `LensDistortion::undistort` in `re_log_types`:
+// For perspective models the ray has a z of one, otherwise it has unit length.
+fn undistort(distorted: Vec2) -> Vec3 {
+    let c0 = depth_cloud_info.distortion_coefficients[0];
+    let c1 = depth_cloud_info.distortion_coefficients[1];
+
+    var ray = Vec3(distorted, 1.0);
+    if depth_cloud_info.distortion_model == DISTORTION_BROWN_CONRADY {
+        // Fixed point iteration, coefficients are (k1, k2, p1, p2), (k3, _, _, _).
+        var p = distorted;
+        for (var i = 0; i < UNDISTORT_ITERATIONS; i += 1) {
+            let r2 = dot(p, p);
+            let radial = 1.0 + r2 * (c0.x + r2 * (c0.y + r2 * c1.x));
+            let tangential = Vec2(
+                2.0 * c0.z * p.x * p.y + c0.w * (r2 + 2.0 * p.x * p.x),
+                c0.z * (r2 + 2.0 * p.y * p.y) + 2.0 * c0.w * p.x * p.y,
+            );
+            p = (distorted - tangential) / radial;
+        }
+        ray = Vec3(p, 1.0);
+    } else if depth_cloud_info.distortion_model == DISTORTION_KANNALA_BRANDT {
+        // Newton's method on the distorted angle, coefficients are (k1, k2, k3, k4).
+        let theta_d = length(distorted);
+        if theta_d > 0.0 {
+            var theta = theta_d;
+            for (var i = 0; i < UNDISTORT_ITERATIONS; i += 1) {
+                let theta2 = theta * theta;
+                let error = theta * (1.0 + theta2 * (c0.x + theta2 * (c0.y + theta2 * (c0.z + theta2 * c0.w)))) - theta_d;
+                let derivative = 1.0 + theta2 * (3.0 * c0.x + theta2 * (5.0 * c0.y + theta2 * (7.0 * c0.z + theta2 * 9.0 * c0.w)));
+                if abs(derivative) < f32eps {
+                    break;
+                }
+                theta -= error / derivative;
+            }
+            ray = Vec3(distorted * (sin(theta) / theta_d), cos(theta));
+        } else {
+            ray = Vec3(0.0, 0.0, 1.0);
+        }
+    } else if depth_cloud_info.distortion_model == DISTORTION_EQUIRECTANGULAR {
+        // Longitude & latitude.
+        ray = Vec3(cos(distorted.y) * sin(distorted.x), sin(distorted.y), cos(distorted.y) * cos(distorted.x));
+    }
+    return ray;
+}
+
+// Backprojects the depth texture using the intrinsics & lens distortion passed in the uniform buffer.
 fn compute_point_data(quad_idx: i32) -> PointData {
     let wh = textureDimensions(depth_texture);
     let texcoords = IVec2(quad_idx % wh.x, quad_idx / wh.x);
@@ -79,15 +138,11 @@ fn compute_point_data(quad_idx: i32) -> PointData {
         // TODO(cmc): albedo textures
         let color = Vec4(colormap_linear(depth_cloud_info.colormap, world_space_depth / depth_cloud_info.max_depth_in_world), 1.0);
 
-        // TODO(cmc): This assumes a pinhole camera; need to support other kinds at some point.
         let intrinsics = depth_cloud_info.depth_camera_intrinsics;
         let focal_length = Vec2(intrinsics[0][0], intrinsics[1][1]);
         let offset = Vec2(intrinsics[2][0], intrinsics[2][1]);
 
-        let pos_in_obj = Vec3(
-            (Vec2(texcoords) - offset) * world_space_depth / focal_length,
-            world_space_depth,
-        );
+        let pos_in_obj = undistort((Vec2(texcoords) - offset) / focal_length) * world_space_depth;
 
         let pos_in_world = depth_cloud_info.world_from_obj * Vec4(pos_in_obj, 1.0);
 
@@ -27,6 +27,27 @@ struct FrameUniformBuffer {
 
     // Size used for all line radii given with Size::AUTO.
     auto_size_lines: f32,
+
+    /// Normalized direction towards the directional light in world space.
+    light_direction: Vec3,
+
+    /// Intensity of the directional light.
+    light_intensity: f32,
+
+    /// Transforms from world space to the clip space of the shadow map.
+    light_projection_from_world: Mat4,
+
+    /// Intensity of the ambient light.
+    ambient_intensity: f32,
+
+    /// Whether the shadow map is valid and should be sampled (0 or 1).
+    shadow_map_enabled: u32,
+
+    /// Offset subtracted from the depth of a fragment before comparing it against the shadow map.
+    shadow_depth_bias: f32,
+
+    /// Size of a single shadow map texel in texture coordinates.
+    shadow_map_texel_size: f32,
 };
 @group(0) @binding(0)
 var<uniform> frame: FrameUniformBuffer;
@@ -36,3 +57,9 @@ var nearest_sampler: sampler;
 
 @group(0) @binding(2)
 var trilinear_sampler: sampler;
+
+@group(0) @binding(3)
+var shadow_map: texture_depth_2d;
+
+@group(0) @binding(4)
+var shadow_map_sampler: sampler_comparison;
@@ -2,6 +2,8 @@
 #import <./global_bindings.wgsl>
 #import <./mesh_vertex.wgsl>
 #import <./utils/srgb.wgsl>
+#import <./utils/order_independent_transparency.wgsl>
+#import <./utils/shadow_map.wgsl>
 
 @group(1) @binding(0)
 var albedo_texture: texture_2d<f32>;
@@ -9,11 +11,26 @@ var albedo_texture: texture_2d<f32>;
 // Keep in sync with gpu_data::MaterialUniformBuffer in mesh.rs
 struct MaterialUniformBuffer {
     albedo_factor: Vec4,
+    metallic_factor: f32,
+    roughness_factor: f32,
+    normal_scale: f32,
+    has_normal_map: u32,
 };
 
 @group(1) @binding(1)
 var<uniform> material: MaterialUniformBuffer;
 
+@group(1) @binding(2)
+var normal_texture: texture_2d<f32>;
+
+@group(1) @binding(3)
+var metallic_roughness_texture: texture_2d<f32>;
+
+// Keep in sync with `MeshShading` in mesh_renderer.rs
+const SHADING_SMOOTH: u32 = 0u;
+const SHADING_FLAT: u32 = 1u;
+const SHADING_UNLIT: u32 = 2u;
+
 struct VertexOut {
     @builtin(position)
     position: Vec4,
@@ -35,6 +52,12 @@ struct VertexOut {
 
     @location(5) @interpolate(flat)
     picking_layer_id: UVec4,
+
+    @location(6)
+    position_world_space: Vec3,
+
+    @location(7) @interpolate(flat)
+    shading: u32,
 };
 
 @vertex
@@ -58,30 +81,150 @@ fn vs_main(in_vertex: VertexIn, in_instance: InstanceIn) -> VertexOut {
     out.additive_tint_rgb = linear_from_srgb(in_instance.additive_tint_srgb.rgb);
     out.outline_mask_ids = in_instance.outline_mask_ids;
     out.picking_layer_id = in_instance.picking_layer_id;
+    out.position_world_space = world_position;
+    out.shading = in_instance.shading.x;
 
     return out;
 }
 
-@fragment
-fn fs_main_shaded(in: VertexOut) -> @location(0) Vec4 {
-    let albedo = textureSample(albedo_texture, trilinear_sampler, in.texcoord).rgb
+// Screen space derivatives of the position and texture coordinates.
+struct Derivatives {
+    dp1: Vec3,
+    dp2: Vec3,
+    duv1: Vec2,
+    duv2: Vec2,
+};
+
+// Perturbs the interpolated normal with a sample of the material's tangent space normal map.
+//
+// We don't have tangents in our vertex data, so we derive the tangent frame from screen space derivatives instead.
+// See http://www.thetenthplanet.de/archives/1180
+fn apply_normal_map(normal: Vec3, derivatives: Derivatives, normal_map_sample: Vec3) -> Vec3 {
+    let dp1 = derivatives.dp1;
+    let dp2 = derivatives.dp2;
+    let duv1 = derivatives.duv1;
+    let duv2 = derivatives.duv2;
+
+    let dp2perp = cross(dp2, normal);
+    let dp1perp = cross(normal, dp1);
+    let tangent = dp2perp * duv1.x + dp1perp * duv2.x;
+    let bitangent = dp2perp * duv1.y + dp1perp * duv2.y;
+    let inv_max = inverseSqrt(max(dot(tangent, tangent), dot(bitangent, bitangent)));
+    if inv_max > 1.0e20 {
+        // Degenerated texture coordinates, can't determine a tangent frame.
+        return normal;
+    }
+
+    var normal_tangent_space = normal_map_sample * 2.0 - 1.0;
+    normal_tangent_space = Vec3(normal_tangent_space.xy * material.normal_scale, normal_tangent_space.z);
+
+    return normalize(
+        tangent * inv_max * normal_tangent_space.x +
+        bitangent * inv_max * normal_tangent_space.y +
+        normal * normal_tangent_space.z
+    );
+}
+
+// GGX/Trowbridge-Reitz normal distribution function.
+fn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {
+    let a = roughness * roughness;
+    let a2 = a * a;
+    let d = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+    return a2 / max(PI * d * d, 1.0e-7);
+}
+
+// Smith-Schlick geometry term for a single direction.
+fn geometry_schlick_ggx(n_dot_x: f32, roughness: f32) -> f32 {
+    let r = roughness + 1.0;
+    let k = r * r / 8.0;
+    return n_dot_x / (n_dot_x * (1.0 - k) + k);
+}
+
+// Shaded color with unmultiplied alpha.
+fn shade(in: VertexOut) -> Vec4 {
+    let albedo_sample = textureSample(albedo_texture, trilinear_sampler, in.texcoord);
+    let albedo = albedo_sample.rgb
                  * in.color.rgb
                  * material.albedo_factor.rgb
                  + in.additive_tint_rgb;
+    let alpha = albedo_sample.a * in.color.a * material.albedo_factor.a;
+
+    // Screen space derivatives, and thus also implicit texture sampling, are only well defined in uniform control flow.
+    // So we compute everything that needs them upfront, even if the material or shading mode doesn't use it.
+    let derivatives = Derivatives(
+        dpdx(in.position_world_space),
+        dpdy(in.position_world_space),
+        dpdx(in.texcoord),
+        dpdy(in.texcoord),
+    );
+    let flat_normal = normalize(cross(derivatives.dp1, derivatives.dp2));
+    let normal_map_sample = textureSample(normal_texture, trilinear_sampler, in.texcoord).xyz;
+    // glTF convention: roughness in green, metalness in blue.
+    let metallic_roughness = textureSample(metallic_roughness_texture, trilinear_sampler, in.texcoord);
 
-    if all(in.normal_world_space == Vec3(0.0, 0.0, 0.0)) {
+    if in.shading == SHADING_UNLIT ||
+       (in.shading == SHADING_SMOOTH && all(in.normal_world_space == Vec3(0.0, 0.0, 0.0))) {
         // no normal, no shading
-        return Vec4(albedo, 1.0);
+        return Vec4(albedo, alpha);
     } else {
-        // Hardcoded lambert lighting. TODO(andreas): Some microfacet model.
-        let light_dir = normalize(vec3(1.0, 2.0, 0.0)); // TODO(andreas): proper lighting
-        let normal = normalize(in.normal_world_space);
-        let shading = clamp(dot(normal, light_dir), 0.0, 1.0) + 0.2;
+        let view_dir = normalize(frame.camera_position - in.position_world_space);
+
+        var normal: Vec3;
+        if in.shading == SHADING_FLAT {
+            // Meshes are drawn double sided, so make sure the derived normal faces the camera.
+            normal = select(-flat_normal, flat_normal, dot(flat_normal, view_dir) >= 0.0);
+        } else {
+            normal = normalize(in.normal_world_space);
+            if material.has_normal_map != 0u {
+                normal = apply_normal_map(normal, derivatives, normal_map_sample);
+            }
+        }
+
+        let roughness = clamp(metallic_roughness.g * material.roughness_factor, 0.04, 1.0);
+        let metallic = clamp(metallic_roughness.b * material.metallic_factor, 0.0, 1.0);
+
+        // Cook-Torrance microfacet model with a single directional light.
+        let light_dir = frame.light_direction;
+        let half_dir = normalize(light_dir + view_dir);
+
+        let n_dot_l = clamp(dot(normal, light_dir), 0.0, 1.0);
+        let n_dot_v = clamp(abs(dot(normal, view_dir)), 1.0e-4, 1.0);
+        let n_dot_h = clamp(dot(normal, half_dir), 0.0, 1.0);
+        let v_dot_h = clamp(dot(view_dir, half_dir), 0.0, 1.0);
+
+        let f0 = mix(Vec3(0.04), albedo, metallic);
+        let fresnel = f0 + (1.0 - f0) * pow(1.0 - v_dot_h, 5.0);
+        let specular = fresnel
+                        * distribution_ggx(n_dot_h, roughness)
+                        * geometry_schlick_ggx(n_dot_l, roughness)
+                        * geometry_schlick_ggx(n_dot_v, roughness)
+                        / max(4.0 * n_dot_l * n_dot_v, 1.0e-4);
+        let diffuse = (1.0 - fresnel) * (1.0 - metallic) * albedo;
+
+        // The diffuse term isn't divided by PI so that a fully rough dielectric looks like the old lambert shading.
+        let ambient = albedo * frame.ambient_intensity;
+        let light = n_dot_l * frame.light_intensity * shadow_visibility(in.position_world_space);
+        let radiance = (diffuse + specular * PI) * light + ambient;
+
+        return Vec4(radiance, alpha);
+    }
+}
+
+@fragment
+fn fs_main_shaded(in: VertexOut) -> @location(0) Vec4 {
+    return Vec4(shade(in).rgb, 1.0);
+}
 
-        let radiance = albedo * shading;
+@fragment
+fn fs_main_shaded_transparent(in: VertexOut) -> @location(0) Vec4 {
+    let color = shade(in);
+    return Vec4(color.rgb * color.a, color.a);
+}
 
-        return Vec4(radiance, 1.0);
-    }
+@fragment
+fn fs_main_shaded_order_independent_transparent(in: VertexOut) -> OrderIndependentTransparencyOut {
+    let color = shade(in);
+    return order_independent_transparency_out(Vec4(color.rgb * color.a, color.a), in.position_world_space);
 }
 
 @fragment
@@ -93,3 +236,8 @@ fn fs_main_picking_layer(in: VertexOut) -> @location(0) UVec4 {
 fn fs_main_outline_mask(in: VertexOut) -> @location(0) UVec2 {
     return in.outline_mask_ids;
 }
+
+@fragment
+fn fs_main_shadow_map(in: VertexOut) {
+    // Only depth is written.
+}
@@ -19,4 +19,5 @@ struct InstanceIn {
     @location(10) additive_tint_srgb: Vec4,
     @location(11) picking_layer_id: UVec4,
     @location(12) outline_mask_ids: UVec2,
+    @location(13) shading: UVec2, // Only the first component is used.
 };
@@ -0,0 +1,17 @@
+// Downsamples a mip level into the next smaller one.
+//
+// Sampling with a linear filter in the middle of each 2x2 texel block of the source level gives a box filter.
+
+#import <./types.wgsl>
+#import <./screen_triangle_vertex.wgsl>
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var linear_sampler: sampler;
+
+@fragment
+fn main(in: FragmentInput) -> @location(0) Vec4 {
+    return textureSample(source_texture, linear_sampler, in.texcoord);
+}
@@ -2,6 +2,7 @@
 #import <./types.wgsl>
 #import <./utils/camera.wgsl>
 #import <./utils/flags.wgsl>
+#import <./utils/order_independent_transparency.wgsl>
 #import <./utils/size.wgsl>
 #import <./utils/sphere_quad.wgsl>
 
@@ -111,21 +112,45 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     return out;
 }
 
+fn shaded_color(in: VertexOut) -> Vec3 {
+    // TODO(andreas): Do we want manipulate the depth buffer depth to actually render spheres?
+    // TODO(andreas): Proper shading
+    // TODO(andreas): This doesn't even use the sphere's world position for shading, the world position used here is flat!
+    var shading = 1.0;
+    if has_any_flag(batch.flags, ENABLE_SHADING) {
+        shading = max(0.4, sqrt(1.2 - distance(in.point_center, in.world_position) / in.radius)); // quick and dirty coloring
+    }
+    return in.color.rgb * shading;
+}
+
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) Vec4 {
     let coverage = sphere_quad_coverage(in.world_position, in.radius, in.point_center);
     if coverage < 0.001 {
         discard;
     }
+    return vec4(shaded_color(in), coverage);
+}
 
-    // TODO(andreas): Do we want manipulate the depth buffer depth to actually render spheres?
-    // TODO(andreas): Proper shading
-    // TODO(andreas): This doesn't even use the sphere's world position for shading, the world position used here is flat!
-    var shading = 1.0;
-    if has_any_flag(batch.flags, ENABLE_SHADING) {
-        shading = max(0.4, sqrt(1.2 - distance(in.point_center, in.world_position) / in.radius)); // quick and dirty coloring
+@fragment
+fn fs_main_transparent(in: VertexOut) -> @location(0) Vec4 {
+    let coverage = sphere_quad_coverage(in.world_position, in.radius, in.point_center);
+    if coverage < 0.001 {
+        discard;
+    }
+    // Alpha to coverage doesn't mix with blending, so we fold coverage into alpha instead.
+    let alpha = in.color.a * coverage;
+    return vec4(shaded_color(in) * alpha, alpha);
+}
+
+@fragment
+fn fs_main_order_independent_transparent(in: VertexOut) -> OrderIndependentTransparencyOut {
+    let coverage = sphere_quad_coverage(in.world_position, in.radius, in.point_center);
+    if coverage < 0.001 {
+        discard;
     }
-    return vec4(in.color.rgb * shading, coverage);
+    let alpha = in.color.a * coverage;
+    return order_independent_transparency_out(vec4(shaded_color(in) * alpha, alpha), in.world_position);
 }
 
 @fragment
@@ -1,6 +1,7 @@
 #import <./types.wgsl>
 #import <./global_bindings.wgsl>
 #import <./utils/depth_offset.wgsl>
+#import <./utils/order_independent_transparency.wgsl>
 
 struct UniformBuffer {
     /// Top left corner position in world space.
@@ -14,6 +15,10 @@ struct UniformBuffer {
 
     depth_offset: f32,
 
+    /// Homogeneous w coordinate of top_left_corner_position, extent_u and extent_v.
+    /// Anything but (1, 0, 0) means the rectangle is projectively warped.
+    homogeneous_w: Vec3,
+
     /// Tint multiplied with the texture color.
     multiplicative_tint: Vec4,
 
@@ -33,6 +38,7 @@ var texture_sampler: sampler;
 struct VertexOut {
     @builtin(position) position: Vec4,
     @location(0) texcoord: Vec2,
+    @location(1) position_in_world: Vec3,
 };
 
 @vertex
@@ -40,18 +46,31 @@ fn vs_main(@builtin(vertex_index) v_idx: u32) -> VertexOut {
     let texcoord = Vec2(f32(v_idx / 2u), f32(v_idx % 2u));
     let pos = texcoord.x * rect_info.extent_u + texcoord.y * rect_info.extent_v +
                 rect_info.top_left_corner_position;
+    let w = rect_info.homogeneous_w.x + texcoord.x * rect_info.homogeneous_w.y + texcoord.y * rect_info.homogeneous_w.z;
 
     var out: VertexOut;
-    out.position = apply_depth_offset(frame.projection_from_world * Vec4(pos, 1.0), rect_info.depth_offset);
+    // Passing on the homogeneous position makes the rasterizer's perspective correct interpolation
+    // also correctly interpolate the texture coordinates of warped rectangles.
+    out.position = apply_depth_offset(frame.projection_from_world * Vec4(pos, w), rect_info.depth_offset);
     out.texcoord = texcoord;
+    out.position_in_world = pos / w;
 
     return out;
 }
 
+fn tinted_texture_color(texcoord: Vec2) -> Vec4 {
+    let texture_color = textureSample(texture, texture_sampler, texcoord);
+    return texture_color * rect_info.multiplicative_tint;
+}
+
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) Vec4 {
-    let texture_color = textureSample(texture, texture_sampler, in.texcoord);
-    return texture_color * rect_info.multiplicative_tint;
+    return tinted_texture_color(in.texcoord);
+}
+
+@fragment
+fn fs_main_order_independent_transparent(in: VertexOut) -> OrderIndependentTransparencyOut {
+    return order_independent_transparency_out(tinted_texture_color(in.texcoord), in.position_in_world);
 }
 
 @fragment
@@ -0,0 +1,28 @@
+// Composites the result of weighted blended order independent transparency onto the main target.
+// See `transparency.rs`.
+
+#import <./types.wgsl>
+#import <./screen_triangle_vertex.wgsl>
+
+@group(0) @binding(0)
+var accumulation_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var revealage_texture: texture_2d<f32>;
+
+@fragment
+fn main(in: FragmentInput) -> @location(0) Vec4 {
+    let resolution = textureDimensions(accumulation_texture).xy;
+    let coord = IVec2(Vec2(resolution) * in.texcoord);
+
+    let revealage = textureLoad(revealage_texture, coord, 0).r;
+    if revealage >= 1.0 {
+        // Nothing transparent was drawn here.
+        discard;
+    }
+
+    let accumulation = textureLoad(accumulation_texture, coord, 0);
+    let average_color = accumulation.rgb / max(accumulation.a, 1.0e-5);
+
+    return Vec4(average_color, 1.0 - revealage);
+}
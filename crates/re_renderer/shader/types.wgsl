@@ -30,6 +30,8 @@ const u32max = 0xffffffffu;
 // Difference between `1.0` and the next larger representable number.
 const f32eps = 0.00000011920928955078125;
 
+const PI = 3.14159265358979323846264338327950288;
+
 const X = Vec3(1.0, 0.0, 0.0);
 const Y = Vec3(0.0, 1.0, 0.0);
 const Z = Vec3(0.0, 0.0, 1.0);
@@ -0,0 +1,31 @@
+// Weighted blended order independent transparency.
+// See McGuire & Bavoil 2013, https://jcgt.org/published/0002/02/09/
+//
+// Keep in sync with `OrderIndependentTransparencyProcessor` in `transparency.rs`.
+
+#import <../global_bindings.wgsl>
+#import <../types.wgsl>
+
+struct OrderIndependentTransparencyOut {
+    @location(0) accumulation: Vec4,
+    @location(1) revealage: f32,
+};
+
+// Computes the output for the order independent transparency targets.
+//
+// `color` is expected to have premultiplied alpha.
+fn order_independent_transparency_out(color: Vec4, position_in_world: Vec3) -> OrderIndependentTransparencyOut {
+    let view_distance = abs(dot(position_in_world - frame.camera_position, frame.camera_forward));
+
+    // Weight function (7) from the paper, giving fragments close to the camera more influence.
+    let weight = color.a * clamp(
+        10.0 / (1.0e-5 + pow(view_distance / 5.0, 2.0) + pow(view_distance / 200.0, 6.0)),
+        1.0e-2,
+        3.0e3
+    );
+
+    var out: OrderIndependentTransparencyOut;
+    out.accumulation = color * weight;
+    out.revealage = color.a;
+    return out;
+}
@@ -29,6 +29,14 @@ impl HardwareTier {
             HardwareTier::Native => true,
         }
     }
+
+    /// Whether the current hardware tier supports blending on floating point render targets.
+    pub fn support_float_target_blending(&self) -> bool {
+        match self {
+            HardwareTier::Web => false,
+            HardwareTier::Native => true,
+        }
+    }
 }
 
 impl Default for HardwareTier {
@@ -172,8 +172,12 @@ impl RenderContext {
             &device,
             &mut resolver,
         )));
-        let texture_manager_2d =
-            TextureManager2D::new(device.clone(), queue.clone(), &mut gpu_resources.textures);
+        let texture_manager_2d = TextureManager2D::new(
+            device.clone(),
+            queue.clone(),
+            &mut gpu_resources,
+            &mut resolver,
+        );
 
         let active_frame = ActiveFrameContext {
             before_view_builder_encoder: Mutex::new(FrameGlobalCommandEncoder::new(&device)),
@@ -337,13 +341,15 @@ impl RenderContext {
         // Unmap all write staging buffers.
         self.cpu_write_gpu_read_belt.lock().before_queue_submit();
 
-        if let Some(command_encoder) = self
-            .active_frame
-            .before_view_builder_encoder
-            .lock()
-            .0
-            .take()
-        {
+        let mut before_view_builder_encoder = self.active_frame.before_view_builder_encoder.lock();
+
+        if let Some(command_encoder) = before_view_builder_encoder.0.as_mut() {
+            // Textures created this frame need their mip levels filled in before any view uses them.
+            self.texture_manager_2d
+                .generate_pending_mipmaps(&mut self.gpu_resources, command_encoder);
+        }
+
+        if let Some(command_encoder) = before_view_builder_encoder.0.take() {
             crate::profile_scope!("finish & submit frame-global encoder");
             let command_buffer = command_encoder.finish();
 
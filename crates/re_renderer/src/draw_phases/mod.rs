@@ -12,17 +12,31 @@ pub use picking_layer::{
 mod screenshot;
 pub use screenshot::ScreenshotProcessor;
 
+mod shadow_map;
+pub use shadow_map::{ShadowMapConfig, ShadowMapProcessor};
+
+mod transparency;
+pub use transparency::{
+    OrderIndependentTransparencyProcessor, TransparencyMode, TransparencySortView,
+};
+
 /// Determines a (very rough) order of rendering and describes the active [`wgpu::RenderPass`].
 ///
-/// Currently we do not support sorting *within* a rendering phase!
-/// See [#702](https://github.com/rerun-io/rerun/issues/702)
 /// Within a phase `DrawData` are drawn in the order they are submitted in.
+/// The only exception is [`DrawPhase::Transparent`], for which `DrawData` are sorted back to front.
+/// See also [#702](https://github.com/rerun-io/rerun/issues/702)
 ///
 /// TODO(andreas): Should every phase/processor be associated with a single `wgpu::RenderPass`?
 ///     Note that this implies sub-phases (e.g. Opaque & background render to the same target).
 ///     Also we should then the higher level one to `RenderPass` or similar!
 #[derive(Debug, enumset::EnumSetType)]
 pub enum DrawPhase {
+    /// Depth of all shadow casters as seen from the directional light.
+    ///
+    /// Drawn before everything else, since all shading phases may sample the shadow map.
+    /// See [`ShadowMapProcessor`].
+    ShadowMap,
+
     /// Opaque objects, performing reads/writes to the depth buffer.
     ///
     /// Typically they are order independent, so everything uses this same index.
@@ -31,6 +45,19 @@ pub enum DrawPhase {
     /// Background, rendering where depth wasn't written.
     Background,
 
+    /// Transparent objects, reading but not writing the depth buffer.
+    ///
+    /// Drawn after [`DrawPhase::Opaque`] & [`DrawPhase::Background`] into the main target.
+    /// Renderers are expected to draw their transparent content back to front,
+    /// see [`crate::renderer::DrawData::sort_transparent_back_to_front`].
+    Transparent,
+
+    /// Transparent objects, drawn in arbitrary order for order independent transparency.
+    ///
+    /// Used instead of [`DrawPhase::Transparent`] if a view uses [`TransparencyMode::OrderIndependent`].
+    /// Render targets are given by [`OrderIndependentTransparencyProcessor::render_targets`].
+    TransparentOrderIndependent,
+
     /// Everything that can be picked with GPU based picking.
     ///
     /// This should be everything in the `Opaque` phase.
@@ -263,6 +263,7 @@ impl PickingLayerProcessor {
             &mut ctx.gpu_resources,
             &ctx.device,
             frame_uniform_buffer,
+            None,
         );
 
         let row_info_id = Texture2DBufferInfo::new(Self::PICKING_LAYER_FORMAT, picking_rect.extent);
@@ -0,0 +1,215 @@
+//! Shadows of a single directional light.
+//!
+//! This module provides the [`ShadowMapProcessor`] which renders the depth of all shadow casters
+//! as seen from the directional light into a shadow map.
+//! The light's frustum is an orthographic projection fitted around the bounding box given in [`ShadowMapConfig`].
+//!
+//! The shadow map is bound as part of the global bindings (see `global_bindings.wgsl`)
+//! so that any renderer can look up shadows in its shading phase.
+//! While rendering the shadow map itself, a dummy texture is bound instead.
+
+use crate::{
+    allocator::create_and_fill_uniform_buffer,
+    global_bindings::FrameUniformBuffer,
+    wgpu_resources::{GpuBindGroup, GpuTexture, TextureDesc},
+    DebugLabel, RenderContext,
+};
+
+/// Configures shadow mapping for a view.
+#[derive(Clone, Debug)]
+pub struct ShadowMapConfig {
+    /// Bounding box of everything that should cast or receive shadows.
+    ///
+    /// The shadow map covers exactly this box, so the tighter it is, the sharper the shadows get.
+    pub scene_bounds: macaw::BoundingBox,
+
+    /// Width & height of the shadow map in texels.
+    pub resolution: u32,
+}
+
+impl ShadowMapConfig {
+    pub const DEFAULT_RESOLUTION: u32 = 2048;
+
+    pub fn new(scene_bounds: macaw::BoundingBox) -> Self {
+        Self {
+            scene_bounds,
+            resolution: Self::DEFAULT_RESOLUTION,
+        }
+    }
+}
+
+/// Manages the shadow map render target and the pass rendering into it.
+///
+/// The view builder creates this for every frame that has shadows enabled.
+pub struct ShadowMapProcessor {
+    shadow_map: GpuTexture,
+    bind_group_0: GpuBindGroup,
+}
+
+impl ShadowMapProcessor {
+    /// Depth format of the shadow map.
+    pub const SHADOW_MAP_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+    /// Depth state any [`crate::renderer::Renderer`] drawing in [`super::DrawPhase::ShadowMap`] needs to use.
+    ///
+    /// Unlike the main target, the shadow map uses regular (not reversed) depth, cleared to 1.0.
+    pub const SHADOW_MAP_DEPTH_STATE: Option<wgpu::DepthStencilState> =
+        Some(wgpu::DepthStencilState {
+            format: Self::SHADOW_MAP_FORMAT,
+            depth_compare: wgpu::CompareFunction::Less,
+            depth_write_enabled: true,
+            stencil: wgpu::StencilState {
+                front: wgpu::StencilFaceState::IGNORE,
+                back: wgpu::StencilFaceState::IGNORE,
+                read_mask: 0,
+                write_mask: 0,
+            },
+            // Slope scaled bias against shadow acne on surfaces at grazing angles to the light.
+            bias: wgpu::DepthBiasState {
+                constant: 0,
+                slope_scale: 2.0,
+                clamp: 0.0,
+            },
+        });
+
+    pub const SHADOW_MAP_MSAA_STATE: wgpu::MultisampleState = wgpu::MultisampleState {
+        count: 1,
+        mask: !0,
+        alpha_to_coverage_enabled: false,
+    };
+
+    /// Constant depth bias applied when comparing against the shadow map, see `shadow_depth_bias` in `global_bindings.wgsl`.
+    pub const SHADOW_DEPTH_BIAS: f32 = 0.002;
+
+    /// Computes the view and projection matrix of the light such that it covers the entire bounding box.
+    ///
+    /// `light_direction` is the direction towards the light in world space.
+    /// Returns `None` if there is no meaningful bounding box.
+    pub fn light_view_and_projection(
+        config: &ShadowMapConfig,
+        light_direction: glam::Vec3,
+    ) -> Option<(glam::Affine3A, glam::Mat4)> {
+        let bounds = &config.scene_bounds;
+        if bounds.is_nothing() || !bounds.is_finite() {
+            return None;
+        }
+        let light_direction = light_direction.try_normalize()?;
+
+        let center = bounds.center();
+        let radius = (bounds.size().length() * 0.5).max(f32::EPSILON);
+
+        // Any up vector will do as long as it isn't parallel to the light direction.
+        let up = if light_direction.z.abs() < 0.9 {
+            glam::Vec3::Z
+        } else {
+            glam::Vec3::Y
+        };
+        let light_from_world =
+            glam::Affine3A::look_at_rh(center + light_direction * radius * 2.0, center, up);
+        let projection_from_light =
+            glam::Mat4::orthographic_rh(-radius, radius, -radius, radius, radius, radius * 3.0);
+
+        Some((light_from_world, projection_from_light))
+    }
+
+    /// Creates the shadow map target and the frame uniform buffer used while rendering into it.
+    ///
+    /// `frame_uniform_buffer_content` is the frame uniform buffer of the main view,
+    /// everything camera related is replaced with the light's view and projection.
+    pub fn new(
+        ctx: &mut RenderContext,
+        view_name: &DebugLabel,
+        config: &ShadowMapConfig,
+        light_from_world: glam::Affine3A,
+        projection_from_light: glam::Mat4,
+        frame_uniform_buffer_content: &FrameUniformBuffer,
+    ) -> Self {
+        crate::profile_function!();
+
+        let shadow_map = ctx.gpu_resources.textures.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: format!("{view_name} - shadow map").into(),
+                size: wgpu::Extent3d {
+                    width: config.resolution,
+                    height: config.resolution,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: Self::SHADOW_MAP_FORMAT,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+            },
+        );
+
+        let world_from_light = light_from_world.inverse();
+        let frame_uniform_buffer_content = FrameUniformBuffer {
+            view_from_world: light_from_world.into(),
+            projection_from_view: projection_from_light.into(),
+            projection_from_world: (projection_from_light * glam::Mat4::from(light_from_world))
+                .into(),
+            camera_position: world_from_light.translation.into(),
+            camera_forward: -glam::Vec3::from(light_from_world.matrix3.row(2)),
+            // Orthographic projection, see `global_bindings.wgsl`.
+            tan_half_fov: glam::vec2(f32::MAX, f32::MAX).into(),
+            pixel_world_size_from_camera_distance: 2.0
+                / projection_from_light.y_axis.y
+                / config.resolution as f32,
+            shadow_map_enabled: 0,
+            ..*frame_uniform_buffer_content
+        };
+        let frame_uniform_buffer = create_and_fill_uniform_buffer(
+            ctx,
+            format!("{view_name} - shadow map frame uniform buffer").into(),
+            frame_uniform_buffer_content,
+        );
+        let bind_group_0 = ctx.shared_renderer_data.global_bindings.create_bind_group(
+            &mut ctx.gpu_resources,
+            &ctx.device,
+            frame_uniform_buffer,
+            None,
+        );
+
+        Self {
+            shadow_map,
+            bind_group_0,
+        }
+    }
+
+    /// The shadow map texture, to be bound in the global bindings of the main view.
+    pub fn shadow_map(&self) -> &GpuTexture {
+        &self.shadow_map
+    }
+
+    /// Size of a single shadow map texel in texture coordinates.
+    pub fn texel_size(config: &ShadowMapConfig) -> f32 {
+        1.0 / config.resolution.max(1) as f32
+    }
+
+    pub fn begin_render_pass<'a>(
+        &'a self,
+        view_name: &DebugLabel,
+        encoder: &'a mut wgpu::CommandEncoder,
+    ) -> wgpu::RenderPass<'a> {
+        crate::profile_function!();
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: DebugLabel::from(format!("{view_name} - shadow map pass")).get(),
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.shadow_map.default_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: true,
+                }),
+                stencil_ops: None,
+            }),
+        });
+
+        pass.set_bind_group(0, &self.bind_group_0, &[]);
+
+        pass
+    }
+}
@@ -0,0 +1,362 @@
+//! Rendering of transparent objects.
+//!
+//! Transparent objects are drawn after all opaque objects (and the background) have been drawn.
+//! They read, but don't write the depth buffer.
+//! Depending on the [`TransparencyMode`] of a view, this happens in one of two ways:
+//!
+//! Sorted transparency ([`DrawPhase::Transparent`]):
+//! =================================================
+//! Draws transparent objects to the main target with regular alpha blending.
+//! The [`crate::view_builder::ViewBuilder`] orders draw data back to front and each [`crate::renderer::DrawData`]
+//! is asked to sort its own transparent content back to front (see [`crate::renderer::DrawData::sort_transparent_back_to_front`]).
+//! Sorting happens per object (rectangle, mesh instance, point batch), so it is exact only for objects that don't intersect each other.
+//!
+//! Order independent transparency ([`DrawPhase::TransparentOrderIndependent`]):
+//! ============================================================================
+//! Implements weighted blended order independent transparency as described by
+//! [McGuire & Bavoil 2013](https://jcgt.org/published/0002/02/09/).
+//! Transparent objects are drawn in arbitrary order into an accumulation & a revealage target,
+//! which are then composited onto the main target.
+//! This is only an approximation, but it handles intersecting objects & dense point clouds gracefully
+//! and doesn't need any sorting.
+//!
+//! [`DrawPhase::Transparent`]: super::DrawPhase::Transparent
+//! [`DrawPhase::TransparentOrderIndependent`]: super::DrawPhase::TransparentOrderIndependent
+
+use smallvec::smallvec;
+
+use crate::{
+    config::HardwareTier,
+    include_shader_module,
+    renderer::screen_triangle_vertex_shader,
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuRenderPipelineHandle,
+        GpuTexture, PipelineLayoutDesc, PoolError, RenderPipelineDesc, TextureDesc,
+        WgpuResourcePools,
+    },
+    DebugLabel, RenderContext,
+};
+
+/// How transparent objects are rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransparencyMode {
+    /// Transparent objects are sorted back to front and blended onto the main target.
+    ///
+    /// Exact for non-intersecting objects, but sorting happens only per object.
+    #[default]
+    Sorted,
+
+    /// Weighted blended order independent transparency.
+    ///
+    /// Approximate, but handles intersecting objects and doesn't need any sorting.
+    /// Not supported on [`HardwareTier::Web`], falls back to [`TransparencyMode::Sorted`] there.
+    OrderIndependent,
+}
+
+/// View information used to sort transparent objects back to front.
+#[derive(Clone, Copy, Debug)]
+pub struct TransparencySortView {
+    pub camera_position: glam::Vec3,
+    pub camera_forward: glam::Vec3,
+}
+
+impl TransparencySortView {
+    /// Distance of a world space position along the view direction.
+    ///
+    /// The bigger the value, the further away the position is from the camera.
+    #[inline]
+    pub fn depth(&self, position_in_world: glam::Vec3) -> f32 {
+        (position_in_world - self.camera_position).dot(self.camera_forward)
+    }
+
+    /// Sorts a list of items back to front, given the world space position for each item.
+    ///
+    /// Returns the depth of the furthest item, `None` if the list was empty.
+    pub fn sort_back_to_front<T>(
+        &self,
+        items: &mut [T],
+        position_in_world: impl Fn(&T) -> glam::Vec3,
+    ) -> Option<f32> {
+        items.sort_by(|a, b| {
+            self.depth(position_in_world(b))
+                .total_cmp(&self.depth(position_in_world(a)))
+        });
+        items
+            .first()
+            .map(|item| self.depth(position_in_world(item)))
+    }
+}
+
+/// Manages the render targets & passes for [`TransparencyMode::OrderIndependent`].
+pub struct OrderIndependentTransparencyProcessor {
+    label: DebugLabel,
+
+    accumulation_msaa: GpuTexture,
+    accumulation: GpuTexture,
+    revealage_msaa: GpuTexture,
+    revealage: GpuTexture,
+
+    bind_group_composite: GpuBindGroup,
+    render_pipeline_composite: GpuRenderPipelineHandle,
+}
+
+impl OrderIndependentTransparencyProcessor {
+    /// Format of the target accumulating weighted, premultiplied colors.
+    pub const ACCUMULATION_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+    /// Format of the target accumulating the product of all `1 - alpha` values.
+    pub const REVEALAGE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
+
+    /// Render targets any [`crate::renderer::Renderer`] drawing in [`super::DrawPhase::TransparentOrderIndependent`] needs to use.
+    ///
+    /// Fragment shaders are expected to output `OrderIndependentTransparencyOut` from
+    /// `utils/order_independent_transparency.wgsl`.
+    pub fn render_targets() -> [Option<wgpu::ColorTargetState>; 2] {
+        [
+            Some(wgpu::ColorTargetState {
+                format: Self::ACCUMULATION_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            }),
+            Some(wgpu::ColorTargetState {
+                format: Self::REVEALAGE_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::Zero,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrc,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent::REPLACE,
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            }),
+        ]
+    }
+
+    /// Whether order independent transparency is available on the given hardware tier.
+    ///
+    /// Requires blending on floating point render targets, which isn't guaranteed on WebGL.
+    pub fn is_supported(tier: HardwareTier) -> bool {
+        tier.support_float_target_blending()
+    }
+
+    pub fn new(
+        ctx: &mut RenderContext,
+        view_name: &DebugLabel,
+        resolution_in_pixel: [u32; 2],
+    ) -> Self {
+        crate::profile_function!();
+        let instance_label: DebugLabel =
+            format!("{view_name} - OrderIndependentTransparencyProcessor").into();
+
+        // ------------- Textures -------------
+
+        let texture_pool = &ctx.gpu_resources.textures;
+        let accumulation_desc = TextureDesc {
+            label: format!("{instance_label}::accumulation").into(),
+            size: wgpu::Extent3d {
+                width: resolution_in_pixel[0],
+                height: resolution_in_pixel[1],
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            // Need to match the main target's sample count since we're re-using its depth buffer.
+            sample_count: ViewBuilder::MAIN_TARGET_SAMPLE_COUNT,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::ACCUMULATION_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        };
+        let accumulation_msaa =
+            texture_pool.alloc(&ctx.device, &accumulation_desc.with_label_push(" msaa"));
+        let accumulation = texture_pool.alloc(
+            &ctx.device,
+            &TextureDesc {
+                sample_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                ..accumulation_desc.clone()
+            },
+        );
+        let revealage_desc = TextureDesc {
+            label: format!("{instance_label}::revealage").into(),
+            format: Self::REVEALAGE_FORMAT,
+            ..accumulation_desc
+        };
+        let revealage_msaa =
+            texture_pool.alloc(&ctx.device, &revealage_desc.with_label_push(" msaa"));
+        let revealage = texture_pool.alloc(
+            &ctx.device,
+            &TextureDesc {
+                sample_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                ..revealage_desc
+            },
+        );
+
+        // ------------- Bind Groups -------------
+
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let bind_group_layout_composite = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "OrderIndependentTransparencyProcessor::bind_group_layout_composite".into(),
+                entries: vec![texture_entry(0), texture_entry(1)],
+            },
+        );
+        let bind_group_composite = ctx.gpu_resources.bind_groups.alloc(
+            &ctx.device,
+            &ctx.gpu_resources,
+            &BindGroupDesc {
+                label: format!("{instance_label}::composite").into(),
+                entries: smallvec![
+                    BindGroupEntry::DefaultTextureView(accumulation.handle),
+                    BindGroupEntry::DefaultTextureView(revealage.handle),
+                ],
+                layout: bind_group_layout_composite,
+            },
+        );
+
+        // ------------- Render Pipelines -------------
+
+        let screen_triangle_vertex_shader =
+            screen_triangle_vertex_shader(&mut ctx.gpu_resources, &ctx.device, &mut ctx.resolver);
+        let render_pipeline_composite = ctx.gpu_resources.render_pipelines.get_or_create(
+            &ctx.device,
+            &RenderPipelineDesc {
+                label: "OrderIndependentTransparencyProcessor::composite".into(),
+                pipeline_layout: ctx.gpu_resources.pipeline_layouts.get_or_create(
+                    &ctx.device,
+                    &PipelineLayoutDesc {
+                        label: "OrderIndependentTransparencyProcessor::composite".into(),
+                        entries: vec![bind_group_layout_composite],
+                    },
+                    &ctx.gpu_resources.bind_group_layouts,
+                ),
+                vertex_entrypoint: "main".into(),
+                vertex_handle: screen_triangle_vertex_shader,
+                fragment_entrypoint: "main".into(),
+                fragment_handle: ctx.gpu_resources.shader_modules.get_or_create(
+                    &ctx.device,
+                    &mut ctx.resolver,
+                    &include_shader_module!("../../shader/transparency_composite.wgsl"),
+                ),
+                vertex_buffers: smallvec![],
+                render_targets: smallvec![Some(wgpu::ColorTargetState {
+                    format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+            },
+            &ctx.gpu_resources.pipeline_layouts,
+            &ctx.gpu_resources.shader_modules,
+        );
+
+        Self {
+            label: instance_label,
+            accumulation_msaa,
+            accumulation,
+            revealage_msaa,
+            revealage,
+            bind_group_composite,
+            render_pipeline_composite,
+        }
+    }
+
+    /// Starts the pass into which all [`super::DrawPhase::TransparentOrderIndependent`] draws go.
+    ///
+    /// `depth_buffer` is the depth buffer of the main target, the opaque scene needs to be already drawn into it.
+    pub fn begin_render_pass<'a>(
+        &'a self,
+        encoder: &'a mut wgpu::CommandEncoder,
+        depth_buffer: &'a GpuTexture,
+    ) -> wgpu::RenderPass<'a> {
+        crate::profile_function!();
+
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: DebugLabel::from(format!("{} - accumulation pass", self.label)).get(),
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.accumulation_msaa.default_view,
+                    resolve_target: Some(&self.accumulation.default_view),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: false,
+                    },
+                }),
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.revealage_msaa.default_view,
+                    resolve_target: Some(&self.revealage.default_view),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        store: false,
+                    },
+                }),
+            ],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth_buffer.default_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: false,
+                }),
+                stencil_ops: None,
+            }),
+        })
+    }
+
+    /// Blends the accumulated transparent objects onto the given (resolved) main target.
+    pub fn composite(
+        self,
+        pools: &WgpuResourcePools,
+        encoder: &mut wgpu::CommandEncoder,
+        main_target_resolved: &GpuTexture,
+    ) -> Result<(), PoolError> {
+        crate::profile_function!();
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: DebugLabel::from(format!("{} - composite", self.label)).get(),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &main_target_resolved.default_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        let render_pipeline = pools
+            .render_pipelines
+            .get_resource(self.render_pipeline_composite)?;
+        pass.set_pipeline(render_pipeline);
+        pass.set_bind_group(0, &self.bind_group_composite, &[]);
+        pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
+    draw_phases::ShadowMapProcessor,
     wgpu_buffer_types,
     wgpu_resources::{
         BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
-        GpuSamplerHandle, SamplerDesc, WgpuResourcePools,
+        GpuSamplerHandle, GpuTexture, SamplerDesc, TextureDesc, WgpuResourcePools,
     },
 };
 
@@ -45,14 +46,38 @@ pub struct FrameUniformBuffer {
     // Size used for all line radii given with Size::AUTO.
     pub auto_size_lines: f32,
 
-    /// Factor used to compute depth offsets, see `depth_offset.wgsl`.
-    pub end_padding: wgpu_buffer_types::PaddingRow,
+    /// Normalized direction towards the directional light in world space.
+    pub light_direction: glam::Vec3,
+
+    /// Intensity of the directional light.
+    pub light_intensity: f32,
+
+    /// Transforms from world space to the clip space of the shadow map.
+    pub light_projection_from_world: wgpu_buffer_types::Mat4,
+
+    /// Intensity of the ambient light.
+    pub ambient_intensity: f32,
+
+    /// Whether the shadow map is valid and should be sampled (0 or 1).
+    pub shadow_map_enabled: u32,
+
+    /// Offset subtracted from the depth of a fragment before comparing it against the shadow map.
+    pub shadow_depth_bias: f32,
+
+    /// Size of a single shadow map texel in texture coordinates.
+    pub shadow_map_texel_size: f32,
+
+    pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 5],
 }
 
 pub(crate) struct GlobalBindings {
     pub(crate) layout: GpuBindGroupLayoutHandle,
     nearest_neighbor_sampler: GpuSamplerHandle,
     trilinear_sampler: GpuSamplerHandle,
+    shadow_map_sampler: GpuSamplerHandle,
+
+    /// Bound whenever there is no shadow map, i.e. shadows are disabled or the shadow map itself is being rendered.
+    dummy_shadow_map: GpuTexture,
 }
 
 impl GlobalBindings {
@@ -93,6 +118,24 @@ impl GlobalBindings {
                             ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                             count: None,
                         },
+                        // Shadow map of the directional light.
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 3,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                sample_type: wgpu::TextureSampleType::Depth,
+                                view_dimension: wgpu::TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        // Comparison sampler for the shadow map.
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 4,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison),
+                            count: None,
+                        },
                     ],
                 },
             ),
@@ -119,16 +162,48 @@ impl GlobalBindings {
                     ..Default::default()
                 },
             ),
+            shadow_map_sampler: pools.samplers.get_or_create(
+                device,
+                &SamplerDesc {
+                    label: "GlobalBindings::shadow_map_sampler".into(),
+                    // Linear filtering on a comparison sampler gives us 2x2 percentage closer filtering for free.
+                    mag_filter: wgpu::FilterMode::Linear,
+                    min_filter: wgpu::FilterMode::Linear,
+                    compare: Some(wgpu::CompareFunction::LessEqual),
+                    ..Default::default()
+                },
+            ),
+            dummy_shadow_map: pools.textures.alloc(
+                device,
+                &TextureDesc {
+                    label: "GlobalBindings::dummy_shadow_map".into(),
+                    size: wgpu::Extent3d {
+                        width: 1,
+                        height: 1,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: ShadowMapProcessor::SHADOW_MAP_FORMAT,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                },
+            ),
         }
     }
 
     /// Creates a bind group that follows the global bind group layout.
+    ///
+    /// If no shadow map is passed, a dummy texture is bound in its place.
     pub fn create_bind_group(
         &self,
         pools: &mut WgpuResourcePools,
         device: &wgpu::Device,
         frame_uniform_buffer_binding: BindGroupEntry,
+        shadow_map: Option<&GpuTexture>,
     ) -> GpuBindGroup {
+        let shadow_map = shadow_map.unwrap_or(&self.dummy_shadow_map);
+
         pools.bind_groups.alloc(
             device,
             pools,
@@ -139,6 +214,8 @@ impl GlobalBindings {
                     frame_uniform_buffer_binding,
                     BindGroupEntry::Sampler(self.nearest_neighbor_sampler),
                     BindGroupEntry::Sampler(self.trilinear_sampler),
+                    BindGroupEntry::DefaultTextureView(shadow_map.handle),
+                    BindGroupEntry::Sampler(self.shadow_map_sampler),
                 ],
                 layout: self.layout,
             },
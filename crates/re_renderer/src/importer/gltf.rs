@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use ahash::{HashMap, HashMapExt};
+use ahash::{HashMap, HashMapExt, HashSet};
 use anyhow::Context as _;
 use gltf::texture::WrappingMode;
 use smallvec::SmallVec;
@@ -24,16 +24,24 @@ pub fn load_gltf_from_buffer(
 ) -> anyhow::Result<Vec<MeshInstance>> {
     let (doc, buffers, images) = gltf::import_slice(buffer)?;
 
+    // Normal maps & metallic-roughness textures hold data, not colors, and must therefore not be treated as srgb.
+    let linear_images = linear_image_indices(&doc);
+
     let mut images_as_textures = Vec::with_capacity(images.len());
-    for (_index, image) in images.into_iter().enumerate() {
-        let (format, data) = if let Some(format) = map_format(image.format) {
+    for (index, image) in images.into_iter().enumerate() {
+        let srgb = !linear_images.contains(&index);
+        let (format, data) = if let Some(format) = map_format(image.format, srgb) {
             (format, image.pixels)
         } else {
             // RGB8 is not supported by wgpu, need to pad out data.
             if image.format == gltf::image::Format::R8G8B8 {
                 re_log::debug!("Converting Rgb8 to Rgba8");
                 (
-                    wgpu::TextureFormat::Rgba8UnormSrgb,
+                    if srgb {
+                        wgpu::TextureFormat::Rgba8UnormSrgb
+                    } else {
+                        wgpu::TextureFormat::Rgba8Unorm
+                    },
                     Texture2DCreationDesc::convert_rgb8_to_rgba8(&image.pixels),
                 )
             } else {
@@ -44,7 +52,7 @@ pub fn load_gltf_from_buffer(
         // Images don't have names, but textures do. Gather all texture names for debug labeling.
         #[cfg(debug_assertions)]
         let texture_names = doc.textures().fold(String::new(), |mut name_list, t| {
-            if t.source().index() == _index {
+            if t.source().index() == index {
                 if !name_list.is_empty() {
                     name_list.push_str(", ");
                 }
@@ -66,6 +74,7 @@ pub fn load_gltf_from_buffer(
             format,
             width: image.width,
             height: image.height,
+            generate_mip_maps: true,
         };
 
         images_as_textures.push(
@@ -92,8 +101,15 @@ pub fn load_gltf_from_buffer(
         );
     }
 
+    // Only the default scene is meant to be shown, fall back to all scenes if none is specified.
+    let scenes: Vec<_> = if let Some(scene) = doc.default_scene() {
+        vec![scene]
+    } else {
+        doc.scenes().collect()
+    };
+
     let mut instances = Vec::new();
-    for scene in doc.scenes() {
+    for scene in scenes {
         for node in scene.nodes() {
             gather_instances_recursive(&mut instances, &node, &glam::Affine3A::IDENTITY, &meshes);
         }
@@ -102,7 +118,27 @@ pub fn load_gltf_from_buffer(
     Ok(instances)
 }
 
-fn map_format(format: gltf::image::Format) -> Option<wgpu::TextureFormat> {
+/// Indices of all images that are used for non-color data.
+fn linear_image_indices(doc: &gltf::Document) -> HashSet<usize> {
+    let mut indices = HashSet::default();
+    for material in doc.materials() {
+        if let Some(normal_texture) = material.normal_texture() {
+            indices.insert(normal_texture.texture().source().index());
+        }
+        if let Some(texture) = material
+            .pbr_metallic_roughness()
+            .metallic_roughness_texture()
+        {
+            indices.insert(texture.texture().source().index());
+        }
+        if let Some(occlusion_texture) = material.occlusion_texture() {
+            indices.insert(occlusion_texture.texture().source().index());
+        }
+    }
+    indices
+}
+
+fn map_format(format: gltf::image::Format, srgb: bool) -> Option<wgpu::TextureFormat> {
     use gltf::image::Format;
     use wgpu::TextureFormat;
 
@@ -111,7 +147,11 @@ fn map_format(format: gltf::image::Format) -> Option<wgpu::TextureFormat> {
         Format::R8 => Some(TextureFormat::R8Unorm),
         Format::R8G8 => Some(TextureFormat::Rg8Unorm),
         Format::R8G8B8 => None,
-        Format::R8G8B8A8 => Some(TextureFormat::Rgba8UnormSrgb),
+        Format::R8G8B8A8 => Some(if srgb {
+            TextureFormat::Rgba8UnormSrgb
+        } else {
+            TextureFormat::Rgba8Unorm
+        }),
 
         Format::R16 => Some(TextureFormat::R16Unorm),
         Format::R16G16 => Some(TextureFormat::Rg16Unorm),
@@ -188,43 +228,21 @@ fn import_mesh(
         let pbr_material = primitive_material.pbr_metallic_roughness();
 
         let albedo = if let Some(texture) = pbr_material.base_color_texture() {
-            anyhow::ensure!(
-                texture.tex_coord() == 0,
-                "Only a single set of texture coordinates is supported"
-            );
-            let texture = &texture.texture();
-
-            let sampler = &texture.sampler();
-            if !matches!(
-                sampler.min_filter(),
-                None | Some(gltf::texture::MinFilter::LinearMipmapLinear)
-            ) || !matches!(
-                sampler.mag_filter(),
-                None | Some(gltf::texture::MagFilter::Linear)
-            ) {
-                re_log::warn!(
-                    "Textures on meshes are always sampled with a trilinear filter.
- Texture {:?} had {:?} for min and {:?} for mag filtering, these settings will be ignored",
-                    texture.name(),
-                    sampler.min_filter(),
-                    sampler.mag_filter()
-                );
-            }
-            if sampler.wrap_s() != WrappingMode::Repeat || sampler.wrap_t() != WrappingMode::Repeat
-            {
-                re_log::warn!(
-                    "Textures on meshes are always sampled repeating address mode.
- exture {:?} had {:?} for s wrapping and {:?} for t wrapping, these settings will be ignored",
-                    texture.name(),
-                    sampler.wrap_s(),
-                    sampler.wrap_t()
-                );
-            }
-
-            gpu_image_handles[texture.source().index()].clone()
+            gpu_texture(&texture.texture(), texture.tex_coord(), gpu_image_handles)?
         } else {
             texture_manager.white_texture_unorm_handle().clone()
         };
+        let normal_map = primitive_material
+            .normal_texture()
+            .map(|texture| {
+                gpu_texture(&texture.texture(), texture.tex_coord(), gpu_image_handles)
+                    .map(|handle| (handle, texture.scale()))
+            })
+            .transpose()?;
+        let metallic_roughness = pbr_material
+            .metallic_roughness_texture()
+            .map(|texture| gpu_texture(&texture.texture(), texture.tex_coord(), gpu_image_handles))
+            .transpose()?;
 
         // The color factor *is* in linear space, making things easier for us
         // https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#_material_pbrmetallicroughness_basecolorfactor
@@ -238,6 +256,11 @@ fn import_mesh(
             index_range: index_offset..indices.len() as u32,
             albedo,
             albedo_multiplier: albedo_factor,
+            normal_scale: normal_map.as_ref().map_or(1.0, |(_, scale)| *scale),
+            normal_map: normal_map.map(|(handle, _)| handle),
+            metallic_roughness,
+            metallic_factor: pbr_material.metallic_factor(),
+            roughness_factor: pbr_material.roughness_factor(),
         });
     }
     if vertex_positions.is_empty() || indices.is_empty() {
@@ -259,31 +282,70 @@ fn import_mesh(
     Ok(mesh)
 }
 
+/// Looks up the gpu texture for a gltf texture, warning about any sampler settings we can't honor.
+fn gpu_texture(
+    texture: &gltf::Texture<'_>,
+    tex_coord: u32,
+    gpu_image_handles: &[GpuTexture2DHandle],
+) -> anyhow::Result<GpuTexture2DHandle> {
+    anyhow::ensure!(
+        tex_coord == 0,
+        "Only a single set of texture coordinates is supported"
+    );
+
+    let sampler = &texture.sampler();
+    if !matches!(
+        sampler.min_filter(),
+        None | Some(gltf::texture::MinFilter::LinearMipmapLinear)
+    ) || !matches!(
+        sampler.mag_filter(),
+        None | Some(gltf::texture::MagFilter::Linear)
+    ) {
+        re_log::warn!(
+            "Textures on meshes are always sampled with a trilinear filter.
+ Texture {:?} had {:?} for min and {:?} for mag filtering, these settings will be ignored",
+            texture.name(),
+            sampler.min_filter(),
+            sampler.mag_filter()
+        );
+    }
+    if sampler.wrap_s() != WrappingMode::Repeat || sampler.wrap_t() != WrappingMode::Repeat {
+        re_log::warn!(
+            "Textures on meshes are always sampled repeating address mode.
+ Texture {:?} had {:?} for s wrapping and {:?} for t wrapping, these settings will be ignored",
+            texture.name(),
+            sampler.wrap_s(),
+            sampler.wrap_t()
+        );
+    }
+
+    gpu_image_handles
+        .get(texture.source().index())
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Texture {:?} refers to a missing image", texture.name()))
+}
+
 fn gather_instances_recursive(
     instances: &mut Vec<MeshInstance>,
     node: &gltf::Node<'_>,
     transform: &glam::Affine3A,
     meshes: &HashMap<usize, (GpuMeshHandle, Arc<Mesh>)>,
 ) {
-    let (scale, rotation, translation) = match node.transform() {
+    let node_transform = match node.transform() {
         gltf::scene::Transform::Matrix { matrix } => {
-            let matrix = glam::Mat4::from_cols_array_2d(&matrix);
-            // gltf specifies there that matrices must be described by rotation, scale & translation only.
-            matrix.to_scale_rotation_translation()
+            // Use the matrix as is, decomposing it would loose any shear.
+            glam::Affine3A::from_mat4(glam::Mat4::from_cols_array_2d(&matrix))
         }
         gltf::scene::Transform::Decomposed {
             translation,
             rotation,
             scale,
-        } => (
+        } => glam::Affine3A::from_scale_rotation_translation(
             glam::Vec3::from(scale),
             glam::Quat::from_array(rotation),
             glam::Vec3::from(translation),
         ),
     };
-
-    let node_transform =
-        glam::Affine3A::from_scale_rotation_translation(scale, rotation, translation);
     let transform = *transform * node_transform;
 
     for child in node.children() {
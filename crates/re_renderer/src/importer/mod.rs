@@ -4,20 +4,8 @@ pub mod obj;
 #[cfg(feature = "import-gltf")]
 pub mod gltf;
 
-use macaw::Vec3Ext as _;
-
 use crate::renderer::MeshInstance;
 
-pub fn to_uniform_scale(scale: glam::Vec3) -> f32 {
-    if scale.has_equal_components(0.001) {
-        scale.x
-    } else {
-        let uniform_scale = (scale.x * scale.y * scale.z).cbrt();
-        re_log::warn!("mesh has non-uniform scale ({:?}). This is currently not supported. Using geometric mean {}", scale,uniform_scale);
-        uniform_scale
-    }
-}
-
 pub fn calculate_bounding_box(instances: &[MeshInstance]) -> macaw::BoundingBox {
     macaw::BoundingBox::from_points(
         instances
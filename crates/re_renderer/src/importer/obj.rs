@@ -89,6 +89,11 @@ pub fn load_obj_from_buffer(
                     index_range: 0..num_indices as u32,
                     albedo: texture.clone(),
                     albedo_multiplier: crate::Rgba::WHITE,
+                    normal_map: None,
+                    normal_scale: 1.0,
+                    metallic_roughness: None,
+                    metallic_factor: Material::DEFAULT_METALLIC_FACTOR,
+                    roughness_factor: Material::DEFAULT_ROUGHNESS_FACTOR,
                 }],
             };
 
@@ -41,14 +41,15 @@ pub use depth_offset::DepthOffset;
 pub use line_strip_builder::{LineStripBuilder, LineStripSeriesBuilder};
 pub use point_cloud_builder::{PointCloudBatchBuilder, PointCloudBuilder};
 pub use size::Size;
-pub use view_builder::AutoSizeConfig;
+pub use view_builder::{AutoSizeConfig, DirectionalLight};
 pub use wgpu_resources::WgpuResourcePoolStatistics;
 
 mod draw_phases;
 pub(crate) use draw_phases::DrawPhase;
 pub use draw_phases::{
     OutlineConfig, OutlineMaskPreference, PickingLayerId, PickingLayerInstanceId,
-    PickingLayerObjectId, PickingLayerProcessor, ScreenshotProcessor,
+    PickingLayerObjectId, PickingLayerProcessor, ScreenshotProcessor, ShadowMapConfig,
+    TransparencyMode, TransparencySortView,
 };
 
 mod file_system;
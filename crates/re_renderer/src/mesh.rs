@@ -143,7 +143,42 @@ pub struct Material {
     pub albedo: GpuTexture2DHandle,
 
     /// Factor applied to the decoded albedo color.
+    ///
+    /// If the alpha is below one, everything using this material is drawn as a transparent object.
     pub albedo_multiplier: Rgba,
+
+    /// Optional tangent space normal map, stored in a linear (non-srgb) format.
+    ///
+    /// If `None`, only the vertex normals are used for shading.
+    pub normal_map: Option<GpuTexture2DHandle>,
+
+    /// Scale applied to the x & y components of the normal map's normals.
+    pub normal_scale: f32,
+
+    /// Optional texture with roughness in its green and metalness in its blue channel,
+    /// stored in a linear (non-srgb) format.
+    ///
+    /// This is the same layout as used by glTF.
+    /// If `None`, [`Self::metallic_factor`] & [`Self::roughness_factor`] are used as is.
+    pub metallic_roughness: Option<GpuTexture2DHandle>,
+
+    /// Factor applied to the metalness from [`Self::metallic_roughness`].
+    ///
+    /// 0.0 for dielectrics, 1.0 for metals.
+    pub metallic_factor: f32,
+
+    /// Factor applied to the roughness from [`Self::metallic_roughness`].
+    ///
+    /// 0.0 is perfectly smooth, 1.0 is completely rough.
+    pub roughness_factor: f32,
+}
+
+impl Material {
+    /// Metalness used by materials that don't specify any, resulting in a plain diffuse look.
+    pub const DEFAULT_METALLIC_FACTOR: f32 = 0.0;
+
+    /// Roughness used by materials that don't specify any, resulting in a plain diffuse look.
+    pub const DEFAULT_ROUGHNESS_FACTOR: f32 = 1.0;
 }
 
 #[derive(Clone)]
@@ -172,6 +207,9 @@ pub(crate) struct GpuMaterial {
     pub index_range: Range<u32>,
 
     pub bind_group: GpuBindGroup,
+
+    /// Whether this material needs to be drawn in the transparent phases.
+    pub is_transparent: bool,
 }
 
 pub(crate) mod gpu_data {
@@ -182,7 +220,15 @@ pub(crate) mod gpu_data {
     #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
     pub struct MaterialUniformBuffer {
         pub albedo_multiplier: wgpu_buffer_types::Vec4,
-        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 1],
+
+        pub metallic_factor: f32,
+        pub roughness_factor: f32,
+        pub normal_scale: f32,
+
+        /// Zero if there is no normal map.
+        pub has_normal_map: u32,
+
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 2],
     }
 }
 
@@ -276,6 +322,10 @@ impl GpuMesh {
                     .iter()
                     .map(|material| gpu_data::MaterialUniformBuffer {
                         albedo_multiplier: material.albedo_multiplier.into(),
+                        metallic_factor: material.metallic_factor,
+                        roughness_factor: material.roughness_factor,
+                        normal_scale: material.normal_scale,
+                        has_normal_map: material.normal_map.is_some() as u32,
                         end_padding: Default::default(),
                     }),
             );
@@ -286,15 +336,28 @@ impl GpuMesh {
                 .iter()
                 .zip(uniform_buffer_bindings.into_iter())
             {
-                let texture = ctx.texture_manager_2d.get(&material.albedo)?;
+                let texture_manager = &ctx.texture_manager_2d;
+                let albedo = texture_manager.get(&material.albedo)?.handle;
+                // Unused optional textures are bound to a dummy texture, the shader knows to ignore them.
+                let normal_map = match &material.normal_map {
+                    Some(normal_map) => texture_manager.get(normal_map)?.handle,
+                    None => texture_manager.white_texture_unorm().handle,
+                };
+                let metallic_roughness = match &material.metallic_roughness {
+                    Some(metallic_roughness) => texture_manager.get(metallic_roughness)?.handle,
+                    None => texture_manager.white_texture_unorm().handle,
+                };
+
                 let bind_group = pools.bind_groups.alloc(
                     device,
                     pools,
                     &BindGroupDesc {
                         label: material.label.clone(),
                         entries: smallvec![
-                            BindGroupEntry::DefaultTextureView(texture.handle),
-                            uniform_buffer_binding
+                            BindGroupEntry::DefaultTextureView(albedo),
+                            uniform_buffer_binding,
+                            BindGroupEntry::DefaultTextureView(normal_map),
+                            BindGroupEntry::DefaultTextureView(metallic_roughness),
                         ],
                         layout: mesh_bind_group_layout,
                     },
@@ -303,6 +366,7 @@ impl GpuMesh {
                 materials.push(GpuMaterial {
                     index_range: material.index_range.clone(),
                     bind_group,
+                    is_transparent: material.albedo_multiplier.a() < 1.0,
                 });
             }
             materials
@@ -250,6 +250,7 @@ where
         let new_range = old_size..self.0.vertices.len();
 
         let max_points = self.0.vertices.len();
+        let batch = self.0.batches.last_mut().unwrap();
 
         PointsBuilder {
             vertices: &mut self.0.vertices[new_range],
@@ -257,12 +258,8 @@ where
             colors: &mut self.0.color_buffer,
             picking_instance_ids: &mut self.0.picking_instance_ids_buffer,
             user_data: &mut self.0.user_data,
-            additional_outline_mask_ids: &mut self
-                .0
-                .batches
-                .last_mut()
-                .unwrap()
-                .additional_outline_mask_ids_vertex_ranges,
+            additional_outline_mask_ids: &mut batch.additional_outline_mask_ids_vertex_ranges,
+            batch_flags: &mut batch.flags,
             start_vertex_index: old_size as _,
         }
     }
@@ -281,18 +278,15 @@ where
         });
         self.0.user_data.push(Default::default());
         self.batch_mut().point_count += 1;
+        let batch = self.0.batches.last_mut().unwrap();
 
         PointBuilder {
             vertex: self.0.vertices.last_mut().unwrap(),
             color: &mut self.0.color_buffer,
             user_data: self.0.user_data.last_mut().unwrap(),
             vertex_index,
-            additional_outline_mask_ids: &mut self
-                .0
-                .batches
-                .last_mut()
-                .unwrap()
-                .additional_outline_mask_ids_vertex_ranges,
+            additional_outline_mask_ids: &mut batch.additional_outline_mask_ids_vertex_ranges,
+            batch_flags: &mut batch.flags,
             outline_mask_id: OutlineMaskPreference::NONE,
         }
     }
@@ -319,8 +313,11 @@ where
     }
 
     /// Set flags for this batch.
+    ///
+    /// [`PointCloudBatchFlags::TRANSPARENT`] is managed automatically and is kept if it was already set.
     pub fn flags(mut self, flags: PointCloudBatchFlags) -> Self {
-        self.batch_mut().flags = flags;
+        let batch = self.batch_mut();
+        batch.flags = flags | (batch.flags & PointCloudBatchFlags::TRANSPARENT);
         self
     }
 
@@ -337,6 +334,7 @@ pub struct PointBuilder<'a, PerPointUserData> {
     user_data: &'a mut PerPointUserData,
     vertex_index: u32,
     additional_outline_mask_ids: &'a mut Vec<(std::ops::Range<u32>, OutlineMaskPreference)>,
+    batch_flags: &'a mut PointCloudBatchFlags,
     outline_mask_id: OutlineMaskPreference,
 }
 
@@ -353,6 +351,9 @@ where
     /// This mustn't call this more than once.
     #[inline]
     pub fn color(self, color: Color32) -> Self {
+        if color.a() < 255 {
+            self.batch_flags.insert(PointCloudBatchFlags::TRANSPARENT);
+        }
         self.color.push(color);
         self
     }
@@ -390,6 +391,7 @@ pub struct PointsBuilder<'a, PerPointUserData> {
     picking_instance_ids: &'a mut CpuWriteGpuReadBuffer<PickingLayerInstanceId>,
     user_data: &'a mut Vec<PerPointUserData>,
     additional_outline_mask_ids: &'a mut Vec<(std::ops::Range<u32>, OutlineMaskPreference)>,
+    batch_flags: &'a mut PointCloudBatchFlags,
     start_vertex_index: u32,
 }
 
@@ -423,8 +425,15 @@ where
     #[inline]
     pub fn colors(self, colors: impl Iterator<Item = Color32>) -> Self {
         crate::profile_function!();
-        self.colors
-            .extend(colors.take(self.max_points - self.colors.num_written()));
+        let mut is_transparent = false;
+        self.colors.extend(
+            colors
+                .take(self.max_points - self.colors.num_written())
+                .inspect(|color| is_transparent |= color.a() < 255),
+        );
+        if is_transparent {
+            self.batch_flags.insert(PointCloudBatchFlags::TRANSPARENT);
+        }
         self
     }
 
@@ -62,9 +62,13 @@ mod gpu_data {
         /// Changes over different draw-phases.
         pub radius_boost_in_ui_points: f32,
 
-        pub row_pad: f32,
+        /// See `DISTORTION_*` constants in `depth_cloud.wgsl`.
+        pub distortion_model: u32,
 
-        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1],
+        /// Coefficients of the distortion model, in the order of [`super::DepthCloudDistortion`]'s fields.
+        pub distortion_coefficients: [wgpu_buffer_types::Vec4; 2],
+
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 2],
     }
 
     impl DepthCloudInfoUBO {
@@ -75,6 +79,7 @@ mod gpu_data {
             let super::DepthCloud {
                 world_from_obj,
                 depth_camera_intrinsics,
+                depth_camera_distortion,
                 world_depth_from_data_depth,
                 point_radius_from_world_depth,
                 max_depth_in_world,
@@ -91,6 +96,18 @@ mod gpu_data {
             let world_depth_from_texture_value =
                 world_depth_from_data_depth * user_depth_from_texture_value;
 
+            let (distortion_model, distortion_coefficients) = match *depth_camera_distortion {
+                super::DepthCloudDistortion::None => (0, [glam::Vec4::ZERO; 2]),
+                super::DepthCloudDistortion::BrownConrady { k1, k2, p1, p2, k3 } => (
+                    1,
+                    [glam::vec4(k1, k2, p1, p2), glam::vec4(k3, 0.0, 0.0, 0.0)],
+                ),
+                super::DepthCloudDistortion::KannalaBrandt { k1, k2, k3, k4 } => {
+                    (2, [glam::vec4(k1, k2, k3, k4), glam::Vec4::ZERO])
+                }
+                super::DepthCloudDistortion::Equirectangular => (3, [glam::Vec4::ZERO; 2]),
+            };
+
             Self {
                 world_from_obj: (*world_from_obj).into(),
                 depth_camera_intrinsics: (*depth_camera_intrinsics).into(),
@@ -100,7 +117,8 @@ mod gpu_data {
                 max_depth_in_world: *max_depth_in_world,
                 colormap: *colormap as u32,
                 radius_boost_in_ui_points,
-                row_pad: Default::default(),
+                distortion_model,
+                distortion_coefficients: distortion_coefficients.map(Into::into),
                 end_padding: Default::default(),
             }
         }
@@ -133,15 +151,44 @@ impl Default for DepthCloudDepthData {
     }
 }
 
+/// Lens distortion of the camera a [`DepthCloud`] was captured with.
+///
+/// Coefficients follow the conventions of `OpenCV`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DepthCloudDistortion {
+    /// Ideal pinhole camera.
+    #[default]
+    None,
+
+    /// Radial & tangential distortion.
+    BrownConrady {
+        k1: f32,
+        k2: f32,
+        p1: f32,
+        p2: f32,
+        k3: f32,
+    },
+
+    /// Fisheye distortion.
+    KannalaBrandt { k1: f32, k2: f32, k3: f32, k4: f32 },
+
+    /// Spherical projection, normalized image coordinates are longitude & latitude.
+    Equirectangular,
+}
+
 pub struct DepthCloud {
     /// The extrinsics of the camera used for the projection.
     pub world_from_obj: glam::Mat4,
 
     /// The intrinsics of the camera used for the projection.
-    ///
-    /// Only supports pinhole cameras at the moment.
     pub depth_camera_intrinsics: glam::Mat3,
 
+    /// Lens distortion of the camera, applied between [`Self::depth_camera_intrinsics`] and camera space.
+    ///
+    /// For [`DepthCloudDistortion::None`] and [`DepthCloudDistortion::BrownConrady`], depth is measured along
+    /// the camera's z axis, for all other models it is the distance to the camera.
+    pub depth_camera_distortion: DepthCloudDistortion,
+
     /// Multiplier to get world-space depth from whatever is in [`Self::depth_data`].
     pub world_depth_from_data_depth: f32,
 
@@ -170,6 +217,17 @@ impl DepthCloud {
     /// World-space bounding-box.
     pub fn bbox(&self) -> macaw::BoundingBox {
         let max_depth = self.max_depth_in_world;
+
+        if self.depth_camera_distortion != DepthCloudDistortion::None {
+            // Distorted cameras may see a lot more than their corners suggest, or even behind themselves.
+            // Conservatively assume points anywhere within the maximum depth.
+            let corners = itertools::iproduct!([-1.0, 1.0], [-1.0, 1.0], [-1.0, 1.0])
+                .map(|(x, y, z)| glam::vec3(x, y, z) * max_depth);
+            return macaw::BoundingBox::from_points(
+                corners.map(|corner| self.world_from_obj.project_point3(corner)),
+            );
+        }
+
         let w = self.depth_dimensions.x as f32;
         let h = self.depth_dimensions.y as f32;
         let corners = [
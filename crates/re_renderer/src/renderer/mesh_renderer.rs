@@ -2,6 +2,11 @@
 //!
 //! Uses instancing to render instances of the same mesh in a single draw call.
 //! Instance data is kept in an instance-stepped vertex data.
+//!
+//! Materials with transparency are drawn in the transparent phases.
+//! Since instances of transparent materials need to be sorted, they are drawn with one draw call per instance.
+//!
+//! Opaque materials cast shadows, see [`crate::draw_phases::ShadowMapProcessor`].
 
 use std::sync::Arc;
 
@@ -9,7 +14,10 @@ use ahash::{HashMap, HashMapExt};
 use smallvec::smallvec;
 
 use crate::{
-    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    draw_phases::{
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor, ShadowMapProcessor,
+        TransparencySortView,
+    },
     include_shader_module,
     mesh::{gpu_data::MaterialUniformBuffer, mesh_vertices, GpuMesh, Mesh},
     resource_managers::{GpuMeshHandle, ResourceHandle},
@@ -51,8 +59,10 @@ mod gpu_data {
 
         pub picking_layer_id: [u32; 4],
 
-        // Need only the first two bytes, but we want to keep everything aligned to at least 4 bytes.
-        pub outline_mask_ids: [u8; 4],
+        pub outline_mask_ids: [u8; 2],
+
+        // Need only the first byte, but we want to keep everything aligned to at least 4 bytes.
+        pub shading: [u8; 2],
     }
 
     impl InstanceData {
@@ -81,6 +91,8 @@ mod gpu_data {
                         // Outline mask.
                         // This adds a tiny bit of overhead to all instances during non-outline pass, but the alternative is having yet another vertex buffer.
                         wgpu::VertexFormat::Uint8x2,
+                        // Shading mode, see `MeshShading`.
+                        wgpu::VertexFormat::Uint8x2,
                     ]
                     .into_iter(),
                 ),
@@ -100,6 +112,19 @@ struct MeshBatch {
     count_with_outlines: u32,
 }
 
+/// Instance of a mesh that has at least one transparent material.
+#[derive(Clone)]
+struct TransparentMeshInstance {
+    /// Index into [`MeshDrawData::batches`].
+    batch_index: usize,
+
+    /// Index of the instance in the instance buffer.
+    instance_index: u32,
+
+    /// Origin of the mesh in world space, used for sorting.
+    position: glam::Vec3,
+}
+
 #[derive(Clone)]
 pub struct MeshDrawData {
     // There is a single instance buffer for all instances of all meshes.
@@ -107,10 +132,39 @@ pub struct MeshDrawData {
     // instance range on every instanced draw call!
     instance_buffer: Option<GpuBuffer>,
     batches: Vec<MeshBatch>,
+
+    /// All instances with transparent materials, after [`DrawData::sort_transparent_back_to_front`] in back to front order.
+    transparent_instances: Vec<TransparentMeshInstance>,
 }
 
 impl DrawData for MeshDrawData {
     type Renderer = MeshRenderer;
+
+    fn sort_transparent_back_to_front(&mut self, view: &TransparencySortView) -> Option<f32> {
+        view.sort_back_to_front(&mut self.transparent_instances, |instance| {
+            instance.position
+        })
+    }
+}
+
+/// How a mesh instance is shaded.
+///
+/// Keep in sync with `instanced_mesh.wgsl`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeshShading {
+    /// Lighting with the interpolated vertex normals.
+    ///
+    /// Meshes without vertex normals are drawn unlit.
+    #[default]
+    Smooth = 0,
+
+    /// Lighting with a single normal per triangle, derived from the triangle's geometry.
+    ///
+    /// Works for meshes without vertex normals as well.
+    Flat = 1,
+
+    /// No lighting at all, only the albedo is shown.
+    Unlit = 2,
 }
 
 pub struct MeshInstance {
@@ -132,6 +186,9 @@ pub struct MeshInstance {
 
     /// Picking layer id.
     pub picking_layer_id: PickingLayerId,
+
+    /// How this instance is shaded.
+    pub shading: MeshShading,
 }
 
 impl Default for MeshInstance {
@@ -143,6 +200,7 @@ impl Default for MeshInstance {
             additive_tint: Color32::TRANSPARENT,
             outline_mask_ids: OutlineMaskPreference::NONE,
             picking_layer_id: PickingLayerId::default(),
+            shading: MeshShading::default(),
         }
     }
 }
@@ -167,6 +225,7 @@ impl MeshDrawData {
             return Ok(MeshDrawData {
                 batches: Vec::new(),
                 instance_buffer: None,
+                transparent_instances: Vec::new(),
             });
         }
 
@@ -196,6 +255,7 @@ impl MeshDrawData {
         }
 
         let mut batches = Vec::new();
+        let mut transparent_instances = Vec::new();
         {
             let mut instance_buffer_staging = ctx
                 .cpu_write_gpu_read_belt
@@ -209,6 +269,14 @@ impl MeshDrawData {
             let mesh_manager = ctx.mesh_manager.read();
             let mut num_processed_instances = 0;
             for (mesh, mut instances) in instances_by_mesh {
+                // We resolve the meshes here already, so the actual draw call doesn't need to
+                // know about the MeshManager.
+                let mesh = mesh_manager.get(mesh)?;
+                let has_transparent_materials = mesh
+                    .materials
+                    .iter()
+                    .any(|material| material.is_transparent);
+
                 let mut count = 0;
                 let mut count_with_outlines = 0;
 
@@ -220,6 +288,14 @@ impl MeshDrawData {
                 });
 
                 for instance in instances {
+                    if has_transparent_materials {
+                        transparent_instances.push(TransparentMeshInstance {
+                            batch_index: batches.len(),
+                            instance_index: (num_processed_instances + count) as u32,
+                            position: instance.world_from_mesh.translation.into(),
+                        });
+                    }
+
                     count += 1;
                     count_with_outlines += instance.outline_mask_ids.is_some() as u32;
 
@@ -243,18 +319,13 @@ impl MeshDrawData {
                         world_from_mesh_normal_row_1: world_from_mesh_normal.row(1).to_array(),
                         world_from_mesh_normal_row_2: world_from_mesh_normal.row(2).to_array(),
                         additive_tint: instance.additive_tint,
-                        outline_mask_ids: instance
-                            .outline_mask_ids
-                            .0
-                            .map_or([0, 0, 0, 0], |mask| [mask[0], mask[1], 0, 0]),
+                        outline_mask_ids: instance.outline_mask_ids.0.unwrap_or_default(),
+                        shading: [instance.shading as u8, 0],
                         picking_layer_id: instance.picking_layer_id.into(),
                     });
                 }
                 num_processed_instances += count;
 
-                // We resolve the meshes here already, so the actual draw call doesn't need to
-                // know about the MeshManager.
-                let mesh = mesh_manager.get(mesh)?;
                 batches.push(MeshBatch {
                     mesh: mesh.clone(),
                     count: count as _,
@@ -272,14 +343,18 @@ impl MeshDrawData {
         Ok(MeshDrawData {
             batches,
             instance_buffer: Some(instance_buffer),
+            transparent_instances,
         })
     }
 }
 
 pub struct MeshRenderer {
     render_pipeline_shaded: GpuRenderPipelineHandle,
+    render_pipeline_shaded_transparent: GpuRenderPipelineHandle,
+    render_pipeline_shaded_order_independent_transparent: GpuRenderPipelineHandle,
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
+    render_pipeline_shadow_map: GpuRenderPipelineHandle,
     pub bind_group_layout: GpuBindGroupLayoutHandle,
 }
 
@@ -288,7 +363,10 @@ impl Renderer for MeshRenderer {
 
     fn participated_phases() -> &'static [DrawPhase] {
         &[
+            DrawPhase::ShadowMap,
             DrawPhase::Opaque,
+            DrawPhase::Transparent,
+            DrawPhase::TransparentOrderIndependent,
             DrawPhase::OutlineMask,
             DrawPhase::PickingLayer,
         ]
@@ -329,6 +407,28 @@ impl Renderer for MeshRenderer {
                         },
                         count: None,
                     },
+                    // Normal map.
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Metallic-roughness texture.
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
                 ],
             },
         );
@@ -377,6 +477,38 @@ impl Renderer for MeshRenderer {
             &pools.pipeline_layouts,
             &pools.shader_modules,
         );
+        let render_pipeline_shaded_transparent = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: "MeshRenderer::render_pipeline_shaded_transparent".into(),
+                fragment_entrypoint: "fs_main_shaded_transparent".into(),
+                render_targets: smallvec![Some(wgpu::ColorTargetState {
+                    format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                ..render_pipeline_shaded_desc.clone()
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
+        let render_pipeline_shaded_order_independent_transparent =
+            pools.render_pipelines.get_or_create(
+                device,
+                &RenderPipelineDesc {
+                    label: "MeshRenderer::render_pipeline_shaded_order_independent_transparent"
+                        .into(),
+                    fragment_entrypoint: "fs_main_shaded_order_independent_transparent".into(),
+                    render_targets: OrderIndependentTransparencyProcessor::render_targets()
+                        .into_iter()
+                        .collect(),
+                    depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                    ..render_pipeline_shaded_desc.clone()
+                },
+                &pools.pipeline_layouts,
+                &pools.shader_modules,
+            );
         let render_pipeline_picking_layer = pools.render_pipelines.get_or_create(
             device,
             &RenderPipelineDesc {
@@ -400,6 +532,19 @@ impl Renderer for MeshRenderer {
                 multisample: OutlineMaskProcessor::mask_default_msaa_state(
                     shared_data.config.hardware_tier,
                 ),
+                ..render_pipeline_shaded_desc.clone()
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
+        let render_pipeline_shadow_map = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: "MeshRenderer::render_pipeline_shadow_map".into(),
+                fragment_entrypoint: "fs_main_shadow_map".into(),
+                render_targets: smallvec![],
+                depth_stencil: ShadowMapProcessor::SHADOW_MAP_DEPTH_STATE,
+                multisample: ShadowMapProcessor::SHADOW_MAP_MSAA_STATE,
                 ..render_pipeline_shaded_desc
             },
             &pools.pipeline_layouts,
@@ -408,8 +553,11 @@ impl Renderer for MeshRenderer {
 
         MeshRenderer {
             render_pipeline_shaded,
+            render_pipeline_shaded_transparent,
+            render_pipeline_shaded_order_independent_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
+            render_pipeline_shadow_map,
             bind_group_layout,
         }
     }
@@ -429,10 +577,47 @@ impl Renderer for MeshRenderer {
 
         let pipeline_handle = match phase {
             DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+            DrawPhase::ShadowMap => self.render_pipeline_shadow_map,
             DrawPhase::Opaque => self.render_pipeline_shaded,
+            DrawPhase::Transparent => self.render_pipeline_shaded_transparent,
+            DrawPhase::TransparentOrderIndependent => {
+                self.render_pipeline_shaded_order_independent_transparent
+            }
             DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
             _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
         };
+
+        if matches!(
+            phase,
+            DrawPhase::Transparent | DrawPhase::TransparentOrderIndependent
+        ) {
+            if draw_data.transparent_instances.is_empty() {
+                return Ok(());
+            }
+
+            let pipeline = pools.render_pipelines.get_resource(pipeline_handle)?;
+            pass.set_pipeline(pipeline);
+            pass.set_vertex_buffer(0, instance_buffer.slice(..));
+
+            // Instances are sorted individually, so we can't make use of instancing here.
+            let mut previous_batch_index = None;
+            for instance in &draw_data.transparent_instances {
+                let mesh = &draw_data.batches[instance.batch_index].mesh;
+                if previous_batch_index != Some(instance.batch_index) {
+                    set_mesh_buffers(pass, mesh);
+                    previous_batch_index = Some(instance.batch_index);
+                }
+
+                let instance_range = instance.instance_index..(instance.instance_index + 1);
+                for material in mesh.materials.iter().filter(|m| m.is_transparent) {
+                    pass.set_bind_group(1, &material.bind_group, &[]);
+                    pass.draw_indexed(material.index_range.clone(), 0, instance_range.clone());
+                }
+            }
+
+            return Ok(());
+        }
+
         let pipeline = pools.render_pipelines.get_resource(pipeline_handle)?;
 
         pass.set_pipeline(pipeline);
@@ -446,29 +631,7 @@ impl Renderer for MeshRenderer {
                 continue;
             }
 
-            let vertex_buffer_combined = &mesh_batch.mesh.vertex_buffer_combined;
-            let index_buffer = &mesh_batch.mesh.index_buffer;
-
-            pass.set_vertex_buffer(
-                1,
-                vertex_buffer_combined.slice(mesh_batch.mesh.vertex_buffer_positions_range.clone()),
-            );
-            pass.set_vertex_buffer(
-                2,
-                vertex_buffer_combined.slice(mesh_batch.mesh.vertex_buffer_colors_range.clone()),
-            );
-            pass.set_vertex_buffer(
-                3,
-                vertex_buffer_combined.slice(mesh_batch.mesh.vertex_buffer_normals_range.clone()),
-            );
-            pass.set_vertex_buffer(
-                4,
-                vertex_buffer_combined.slice(mesh_batch.mesh.vertex_buffer_texcoord_range.clone()),
-            );
-            pass.set_index_buffer(
-                index_buffer.slice(mesh_batch.mesh.index_buffer_range.clone()),
-                wgpu::IndexFormat::Uint32,
-            );
+            set_mesh_buffers(pass, &mesh_batch.mesh);
 
             let num_meshes_to_draw = if phase == DrawPhase::OutlineMask {
                 mesh_batch.count_with_outlines
@@ -480,6 +643,13 @@ impl Renderer for MeshRenderer {
             for material in &mesh_batch.mesh.materials {
                 debug_assert!(num_meshes_to_draw > 0);
 
+                // Transparent materials are drawn in the transparent phases and don't cast shadows.
+                if matches!(phase, DrawPhase::Opaque | DrawPhase::ShadowMap)
+                    && material.is_transparent
+                {
+                    continue;
+                }
+
                 pass.set_bind_group(1, &material.bind_group, &[]);
                 pass.draw_indexed(material.index_range.clone(), 0, instance_range.clone());
             }
@@ -491,3 +661,28 @@ impl Renderer for MeshRenderer {
         Ok(())
     }
 }
+
+fn set_mesh_buffers<'a>(pass: &mut wgpu::RenderPass<'a>, mesh: &'a GpuMesh) {
+    let vertex_buffer_combined = &mesh.vertex_buffer_combined;
+
+    pass.set_vertex_buffer(
+        1,
+        vertex_buffer_combined.slice(mesh.vertex_buffer_positions_range.clone()),
+    );
+    pass.set_vertex_buffer(
+        2,
+        vertex_buffer_combined.slice(mesh.vertex_buffer_colors_range.clone()),
+    );
+    pass.set_vertex_buffer(
+        3,
+        vertex_buffer_combined.slice(mesh.vertex_buffer_normals_range.clone()),
+    );
+    pass.set_vertex_buffer(
+        4,
+        vertex_buffer_combined.slice(mesh.vertex_buffer_texcoord_range.clone()),
+    );
+    pass.set_index_buffer(
+        mesh.index_buffer.slice(mesh.index_buffer_range.clone()),
+        wgpu::IndexFormat::Uint32,
+    );
+}
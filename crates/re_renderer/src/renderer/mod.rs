@@ -12,7 +12,8 @@ pub use point_cloud::{
 
 mod depth_cloud;
 pub use self::depth_cloud::{
-    DepthCloud, DepthCloudDepthData, DepthCloudDrawData, DepthCloudRenderer, DepthClouds,
+    DepthCloud, DepthCloudDepthData, DepthCloudDistortion, DepthCloudDrawData, DepthCloudRenderer,
+    DepthClouds,
 };
 
 mod test_triangle;
@@ -23,7 +24,7 @@ pub use rectangles::{RectangleDrawData, TextureFilterMag, TextureFilterMin, Text
 
 mod mesh_renderer;
 pub(crate) use mesh_renderer::MeshRenderer;
-pub use mesh_renderer::{MeshDrawData, MeshInstance};
+pub use mesh_renderer::{MeshDrawData, MeshInstance, MeshShading};
 
 mod compositor;
 pub(crate) use compositor::CompositorDrawData;
@@ -33,7 +34,7 @@ pub use debug_overlay::{DebugOverlayDrawData, DebugOverlayRenderer};
 
 use crate::{
     context::{RenderContext, SharedRendererData},
-    draw_phases::DrawPhase,
+    draw_phases::{DrawPhase, TransparencySortView},
     include_shader_module,
     wgpu_resources::WgpuResourcePools,
     FileResolver, FileSystem,
@@ -45,6 +46,15 @@ use crate::{
 /// TODO(andreas): Add a mechanism to validate this.
 pub trait DrawData {
     type Renderer: Renderer<RendererDrawData = Self> + Send + Sync;
+
+    /// Sorts all transparent content back to front for the given view.
+    ///
+    /// Returns the depth of the furthest transparent object (see [`TransparencySortView::depth`]),
+    /// which is used to order different draw data relative to each other in [`DrawPhase::Transparent`].
+    /// `None` if there is no transparent content.
+    fn sort_transparent_back_to_front(&mut self, _view: &TransparencySortView) -> Option<f32> {
+        None
+    }
 }
 
 /// A Renderer encapsulate the knowledge of how to render a certain kind of primitives.
@@ -12,12 +12,19 @@
 //! For WebGL compatibility, data is uploaded as textures. Color is stored in a separate srgb texture, meaning
 //! that srgb->linear conversion happens on texture load.
 //!
+//! Batches containing points with a color alpha below 1 are considered transparent and are drawn in the
+//! transparent phases instead of [`DrawPhase::Opaque`].
+//! Sorting for these happens per batch, not per point.
+//!
 
 use std::{num::NonZeroU64, ops::Range};
 
 use crate::{
     allocator::create_and_fill_uniform_buffer_batch,
-    draw_phases::{DrawPhase, OutlineMaskProcessor, PickingLayerObjectId, PickingLayerProcessor},
+    draw_phases::{
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor,
+        PickingLayerObjectId, PickingLayerProcessor, TransparencySortView,
+    },
     include_shader_module, DebugLabel, OutlineMaskPreference, PointCloudBuilder,
 };
 use bitflags::bitflags;
@@ -49,6 +56,12 @@ bitflags! {
     pub struct PointCloudBatchFlags : u32 {
         /// If true, we shade all points in the batch like spheres.
         const ENABLE_SHADING = 0b0001;
+
+        /// If true, the batch is drawn in the transparent phases.
+        ///
+        /// Only used on the CPU side.
+        /// Set automatically by [`PointCloudBuilder`] whenever a color with alpha below 1 is added to the batch.
+        const TRANSPARENT = 0b0010;
     }
 }
 
@@ -91,6 +104,9 @@ struct PointCloudBatch {
     bind_group: GpuBindGroup,
     vertex_range: Range<u32>,
     active_phases: EnumSet<DrawPhase>,
+
+    /// Center of all points in the batch in world space, used for sorting transparent batches.
+    center_in_world: glam::Vec3,
 }
 
 /// A point cloud drawing operation.
@@ -104,6 +120,15 @@ pub struct PointCloudDrawData {
 
 impl DrawData for PointCloudDrawData {
     type Renderer = PointCloudRenderer;
+
+    fn sort_transparent_back_to_front(&mut self, view: &TransparencySortView) -> Option<f32> {
+        // Draw order doesn't matter for any of the other phases, so we can just sort all batches.
+        view.sort_back_to_front(&mut self.batches, |batch| batch.center_in_world);
+        self.batches
+            .iter()
+            .find(|batch| batch.active_phases.contains(DrawPhase::Transparent))
+            .map(|batch| view.depth(batch.center_in_world))
+    }
 }
 
 /// Data that is valid for a batch of point cloud points.
@@ -439,18 +464,42 @@ impl PointCloudDrawData {
             {
                 let point_vertex_range_end = (start_point_for_next_batch + batch_info.point_count)
                     .min(Self::MAX_NUM_POINTS as u32);
-                let mut active_phases = enum_set![DrawPhase::Opaque | DrawPhase::PickingLayer];
+                let mut active_phases =
+                    if batch_info.flags.contains(PointCloudBatchFlags::TRANSPARENT) {
+                        enum_set![
+                            DrawPhase::Transparent
+                                | DrawPhase::TransparentOrderIndependent
+                                | DrawPhase::PickingLayer
+                        ]
+                    } else {
+                        enum_set![DrawPhase::Opaque | DrawPhase::PickingLayer]
+                    };
                 // Does the entire batch participate in the outline mask phase?
                 if batch_info.overall_outline_mask_ids.is_some() {
                     active_phases.insert(DrawPhase::OutlineMask);
                 }
 
+                let batch_vertices = vertices
+                    .get(start_point_for_next_batch as usize..point_vertex_range_end as usize)
+                    .unwrap_or_default();
+                let center_in_world = if batch_vertices.is_empty() {
+                    batch_info.world_from_obj.transform_point3(glam::Vec3::ZERO)
+                } else {
+                    let center_in_obj = batch_vertices
+                        .iter()
+                        .map(|vertex| vertex.position)
+                        .sum::<glam::Vec3>()
+                        / batch_vertices.len() as f32;
+                    batch_info.world_from_obj.transform_point3(center_in_obj)
+                };
+
                 batches_internal.push(point_renderer.create_point_cloud_batch(
                     ctx,
                     batch_info.label.clone(),
                     uniform_buffer_binding,
                     start_point_for_next_batch..point_vertex_range_end,
                     active_phases,
+                    center_in_world,
                 ));
 
                 for (range, _) in &batch_info.additional_outline_mask_ids_vertex_ranges {
@@ -460,6 +509,7 @@ impl PointCloudDrawData {
                         uniform_buffer_bindings_mask_only_batches.next().unwrap(),
                         range.clone(),
                         enum_set![DrawPhase::OutlineMask],
+                        center_in_world,
                     ));
                 }
 
@@ -482,6 +532,8 @@ impl PointCloudDrawData {
 
 pub struct PointCloudRenderer {
     render_pipeline_color: GpuRenderPipelineHandle,
+    render_pipeline_color_transparent: GpuRenderPipelineHandle,
+    render_pipeline_color_order_independent_transparent: GpuRenderPipelineHandle,
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     bind_group_layout_all_points: GpuBindGroupLayoutHandle,
@@ -496,6 +548,7 @@ impl PointCloudRenderer {
         uniform_buffer_binding: BindGroupEntry,
         vertex_range: Range<u32>,
         active_phases: EnumSet<DrawPhase>,
+        center_in_world: glam::Vec3,
     ) -> PointCloudBatch {
         // TODO(andreas): There should be only a single bindgroup with dynamic indices for all batches.
         //                  (each batch would then know which dynamic indices to use in the bindgroup)
@@ -513,6 +566,7 @@ impl PointCloudRenderer {
             bind_group,
             vertex_range: (vertex_range.start * 6)..(vertex_range.end * 6),
             active_phases,
+            center_in_world,
         }
     }
 }
@@ -524,6 +578,8 @@ impl Renderer for PointCloudRenderer {
         &[
             DrawPhase::OutlineMask,
             DrawPhase::Opaque,
+            DrawPhase::Transparent,
+            DrawPhase::TransparentOrderIndependent,
             DrawPhase::PickingLayer,
         ]
     }
@@ -652,6 +708,42 @@ impl Renderer for PointCloudRenderer {
             &pools.pipeline_layouts,
             &pools.shader_modules,
         );
+        let render_pipeline_color_transparent = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: "PointCloudRenderer::render_pipeline_color_transparent".into(),
+                fragment_entrypoint: "fs_main_transparent".into(),
+                render_targets: smallvec![Some(wgpu::ColorTargetState {
+                    format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                // Coverage is folded into alpha instead.
+                multisample: ViewBuilder::MAIN_TARGET_DEFAULT_MSAA_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
+        let render_pipeline_color_order_independent_transparent =
+            pools.render_pipelines.get_or_create(
+                device,
+                &RenderPipelineDesc {
+                    label:
+                        "PointCloudRenderer::render_pipeline_color_order_independent_transparent"
+                            .into(),
+                    fragment_entrypoint: "fs_main_order_independent_transparent".into(),
+                    render_targets: OrderIndependentTransparencyProcessor::render_targets()
+                        .into_iter()
+                        .collect(),
+                    depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                    multisample: ViewBuilder::MAIN_TARGET_DEFAULT_MSAA_STATE,
+                    ..render_pipeline_desc_color.clone()
+                },
+                &pools.pipeline_layouts,
+                &pools.shader_modules,
+            );
         let render_pipeline_picking_layer = pools.render_pipelines.get_or_create(
             device,
             &RenderPipelineDesc {
@@ -684,6 +776,8 @@ impl Renderer for PointCloudRenderer {
 
         PointCloudRenderer {
             render_pipeline_color,
+            render_pipeline_color_transparent,
+            render_pipeline_color_order_independent_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout_all_points,
@@ -704,6 +798,14 @@ impl Renderer for PointCloudRenderer {
                 &draw_data.bind_group_all_points_outline_mask,
             ),
             DrawPhase::Opaque => (self.render_pipeline_color, &draw_data.bind_group_all_points),
+            DrawPhase::Transparent => (
+                self.render_pipeline_color_transparent,
+                &draw_data.bind_group_all_points,
+            ),
+            DrawPhase::TransparentOrderIndependent => (
+                self.render_pipeline_color_order_independent_transparent,
+                &draw_data.bind_group_all_points,
+            ),
             DrawPhase::PickingLayer => (
                 self.render_pipeline_picking_layer,
                 &draw_data.bind_group_all_points,
@@ -1,23 +1,30 @@
 //! Renderer that makes it easy to draw textured 2d rectangles with transparency
 //!
-//! Transparency: (TODO(andreas):)
-//! We're not performing any sorting on transparency yet, so the transparent rectangles pretty much
-//! only work correctly when they are directly layered in front of another opaque rectangle.
-//! We do *not* disable depth write.
+//! Transparency:
+//! Rectangles with a [`TexturedRect::multiplicative_tint`] alpha below one are drawn in the transparent phases,
+//! sorted back to front by their center, and don't write depth.
+//! All other rectangles are drawn in the opaque phase, but still blended with what's behind them,
+//! which is enough for textures with (partially) transparent texels that are layered in front of an opaque rectangle.
 //!
 //! Implementation details:
 //! We assume the standard usecase are individual textured rectangles.
 //! Since we're not allowed to bind many textures at once (no widespread bindless support!),
 //! we are forced to have individual bind groups per rectangle and thus a draw call per rectangle.
+//!
+//! Textures that are too large for the gpu are split up into tiles by the texture manager.
+//! Each tile is drawn as a separate rectangle covering the respective part of the original rectangle.
 
 use smallvec::smallvec;
 
 use crate::{
     allocator::create_and_fill_uniform_buffer_batch,
     depth_offset::DepthOffset,
-    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    draw_phases::{
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor,
+        TransparencySortView,
+    },
     include_shader_module,
-    resource_managers::{GpuTexture2DHandle, ResourceManagerError},
+    resource_managers::{GpuTexture2DHandle, GpuTextureTile, ResourceManagerError},
     view_builder::ViewBuilder,
     wgpu_resources::{
         BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
@@ -42,10 +49,11 @@ mod gpu_data {
         pub extent_u: wgpu_buffer_types::Vec3RowPadded,
         pub extent_v: wgpu_buffer_types::Vec3Unpadded,
         pub depth_offset: f32,
+        pub homogeneous_w: wgpu_buffer_types::Vec3RowPadded,
         pub multiplicative_tint: crate::Rgba,
         pub outline_mask: wgpu_buffer_types::UVec2RowPadded,
 
-        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 5],
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 6],
     }
 }
 
@@ -62,7 +70,12 @@ pub enum TextureFilterMag {
 pub enum TextureFilterMin {
     Linear,
     Nearest,
-    // TODO(andreas): Offer mipmapping here?
+
+    /// Trilinear filtering, blending linearly filtered samples of the two closest mip levels.
+    ///
+    /// Behaves like [`TextureFilterMin::Linear`] for textures without mipmaps,
+    /// see [`crate::resource_managers::Texture2DCreationDesc::generate_mip_maps`].
+    LinearMipmapLinear,
 }
 
 pub struct TexturedRect {
@@ -75,6 +88,13 @@ pub struct TexturedRect {
     /// Vector that spans up the rectangle from its top left corner along the v axis of the texture.
     pub extent_v: glam::Vec3,
 
+    /// Homogeneous w coordinate of the top left corner, extent u and extent v respectively.
+    ///
+    /// Allows drawing the rectangle with a projective transformation (like a homography) applied,
+    /// in which case the corners are no longer spanning a parallelogram.
+    /// For regular rectangles this is `(1, 0, 0)`.
+    pub homogeneous_w: glam::Vec3,
+
     /// Texture that fills the rectangle
     pub texture: GpuTexture2DHandle,
 
@@ -82,6 +102,8 @@ pub struct TexturedRect {
     pub texture_filter_minification: TextureFilterMin,
 
     /// Tint that is multiplied to the rect, supports pre-multiplied alpha.
+    ///
+    /// If the alpha is below one, the rectangle is drawn as a transparent object.
     pub multiplicative_tint: Rgba,
 
     pub depth_offset: DepthOffset,
@@ -96,6 +118,7 @@ impl Default for TexturedRect {
             top_left_corner_position: glam::Vec3::ZERO,
             extent_u: glam::Vec3::ZERO,
             extent_v: glam::Vec3::ZERO,
+            homogeneous_w: glam::Vec3::X,
             texture: GpuTexture2DHandle::invalid(),
             texture_filter_magnification: TextureFilterMag::Nearest,
             texture_filter_minification: TextureFilterMin::Linear,
@@ -106,19 +129,56 @@ impl Default for TexturedRect {
     }
 }
 
+impl TexturedRect {
+    /// Center of the rectangle in world space.
+    pub fn center(&self) -> glam::Vec3 {
+        self.point_at(glam::vec2(0.5, 0.5))
+    }
+
+    /// The four corners in world space, in the order top left, top right, bottom left, bottom right.
+    pub fn corners(&self) -> [glam::Vec3; 4] {
+        [
+            self.point_at(glam::vec2(0.0, 0.0)),
+            self.point_at(glam::vec2(1.0, 0.0)),
+            self.point_at(glam::vec2(0.0, 1.0)),
+            self.point_at(glam::vec2(1.0, 1.0)),
+        ]
+    }
+
+    /// Position in world space of the given texture coordinate.
+    pub fn point_at(&self, texcoord: glam::Vec2) -> glam::Vec3 {
+        let position =
+            self.top_left_corner_position + self.extent_u * texcoord.x + self.extent_v * texcoord.y;
+        let w = self.homogeneous_w.x
+            + self.homogeneous_w.y * texcoord.x
+            + self.homogeneous_w.z * texcoord.y;
+        position / w
+    }
+}
+
 #[derive(Clone)]
 struct RectangleInstance {
     bind_group: GpuBindGroup,
     draw_outline_mask: bool,
+
+    /// Center in world space, used for sorting transparent rectangles.
+    center: glam::Vec3,
 }
 
 #[derive(Clone)]
 pub struct RectangleDrawData {
-    instances: Vec<RectangleInstance>,
+    opaque_instances: Vec<RectangleInstance>,
+
+    /// Instances with transparency, after [`DrawData::sort_transparent_back_to_front`] in back to front order.
+    transparent_instances: Vec<RectangleInstance>,
 }
 
 impl DrawData for RectangleDrawData {
     type Renderer = RectangleRenderer;
+
+    fn sort_transparent_back_to_front(&mut self, view: &TransparencySortView) -> Option<f32> {
+        view.sort_back_to_front(&mut self.transparent_instances, |instance| instance.center)
+    }
 }
 
 impl RectangleDrawData {
@@ -138,30 +198,50 @@ impl RectangleDrawData {
 
         if rectangles.is_empty() {
             return Ok(RectangleDrawData {
-                instances: Vec::new(),
+                opaque_instances: Vec::new(),
+                transparent_instances: Vec::new(),
             });
         }
 
+        // Rectangles with tiled textures are split up into one rectangle per tile.
+        let rectangle_tiles = rectangles
+            .iter()
+            .map(|rectangle| {
+                ctx.texture_manager_2d
+                    .get_tiles(&rectangle.texture)
+                    .map(|tiles| (rectangle, tiles))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let rectangle_tiles = rectangle_tiles
+            .iter()
+            .flat_map(|(rectangle, tiles)| tiles.iter().map(move |tile| (*rectangle, tile)))
+            .collect::<Vec<_>>();
+
         let uniform_buffer_bindings = create_and_fill_uniform_buffer_batch(
             ctx,
             "rectangle uniform buffers".into(),
-            rectangles.iter().map(|rectangle| gpu_data::UniformBuffer {
-                top_left_corner_position: rectangle.top_left_corner_position.into(),
-                extent_u: rectangle.extent_u.into(),
-                extent_v: rectangle.extent_v.into(),
-                depth_offset: rectangle.depth_offset as f32,
-                multiplicative_tint: rectangle.multiplicative_tint,
-                outline_mask: rectangle.outline_mask.0.unwrap_or_default().into(),
-                end_padding: Default::default(),
+            rectangle_tiles.iter().map(|(rectangle, tile)| {
+                let (top_left_corner_position, extent_u, extent_v) = tile_rect(rectangle, tile);
+                gpu_data::UniformBuffer {
+                    top_left_corner_position: top_left_corner_position.truncate().into(),
+                    extent_u: extent_u.truncate().into(),
+                    extent_v: extent_v.truncate().into(),
+                    depth_offset: rectangle.depth_offset as f32,
+                    homogeneous_w: glam::vec3(top_left_corner_position.w, extent_u.w, extent_v.w)
+                        .into(),
+                    multiplicative_tint: rectangle.multiplicative_tint,
+                    outline_mask: rectangle.outline_mask.0.unwrap_or_default().into(),
+                    end_padding: Default::default(),
+                }
             }),
         );
 
-        let mut instances = Vec::with_capacity(rectangles.len());
-        for (rectangle, uniform_buffer) in
-            rectangles.iter().zip(uniform_buffer_bindings.into_iter())
+        let mut opaque_instances = Vec::with_capacity(rectangle_tiles.len());
+        let mut transparent_instances = Vec::new();
+        for ((rectangle, tile), uniform_buffer) in rectangle_tiles
+            .iter()
+            .zip(uniform_buffer_bindings.into_iter())
         {
-            let texture = ctx.texture_manager_2d.get(&rectangle.texture)?;
-
             let sampler = ctx.gpu_resources.samplers.get_or_create(
                 &ctx.device,
                 &SamplerDesc {
@@ -176,15 +256,22 @@ impl RectangleDrawData {
                         TextureFilterMag::Nearest => wgpu::FilterMode::Nearest,
                     },
                     min_filter: match rectangle.texture_filter_minification {
-                        TextureFilterMin::Linear => wgpu::FilterMode::Linear,
+                        TextureFilterMin::Linear | TextureFilterMin::LinearMipmapLinear => {
+                            wgpu::FilterMode::Linear
+                        }
                         TextureFilterMin::Nearest => wgpu::FilterMode::Nearest,
                     },
-                    mipmap_filter: wgpu::FilterMode::Nearest,
+                    mipmap_filter: match rectangle.texture_filter_minification {
+                        TextureFilterMin::LinearMipmapLinear => wgpu::FilterMode::Linear,
+                        TextureFilterMin::Linear | TextureFilterMin::Nearest => {
+                            wgpu::FilterMode::Nearest
+                        }
+                    },
                     ..Default::default()
                 },
             );
 
-            instances.push(RectangleInstance {
+            let instance = RectangleInstance {
                 bind_group: ctx.gpu_resources.bind_groups.alloc(
                     &ctx.device,
                     &ctx.gpu_resources,
@@ -192,22 +279,54 @@ impl RectangleDrawData {
                         label: "RectangleInstance::bind_group".into(),
                         entries: smallvec![
                             uniform_buffer,
-                            BindGroupEntry::DefaultTextureView(texture.handle),
+                            BindGroupEntry::DefaultTextureView(tile.texture.handle),
                             BindGroupEntry::Sampler(sampler)
                         ],
                         layout: rectangle_renderer.bind_group_layout,
                     },
                 ),
                 draw_outline_mask: rectangle.outline_mask.is_some(),
-            });
+                center: rectangle.center(),
+            };
+            if rectangle.multiplicative_tint.a() < 1.0 {
+                transparent_instances.push(instance);
+            } else {
+                opaque_instances.push(instance);
+            }
         }
 
-        Ok(RectangleDrawData { instances })
+        Ok(RectangleDrawData {
+            opaque_instances,
+            transparent_instances,
+        })
     }
 }
 
+/// Top left corner, extent u & extent v of the part of a rectangle that is covered by the given texture tile.
+///
+/// All in homogeneous coordinates, see [`TexturedRect::homogeneous_w`].
+fn tile_rect(
+    rectangle: &TexturedRect,
+    tile: &GpuTextureTile,
+) -> (glam::Vec4, glam::Vec4, glam::Vec4) {
+    let top_left_corner_position = rectangle
+        .top_left_corner_position
+        .extend(rectangle.homogeneous_w.x);
+    let extent_u = rectangle.extent_u.extend(rectangle.homogeneous_w.y);
+    let extent_v = rectangle.extent_v.extend(rectangle.homogeneous_w.z);
+
+    let uv_extent = tile.uv_max - tile.uv_min;
+    (
+        top_left_corner_position + extent_u * tile.uv_min.x + extent_v * tile.uv_min.y,
+        extent_u * uv_extent.x,
+        extent_v * uv_extent.y,
+    )
+}
+
 pub struct RectangleRenderer {
     render_pipeline_color: GpuRenderPipelineHandle,
+    render_pipeline_transparent: GpuRenderPipelineHandle,
+    render_pipeline_order_independent_transparent: GpuRenderPipelineHandle,
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     bind_group_layout: GpuBindGroupLayoutHandle,
@@ -289,7 +408,7 @@ impl Renderer for RectangleRenderer {
             vertex_buffers: smallvec![],
             render_targets: smallvec![Some(wgpu::ColorTargetState {
                 format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
-                // TODO(andreas): have two render pipelines, an opaque one and a transparent one. Transparent shouldn't write depth!
+                // Blend even when opaque, so that textures with transparent texels can be layered over other rectangles.
                 blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                 write_mask: wgpu::ColorWrites::ALL,
             })],
@@ -307,6 +426,30 @@ impl Renderer for RectangleRenderer {
             &pools.pipeline_layouts,
             &pools.shader_modules,
         );
+        let render_pipeline_transparent = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: "RectangleRenderer::render_pipeline_transparent".into(),
+                depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
+        let render_pipeline_order_independent_transparent = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: "RectangleRenderer::render_pipeline_order_independent_transparent".into(),
+                fragment_entrypoint: "fs_main_order_independent_transparent".into(),
+                render_targets: OrderIndependentTransparencyProcessor::render_targets()
+                    .into_iter()
+                    .collect(),
+                depth_stencil: ViewBuilder::MAIN_TARGET_TRANSPARENT_DEPTH_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
         let render_pipeline_picking_layer = pools.render_pipelines.get_or_create(
             device,
             &RenderPipelineDesc {
@@ -338,6 +481,8 @@ impl Renderer for RectangleRenderer {
 
         RectangleRenderer {
             render_pipeline_color,
+            render_pipeline_transparent,
+            render_pipeline_order_independent_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout,
@@ -352,21 +497,50 @@ impl Renderer for RectangleRenderer {
         draw_data: &'a Self::RendererDrawData,
     ) -> anyhow::Result<()> {
         crate::profile_function!();
-        if draw_data.instances.is_empty() {
-            return Ok(());
-        }
 
-        let pipeline_handle = match phase {
-            DrawPhase::Opaque => self.render_pipeline_color,
-            DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
-            DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+        let (pipeline_handle, instances) = match phase {
+            DrawPhase::Opaque => (
+                self.render_pipeline_color,
+                itertools::Either::Left(draw_data.opaque_instances.iter()),
+            ),
+            DrawPhase::Transparent => (
+                self.render_pipeline_transparent,
+                itertools::Either::Left(draw_data.transparent_instances.iter()),
+            ),
+            DrawPhase::TransparentOrderIndependent => (
+                self.render_pipeline_order_independent_transparent,
+                itertools::Either::Left(draw_data.transparent_instances.iter()),
+            ),
+            DrawPhase::PickingLayer => (
+                self.render_pipeline_picking_layer,
+                itertools::Either::Right(
+                    draw_data
+                        .opaque_instances
+                        .iter()
+                        .chain(draw_data.transparent_instances.iter()),
+                ),
+            ),
+            DrawPhase::OutlineMask => (
+                self.render_pipeline_outline_mask,
+                itertools::Either::Right(
+                    draw_data
+                        .opaque_instances
+                        .iter()
+                        .chain(draw_data.transparent_instances.iter()),
+                ),
+            ),
             _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
         };
+        let mut instances = instances.peekable();
+        if instances.peek().is_none() {
+            return Ok(());
+        }
+
         let pipeline = pools.render_pipelines.get_resource(pipeline_handle)?;
 
         pass.set_pipeline(pipeline);
 
-        for rectangles in &draw_data.instances {
+        for rectangles in instances {
             if phase == DrawPhase::OutlineMask && !rectangles.draw_outline_mask {
                 continue;
             }
@@ -378,10 +552,11 @@ impl Renderer for RectangleRenderer {
     }
 
     fn participated_phases() -> &'static [DrawPhase] {
-        // TODO(andreas): This a hack. We have both opaque and transparent.
         &[
             DrawPhase::OutlineMask,
             DrawPhase::Opaque,
+            DrawPhase::Transparent,
+            DrawPhase::TransparentOrderIndependent,
             DrawPhase::PickingLayer,
         ]
     }
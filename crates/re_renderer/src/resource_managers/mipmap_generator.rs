@@ -0,0 +1,195 @@
+use smallvec::smallvec;
+
+use crate::{
+    include_shader_module,
+    renderer::screen_triangle_vertex_shader,
+    wgpu_resources::{
+        BindGroupLayoutDesc, GpuBindGroupLayoutHandle, GpuPipelineLayoutHandle, GpuSamplerHandle,
+        GpuShaderModuleHandle, GpuTexture, PipelineLayoutDesc, PoolError, RenderPipelineDesc,
+        SamplerDesc, WgpuResourcePools,
+    },
+    DebugLabel, FileResolver, FileSystem,
+};
+
+/// Fills all mip levels of a texture from its first level by repeatedly downsampling on the gpu.
+pub(crate) struct MipmapGenerator {
+    bind_group_layout: GpuBindGroupLayoutHandle,
+    pipeline_layout: GpuPipelineLayoutHandle,
+    vertex_shader: GpuShaderModuleHandle,
+    fragment_shader: GpuShaderModuleHandle,
+    sampler: GpuSamplerHandle,
+}
+
+impl MipmapGenerator {
+    pub fn new<Fs: FileSystem>(
+        pools: &mut WgpuResourcePools,
+        device: &wgpu::Device,
+        resolver: &mut FileResolver<Fs>,
+    ) -> Self {
+        crate::profile_function!();
+
+        let bind_group_layout = pools.bind_group_layouts.get_or_create(
+            device,
+            &BindGroupLayoutDesc {
+                label: "MipmapGenerator::bind_group_layout".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            },
+        );
+        let pipeline_layout = pools.pipeline_layouts.get_or_create(
+            device,
+            &PipelineLayoutDesc {
+                label: "MipmapGenerator::pipeline_layout".into(),
+                entries: vec![bind_group_layout],
+            },
+            &pools.bind_group_layouts,
+        );
+
+        let vertex_shader = screen_triangle_vertex_shader(pools, device, resolver);
+        let fragment_shader = pools.shader_modules.get_or_create(
+            device,
+            resolver,
+            &include_shader_module!("../../shader/mipmap_downsample.wgsl"),
+        );
+
+        let sampler = pools.samplers.get_or_create(
+            device,
+            &SamplerDesc {
+                label: "MipmapGenerator::sampler".into(),
+                mag_filter: wgpu::FilterMode::Linear,
+                min_filter: wgpu::FilterMode::Linear,
+                ..Default::default()
+            },
+        );
+
+        Self {
+            bind_group_layout,
+            pipeline_layout,
+            vertex_shader,
+            fragment_shader,
+            sampler,
+        }
+    }
+
+    /// Whether mipmaps can be generated for textures of the given format.
+    ///
+    /// The format needs to be both filterable and renderable.
+    pub fn supports_format(format: wgpu::TextureFormat) -> bool {
+        let features = format.describe().guaranteed_format_features;
+        features
+            .allowed_usages
+            .contains(wgpu::TextureUsages::RENDER_ATTACHMENT)
+            && features
+                .flags
+                .contains(wgpu::TextureFormatFeatureFlags::FILTERABLE)
+    }
+
+    /// Number of mip levels a full mip chain for a texture of the given size has.
+    pub fn num_mip_levels(width: u32, height: u32) -> u32 {
+        u32::BITS - width.max(height).max(1).leading_zeros()
+    }
+
+    /// Records the generation of all mip levels (except the first) into the given encoder.
+    ///
+    /// The texture needs to be created with [`wgpu::TextureUsages::RENDER_ATTACHMENT`] and
+    /// [`wgpu::TextureUsages::TEXTURE_BINDING`] and a format for which [`Self::supports_format`] is true.
+    pub fn generate(
+        &self,
+        pools: &mut WgpuResourcePools,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &GpuTexture,
+    ) -> Result<(), PoolError> {
+        crate::profile_function!();
+
+        let desc = &texture.creation_desc;
+
+        let render_pipeline = pools.render_pipelines.get_or_create(
+            device,
+            &RenderPipelineDesc {
+                label: format!("MipmapGenerator::render_pipeline {:?}", desc.format).into(),
+                pipeline_layout: self.pipeline_layout,
+                vertex_entrypoint: "main".into(),
+                vertex_handle: self.vertex_shader,
+                fragment_entrypoint: "main".into(),
+                fragment_handle: self.fragment_shader,
+                vertex_buffers: smallvec![],
+                render_targets: smallvec![Some(desc.format.into())],
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+            },
+            &pools.pipeline_layouts,
+            &pools.shader_modules,
+        );
+        let render_pipeline = pools.render_pipelines.get_resource(render_pipeline)?;
+        let bind_group_layout = pools
+            .bind_group_layouts
+            .get_resource(self.bind_group_layout)?;
+        let sampler = pools.samplers.get_resource(self.sampler)?;
+
+        // Bind groups and views are specific to a single mip level of a single texture,
+        // so there is no point in going through the resource pools for them.
+        let mip_views = (0..desc.mip_level_count)
+            .map(|mip_level| {
+                texture.texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: None,
+                    base_mip_level: mip_level,
+                    mip_level_count: std::num::NonZeroU32::new(1),
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (source_view, target_view) in mip_views.iter().zip(mip_views.iter().skip(1)) {
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(source_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                ],
+            });
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: DebugLabel::from(format!("{} - mipmap generation", desc.label)).get(),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+            pass.set_pipeline(render_pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        Ok(())
+    }
+}
@@ -9,7 +9,10 @@
 mod mesh_manager;
 pub use mesh_manager::{GpuMeshHandle, MeshManager};
 
+mod mipmap_generator;
+
 mod texture_manager;
+pub(crate) use texture_manager::GpuTextureTile;
 pub use texture_manager::{GpuTexture2DHandle, Texture2DCreationDesc, TextureManager2D};
 
 mod resource_manager;
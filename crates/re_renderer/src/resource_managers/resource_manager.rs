@@ -40,6 +40,9 @@ pub enum ResourceManagerError {
     #[error("The passed resource handle was null")]
     NullHandle,
 
+    #[error("The passed texture is split up into several tiles, which isn't supported here")]
+    TiledTexture,
+
     #[error("Failed accessing resource pools")]
     ResourcePoolError(PoolError),
 
@@ -3,24 +3,53 @@ use std::{num::NonZeroU32, sync::Arc};
 use ahash::{HashMap, HashSet};
 
 use crate::{
-    wgpu_resources::{GpuTexture, GpuTexturePool, TextureDesc},
-    DebugLabel,
+    wgpu_resources::{GpuTexture, GpuTexturePool, TextureDesc, WgpuResourcePools},
+    DebugLabel, FileResolver, FileSystem,
 };
 
-use super::ResourceManagerError;
+use super::{mipmap_generator::MipmapGenerator, ResourceManagerError};
 
 /// Handle to a 2D resource.
 ///
-/// Currently, this is solely a more strongly typed regular gpu texture handle.
+/// Usually, this is solely a more strongly typed regular gpu texture handle.
 /// Since all textures have "long lived" behavior (no temp allocation, alive until unused),
 /// there is no difference as with buffer reliant data like meshes or most contents of draw-data.
+///
+/// Images that exceed the maximum texture size of the device are split up into several tiles,
+/// see [`GpuTexture2DHandle::is_tiled`].
+#[derive(Clone)]
+pub struct GpuTexture2DHandle(Option<GpuTexture2D>);
+
 #[derive(Clone)]
-pub struct GpuTexture2DHandle(Option<GpuTexture>);
+enum GpuTexture2D {
+    Single(GpuTexture),
+    Tiled(Arc<[GpuTextureTile]>),
+}
+
+/// A part of a texture that was too large to be stored as a single gpu texture.
+#[derive(Clone)]
+pub(crate) struct GpuTextureTile {
+    pub texture: GpuTexture,
+
+    /// Minimum texture coordinate covered by this tile (top left corner).
+    pub uv_min: glam::Vec2,
+
+    /// Maximum texture coordinate covered by this tile (bottom right corner).
+    pub uv_max: glam::Vec2,
+}
 
 impl GpuTexture2DHandle {
     pub fn invalid() -> Self {
         Self(None)
     }
+
+    /// Whether the texture is made up of several gpu textures.
+    ///
+    /// Tiled textures can only be used by renderers that explicitly support them,
+    /// e.g. [`crate::renderer::TexturedRect`].
+    pub fn is_tiled(&self) -> bool {
+        matches!(self.0, Some(GpuTexture2D::Tiled(_)))
+    }
 }
 
 /// Data required to create a texture 2d resource.
@@ -36,7 +65,12 @@ pub struct Texture2DCreationDesc<'a> {
     pub format: wgpu::TextureFormat,
     pub width: u32,
     pub height: u32,
-    //generate_mip_maps: bool, // TODO(andreas): generate mipmaps!
+
+    /// Whether to generate a full mip chain for the texture.
+    ///
+    /// Mipmaps are generated on the gpu and only for formats that are both filterable and renderable,
+    /// for all other formats this setting is ignored.
+    pub generate_mip_maps: bool,
 }
 
 impl<'a> Texture2DCreationDesc<'a> {
@@ -62,6 +96,11 @@ pub struct TextureManager2D {
     white_texture_unorm: GpuTexture2DHandle,
     zeroed_texture_uint: GpuTexture2DHandle,
 
+    mipmap_generator: MipmapGenerator,
+
+    /// Textures whose mip levels need to be filled in before the next submit.
+    textures_pending_mip_generation: Vec<GpuTexture>,
+
     // For convenience to reduce amount of times we need to pass them around
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
@@ -78,47 +117,57 @@ pub struct TextureManager2D {
 }
 
 impl TextureManager2D {
-    pub(crate) fn new(
+    pub(crate) fn new<Fs: FileSystem>(
         device: Arc<wgpu::Device>,
         queue: Arc<wgpu::Queue>,
-        texture_pool: &mut GpuTexturePool,
+        pools: &mut WgpuResourcePools,
+        resolver: &mut FileResolver<Fs>,
     ) -> Self {
         crate::profile_function!();
 
+        let mipmap_generator = MipmapGenerator::new(pools, &device, resolver);
+        let mut textures_pending_mip_generation = Vec::new();
+        let texture_pool = &mut pools.textures;
+
         let white_texture_unorm = Self::create_and_upload_texture(
             &device,
             &queue,
             texture_pool,
+            &mut textures_pending_mip_generation,
             &Texture2DCreationDesc {
                 label: "white pixel - unorm".into(),
                 data: &[255, 255, 255, 255],
                 format: wgpu::TextureFormat::Rgba8Unorm,
                 width: 1,
                 height: 1,
+                generate_mip_maps: false,
             },
         );
 
         // Wgpu zeros out new textures automatically
-        let zeroed_texture_uint = GpuTexture2DHandle(Some(texture_pool.alloc(
-            &device,
-            &TextureDesc {
-                label: "zeroed pixel - uint".into(),
-                format: wgpu::TextureFormat::Rgba8Uint,
-                size: wgpu::Extent3d {
-                    width: 1,
-                    height: 1,
-                    depth_or_array_layers: 1,
+        let zeroed_texture_uint =
+            GpuTexture2DHandle(Some(GpuTexture2D::Single(texture_pool.alloc(
+                &device,
+                &TextureDesc {
+                    label: "zeroed pixel - uint".into(),
+                    format: wgpu::TextureFormat::Rgba8Uint,
+                    size: wgpu::Extent3d {
+                        width: 1,
+                        height: 1,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING,
                 },
-                mip_level_count: 1,
-                sample_count: 1,
-                dimension: wgpu::TextureDimension::D2,
-                usage: wgpu::TextureUsages::TEXTURE_BINDING,
-            },
-        )));
+            ))));
 
         Self {
             white_texture_unorm,
             zeroed_texture_uint,
+            mipmap_generator,
+            textures_pending_mip_generation,
             device,
             queue,
             texture_cache: Default::default(),
@@ -147,9 +196,15 @@ impl TextureManager2D {
         // }
 
         // Currently we don't store any data in the the texture manager.
-        // In the future we might handle (lazy?) mipmap generation in here or keep track of lazy upload processing.
+        // In the future we might keep track of lazy upload processing in here.
 
-        Self::create_and_upload_texture(&self.device, &self.queue, texture_pool, creation_desc)
+        Self::create_and_upload_texture(
+            &self.device,
+            &self.queue,
+            texture_pool,
+            &mut self.textures_pending_mip_generation,
+            creation_desc,
+        )
     }
 
     /// Creates a new 2D texture resource and schedules data upload to the GPU if a texture
@@ -161,7 +216,13 @@ impl TextureManager2D {
         creation_desc: &Texture2DCreationDesc<'_>,
     ) -> GpuTexture2DHandle {
         let texture_handle = self.texture_cache.entry(key).or_insert_with(|| {
-            Self::create_and_upload_texture(&self.device, &self.queue, texture_pool, creation_desc)
+            Self::create_and_upload_texture(
+                &self.device,
+                &self.queue,
+                texture_pool,
+                &mut self.textures_pending_mip_generation,
+                creation_desc,
+            )
         });
         self.accessed_textures.insert(key);
         texture_handle.clone()
@@ -174,42 +235,142 @@ impl TextureManager2D {
 
     /// Returns a single pixel white pixel with an rgba8unorm format.
     pub fn white_texture_unorm(&self) -> &GpuTexture {
-        self.white_texture_unorm.0.as_ref().unwrap()
+        match &self.white_texture_unorm.0 {
+            Some(GpuTexture2D::Single(texture)) => texture,
+            _ => unreachable!(),
+        }
     }
 
     /// Returns a single pixel white pixel with an rgba8unorm format.
     pub fn zeroed_texture_uint(&self) -> &GpuTexture {
-        self.zeroed_texture_uint.0.as_ref().unwrap()
+        match &self.zeroed_texture_uint.0 {
+            Some(GpuTexture2D::Single(texture)) => texture,
+            _ => unreachable!(),
+        }
     }
 
     fn create_and_upload_texture(
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         texture_pool: &mut GpuTexturePool,
+        textures_pending_mip_generation: &mut Vec<GpuTexture>,
         creation_desc: &Texture2DCreationDesc<'_>,
     ) -> GpuTexture2DHandle {
         crate::profile_function!();
+
+        let max_texture_dimension = device.limits().max_texture_dimension_2d;
+        if creation_desc.width <= max_texture_dimension
+            && creation_desc.height <= max_texture_dimension
+        {
+            let texture = Self::create_and_upload_tile(
+                device,
+                queue,
+                texture_pool,
+                textures_pending_mip_generation,
+                creation_desc,
+                creation_desc.label.clone(),
+                [0, 0],
+                [creation_desc.width, creation_desc.height],
+            );
+            return GpuTexture2DHandle(Some(GpuTexture2D::Single(texture)));
+        }
+
+        // The image is too large for a single texture, split it up into tiles of the maximum size.
+        // Filtering doesn't cross tile borders, which may cause faint seams when zoomed in with linear filtering.
+        re_log::debug!(
+            "Texture {:?} with resolution {}x{} exceeds the maximum texture size of {max_texture_dimension}, splitting it into tiles.",
+            creation_desc.label,
+            creation_desc.width,
+            creation_desc.height,
+        );
+        let image_size = glam::vec2(creation_desc.width as f32, creation_desc.height as f32);
+        let mut tiles = Vec::new();
+        for y in (0..creation_desc.height).step_by(max_texture_dimension as usize) {
+            for x in (0..creation_desc.width).step_by(max_texture_dimension as usize) {
+                let tile_size = [
+                    max_texture_dimension.min(creation_desc.width - x),
+                    max_texture_dimension.min(creation_desc.height - y),
+                ];
+                let texture = Self::create_and_upload_tile(
+                    device,
+                    queue,
+                    texture_pool,
+                    textures_pending_mip_generation,
+                    creation_desc,
+                    format!("{} - tile {x}x{y}", creation_desc.label).into(),
+                    [x, y],
+                    tile_size,
+                );
+                tiles.push(GpuTextureTile {
+                    texture,
+                    uv_min: glam::vec2(x as f32, y as f32) / image_size,
+                    uv_max: glam::vec2((x + tile_size[0]) as f32, (y + tile_size[1]) as f32)
+                        / image_size,
+                });
+            }
+        }
+
+        GpuTexture2DHandle(Some(GpuTexture2D::Tiled(tiles.into())))
+    }
+
+    /// Creates a texture for a rectangular section of the image described by `creation_desc` and schedules its upload.
+    #[allow(clippy::too_many_arguments)]
+    fn create_and_upload_tile(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture_pool: &mut GpuTexturePool,
+        textures_pending_mip_generation: &mut Vec<GpuTexture>,
+        creation_desc: &Texture2DCreationDesc<'_>,
+        label: DebugLabel,
+        origin: [u32; 2],
+        tile_size: [u32; 2],
+    ) -> GpuTexture {
         let size = wgpu::Extent3d {
-            width: creation_desc.width,
-            height: creation_desc.height,
+            width: tile_size[0],
+            height: tile_size[1],
             depth_or_array_layers: 1,
         };
+
+        let generate_mip_maps = creation_desc.generate_mip_maps
+            && MipmapGenerator::supports_format(creation_desc.format);
+        let (mip_level_count, usage) = if generate_mip_maps {
+            (
+                MipmapGenerator::num_mip_levels(tile_size[0], tile_size[1]),
+                wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_DST
+                    | wgpu::TextureUsages::RENDER_ATTACHMENT,
+            )
+        } else {
+            (
+                1,
+                wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            )
+        };
+
         let texture = texture_pool.alloc(
             device,
             &TextureDesc {
-                label: creation_desc.label.clone(),
+                label,
                 size,
-                mip_level_count: 1, // TODO(andreas)
+                mip_level_count,
                 sample_count: 1,
                 dimension: wgpu::TextureDimension::D2,
                 format: creation_desc.format,
-                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                usage,
             },
         );
 
         let format_info = creation_desc.format.describe();
-        let width_blocks = creation_desc.width / format_info.block_dimensions.0 as u32;
-        let bytes_per_row_unaligned = width_blocks * format_info.block_size as u32;
+        let block_width = format_info.block_dimensions.0 as u32;
+        let block_height = format_info.block_dimensions.1 as u32;
+        let block_size = format_info.block_size as u32;
+        let width_blocks = creation_desc.width / block_width;
+        let bytes_per_row_unaligned = width_blocks * block_size;
+
+        // Tiles read directly from the full image data, skipping everything outside of the tile
+        // by starting at an offset and keeping the row pitch of the full image.
+        let offset = (origin[1] / block_height) as u64 * bytes_per_row_unaligned as u64
+            + (origin[0] / block_width) as u64 * block_size as u64;
 
         // TODO(andreas): Once we have our own temp buffer for uploading, we can do the padding inplace
         // I.e. the only difference will be if we do one memcopy or one memcopy per row, making row padding a nuisance!
@@ -227,7 +388,7 @@ impl TextureManager2D {
             },
             data,
             wgpu::ImageDataLayout {
-                offset: 0,
+                offset,
                 bytes_per_row: Some(
                     NonZeroU32::new(bytes_per_row_unaligned).expect("invalid bytes per row"),
                 ),
@@ -236,22 +397,67 @@ impl TextureManager2D {
             size,
         );
 
-        // TODO(andreas): mipmap generation
+        if generate_mip_maps {
+            textures_pending_mip_generation.push(texture.clone());
+        }
 
-        GpuTexture2DHandle(Some(texture))
+        texture
     }
 
     /// Retrieves gpu handle.
+    ///
+    /// Fails for tiled textures, use [`Self::get_tiles`] for renderers that support them.
     #[allow(clippy::unused_self)]
     pub(crate) fn get(
         &self,
         handle: &GpuTexture2DHandle,
     ) -> Result<GpuTexture, ResourceManagerError> {
-        handle
-            .0
-            .as_ref()
-            .ok_or(ResourceManagerError::NullHandle)
-            .map(|h| h.clone())
+        match handle.0.as_ref().ok_or(ResourceManagerError::NullHandle)? {
+            GpuTexture2D::Single(texture) => Ok(texture.clone()),
+            GpuTexture2D::Tiled(_) => Err(ResourceManagerError::TiledTexture),
+        }
+    }
+
+    /// Retrieves all tiles of a texture.
+    ///
+    /// Textures that weren't split up yield a single tile covering the whole texture.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn get_tiles(
+        &self,
+        handle: &GpuTexture2DHandle,
+    ) -> Result<Arc<[GpuTextureTile]>, ResourceManagerError> {
+        match handle.0.as_ref().ok_or(ResourceManagerError::NullHandle)? {
+            GpuTexture2D::Single(texture) => Ok(Arc::new([GpuTextureTile {
+                texture: texture.clone(),
+                uv_min: glam::Vec2::ZERO,
+                uv_max: glam::Vec2::ONE,
+            }])),
+            GpuTexture2D::Tiled(tiles) => Ok(tiles.clone()),
+        }
+    }
+
+    /// Records mipmap generation for all textures created since the last call.
+    ///
+    /// Texture data is written via the queue, so this needs to be recorded into an encoder
+    /// that is submitted after all texture creations of this frame.
+    pub(crate) fn generate_pending_mipmaps(
+        &mut self,
+        pools: &mut WgpuResourcePools,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        crate::profile_function!();
+
+        for texture in self.textures_pending_mip_generation.drain(..) {
+            if let Err(err) = self
+                .mipmap_generator
+                .generate(pools, &self.device, encoder, &texture)
+            {
+                re_log::error_once!(
+                    "Failed to generate mipmaps for texture {:?}: {err}",
+                    texture.creation_desc.label
+                );
+            }
+        }
     }
 
     pub(crate) fn begin_frame(&mut self, _frame_index: u64) {
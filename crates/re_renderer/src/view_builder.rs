@@ -6,7 +6,9 @@ use crate::{
     allocator::{create_and_fill_uniform_buffer, GpuReadbackIdentifier},
     context::RenderContext,
     draw_phases::{
-        DrawPhase, OutlineConfig, OutlineMaskProcessor, PickingLayerProcessor, ScreenshotProcessor,
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineConfig, OutlineMaskProcessor,
+        PickingLayerProcessor, ScreenshotProcessor, ShadowMapConfig, ShadowMapProcessor,
+        TransparencyMode, TransparencySortView,
     },
     global_bindings::FrameUniformBuffer,
     renderer::{CompositorDrawData, DebugOverlayDrawData, DrawData, Renderer},
@@ -28,6 +30,9 @@ struct QueuedDraw {
     draw_data: Box<dyn std::any::Any + std::marker::Send + std::marker::Sync>,
     renderer_name: &'static str,
     participated_phases: &'static [DrawPhase],
+
+    /// Depth of the furthest transparent object in the draw data, see [`DrawData::sort_transparent_back_to_front`].
+    transparent_sort_depth: Option<f32>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -54,6 +59,8 @@ pub struct ViewBuilder {
     outline_mask_processor: Option<OutlineMaskProcessor>,
     screenshot_processor: Option<ScreenshotProcessor>,
     picking_processor: Option<PickingLayerProcessor>,
+    order_independent_transparency_processor: Option<OrderIndependentTransparencyProcessor>,
+    shadow_map_processor: Option<ShadowMapProcessor>,
 }
 
 struct ViewTargetSetup {
@@ -69,6 +76,15 @@ struct ViewTargetSetup {
     resolution_in_pixel: [u32; 2],
 }
 
+impl ViewTargetSetup {
+    fn transparency_sort_view(&self) -> TransparencySortView {
+        TransparencySortView {
+            camera_position: self.frame_uniform_buffer_content.camera_position,
+            camera_forward: self.frame_uniform_buffer_content.camera_forward,
+        }
+    }
+}
+
 /// [`ViewBuilder`] that can be shared between threads.
 ///
 /// Innermost field is an Option, so it can be consumed for `composite`.
@@ -149,6 +165,48 @@ impl Default for AutoSizeConfig {
     }
 }
 
+/// The single directional light used for shading.
+#[derive(Clone, Copy, Debug)]
+pub struct DirectionalLight {
+    /// Direction towards the light.
+    ///
+    /// In world space, unless [`Self::follow_camera`] is set.
+    pub direction: glam::Vec3,
+
+    /// If true, [`Self::direction`] is given in view space, i.e. the light moves with the camera.
+    ///
+    /// View space uses `RUB` (X=Right, Y=Up, Z=Back).
+    pub follow_camera: bool,
+
+    /// Intensity of the directional light.
+    pub intensity: f32,
+
+    /// Intensity of the ambient light, lighting everything evenly regardless of its orientation.
+    pub ambient_intensity: f32,
+}
+
+impl Default for DirectionalLight {
+    fn default() -> Self {
+        Self {
+            direction: glam::vec3(1.0, 2.0, 0.0),
+            follow_camera: false,
+            intensity: 1.0,
+            ambient_intensity: 0.2,
+        }
+    }
+}
+
+impl DirectionalLight {
+    /// A light coming from above and behind the camera, moving with the camera.
+    pub fn camera_headlight() -> Self {
+        Self {
+            direction: glam::vec3(0.5, 1.0, 1.0),
+            follow_camera: true,
+            ..Default::default()
+        }
+    }
+}
+
 /// Basic configuration for a target view.
 #[derive(Debug, Clone)]
 pub struct TargetConfiguration {
@@ -165,6 +223,15 @@ pub struct TargetConfiguration {
     pub auto_size_config: AutoSizeConfig,
 
     pub outline_config: Option<OutlineConfig>,
+
+    /// How transparent objects are rendered.
+    pub transparency_mode: TransparencyMode,
+
+    /// Light used for shading.
+    pub light: DirectionalLight,
+
+    /// If set, the light casts shadows.
+    pub shadow_map_config: Option<ShadowMapConfig>,
 }
 
 impl Default for TargetConfiguration {
@@ -180,6 +247,9 @@ impl Default for TargetConfiguration {
             pixels_from_point: 1.0,
             auto_size_config: Default::default(),
             outline_config: None,
+            transparency_mode: TransparencyMode::default(),
+            light: DirectionalLight::default(),
+            shadow_map_config: None,
         }
     }
 }
@@ -248,6 +318,25 @@ impl ViewBuilder {
             },
         });
 
+    /// Depth state for transparent objects, reading but not writing depth.
+    pub const MAIN_TARGET_TRANSPARENT_DEPTH_STATE: Option<wgpu::DepthStencilState> =
+        Some(wgpu::DepthStencilState {
+            format: Self::MAIN_TARGET_DEPTH_FORMAT,
+            depth_compare: wgpu::CompareFunction::Greater,
+            depth_write_enabled: false,
+            stencil: wgpu::StencilState {
+                front: wgpu::StencilFaceState::IGNORE,
+                back: wgpu::StencilFaceState::IGNORE,
+                read_mask: 0,
+                write_mask: 0,
+            },
+            bias: wgpu::DepthBiasState {
+                constant: 0,
+                slope_scale: 0.0,
+                clamp: 0.0,
+            },
+        });
+
     pub fn setup_view(
         &mut self,
         ctx: &mut RenderContext,
@@ -306,6 +395,26 @@ impl ViewBuilder {
             )
         });
 
+        self.order_independent_transparency_processor = match config.transparency_mode {
+            TransparencyMode::Sorted => None,
+            TransparencyMode::OrderIndependent => {
+                if OrderIndependentTransparencyProcessor::is_supported(
+                    ctx.shared_renderer_data.config.hardware_tier,
+                ) {
+                    Some(OrderIndependentTransparencyProcessor::new(
+                        ctx,
+                        &config.name,
+                        config.resolution_in_pixel,
+                    ))
+                } else {
+                    re_log::warn_once!(
+                        "Order independent transparency is not supported on this hardware, falling back to sorted transparency."
+                    );
+                    None
+                }
+            }
+        };
+
         self.queue_draw(&CompositorDrawData::new(
             ctx,
             &main_target_resolved,
@@ -425,6 +534,20 @@ impl ViewBuilder {
             config.auto_size_config.line_radius
         };
 
+        let light_direction = if config.light.follow_camera {
+            config
+                .view_from_world
+                .inverse()
+                .transform_vector3(config.light.direction)
+        } else {
+            config.light.direction
+        }
+        .normalize_or_zero();
+        let light_view_and_projection =
+            config.shadow_map_config.as_ref().and_then(|shadow_config| {
+                ShadowMapProcessor::light_view_and_projection(shadow_config, light_direction)
+            });
+
         // Setup frame uniform buffer
         let frame_uniform_buffer_content = FrameUniformBuffer {
             view_from_world: glam::Affine3A::from_mat4(view_from_world).into(),
@@ -439,6 +562,24 @@ impl ViewBuilder {
             auto_size_points: auto_size_points.0,
             auto_size_lines: auto_size_lines.0,
 
+            light_direction,
+            light_intensity: config.light.intensity,
+            light_projection_from_world: light_view_and_projection
+                .map_or(
+                    glam::Mat4::IDENTITY,
+                    |(light_from_world, projection_from_light)| {
+                        projection_from_light * glam::Mat4::from(light_from_world)
+                    },
+                )
+                .into(),
+            ambient_intensity: config.light.ambient_intensity,
+            shadow_map_enabled: light_view_and_projection.is_some() as u32,
+            shadow_depth_bias: ShadowMapProcessor::SHADOW_DEPTH_BIAS,
+            shadow_map_texel_size: config
+                .shadow_map_config
+                .as_ref()
+                .map_or(0.0, ShadowMapProcessor::texel_size),
+
             end_padding: Default::default(),
         };
         let frame_uniform_buffer = create_and_fill_uniform_buffer(
@@ -447,10 +588,30 @@ impl ViewBuilder {
             frame_uniform_buffer_content,
         );
 
+        self.shadow_map_processor = config
+            .shadow_map_config
+            .as_ref()
+            .zip(light_view_and_projection)
+            .map(
+                |(shadow_config, (light_from_world, projection_from_light))| {
+                    ShadowMapProcessor::new(
+                        ctx,
+                        &config.name,
+                        shadow_config,
+                        light_from_world,
+                        projection_from_light,
+                        &frame_uniform_buffer_content,
+                    )
+                },
+            );
+
         let bind_group_0 = ctx.shared_renderer_data.global_bindings.create_bind_group(
             &mut ctx.gpu_resources,
             &ctx.device,
             frame_uniform_buffer,
+            self.shadow_map_processor
+                .as_ref()
+                .map(|processor| processor.shadow_map()),
         );
 
         self.setup = Some(ViewTargetSetup {
@@ -474,14 +635,27 @@ impl ViewBuilder {
     ) {
         crate::profile_function!();
 
-        for queued_draw in &self.queued_draws {
-            if queued_draw.participated_phases.contains(&phase) {
-                let res = (queued_draw.draw_func)(ctx, phase, pass, queued_draw.draw_data.as_ref())
-                    .with_context(|| format!("draw call during phase {phase:?}"));
-                if let Err(err) = res {
-                    re_log::error!(renderer=%queued_draw.renderer_name, %err,
-                        "renderer failed to draw");
+        let mut queued_draws = self
+            .queued_draws
+            .iter()
+            .filter(|queued_draw| queued_draw.participated_phases.contains(&phase))
+            .collect::<Vec<_>>();
+        if phase == DrawPhase::Transparent {
+            // Back to front. Draw data without any sorting information go first, in submission order.
+            queued_draws.sort_by(|a, b| {
+                match (a.transparent_sort_depth, b.transparent_sort_depth) {
+                    (Some(depth_a), Some(depth_b)) => depth_b.total_cmp(&depth_a),
+                    (depth_a, depth_b) => depth_a.is_some().cmp(&depth_b.is_some()),
                 }
+            });
+        }
+
+        for queued_draw in queued_draws {
+            let res = (queued_draw.draw_func)(ctx, phase, pass, queued_draw.draw_data.as_ref())
+                .with_context(|| format!("draw call during phase {phase:?}"));
+            if let Err(err) = res {
+                re_log::error!(renderer=%queued_draw.renderer_name, %err,
+                    "renderer failed to draw");
             }
         }
     }
@@ -491,6 +665,12 @@ impl ViewBuilder {
         draw_data: &D,
     ) -> &mut Self {
         crate::profile_function!();
+
+        let mut draw_data = draw_data.clone();
+        let transparent_sort_depth = self.setup.as_ref().and_then(|setup| {
+            draw_data.sort_transparent_back_to_front(&setup.transparency_sort_view())
+        });
+
         self.queued_draws.push(QueuedDraw {
             draw_func: Box::new(move |ctx, phase, pass, draw_data| {
                 let renderers = ctx.renderers.read();
@@ -502,9 +682,10 @@ impl ViewBuilder {
                     .expect("passed wrong type of draw data");
                 renderer.draw(&ctx.gpu_resources, phase, pass, draw_data)
             }),
-            draw_data: Box::new(draw_data.clone()),
+            draw_data: Box::new(draw_data),
             renderer_name: std::any::type_name::<D::Renderer>(),
             participated_phases: D::Renderer::participated_phases(),
+            transparent_sort_depth,
         });
 
         self
@@ -529,6 +710,12 @@ impl ViewBuilder {
                 label: setup.name.clone().get(),
             });
 
+        if let Some(shadow_map_processor) = &self.shadow_map_processor {
+            crate::profile_scope!("shadow map pass");
+            let mut pass = shadow_map_processor.begin_render_pass(&setup.name, &mut encoder);
+            self.draw_phase(ctx, DrawPhase::ShadowMap, &mut pass);
+        }
+
         {
             crate::profile_scope!("main target pass");
 
@@ -553,7 +740,8 @@ impl ViewBuilder {
                     view: &setup.depth_buffer.default_view,
                     depth_ops: Some(wgpu::Operations {
                         load: Self::DEFAULT_DEPTH_CLEAR,
-                        store: false,
+                        // Order independent transparency is drawn in a separate pass that needs the depth buffer.
+                        store: self.order_independent_transparency_processor.is_some(),
                     }),
                     stencil_ops: None,
                 }),
@@ -564,6 +752,24 @@ impl ViewBuilder {
             for phase in [DrawPhase::Opaque, DrawPhase::Background] {
                 self.draw_phase(ctx, phase, &mut pass);
             }
+            if self.order_independent_transparency_processor.is_none() {
+                self.draw_phase(ctx, DrawPhase::Transparent, &mut pass);
+            }
+        }
+
+        if let Some(transparency_processor) = self.order_independent_transparency_processor.take() {
+            crate::profile_scope!("order independent transparency");
+            {
+                let mut pass =
+                    transparency_processor.begin_render_pass(&mut encoder, &setup.depth_buffer);
+                pass.set_bind_group(0, &setup.bind_group_0, &[]);
+                self.draw_phase(ctx, DrawPhase::TransparentOrderIndependent, &mut pass);
+            }
+            transparency_processor.composite(
+                &ctx.gpu_resources,
+                &mut encoder,
+                &setup.main_target_resolved,
+            )?;
         }
 
         if let Some(picking_processor) = self.picking_processor.take() {
@@ -73,6 +73,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/mipmap_downsample.wgsl");
+        let content = include_str!("../shader/mipmap_downsample.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/outlines/jumpflooding_init.wgsl");
         let content = include_str!("../shader/outlines/jumpflooding_init.wgsl").into();
@@ -127,6 +133,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/transparency_composite.wgsl");
+        let content = include_str!("../shader/transparency_composite.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/types.wgsl");
         let content = include_str!("../shader/types.wgsl").into();
@@ -157,12 +169,24 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/utils/order_independent_transparency.wgsl");
+        let content = include_str!("../shader/utils/order_independent_transparency.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/utils/quaternion.wgsl");
         let content = include_str!("../shader/utils/quaternion.wgsl").into();
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/utils/shadow_map.wgsl");
+        let content = include_str!("../shader/utils/shadow_map.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/utils/size.wgsl");
         let content = include_str!("../shader/utils/size.wgsl").into();
@@ -19,6 +19,9 @@ mod log_sink;
 mod msg_sender;
 mod session;
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod text_log;
+
 // -------------
 // Public items:
 
@@ -26,12 +29,25 @@ mod session;
 pub use self::global::global_session;
 
 pub use self::msg_sender::{MsgSender, MsgSenderError};
-pub use self::session::{Session, SessionBuilder};
+pub use self::session::{Session, SessionBuilder, ViewerEvents};
+
+#[cfg(feature = "log")]
+pub use self::text_log::TextLogger;
+
+#[cfg(feature = "tracing")]
+pub use self::text_log::TextLogLayer;
 
 pub use re_sdk_comms::default_server_addr;
 
+/// Derive [`Component`] for your own types.
+///
+/// See the [`re_sdk_derive`] crate for the supported attributes.
+#[cfg(feature = "derive")]
+pub use re_sdk_derive::Component;
+
 pub use re_log_types::{
     ApplicationId, Component, ComponentName, EntityPath, RecordingId, SerializableComponent,
+    ViewerEvent,
 };
 
 // ---------------
@@ -67,12 +83,13 @@ pub mod time {
 /// and can be used in [`MsgSender::with_component`].
 pub mod components {
     pub use re_log_types::component_types::{
-        AnnotationContext, AnnotationInfo, Arrow3D, Box3D, ClassDescription, ClassId, ColorRGBA,
-        EncodedMesh3D, InstanceKey, KeypointId, Label, LineStrip2D, LineStrip3D, Mat3x3, Mesh3D,
+        Affine3, AnnotationContext, AnnotationInfo, Arrow3D, Box3D, BrownConrady, ClassDescription,
+        ClassId, ColorRGBA, EncodedMesh3D, Homography2D, InstanceKey, KannalaBrandt, KeypointId,
+        KeypointVisibility, Label, LensDistortion, LineStrip2D, LineStrip3D, Mat3x3, Mesh3D,
         MeshFormat, MeshId, Pinhole, Point2D, Point3D, Quaternion, Radius, RawMesh3D, Rect2D,
-        Rigid3, Scalar, ScalarPlotProps, Size3D, Tensor, TensorData, TensorDataMeaning,
-        TensorDimension, TensorId, TensorTrait, TextEntry, Transform, Vec2D, Vec3D, Vec4D,
-        ViewCoordinates,
+        Rigid3, Scalar, ScalarPlotProps, Scale3D, Size3D, Tensor, TensorData, TensorDataMeaning,
+        TensorDimension, TensorId, TensorTrait, TextEntry, TextEntryField, TextEntryFields,
+        TimeMarker, Transform, Vec2D, Vec3D, Vec4D, ViewCoordinates,
     };
 }
 
@@ -1,4 +1,4 @@
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
 
 /// Where the SDK sends its log messages.
 pub trait LogSink: Send + Sync + 'static {
@@ -27,6 +27,13 @@ pub trait LogSink: Send + Sync + 'static {
     fn is_enabled(&self) -> bool {
         true
     }
+
+    /// The [`ViewerEvent`]s sent back by the viewer this sink sends to.
+    ///
+    /// `None` if this sink doesn't talk to a viewer.
+    fn viewer_events(&self) -> Option<crossbeam::channel::Receiver<ViewerEvent>> {
+        None
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -105,4 +112,8 @@ impl LogSink for TcpSink {
     fn drop_msgs_if_disconnected(&self) {
         self.client.drop_if_disconnected();
     }
+
+    fn viewer_events(&self) -> Option<crossbeam::channel::Receiver<ViewerEvent>> {
+        Some(self.client.viewer_events())
+    }
 }
@@ -390,6 +390,35 @@ mod tests {
         Ok(())
     }
 
+    #[derive(Clone, Debug, Default, PartialEq, crate::Component)]
+    #[component(name = "test.wheel_odometry", crate = "crate")]
+    struct WheelOdometry {
+        left_velocity: f32,
+        right_velocity: f32,
+    }
+
+    #[test]
+    fn derived_component() -> Result<(), MsgSenderError> {
+        let odometry = [WheelOdometry {
+            left_velocity: 1.0,
+            right_velocity: 1.5,
+        }];
+
+        let [standard, _, _] = MsgSender::new("robot/wheels")
+            .with_component(odometry.as_slice())?
+            .into_rows();
+
+        let standard = standard.unwrap();
+        let idx = standard.find_cell(&WheelOdometry::name()).unwrap();
+        let cell = &standard.cells[idx];
+        assert_eq!(
+            cell.as_native::<WheelOdometry>().collect::<Vec<_>>(),
+            odometry
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn timepoint_last_write_wins() {
         let my_timeline = Timeline::new("my_timeline", time::TimeType::Sequence);
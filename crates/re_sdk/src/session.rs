@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
-use re_log_types::{ApplicationId, LogMsg, RecordingId, RecordingInfo, RecordingSource, Time};
+use parking_lot::RwLock;
+use re_log_types::{
+    ApplicationId, LogMsg, RecordingId, RecordingInfo, RecordingSource, Time, TimeInt, TimePoint,
+    Timeline, ViewerEvent,
+};
 
 use crate::sink::LogSink;
 
@@ -190,8 +194,11 @@ impl SessionBuilder {
 #[derive(Clone)]
 pub struct Session {
     sink: Arc<dyn LogSink>,
-    // TODO(emilk): add convenience `TimePoint` here so that users can
-    // do things like `session.set_time_sequence("frame", frame_idx);`
+
+    /// The current time, see [`Self::time_point`].
+    ///
+    /// Shared between all clones of the session.
+    time_point: Arc<RwLock<TimePoint>>,
 }
 
 #[test]
@@ -228,7 +235,10 @@ impl Session {
             );
         }
 
-        Self { sink: sink.into() }
+        Self {
+            sink: sink.into(),
+            time_point: Default::default(),
+        }
     }
 
     /// Construct a new session with a disabled "dummy" sink that drops all logging messages.
@@ -237,6 +247,7 @@ impl Session {
     pub fn disabled() -> Self {
         Self {
             sink: crate::sink::disabled().into(),
+            time_point: Default::default(),
         }
     }
 
@@ -283,6 +294,107 @@ impl Session {
     pub fn drain_backlog(&self) -> Vec<LogMsg> {
         self.sink.drain_backlog()
     }
+
+    /// The interactions in the viewer that this session is connected to.
+    ///
+    /// Returns `None` if the sink doesn't talk to a viewer, e.g. when saving to a file.
+    ///
+    /// All clones of the session share the same events: each event is only returned once.
+    pub fn viewer_events(&self) -> Option<ViewerEvents> {
+        self.sink.viewer_events().map(ViewerEvents)
+    }
+
+    /// Call `callback` on a background thread for each [`ViewerEvent`], see [`Self::viewer_events`].
+    ///
+    /// The thread keeps running until the session (and all of its clones) has been dropped.
+    ///
+    /// Only logs a warning if the sink doesn't talk to a viewer.
+    pub fn on_viewer_event(&self, mut callback: impl FnMut(ViewerEvent) + Send + 'static) {
+        let Some(viewer_events) = self.viewer_events() else {
+            re_log::warn!("This session isn't connected to a viewer - there won't be any viewer events.");
+            return;
+        };
+
+        std::thread::Builder::new()
+            .name("viewer_event_callback".into())
+            .spawn(move || {
+                for event in viewer_events {
+                    callback(event);
+                }
+            })
+            .expect("Failed to spawn thread");
+    }
+
+    /// The current time of the session.
+    ///
+    /// This is not applied to messages sent with [`crate::MsgSender`] automatically,
+    /// use [`crate::MsgSender::with_timepoint`] for that.
+    /// It is used by helpers logging on your behalf, e.g. `TextLogger` and `TextLogLayer`.
+    pub fn time_point(&self) -> TimePoint {
+        self.time_point.read().clone()
+    }
+
+    /// Set the current time of the session on the given timeline.
+    pub fn set_time(&self, timeline: Timeline, time: impl Into<TimeInt>) {
+        self.time_point.write().insert(timeline, time.into());
+    }
+
+    /// Set the current time of the session on a sequence timeline, e.g. a frame number.
+    pub fn set_time_sequence(&self, timeline_name: &str, sequence: i64) {
+        self.set_time(Timeline::new_sequence(timeline_name), sequence);
+    }
+
+    /// Set the current time of the session on a temporal timeline, in seconds since the unix epoch.
+    pub fn set_time_seconds(&self, timeline_name: &str, seconds: f64) {
+        self.set_time(
+            Timeline::new_temporal(timeline_name),
+            Time::from_seconds_since_epoch(seconds),
+        );
+    }
+
+    /// Set the current time of the session on a temporal timeline, in nanoseconds since the unix epoch.
+    pub fn set_time_nanos(&self, timeline_name: &str, ns: i64) {
+        self.set_time(
+            Timeline::new_temporal(timeline_name),
+            Time::from_ns_since_epoch(ns),
+        );
+    }
+
+    /// Remove the current time of the session on the given timeline.
+    pub fn clear_time(&self, timeline: &Timeline) {
+        self.time_point.write().remove(timeline);
+    }
+
+    /// Remove the current time of the session on all timelines.
+    pub fn reset_time(&self) {
+        *self.time_point.write() = TimePoint::default();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The [`ViewerEvent`]s sent back by a viewer, see [`Session::viewer_events`].
+///
+/// Iterating blocks until the next event arrives.
+/// The client reconnects to the viewer whenever it needs to send something, so a viewer
+/// disconnecting doesn't end the iteration: it only ends once the sink of the [`Session`]
+/// (and all of its clones) has been dropped.
+/// Use [`Self::try_iter`] to instead poll for events, e.g. once per logged frame.
+pub struct ViewerEvents(crossbeam::channel::Receiver<ViewerEvent>);
+
+impl ViewerEvents {
+    /// The events that have arrived so far, without blocking.
+    pub fn try_iter(&self) -> impl Iterator<Item = ViewerEvent> + '_ {
+        self.0.try_iter()
+    }
+}
+
+impl Iterator for ViewerEvents {
+    type Item = ViewerEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.recv().ok()
+    }
 }
 
 impl AsRef<dyn LogSink> for Session {
@@ -0,0 +1,288 @@
+//! Forward the text logs of an application into a recording.
+//!
+//! Each record becomes a [`TextEntry`] under a single entity path,
+//! stamped with the current time of the [`Session`] (see [`Session::set_time`]).
+
+use re_log_types::{
+    component_types::{TextEntry, TextEntryFields},
+    EntityPath,
+};
+
+use crate::{MsgSender, Session};
+
+/// Entity path text logs go to unless configured otherwise.
+const DEFAULT_ENTITY_PATH: &str = "logs";
+
+/// Records from these crates are never forwarded.
+///
+/// Sending a message may itself log, which would otherwise end up in an infinite loop.
+const IGNORED_CRATES: [&str; 5] = [
+    "re_log",
+    "re_log_types",
+    "re_sdk",
+    "re_sdk_comms",
+    "re_smart_channel",
+];
+
+fn is_ignored_target(target: &str) -> bool {
+    let crate_name = target.split("::").next().unwrap_or(target);
+    IGNORED_CRATES.contains(&crate_name)
+}
+
+fn send_text_entry(
+    session: &Session,
+    entity_path: &EntityPath,
+    level: &str, // one of `TextEntry::RECOMMENDED_LEVELS`
+    body: String,
+    fields: Vec<(String, String)>,
+) {
+    if !session.is_enabled() {
+        return;
+    }
+
+    let text_entry = TextEntry::new(body, Some(level.to_owned()));
+    let fields = TextEntryFields::new(fields);
+    let result = MsgSender::new(entity_path.clone())
+        .with_timepoint(session.time_point())
+        .with_component(&[text_entry])
+        .and_then(|msg| {
+            if fields.is_empty() {
+                Ok(msg)
+            } else {
+                msg.with_component(&[fields])
+            }
+        })
+        .and_then(|msg| msg.send(session).map_err(Into::into));
+    if let Err(err) = result {
+        // Safe to log: our own targets are ignored.
+        re_log::warn_once!("Failed to forward text log: {err}");
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`log::Log`] forwarding all records into a [`Session`] as [`TextEntry`]s.
+///
+/// The target of the record is stored as the `target` field of the text entry.
+///
+/// ```
+/// # use re_sdk::{SessionBuilder, TextLogger};
+/// let session = SessionBuilder::new("my_app").buffered();
+/// TextLogger::new(session.clone())
+///     .with_entity_path("robot/logs")
+///     .init()
+///     .ok();
+///
+/// session.set_time_sequence("frame", 42);
+/// log::info!("Hello from frame 42");
+/// ```
+///
+/// If you use `re_log` for setting up logging, add it with `re_log::add_boxed_logger` instead of
+/// calling [`Self::init`].
+#[cfg(feature = "log")]
+pub struct TextLogger {
+    session: Session,
+    entity_path: EntityPath,
+    filter: log::LevelFilter,
+}
+
+#[cfg(feature = "log")]
+impl TextLogger {
+    /// Forwards all records of level [`log::Level::Info`] and above to `logs`.
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            entity_path: DEFAULT_ENTITY_PATH.into(),
+            filter: log::LevelFilter::Info,
+        }
+    }
+
+    /// The entity path the text entries are logged to.
+    pub fn with_entity_path(mut self, entity_path: impl Into<EntityPath>) -> Self {
+        self.entity_path = entity_path.into();
+        self
+    }
+
+    /// Only forward records at this level or more severe.
+    pub fn with_filter(mut self, filter: log::LevelFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Install this as the global logger.
+    ///
+    /// Fails if another logger has already been installed.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let filter = self.filter;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(filter);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for TextLogger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.filter
+            && !is_ignored_target(metadata.target())
+            && self.session.is_enabled()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let fields = vec![("target".to_owned(), record.target().to_owned())];
+
+        send_text_entry(
+            &self.session,
+            &self.entity_path,
+            record.level().as_str(),
+            record.args().to_string(),
+            fields,
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`tracing_subscriber::Layer`] forwarding all events into a [`Session`] as [`TextEntry`]s.
+///
+/// The `message` of the event becomes the body of the text entry,
+/// all other fields of the event as well as its target become fields of the text entry.
+///
+/// ```
+/// # use re_sdk::{SessionBuilder, TextLogLayer};
+/// use tracing_subscriber::layer::SubscriberExt as _;
+///
+/// let session = SessionBuilder::new("my_app").buffered();
+/// let layer = TextLogLayer::new(session.clone()).with_entity_path("robot/logs");
+/// let subscriber = tracing_subscriber::registry().with(layer);
+/// tracing::subscriber::set_global_default(subscriber).ok();
+///
+/// session.set_time_sequence("frame", 42);
+/// tracing::warn!(speed = 4.2, "Slowing down");
+/// ```
+#[cfg(feature = "tracing")]
+pub struct TextLogLayer {
+    session: Session,
+    entity_path: EntityPath,
+}
+
+#[cfg(feature = "tracing")]
+impl TextLogLayer {
+    /// Forwards all events to `logs`.
+    ///
+    /// Use the filtering of `tracing_subscriber` to select which events to forward.
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            entity_path: DEFAULT_ENTITY_PATH.into(),
+        }
+    }
+
+    /// The entity path the text entries are logged to.
+    pub fn with_entity_path(mut self, entity_path: impl Into<EntityPath>) -> Self {
+        self.entity_path = entity_path.into();
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for TextLogLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        if is_ignored_target(metadata.target()) || !self.session.is_enabled() {
+            return;
+        }
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let FieldVisitor {
+            message,
+            mut fields,
+        } = visitor;
+        fields.push(("target".to_owned(), metadata.target().to_owned()));
+
+        send_text_entry(
+            &self.session,
+            &self.entity_path,
+            metadata.level().as_str(),
+            message.unwrap_or_default(),
+            fields,
+        );
+    }
+}
+
+/// Splits the fields of a [`tracing::Event`] into its message and everything else.
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: Vec<(String, String)>,
+}
+
+#[cfg(feature = "tracing")]
+impl FieldVisitor {
+    fn record(&mut self, field: &tracing::field::Field, value: String) {
+        if field.name() == "message" {
+            self.message = Some(value);
+        } else {
+            self.fields.push((field.name().to_owned(), value));
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for FieldVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.record(field, value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwards_log_records() {
+        use log::Log as _;
+
+        let session = crate::SessionBuilder::new("test").buffered();
+        session.drain_backlog(); // the recording info
+
+        let logger = TextLogger::new(session.clone());
+        session.set_time_sequence("frame", 42);
+
+        for (level, target) in [
+            (log::Level::Info, "my_app"),
+            (log::Level::Debug, "my_app"), // filtered out by level
+            (log::Level::Info, "re_sdk::session"), // our own
+        ] {
+            logger.log(
+                &log::Record::builder()
+                    .level(level)
+                    .target(target)
+                    .args(format_args!("hello"))
+                    .build(),
+            );
+        }
+
+        let msgs = session.drain_backlog();
+        assert_eq!(msgs.len(), 1);
+        assert!(matches!(msgs[0], re_log_types::LogMsg::ArrowMsg(_)));
+    }
+}
@@ -2,7 +2,7 @@ use std::{net::SocketAddr, thread::JoinHandle};
 
 use crossbeam::channel::{select, Receiver, Sender};
 
-use re_log_types::{LogMsg, MsgId};
+use re_log_types::{LogMsg, MsgId, ViewerEvent};
 
 #[derive(Debug, PartialEq, Eq)]
 struct FlushedMsg;
@@ -34,6 +34,9 @@ enum PacketMsg {
     Flush,
 }
 
+/// How many [`ViewerEvent`]s to keep around until they are received, before dropping new ones.
+const VIEWER_EVENT_BUFFER_SIZE: usize = 1024;
+
 /// Send [`LogMsg`]es to a server over TCP.
 ///
 /// The messages are encoded and sent on separate threads
@@ -41,6 +44,7 @@ enum PacketMsg {
 pub struct Client {
     msg_tx: Sender<MsgMsg>,
     flushed_rx: Receiver<FlushedMsg>,
+    viewer_event_rx: Receiver<ViewerEvent>,
     encode_quit_tx: Sender<QuitMsg>,
     send_quit_tx: Sender<InterruptMsg>,
     drop_quit_tx: Sender<QuitMsg>,
@@ -69,6 +73,8 @@ impl Client {
         let (encode_quit_tx, encode_quit_rx) = crossbeam::channel::unbounded();
         let (send_quit_tx, send_quit_rx) = crossbeam::channel::unbounded();
         let (drop_quit_tx, drop_quit_rx) = crossbeam::channel::unbounded();
+        let (viewer_event_tx, viewer_event_rx) =
+            crossbeam::channel::bounded(VIEWER_EVENT_BUFFER_SIZE);
 
         let encode_join = std::thread::Builder::new()
             .name("msg_encoder".into())
@@ -81,7 +87,13 @@ impl Client {
         let send_join = std::thread::Builder::new()
             .name("tcp_sender".into())
             .spawn(move || {
-                tcp_sender(addr, &packet_rx, &send_quit_rx, &flushed_tx);
+                tcp_sender(
+                    addr,
+                    viewer_event_tx,
+                    &packet_rx,
+                    &send_quit_rx,
+                    &flushed_tx,
+                );
                 re_log::debug!("Shutting down TCP sender thread");
             })
             .expect("Failed to spawn thread");
@@ -97,6 +109,7 @@ impl Client {
         Self {
             msg_tx,
             flushed_rx,
+            viewer_event_rx,
             encode_quit_tx,
             send_quit_tx,
             drop_quit_tx,
@@ -126,6 +139,13 @@ impl Client {
         }
     }
 
+    /// The [`ViewerEvent`]s sent back by the viewer we are connected to.
+    ///
+    /// If they aren't received, the oldest ones are kept and new ones are dropped.
+    pub fn viewer_events(&self) -> Receiver<ViewerEvent> {
+        self.viewer_event_rx.clone()
+    }
+
     /// Switch to a mode where we drop messages if disconnected.
     ///
     /// Calling this before a flush (or drop) ensures we won't get stuck trying to send
@@ -214,11 +234,12 @@ fn msg_encode(
 
 fn tcp_sender(
     addr: SocketAddr,
+    viewer_event_tx: Sender<ViewerEvent>,
     packet_rx: &Receiver<PacketMsg>,
     quit_rx: &Receiver<InterruptMsg>,
     flushed_tx: &Sender<FlushedMsg>,
 ) {
-    let mut tcp_client = crate::tcp_client::TcpClient::new(addr);
+    let mut tcp_client = crate::tcp_client::TcpClient::new(addr, Some(viewer_event_tx));
     // Once this flag has been set, we will drop all messages if the tcp_client is
     // no longer connected.
     let mut drop_if_disconnected = false;
@@ -0,0 +1,38 @@
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    net::{SocketAddr, TcpStream},
+};
+
+use anyhow::Context as _;
+
+use re_log_types::{ViewerCommand, ViewerCommandResponse};
+
+/// A blocking connection to the control socket of a running viewer.
+///
+/// See `serve_control` for the protocol.
+pub struct ControlClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl ControlClient {
+    pub fn connect(addr: SocketAddr) -> anyhow::Result<Self> {
+        let writer = TcpStream::connect(addr)
+            .with_context(|| format!("Failed to connect to the viewer control socket at {addr}"))?;
+        let reader = BufReader::new(writer.try_clone()?);
+        Ok(Self { reader, writer })
+    }
+
+    /// Sends the command, and waits for the viewer to carry it out.
+    pub fn send(&mut self, command: &ViewerCommand) -> anyhow::Result<ViewerCommandResponse> {
+        let mut line = serde_json::to_string(command)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+
+        let mut response = String::new();
+        if self.reader.read_line(&mut response)? == 0 {
+            anyhow::bail!("The viewer closed the control connection");
+        }
+        serde_json::from_str(&response).context("Bad response from the viewer")
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Context;
+
+use re_log_types::{ViewerCommand, ViewerCommandReply, ViewerCommandResponse};
+use re_smart_channel::{Receiver, Sender};
+use tokio::{
+    io::{AsyncBufReadExt as _, AsyncWriteExt as _, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+/// Listen to test harnesses, notebooks etc. that want to remote-control the viewer.
+///
+/// The protocol is line-based JSON: each line sent by the client is a [`ViewerCommand`],
+/// and is answered by a line containing a [`ViewerCommandResponse`], e.g.:
+///
+/// ```text
+/// > {"RunCommand":{"command":"ToggleTimePanel"}}
+/// < "Ok"
+/// ```
+///
+/// There is no authentication, so we only listen on localhost.
+///
+/// The viewer is expected to call the [`ViewerCommandReply`] of every received command.
+pub async fn serve_control(
+    port: u16,
+    shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+) -> anyhow::Result<Receiver<(ViewerCommand, ViewerCommandReply)>> {
+    let (tx, rx) = re_smart_channel::smart_channel(re_smart_channel::Source::TcpServer { port });
+
+    let bind_addr = format!("127.0.0.1:{port}");
+    let listener = TcpListener::bind(&bind_addr).await.with_context(|| {
+        format!(
+            "Failed to bind TCP address {bind_addr:?}. Another Rerun instance is probably running."
+        )
+    })?;
+
+    re_log::info!("Listening for viewer commands at {bind_addr}.");
+
+    tokio::spawn(listen_for_new_clients(listener, tx, shutdown_rx));
+
+    Ok(rx)
+}
+
+async fn listen_for_new_clients(
+    listener: TcpListener,
+    tx: Sender<(ViewerCommand, ViewerCommandReply)>,
+    mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+) {
+    loop {
+        let incoming = tokio::select! {
+            res = listener.accept() => res,
+            _ = shutdown_rx.recv() => {
+                return;
+            }
+        };
+        match incoming {
+            Ok((stream, _)) => {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = run_client(stream, &tx).await {
+                        re_log::warn!("Closing connection to control client: {err}");
+                    }
+                });
+            }
+            Err(err) => {
+                re_log::warn!("Failed to accept incoming control client: {err}");
+            }
+        }
+    }
+}
+
+async fn run_client(
+    stream: TcpStream,
+    tx: &Sender<(ViewerCommand, ViewerCommandReply)>,
+) -> anyhow::Result<()> {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<ViewerCommand>(&line) {
+            Ok(command) => run_command(tx, command).await,
+            Err(err) => ViewerCommandResponse::error(format!("Bad viewer command: {err}")),
+        };
+
+        let mut response = serde_json::to_string(&response)?;
+        response.push('\n');
+        write_half.write_all(response.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Passes the command on to the viewer, and waits for it to be carried out.
+async fn run_command(
+    tx: &Sender<(ViewerCommand, ViewerCommandReply)>,
+    command: ViewerCommand,
+) -> ViewerCommandResponse {
+    let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+    let reply: ViewerCommandReply = Box::new(move |response| {
+        reply_tx.send(response).ok();
+    });
+
+    if tx.send((command, reply)).is_err() {
+        return ViewerCommandResponse::error("The viewer has shut down");
+    }
+
+    reply_rx.await.unwrap_or_else(|_| {
+        ViewerCommandResponse::error("The viewer dropped the command without answering")
+    })
+}
@@ -13,20 +13,37 @@ mod buffered_client;
 #[cfg(feature = "client")]
 pub use buffered_client::Client;
 
+#[cfg(feature = "client")]
+mod control_client;
+
+#[cfg(feature = "client")]
+pub use control_client::ControlClient;
+
 #[cfg(feature = "server")]
 mod server;
 
 #[cfg(feature = "server")]
-pub use server::{serve, ServerOptions};
+pub use server::{serve, serve_with_viewer_events, ServerOptions};
+
+#[cfg(feature = "server")]
+mod control_server;
+
+#[cfg(feature = "server")]
+pub use control_server::serve_control;
 
 use re_log_types::LogMsg;
 
 pub type Result<T> = anyhow::Result<T>;
 
-pub const PROTOCOL_VERSION: u16 = 0;
+/// Sent by the client when connecting, followed by a byte that is `1` if the client wants
+/// the server to send [`re_log_types::ViewerEvent`]s back, and `0` otherwise.
+pub const PROTOCOL_VERSION: u16 = 1;
 
 pub const DEFAULT_SERVER_PORT: u16 = 9876;
 
+/// The default port of the control socket of a viewer, see `serve_control`.
+pub const DEFAULT_CONTROL_PORT: u16 = 9877;
+
 /// The default address of a Rerun TCP server which an SDK connects to.
 pub fn default_server_addr() -> std::net::SocketAddr {
     std::net::SocketAddr::from(([127, 0, 0, 1], DEFAULT_SERVER_PORT))
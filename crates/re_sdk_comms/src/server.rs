@@ -5,9 +5,9 @@ use std::time::Instant;
 use anyhow::Context;
 use rand::{Rng as _, SeedableRng};
 
-use re_log_types::{LogMsg, TimePoint, TimeType, TimelineName};
+use re_log_types::{LogMsg, TimePoint, TimeType, TimelineName, ViewerEvent};
 use re_smart_channel::{Receiver, Sender};
-use tokio::net::{TcpListener, TcpStream};
+use tokio::net::{tcp::OwnedWriteHalf, TcpListener, TcpStream};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ServerOptions {
@@ -32,6 +32,7 @@ async fn listen_for_new_clients(
     listener: TcpListener,
     options: ServerOptions,
     tx: Sender<LogMsg>,
+    viewer_events: tokio::sync::broadcast::Sender<ViewerEvent>,
     mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
 ) {
     loop {
@@ -44,7 +45,8 @@ async fn listen_for_new_clients(
         match incoming {
             Ok((stream, _)) => {
                 let tx = tx.clone();
-                spawn_client(stream, tx, options);
+                let viewer_event_rx = viewer_events.subscribe();
+                spawn_client(stream, tx, viewer_event_rx, options);
             }
             Err(err) => {
                 re_log::warn!("Failed to accept incoming SDK client: {err}");
@@ -67,6 +69,20 @@ pub async fn serve(
     port: u16,
     options: ServerOptions,
     shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+) -> anyhow::Result<Receiver<LogMsg>> {
+    let (viewer_events, _) = tokio::sync::broadcast::channel(1);
+    serve_with_viewer_events(port, options, shutdown_rx, viewer_events).await
+}
+
+/// Like [`serve`], but also sends the [`ViewerEvent`]s put into `viewer_events`
+/// back to every connected SDK client that asked for them when connecting.
+///
+/// Events are dropped for clients that don't keep up with them.
+pub async fn serve_with_viewer_events(
+    port: u16,
+    options: ServerOptions,
+    shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+    viewer_events: tokio::sync::broadcast::Sender<ViewerEvent>,
 ) -> anyhow::Result<Receiver<LogMsg>> {
     let (tx, rx) = re_smart_channel::smart_channel(re_smart_channel::Source::TcpServer { port });
 
@@ -87,12 +103,23 @@ pub async fn serve(
         );
     }
 
-    tokio::spawn(listen_for_new_clients(listener, options, tx, shutdown_rx));
+    tokio::spawn(listen_for_new_clients(
+        listener,
+        options,
+        tx,
+        viewer_events,
+        shutdown_rx,
+    ));
 
     Ok(rx)
 }
 
-fn spawn_client(stream: TcpStream, tx: Sender<LogMsg>, options: ServerOptions) {
+fn spawn_client(
+    stream: TcpStream,
+    tx: Sender<LogMsg>,
+    viewer_event_rx: tokio::sync::broadcast::Receiver<ViewerEvent>,
+    options: ServerOptions,
+) {
     tokio::spawn(async move {
         let addr_string = stream
             .peer_addr()
@@ -102,7 +129,7 @@ fn spawn_client(stream: TcpStream, tx: Sender<LogMsg>, options: ServerOptions) {
         } else {
             re_log::info!("New SDK client connected: {addr_string}");
         }
-        if let Err(err) = run_client(stream, &tx, options).await {
+        if let Err(err) = run_client(stream, &tx, viewer_event_rx, options).await {
             re_log::warn!("Closing connection to client: {err}");
         }
     });
@@ -111,10 +138,9 @@ fn spawn_client(stream: TcpStream, tx: Sender<LogMsg>, options: ServerOptions) {
 async fn run_client(
     mut stream: TcpStream,
     tx: &Sender<LogMsg>,
+    viewer_event_rx: tokio::sync::broadcast::Receiver<ViewerEvent>,
     options: ServerOptions,
 ) -> anyhow::Result<()> {
-    #![allow(clippy::read_zero_byte_vec)] // false positive: https://github.com/rust-lang/rust-clippy/issues/9274
-
     use tokio::io::AsyncReadExt as _;
 
     let mut client_version = [0_u8; 2];
@@ -139,6 +165,28 @@ async fn run_client(
         }
     }
 
+    // Clients that don't read the viewer events would eventually fill up the socket's send buffer.
+    let wants_viewer_events = stream.read_u8().await? != 0;
+    if !wants_viewer_events {
+        return receive_log_msgs(stream, tx, options).await;
+    }
+
+    let (read_half, write_half) = stream.into_split();
+    let event_sender = tokio::spawn(send_viewer_events(write_half, viewer_event_rx));
+    let result = receive_log_msgs(read_half, tx, options).await;
+    event_sender.abort();
+    result
+}
+
+async fn receive_log_msgs(
+    mut stream: impl tokio::io::AsyncRead + Unpin,
+    tx: &Sender<LogMsg>,
+    options: ServerOptions,
+) -> anyhow::Result<()> {
+    #![allow(clippy::read_zero_byte_vec)] // false positive: https://github.com/rust-lang/rust-clippy/issues/9274
+
+    use tokio::io::AsyncReadExt as _;
+
     let mut congestion_manager = CongestionManager::new(options.max_latency_sec);
 
     let mut packet = Vec::new();
@@ -173,6 +221,40 @@ async fn run_client(
     }
 }
 
+/// Sends the viewer events to the SDK, using the same framing as the log messages.
+async fn send_viewer_events(
+    mut stream: OwnedWriteHalf,
+    mut viewer_event_rx: tokio::sync::broadcast::Receiver<ViewerEvent>,
+) {
+    use tokio::io::AsyncWriteExt as _;
+    use tokio::sync::broadcast::error::RecvError;
+
+    loop {
+        match viewer_event_rx.recv().await {
+            Ok(event) => {
+                let packet = re_log_types::encode_viewer_event(&event);
+                re_log::trace!("Sending viewer event of size {}.", packet.len());
+                let sent = async {
+                    stream
+                        .write_all(&(packet.len() as u32).to_le_bytes())
+                        .await?;
+                    stream.write_all(&packet).await
+                };
+                if let Err(err) = sent.await {
+                    re_log::debug!("Stopped sending viewer events to client: {err}");
+                    return;
+                }
+            }
+            Err(RecvError::Lagged(_)) => {
+                re_log::debug_once!("SDK client isn't reading viewer events - dropping some.");
+            }
+            Err(RecvError::Closed) => {
+                return;
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Decides how many messages to drop so that we achieve a desired maximum latency.
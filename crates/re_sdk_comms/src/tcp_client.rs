@@ -3,6 +3,10 @@ use std::{
     net::{SocketAddr, TcpStream},
 };
 
+use crossbeam::channel::{Sender, TrySendError};
+
+use re_log_types::ViewerEvent;
+
 /// State of the [`TcpStream`]
 ///
 /// Because the [`TcpClient`] lazily connects on [`TcpClient::send`], it needs a
@@ -44,19 +48,25 @@ enum TcpStreamState {
 pub struct TcpClient {
     addrs: Vec<SocketAddr>,
     stream_state: TcpStreamState,
+
+    /// Where to put the [`ViewerEvent`]s sent back by the server, if anywhere.
+    viewer_event_tx: Option<Sender<ViewerEvent>>,
 }
 
 impl Default for TcpClient {
     fn default() -> Self {
-        Self::new(crate::default_server_addr())
+        Self::new(crate::default_server_addr(), None)
     }
 }
 
 impl TcpClient {
-    pub fn new(addr: SocketAddr) -> Self {
+    /// If `viewer_event_tx` is set, the [`ViewerEvent`]s sent back by the server are read
+    /// on a separate thread for as long as the connection lasts.
+    pub fn new(addr: SocketAddr, viewer_event_tx: Option<Sender<ViewerEvent>>) -> Self {
         Self {
             addrs: vec![addr],
             stream_state: TcpStreamState::Pending,
+            viewer_event_tx,
         }
     }
 
@@ -70,10 +80,16 @@ impl TcpClient {
             re_log::debug!("Connecting to {:?}…", self.addrs);
             match TcpStream::connect(&self.addrs[..]) {
                 Ok(mut stream) => {
-                    if let Err(err) = stream.write(&crate::PROTOCOL_VERSION.to_le_bytes()) {
+                    let [version_lo, version_hi] = crate::PROTOCOL_VERSION.to_le_bytes();
+                    let wants_viewer_events = self.viewer_event_tx.is_some() as u8;
+                    let handshake = [version_lo, version_hi, wants_viewer_events];
+                    if let Err(err) = stream.write_all(&handshake) {
                         self.stream_state = TcpStreamState::Disconnected;
                         anyhow::bail!("Failed to send to Rerun server at {:?}: {err}", self.addrs);
                     } else {
+                        if let Some(viewer_event_tx) = &self.viewer_event_tx {
+                            spawn_viewer_event_receiver(&stream, viewer_event_tx.clone());
+                        }
                         self.stream_state = TcpStreamState::Connected(stream);
                         Ok(())
                     }
@@ -134,3 +150,68 @@ impl TcpClient {
         }
     }
 }
+
+impl Drop for TcpClient {
+    fn drop(&mut self) {
+        if let TcpStreamState::Connected(stream) = &self.stream_state {
+            // Also stops the thread receiving viewer events, which holds a clone of the stream.
+            stream.shutdown(std::net::Shutdown::Both).ok();
+        }
+    }
+}
+
+fn spawn_viewer_event_receiver(stream: &TcpStream, viewer_event_tx: Sender<ViewerEvent>) {
+    let stream = match stream.try_clone() {
+        Ok(stream) => stream,
+        Err(err) => {
+            re_log::warn!("Can't receive viewer events: {err}");
+            return;
+        }
+    };
+
+    std::thread::Builder::new()
+        .name("viewer_event_receiver".into())
+        .spawn(move || {
+            receive_viewer_events(stream, &viewer_event_tx);
+            re_log::debug!("Shutting down viewer event receiver thread");
+        })
+        .expect("Failed to spawn thread");
+}
+
+/// Reads viewer events until the connection is closed.
+fn receive_viewer_events(mut stream: TcpStream, viewer_event_tx: &Sender<ViewerEvent>) {
+    use std::io::Read as _;
+
+    let mut packet = Vec::new();
+
+    loop {
+        let mut packet_size = [0_u8; 4];
+        if stream.read_exact(&mut packet_size).is_err() {
+            return; // connection closed
+        }
+        let packet_size = u32::from_le_bytes(packet_size);
+
+        packet.resize(packet_size as usize, 0_u8);
+        if stream.read_exact(&mut packet).is_err() {
+            return; // connection closed
+        }
+
+        let event = match re_log_types::decode_viewer_event(&packet) {
+            Ok(event) => event,
+            Err(err) => {
+                re_log::warn!("Failed to decode viewer event: {err}");
+                return;
+            }
+        };
+
+        match viewer_event_tx.try_send(event) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                re_log::debug_once!("Dropping viewer events because nobody is receiving them.");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,372 @@
+//! Derive macros for the Rerun SDK.
+//!
+//! Use them through `re_sdk` (or `rerun`), which re-exports them next to the traits they implement.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Implements `Component` for a struct, including its mapping to arrow.
+///
+/// ```ignore
+/// #[derive(Clone, Debug, Default, PartialEq, re_sdk::Component)]
+/// #[component(name = "myorg.wheel_odometry")]
+/// struct WheelOdometry {
+///     left_velocity: f32,
+///     right_velocity: f32,
+/// }
+/// ```
+///
+/// Works on structs with named fields and on newtypes.
+/// All fields need to be mappable to arrow themselves
+/// (e.g. primitives, `String`s, `Vec`s and other components).
+/// They are serialized straight from the borrowed struct, without copying them.
+///
+/// The `component` attribute takes:
+/// * `name`: the name of the component, in the form `<namespace>.<name>`, using lowercase
+///   letters, digits and underscores. The `rerun` namespace is reserved for the built-in components.
+/// * `skip_roundtrip_test` (optional): by default a unit test is added that converts
+///   `Default::default()` to arrow and back, which requires the type to implement `Default`,
+///   `PartialEq` and `Debug`. Use this to opt out of it.
+/// * `crate` (optional): the path of the crate the generated code refers to,
+///   e.g. `crate = "rerun"` when not depending on `re_sdk` directly. Defaults to `re_sdk`.
+#[proc_macro_derive(Component, attributes(component))]
+pub fn derive_component(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_component_impl(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+struct ComponentAttributes {
+    name: String,
+    skip_roundtrip_test: bool,
+    krate: syn::Path,
+}
+
+fn parse_attributes(input: &DeriveInput) -> syn::Result<ComponentAttributes> {
+    let mut name = None;
+    let mut skip_roundtrip_test = false;
+    let mut krate = syn::parse_quote!(::re_sdk);
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("component"))
+    {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(attr, "expected `#[component(name = \"…\")]`"));
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("name") =>
+                {
+                    let Lit::Str(lit) = &name_value.lit else {
+                        return Err(syn::Error::new_spanned(&name_value.lit, "expected a string"));
+                    };
+                    validate_name(&lit.value()).map_err(|err| syn::Error::new_spanned(lit, err))?;
+                    name = Some(lit.value());
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("crate") =>
+                {
+                    let Lit::Str(lit) = &name_value.lit else {
+                        return Err(syn::Error::new_spanned(&name_value.lit, "expected a string"));
+                    };
+                    krate = lit.parse()?;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip_roundtrip_test") => {
+                    skip_roundtrip_test = true;
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "unknown attribute, expected `name = \"…\"`, `skip_roundtrip_test` or `crate = \"…\"`",
+                    ));
+                }
+            }
+        }
+    }
+
+    let name = name.ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "missing the component name, e.g. `#[component(name = \"myorg.foo\")]`",
+        )
+    })?;
+
+    Ok(ComponentAttributes {
+        name,
+        skip_roundtrip_test,
+        krate,
+    })
+}
+
+/// Checks that a component name is `<namespace>.<name>`, outside of the namespace reserved for Rerun.
+fn validate_name(name: &str) -> Result<(), String> {
+    let Some((namespace, short_name)) = name.split_once('.') else {
+        return Err(format!(
+            "the component name {name:?} needs a namespace, e.g. \"myorg.{name}\""
+        ));
+    };
+    if namespace == "rerun" {
+        return Err(format!(
+            "the \"rerun\" namespace is reserved for the built-in components, use your own, e.g. \"myorg.{short_name}\""
+        ));
+    }
+    for part in name.split('.') {
+        if part.is_empty() {
+            return Err(format!("the component name {name:?} has an empty part"));
+        }
+        if !part
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(format!(
+                "the component name {name:?} may only contain lowercase letters, digits, underscores and dots"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn derive_component_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ComponentAttributes {
+        name,
+        skip_roundtrip_test,
+        krate,
+    } = parse_attributes(input)?;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic components are not supported",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "only structs can be components"));
+    };
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+
+    // Newtypes map to arrow exactly like the type they wrap.
+    // Structs with named fields borrow their arrow datatype and deserialization from a mirror
+    // of the struct deriving the `arrow2_convert` traits, but are serialized field by field
+    // straight into a struct array, so that nothing needs to be cloned.
+    // The mirror needs the same visibility, as it shows up in the trait implementations.
+    let arrow_impls = match &data.fields {
+        Fields::Named(fields) => {
+            let arrow_ident = format_ident!("__{}Arrow", ident);
+            let field_names = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap())
+                .collect::<Vec<_>>();
+            let field_types = fields
+                .named
+                .iter()
+                .map(|field| &field.ty)
+                .collect::<Vec<_>>();
+            let field_indices = 0..field_names.len();
+            quote! {
+                #[derive(arrow2_convert::ArrowField, arrow2_convert::ArrowDeserialize)]
+                #vis struct #arrow_ident { #(#field_names: #field_types,)* }
+
+                impl arrow2_convert::field::ArrowField for #ident {
+                    type Type = Self;
+
+                    #[inline]
+                    fn data_type() -> arrow2::datatypes::DataType {
+                        <#arrow_ident as arrow2_convert::field::ArrowField>::data_type()
+                    }
+                }
+
+                impl arrow2_convert::serialize::ArrowSerialize for #ident {
+                    type MutableArrayType = arrow2::array::MutableStructArray;
+
+                    #[inline]
+                    fn new_array() -> Self::MutableArrayType {
+                        arrow2::array::MutableStructArray::new(
+                            <Self as arrow2_convert::field::ArrowField>::data_type(),
+                            vec![#(
+                                ::std::boxed::Box::new(
+                                    <#field_types as arrow2_convert::serialize::ArrowSerialize>::new_array(),
+                                ),
+                            )*],
+                        )
+                    }
+
+                    #[inline]
+                    fn arrow_serialize(
+                        v: &Self,
+                        array: &mut Self::MutableArrayType,
+                    ) -> arrow2::error::Result<()> {
+                        #(
+                            <#field_types as arrow2_convert::serialize::ArrowSerialize>::arrow_serialize(
+                                &v.#field_names,
+                                array
+                                    .value::<<#field_types as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType>(#field_indices)
+                                    .expect("the children were created in new_array"),
+                            )?;
+                        )*
+                        array.push(true);
+                        Ok(())
+                    }
+                }
+
+                impl arrow2_convert::deserialize::ArrowDeserialize for #ident {
+                    type ArrayType =
+                        <#arrow_ident as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType;
+
+                    #[inline]
+                    fn arrow_deserialize(
+                        v: <&Self::ArrayType as ::std::iter::IntoIterator>::Item,
+                    ) -> ::std::option::Option<Self> {
+                        <#arrow_ident as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v)
+                            .map(|v| #ident { #(#field_names: v.#field_names,)* })
+                    }
+                }
+            }
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field_type = &fields.unnamed[0].ty;
+            quote! {
+                impl arrow2_convert::field::ArrowField for #ident {
+                    type Type = Self;
+
+                    #[inline]
+                    fn data_type() -> arrow2::datatypes::DataType {
+                        <#field_type as arrow2_convert::field::ArrowField>::data_type()
+                    }
+                }
+
+                impl arrow2_convert::serialize::ArrowSerialize for #ident {
+                    type MutableArrayType =
+                        <#field_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType;
+
+                    #[inline]
+                    fn new_array() -> Self::MutableArrayType {
+                        <#field_type as arrow2_convert::serialize::ArrowSerialize>::new_array()
+                    }
+
+                    #[inline]
+                    fn arrow_serialize(
+                        v: &Self,
+                        array: &mut Self::MutableArrayType,
+                    ) -> arrow2::error::Result<()> {
+                        <#field_type as arrow2_convert::serialize::ArrowSerialize>::arrow_serialize(
+                            &v.0, array,
+                        )
+                    }
+                }
+
+                impl arrow2_convert::deserialize::ArrowDeserialize for #ident {
+                    type ArrayType =
+                        <#field_type as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType;
+
+                    #[inline]
+                    fn arrow_deserialize(
+                        v: <&Self::ArrayType as ::std::iter::IntoIterator>::Item,
+                    ) -> ::std::option::Option<Self> {
+                        <#field_type as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v)
+                            .map(#ident)
+                    }
+                }
+            }
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "components need named fields, or a single unnamed field",
+            ));
+        }
+    };
+
+    let test = (!skip_roundtrip_test).then(|| {
+        let mod_ident = format_ident!("__{}_roundtrip", to_snake_case(&ident.to_string()));
+        quote! {
+            #[cfg(test)]
+            mod #mod_ident {
+                #[test]
+                fn roundtrip() {
+                    use #krate::Component as _;
+
+                    let values = (0..3)
+                        .map(|_| <super::#ident as ::std::default::Default>::default())
+                        .collect::<Vec<_>>();
+                    let cell = #krate::log::DataCell::from_native(values.iter());
+                    assert_eq!(cell.component_name(), <super::#ident>::name());
+
+                    let roundtripped = cell.as_native::<super::#ident>().collect::<Vec<_>>();
+                    assert_eq!(values, roundtripped);
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            use #krate::external::re_log_types::external::{arrow2, arrow2_convert};
+
+            #arrow_impls
+
+            arrow2_convert::arrow_enable_vec_for_type!(#ident);
+
+            impl #krate::Component for #ident {
+                #[inline]
+                fn name() -> #krate::ComponentName {
+                    #name.into()
+                }
+            }
+
+            // Report fields that can't be mapped to arrow here, rather than where the component is used.
+            fn assert_component<C>()
+            where
+                C: #krate::SerializableComponent
+                    + #krate::external::re_log_types::DeserializableComponent,
+                for<'a> &'a C::ArrayType: ::std::iter::IntoIterator,
+            {
+            }
+            let _ = assert_component::<#ident>;
+        };
+
+        #test
+    })
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(validate_name("myorg.wheel_odometry").is_ok());
+    assert!(validate_name("myorg.robot.wheel_odometry2").is_ok());
+
+    assert!(validate_name("wheel_odometry").is_err());
+    assert!(validate_name("rerun.wheel_odometry").is_err());
+    assert!(validate_name("myorg.").is_err());
+    assert!(validate_name("myorg..wheel_odometry").is_err());
+    assert!(validate_name("myorg.WheelOdometry").is_err());
+    assert!(validate_name("myorg.wheel odometry").is_err());
+}
+
+#[test]
+fn test_to_snake_case() {
+    assert_eq!(to_snake_case("WheelOdometry"), "wheel_odometry");
+    assert_eq!(to_snake_case("Speed"), "speed");
+}
@@ -45,6 +45,8 @@ pub enum Command {
     PlaybackTogglePlayPause,
     PlaybackStepBack,
     PlaybackStepForward,
+    PlaybackPreviousMarker,
+    PlaybackNextMarker,
 }
 
 impl Command {
@@ -124,6 +126,14 @@ impl Command {
                 "Step time forward",
                 "Move the time marker to the next point in time with any data",
             ),
+            Command::PlaybackPreviousMarker => (
+                "Previous marker",
+                "Move the time marker back to the previous bookmark or logged marker",
+            ),
+            Command::PlaybackNextMarker => (
+                "Next marker",
+                "Move the time marker to the next bookmark or logged marker",
+            ),
         }
     }
 
@@ -183,9 +193,18 @@ impl Command {
             Command::PlaybackTogglePlayPause => Some(key(Key::Space)),
             Command::PlaybackStepBack => Some(key(Key::ArrowLeft)),
             Command::PlaybackStepForward => Some(key(Key::ArrowRight)),
+            Command::PlaybackPreviousMarker => Some(key(Key::PageUp)),
+            Command::PlaybackNextMarker => Some(key(Key::PageDown)),
         }
     }
 
+    /// The command with this variant name, e.g. `"ToggleTimePanel"`.
+    pub fn from_name(name: &str) -> Option<Command> {
+        use strum::IntoEnumIterator as _;
+
+        Command::iter().find(|command| format!("{command:?}") == name)
+    }
+
     #[must_use = "Returns the Command that was triggered by some keyboard shortcut"]
     pub fn listen_for_kb_shortcut(egui_ctx: &egui::Context) -> Option<Command> {
         use strum::IntoEnumIterator as _;
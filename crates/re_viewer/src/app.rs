@@ -8,17 +8,28 @@ use nohash_hasher::IntMap;
 use poll_promise::Promise;
 
 use re_arrow_store::DataStoreStats;
-use re_data_store::log_db::LogDb;
+use re_data_store::{log_db::LogDb, InstancePath};
 use re_format::format_number;
-use re_log_types::{ApplicationId, LogMsg, RecordingId};
+use re_log_types::{
+    component_types::InstanceKey, ApplicationId, ArrowMsg, BeginRecordingMsg, DataRow, EntityPath,
+    LogMsg, MsgId, RecordingId, RecordingInfo, RecordingSource, Time, TimeInt, Timeline,
+    ViewerCommand, ViewerCommandReply, ViewerCommandResponse, ViewerEvent,
+};
 use re_renderer::WgpuResourcePoolStatistics;
 use re_smart_channel::Receiver;
 use re_ui::{toasts, Command};
 
 use crate::{
     app_icon::setup_app_icon,
-    misc::{AppOptions, Caches, RecordingConfig, ViewerContext},
-    ui::{data_ui::ComponentUiRegistry, Blueprint},
+    misc::{
+        file_import::{data_rows_from_file_contents, file_stem, FileKind},
+        AppOptions, Caches, Item, RecordingConfig, ViewerContext,
+    },
+    time_panel,
+    ui::{
+        data_ui::ComponentUiRegistry, Blueprint, SpaceViewClass, SpaceViewClassRegistry,
+        SpaceViewId,
+    },
     viewer_analytics::ViewerAnalytics,
 };
 
@@ -36,6 +47,8 @@ enum TimeControlCommand {
     TogglePlayPause,
     StepBack,
     StepForward,
+    PreviousMarker,
+    NextMarker,
 }
 
 // ----------------------------------------------------------------------------
@@ -48,6 +61,29 @@ pub struct StartupOptions {
 
 // ----------------------------------------------------------------------------
 
+/// Sends the interactions in the viewer to the logging SDK, see [`App::set_viewer_event_callback`].
+struct ViewerEventSender {
+    callback: Box<dyn Fn(ViewerEvent)>,
+
+    /// The last sent selection, to only send changes.
+    selection: Vec<(EntityPath, InstanceKey)>,
+
+    /// The last sent time, to only send changes.
+    time: Option<(Timeline, Option<TimeInt>)>,
+}
+
+/// A [`ViewerCommand::Screenshot`] waiting for the GPU readback.
+struct PendingScreenshot {
+    space_view_id: SpaceViewId,
+    requested_at: Instant,
+    reply: ViewerCommandReply,
+}
+
+/// Give up on a [`ViewerCommand::Screenshot`] after this long, e.g. if the space view is hidden.
+const SCREENSHOT_TIMEOUT_SEC: f32 = 5.0;
+
+// ----------------------------------------------------------------------------
+
 #[cfg(not(target_arch = "wasm32"))]
 const MIN_ZOOM_FACTOR: f32 = 0.2;
 #[cfg(not(target_arch = "wasm32"))]
@@ -64,6 +100,7 @@ pub struct App {
     text_log_rx: std::sync::mpsc::Receiver<re_log::LogMsg>,
 
     component_ui_registry: ComponentUiRegistry,
+    space_view_class_registry: SpaceViewClassRegistry,
 
     rx: Receiver<LogMsg>,
 
@@ -97,6 +134,12 @@ pub struct App {
     analytics: ViewerAnalytics,
 
     icon_status: AppIconStatus,
+
+    viewer_event_sender: Option<ViewerEventSender>,
+
+    /// See [`App::set_viewer_command_receiver`].
+    viewer_command_rx: Option<Receiver<(ViewerCommand, ViewerCommandReply)>>,
+    pending_screenshots: Vec<PendingScreenshot>,
 }
 
 impl App {
@@ -132,6 +175,7 @@ impl App {
             re_ui,
             text_log_rx,
             component_ui_registry: Default::default(),
+            space_view_class_registry: Default::default(),
             rx,
             log_dbs: Default::default(),
             state,
@@ -151,9 +195,48 @@ impl App {
             analytics,
 
             icon_status: AppIconStatus::NotSetTryAgain,
+
+            viewer_event_sender: None,
+
+            viewer_command_rx: None,
+            pending_screenshots: Default::default(),
         }
     }
 
+    /// Call `callback` with every interaction in the viewer, e.g. to send it back to the logging SDK.
+    ///
+    /// Called on the UI thread at the end of each frame.
+    pub fn set_viewer_event_callback(&mut self, callback: Box<dyn Fn(ViewerEvent)>) {
+        self.viewer_event_sender = Some(ViewerEventSender {
+            callback,
+            selection: Default::default(),
+            time: None,
+        });
+    }
+
+    /// Carry out the [`ViewerCommand`]s arriving on `rx`, e.g. from a control socket.
+    ///
+    /// Every command is answered through its [`ViewerCommandReply`].
+    /// Wake up the ui thread on each command, e.g. with [`crate::wake_up_ui_thread_on_each_msg`].
+    pub fn set_viewer_command_receiver(
+        &mut self,
+        rx: Receiver<(ViewerCommand, ViewerCommandReply)>,
+    ) {
+        self.viewer_command_rx = Some(rx);
+    }
+
+    /// Register custom uis for components here, e.g. for your own components.
+    pub fn component_ui_registry_mut(&mut self) -> &mut ComponentUiRegistry {
+        &mut self.component_ui_registry
+    }
+
+    /// Add a custom kind of space view, e.g. for an in-house visualization.
+    ///
+    /// Call this at startup, before any space views of the class are shown.
+    pub fn add_space_view_class<T: SpaceViewClass + 'static>(&mut self, class: T) {
+        self.space_view_class_registry.add(class);
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     pub fn set_profiler(&mut self, profiler: crate::Profiler) {
         self.state.profiler = profiler;
@@ -336,10 +419,316 @@ impl App {
             Command::PlaybackStepForward => {
                 self.run_time_control_command(TimeControlCommand::StepForward);
             }
+            Command::PlaybackPreviousMarker => {
+                self.run_time_control_command(TimeControlCommand::PreviousMarker);
+            }
+            Command::PlaybackNextMarker => {
+                self.run_time_control_command(TimeControlCommand::NextMarker);
+            }
+        }
+    }
+
+    /// Adds the data created in the viewer during this frame to the current recording.
+    fn add_rows_from_viewer(&mut self) {
+        let rec_id = self.state.selected_rec_id;
+        let Some(rec_cfg) = self.state.recording_configs.get_mut(&rec_id) else { return };
+        if rec_cfg.pending_rows.is_empty() {
+            return;
+        }
+        let rows = std::mem::take(&mut rec_cfg.pending_rows);
+
+        let Some(log_db) = self.log_dbs.get_mut(&rec_id) else { return };
+        for row in rows {
+            match ArrowMsg::try_from(&row.into_table()) {
+                Ok(msg) => {
+                    if let Err(err) = log_db.add(LogMsg::ArrowMsg(msg)) {
+                        re_log::error!("Failed to add data created in the viewer: {err}");
+                    }
+                }
+                Err(err) => {
+                    re_log::error!("Failed to encode data created in the viewer: {err}");
+                }
+            }
+        }
+    }
+
+    /// Sends the interactions during this frame to the logging SDK, if it is listening.
+    fn send_viewer_events(&mut self) {
+        let rec_id = self.state.selected_rec_id;
+        let Some(rec_cfg) = self.state.recording_configs.get_mut(&rec_id) else { return };
+        let pending_events = std::mem::take(&mut rec_cfg.pending_viewer_events);
+        let Some(sender) = &mut self.viewer_event_sender else { return };
+
+        for event in pending_events {
+            (sender.callback)(event);
+        }
+
+        let selection = rec_cfg
+            .selection_state
+            .current()
+            .iter()
+            .filter_map(|item| match item {
+                Item::InstancePath(_, instance_path) => Some((
+                    instance_path.entity_path.clone(),
+                    instance_path.instance_key,
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if selection != sender.selection {
+            sender.selection = selection.clone();
+            (sender.callback)(ViewerEvent::SelectionChanged { selection });
+        }
+
+        let timeline = *rec_cfg.time_ctrl.timeline();
+        let time = rec_cfg.time_ctrl.time_int();
+        if sender.time != Some((timeline, time)) {
+            sender.time = Some((timeline, time));
+            (sender.callback)(ViewerEvent::TimeChanged { timeline, time });
+        }
+    }
+
+    fn receive_viewer_commands(&mut self, egui_ctx: &egui::Context) {
+        let Some(rx) = &self.viewer_command_rx else { return };
+        let mut commands = Vec::new();
+        while let Ok(command) = rx.try_recv() {
+            commands.push(command);
+        }
+
+        for (command, reply) in commands {
+            re_log::debug!("Running viewer command: {command:?}");
+            if let ViewerCommand::Screenshot { space_view } = &command {
+                // Answered once the GPU readback is done, see `Self::send_screenshots`.
+                self.request_screenshot(egui_ctx, space_view, reply);
+            } else {
+                let response = self
+                    .run_viewer_command(egui_ctx, command)
+                    .unwrap_or_else(|err| ViewerCommandResponse::error(re_error::format(&err)));
+                reply(response);
+            }
+        }
+    }
+
+    /// Runs anything but [`ViewerCommand::Screenshot`].
+    fn run_viewer_command(
+        &mut self,
+        egui_ctx: &egui::Context,
+        command: ViewerCommand,
+    ) -> anyhow::Result<ViewerCommandResponse> {
+        let rec_id = self.state.selected_rec_id;
+
+        match command {
+            ViewerCommand::RunCommand { command } => {
+                let Some(command) = Command::from_name(&command) else {
+                    anyhow::bail!("Unknown command {command:?}");
+                };
+                self.pending_commands.push(command);
+            }
+
+            ViewerCommand::SetTime { timeline, time } => {
+                let Some(log_db) = self.log_dbs.get(&rec_id) else {
+                    anyhow::bail!("No recording is shown");
+                };
+                if !log_db.timelines().any(|t| *t == timeline) {
+                    anyhow::bail!("Unknown timeline {:?}", timeline.name());
+                }
+                let rec_cfg = recording_config_entry(
+                    &mut self.state.recording_configs,
+                    rec_id,
+                    self.rx.source(),
+                    log_db,
+                );
+                rec_cfg
+                    .time_ctrl
+                    .set_timeline_related(timeline, &self.state.app_options.timeline_relations);
+                if let Some(time) = time {
+                    rec_cfg.time_ctrl.set_time(time);
+                    rec_cfg.time_ctrl.pause();
+                }
+            }
+
+            ViewerCommand::SetSelection { selection } => {
+                let Some(log_db) = self.log_dbs.get(&rec_id) else {
+                    anyhow::bail!("No recording is shown");
+                };
+                let rec_cfg = recording_config_entry(
+                    &mut self.state.recording_configs,
+                    rec_id,
+                    self.rx.source(),
+                    log_db,
+                );
+                let items = selection.into_iter().map(|(entity_path, instance_key)| {
+                    let instance_path = InstancePath::instance(entity_path, instance_key);
+                    Item::InstancePath(None, instance_path)
+                });
+                rec_cfg.selection_state.set_multi_selection(items);
+            }
+
+            ViewerCommand::SetPanels {
+                blueprint_panel,
+                selection_panel,
+                time_panel,
+            } => {
+                let blueprint = self.blueprint_mut(egui_ctx);
+                if let Some(expanded) = blueprint_panel {
+                    blueprint.blueprint_panel_expanded = expanded;
+                }
+                if let Some(expanded) = selection_panel {
+                    blueprint.selection_panel_expanded = expanded;
+                }
+                if let Some(expanded) = time_panel {
+                    blueprint.time_panel_expanded = expanded;
+                }
+            }
+
+            ViewerCommand::MaximizeSpaceView { space_view } => {
+                let viewport = &mut self.blueprint_mut(egui_ctx).viewport;
+                let space_view_id = if let Some(name) = space_view {
+                    let Some(space_view_id) = viewport.space_view_by_name(&name) else {
+                        anyhow::bail!("No space view named {name:?}");
+                    };
+                    Some(space_view_id)
+                } else {
+                    None
+                };
+                viewport.set_maximized(space_view_id);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            ViewerCommand::LoadRecording { path } => {
+                use anyhow::Context as _;
+                let loaded_file = load_file_path_impl(std::path::Path::new(&path))
+                    .with_context(|| format!("Failed loading {path:?}"))?;
+                self.show_loaded_file(loaded_file);
+            }
+            #[cfg(target_arch = "wasm32")]
+            ViewerCommand::LoadRecording { .. } => {
+                anyhow::bail!("Loading files is not supported on the web");
+            }
+
+            ViewerCommand::ListRecordings => {
+                let recordings = self
+                    .log_dbs
+                    .values()
+                    .map(|log_db| {
+                        let app_id = log_db
+                            .recording_info()
+                            .map_or_else(ApplicationId::unknown, |rec_info| {
+                                rec_info.application_id.clone()
+                            });
+                        (log_db.recording_id(), app_id)
+                    })
+                    .collect();
+                let selected = self.log_dbs.contains_key(&rec_id).then_some(rec_id);
+                return Ok(ViewerCommandResponse::Recordings {
+                    recordings,
+                    selected,
+                });
+            }
+
+            ViewerCommand::SelectRecording { recording_id } => {
+                if !self.log_dbs.contains_key(&recording_id) {
+                    anyhow::bail!("No recording with id {recording_id}");
+                }
+                self.state.selected_rec_id = recording_id;
+            }
+
+            ViewerCommand::CloseRecording { recording_id } => {
+                let recording_id = recording_id.unwrap_or(rec_id);
+                if self.log_dbs.remove(&recording_id).is_none() {
+                    anyhow::bail!("No recording with id {recording_id}");
+                }
+                // `Self::cleanup` selects another recording and forgets the config of this one.
+            }
+
+            ViewerCommand::Screenshot { .. } => {
+                anyhow::bail!("Screenshots are handled by `request_screenshot`");
+            }
+        }
+
+        Ok(ViewerCommandResponse::Ok)
+    }
+
+    fn request_screenshot(
+        &mut self,
+        egui_ctx: &egui::Context,
+        space_view: &str,
+        reply: ViewerCommandReply,
+    ) {
+        let rec_id = self.state.selected_rec_id;
+        let space_view_id = self
+            .blueprint_mut(egui_ctx)
+            .viewport
+            .space_view_by_name(space_view);
+        let rec_cfg = self.state.recording_configs.get_mut(&rec_id);
+        let (Some(space_view_id), Some(rec_cfg)) = (space_view_id, rec_cfg) else {
+            reply(ViewerCommandResponse::error(format!("No space view named {space_view:?}")));
+            return;
+        };
+
+        rec_cfg.screenshot_requests.push(space_view_id);
+        self.pending_screenshots.push(PendingScreenshot {
+            space_view_id,
+            requested_at: Instant::now(),
+            reply,
+        });
+    }
+
+    /// Answers the [`ViewerCommand::Screenshot`]s that were taken during this frame, or took too long.
+    fn send_screenshots(&mut self, egui_ctx: &egui::Context) {
+        if self.pending_screenshots.is_empty() {
+            return;
+        }
+
+        let rec_id = self.state.selected_rec_id;
+        let mut rec_cfg = self.state.recording_configs.get_mut(&rec_id);
+
+        let taken_screenshots = rec_cfg
+            .as_mut()
+            .map(|rec_cfg| std::mem::take(&mut rec_cfg.taken_screenshots))
+            .unwrap_or_default();
+        for screenshot in taken_screenshots {
+            let Some(index) = self
+                .pending_screenshots
+                .iter()
+                .position(|pending| pending.space_view_id == screenshot.space_view_id)
+            else {
+                continue;
+            };
+            let pending = self.pending_screenshots.swap_remove(index);
+            (pending.reply)(encode_screenshot(&screenshot));
+        }
+
+        let (timed_out, pending) = std::mem::take(&mut self.pending_screenshots)
+            .into_iter()
+            .partition::<Vec<_>, _>(|pending| {
+                pending.requested_at.elapsed().as_secs_f32() > SCREENSHOT_TIMEOUT_SEC
+            });
+        self.pending_screenshots = pending;
+        for pending in timed_out {
+            if let Some(rec_cfg) = &mut rec_cfg {
+                rec_cfg
+                    .screenshot_requests
+                    .retain(|id| *id != pending.space_view_id);
+            }
+            (pending.reply)(ViewerCommandResponse::error(
+                "Timed out waiting for the screenshot. Is the space view a visible 2D or 3D view?",
+            ));
+        }
+
+        if !self.pending_screenshots.is_empty() {
+            egui_ctx.request_repaint(); // Keep going until the readback is done.
         }
     }
 
     fn run_time_control_command(&mut self, command: TimeControlCommand) {
+        let selected_app_id = self.selected_app_id();
+        let bookmarks = self
+            .state
+            .blueprints
+            .get(&selected_app_id)
+            .map_or(&[][..], |blueprint| &blueprint.time_bookmarks);
+
         let rec_id = self.state.selected_rec_id;
         let Some(rec_cfg) = self.state.recording_configs.get_mut(&rec_id) else {return;};
         let time_ctrl = &mut rec_cfg.time_ctrl;
@@ -357,6 +746,12 @@ impl App {
             TimeControlCommand::StepForward => {
                 time_ctrl.step_time_fwd(times_per_timeline);
             }
+            TimeControlCommand::PreviousMarker | TimeControlCommand::NextMarker => {
+                let markers =
+                    time_panel::markers_on_timeline(log_db, bookmarks, time_ctrl.timeline());
+                let forward = matches!(command, TimeControlCommand::NextMarker);
+                time_panel::jump_to_marker(time_ctrl, &markers, forward);
+            }
         }
     }
 
@@ -476,6 +871,7 @@ impl eframe::App for App {
 
         self.show_text_logs_as_notifications();
         self.receive_messages(egui_ctx);
+        self.receive_viewer_commands(egui_ctx);
 
         self.cleanup();
 
@@ -498,6 +894,12 @@ impl eframe::App for App {
                 self.memory_panel_ui(ui, &gpu_resource_stats, &store_stats);
 
                 let log_db = self.log_dbs.entry(self.state.selected_rec_id).or_default();
+                // Timeline relations are a viewer option, but the store needs them to answer
+                // queries on related timelines.
+                log_db
+                    .entity_db
+                    .data_store
+                    .set_timeline_relations(&self.state.app_options.timeline_relations);
                 let selected_app_id = log_db
                     .recording_info()
                     .map_or_else(ApplicationId::unknown, |rec_info| {
@@ -539,6 +941,7 @@ impl eframe::App for App {
                             log_db,
                             &self.re_ui,
                             &self.component_ui_registry,
+                            &self.space_view_class_registry,
                             self.rx.source(),
                         );
                     }
@@ -552,6 +955,9 @@ impl eframe::App for App {
             paint_native_window_frame(egui_ctx);
         }
 
+        self.add_rows_from_viewer();
+        self.send_viewer_events();
+        self.send_screenshots(egui_ctx);
         self.handle_dropping_files(egui_ctx);
         self.toasts.show(egui_ctx);
 
@@ -628,6 +1034,9 @@ fn wait_screen_ui(ui: &mut egui::Ui, rx: &Receiver<LogMsg>) {
             }
             re_smart_channel::Source::RrdHttpStream { url } => {
                 ui.strong(format!("Loading {url}…"));
+                if let Some(progress) = crate::stream_rrd_from_http::progress(url) {
+                    ui.weak(download_progress_text(&progress));
+                }
             }
             re_smart_channel::Source::Sdk => {
                 ready_and_waiting(ui, "Waiting for logging data from SDK");
@@ -832,31 +1241,78 @@ impl App {
         self.log_dbs.insert(log_db.recording_id(), log_db);
     }
 
+    fn show_loaded_file(&mut self, loaded_file: LoadedFile) {
+        match loaded_file {
+            LoadedFile::Recording(log_db) => self.show_log_db(log_db),
+            LoadedFile::Data { file_name, rows } => self.add_data_rows(&file_name, rows),
+        }
+    }
+
+    /// Add loose data (e.g. from a dropped image) to the current recording.
+    ///
+    /// If there is no current recording, a new one is created.
+    fn add_data_rows(&mut self, file_name: &str, rows: Vec<DataRow>) {
+        if !self.log_db_is_nonempty() {
+            #[cfg(not(target_arch = "wasm32"))]
+            let started = Time::now();
+            #[cfg(target_arch = "wasm32")]
+            let started = Time::from_seconds_since_epoch(js_sys::Date::now() / 1000.0);
+
+            let mut log_db = LogDb::default();
+            let begin_recording_msg = BeginRecordingMsg {
+                msg_id: MsgId::random(),
+                info: RecordingInfo {
+                    application_id: ApplicationId(file_stem(file_name)),
+                    recording_id: RecordingId::random(),
+                    is_official_example: false,
+                    started,
+                    recording_source: RecordingSource::Other("file import".to_owned()),
+                },
+            };
+            if let Err(err) = log_db.add(LogMsg::BeginRecordingMsg(begin_recording_msg)) {
+                re_log::error!("Failed to create a recording for {file_name:?}: {err}");
+                return;
+            }
+            log_db.data_source = Some(re_smart_channel::Source::File {
+                path: file_name.into(),
+            });
+            self.show_log_db(log_db);
+        }
+
+        let log_db = self.log_db();
+        for row in rows {
+            match ArrowMsg::try_from(&row.into_table()) {
+                Ok(msg) => {
+                    if let Err(err) = log_db.add(LogMsg::ArrowMsg(msg)) {
+                        re_log::error!("Failed to add data from {file_name:?}: {err}");
+                    }
+                }
+                Err(err) => {
+                    re_log::error!("Failed to encode data from {file_name:?}: {err}");
+                }
+            }
+        }
+    }
+
     fn handle_dropping_files(&mut self, egui_ctx: &egui::Context) {
         preview_files_being_dropped(egui_ctx);
 
         // Collect dropped files:
-        if egui_ctx.input(|i| i.raw.dropped_files.len()) > 2 {
-            rfd::MessageDialog::new()
-                .set_level(rfd::MessageLevel::Error)
-                .set_description("Can only load one file at a time")
-                .show();
-        }
-        if let Some(file) = egui_ctx.input(|i| i.raw.dropped_files.first().cloned()) {
+        let dropped_files = egui_ctx.input(|i| i.raw.dropped_files.clone());
+        for file in dropped_files {
             if let Some(bytes) = &file.bytes {
                 let mut bytes: &[u8] = &(*bytes)[..];
-                if let Some(log_db) = load_file_contents(&file.name, &mut bytes) {
-                    self.show_log_db(log_db);
-
-                    #[allow(clippy::needless_return)] // false positive on wasm32
-                    return;
+                if let Some(loaded_file) = load_file_contents(&file.name, &mut bytes) {
+                    self.show_loaded_file(loaded_file);
+                    continue;
                 }
             }
 
+            // Fall back to loading from the path, if the contents were missing or failed to load:
             #[cfg(not(target_arch = "wasm32"))]
             if let Some(path) = &file.path {
-                if let Some(log_db) = load_file_path(path) {
-                    self.show_log_db(log_db);
+                if let Some(loaded_file) = load_file_path(path) {
+                    self.show_loaded_file(loaded_file);
                 }
             }
         }
@@ -904,6 +1360,8 @@ enum PanelSelection {
     Viewport,
 
     EventLog,
+
+    DataProfile,
 }
 
 #[derive(Default, serde::Deserialize, serde::Serialize)]
@@ -927,6 +1385,7 @@ struct AppState {
     panel_selection: PanelSelection,
 
     event_log_view: crate::event_log_view::EventLogView,
+    data_profile_view: crate::data_profile_view::DataProfileView,
 
     selection_panel: crate::selection_panel::SelectionPanel,
     time_panel: crate::time_panel::TimePanel,
@@ -945,6 +1404,7 @@ impl AppState {
         log_db: &LogDb,
         re_ui: &re_ui::ReUi,
         component_ui_registry: &ComponentUiRegistry,
+        space_view_class_registry: &SpaceViewClassRegistry,
         data_source: &re_smart_channel::Source,
     ) {
         crate::profile_function!();
@@ -956,6 +1416,7 @@ impl AppState {
             recording_configs,
             panel_selection,
             event_log_view,
+            data_profile_view,
             blueprints,
             selection_panel,
             time_panel,
@@ -975,6 +1436,7 @@ impl AppState {
             app_options: options,
             cache,
             component_ui_registry,
+            space_view_class_registry,
             log_db,
             rec_cfg,
             re_ui,
@@ -1001,6 +1463,7 @@ impl AppState {
                     .or_insert_with(|| Blueprint::new(ui.ctx()))
                     .blueprint_panel_and_viewport(&mut ctx, ui),
                 PanelSelection::EventLog => event_log_view.ui(&mut ctx, ui),
+                PanelSelection::DataProfile => data_profile_view.ui(&mut ctx, ui),
             });
 
         // move time last, so we get to see the first data first!
@@ -1223,6 +1686,8 @@ fn top_bar_ui(
         input_latency_label_ui(ui, app);
     }
 
+    download_progress_ui(ui, app);
+
     if let Some(log_db) = app.log_dbs.get(&app.state.selected_rec_id) {
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             let selected_app_id = log_db
@@ -1393,6 +1858,49 @@ fn input_latency_label_ui(ui: &mut egui::Ui, app: &mut App) {
     }
 }
 
+/// Shows how far along the download of an `.rrd` file streamed over http is, while it is in progress.
+fn download_progress_ui(ui: &mut egui::Ui, app: &App) {
+    let re_smart_channel::Source::RrdHttpStream { url } = app.rx.source() else {
+        return;
+    };
+    let Some(progress) = crate::stream_rrd_from_http::progress(url) else {
+        return;
+    };
+    if progress.finished {
+        return;
+    }
+
+    ui.separator();
+    let text = download_progress_text(&progress);
+    if let Some(num_bytes_total) = progress.num_bytes_total {
+        let fraction = progress.num_bytes_received as f32 / num_bytes_total.max(1) as f32;
+        ui.add(
+            egui::ProgressBar::new(fraction)
+                .desired_width(160.0)
+                .text(text),
+        )
+        .on_hover_text(format!("Downloading {url}"));
+    } else {
+        ui.weak(text).on_hover_text(format!("Downloading {url}"));
+    }
+
+    // Keep the progress up-to-date, even if no messages arrive.
+    ui.ctx()
+        .request_repaint_after(std::time::Duration::from_millis(250));
+}
+
+fn download_progress_text(progress: &crate::stream_rrd_from_http::HttpStreamProgress) -> String {
+    let received = re_format::format_bytes(progress.num_bytes_received as _);
+    if let Some(num_bytes_total) = progress.num_bytes_total {
+        format!(
+            "{received} / {}",
+            re_format::format_bytes(num_bytes_total as _)
+        )
+    } else {
+        received
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 const FILE_SAVER_PROMISE: &str = "file_saver";
@@ -1487,11 +1995,12 @@ fn save_buttons_ui(ui: &mut egui::Ui, app: &mut App) {
 #[cfg(not(target_arch = "wasm32"))]
 fn open(app: &mut App) {
     if let Some(path) = rfd::FileDialog::new()
+        .add_filter("supported files", FileKind::EXTENSIONS)
         .add_filter("rerun data file", &["rrd"])
         .pick_file()
     {
-        if let Some(log_db) = load_file_path(&path) {
-            app.show_log_db(log_db);
+        if let Some(loaded_file) = load_file_path(&path) {
+            app.show_loaded_file(loaded_file);
         }
     }
 }
@@ -1542,6 +2051,16 @@ fn main_view_selector_ui(ui: &mut egui::Ui, app: &mut App) {
             {
                 ui.close_menu();
             }
+            if ui
+                .selectable_value(
+                    &mut app.state.panel_selection,
+                    PanelSelection::DataProfile,
+                    "Data Profile",
+                )
+                .clicked()
+            {
+                ui.close_menu();
+            }
         });
     }
 }
@@ -1589,6 +2108,17 @@ fn options_menu_ui(ui: &mut egui::Ui, _frame: &mut eframe::Frame, options: &mut
         ui.close_menu();
     }
 
+    if ui
+        .checkbox(
+            &mut options.experimental_order_independent_transparency,
+            "(experimental) Order independent transparency",
+        )
+        .on_hover_text("Blend intersecting transparent objects correctly in 2D & 3D space views, at the cost of some accuracy. Not available on the web.")
+        .clicked()
+    {
+        ui.close_menu();
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     {
         if ui
@@ -1812,23 +2342,73 @@ fn load_rrd_to_log_db(mut read: impl std::io::Read) -> anyhow::Result<LogDb> {
     Ok(log_db)
 }
 
+/// The contents of a file that was opened or dropped into the viewer.
+enum LoadedFile {
+    /// A whole recording, from an `.rrd` file.
+    Recording(LogDb),
+
+    /// Loose data, e.g. from an image, mesh or point cloud file.
+    Data {
+        file_name: String,
+        rows: Vec<DataRow>,
+    },
+}
+
+fn load_file_contents_impl(
+    file_name: &str,
+    mut read: impl std::io::Read,
+) -> anyhow::Result<LoadedFile> {
+    match FileKind::from_file_name(file_name) {
+        Some(FileKind::Image | FileKind::Mesh(_) | FileKind::PointCloud) => {
+            let mut bytes = Vec::new();
+            read.read_to_end(&mut bytes)?;
+            let rows = data_rows_from_file_contents(file_name, bytes)?;
+            Ok(LoadedFile::Data {
+                file_name: file_name.to_owned(),
+                rows,
+            })
+        }
+        Some(FileKind::Rrd) | None => Ok(LoadedFile::Recording(load_rrd_to_log_db(read)?)),
+    }
+}
+
+fn encode_screenshot(screenshot: &crate::misc::TakenScreenshot) -> ViewerCommandResponse {
+    let [width, height] = screenshot.extent;
+    let Some(image) = image::RgbaImage::from_raw(width, height, screenshot.rgba.clone()) else {
+        return ViewerCommandResponse::error("Bad screenshot size");
+    };
+    let mut png = std::io::Cursor::new(Vec::new());
+    match image::DynamicImage::ImageRgba8(image).write_to(&mut png, image::ImageOutputFormat::Png) {
+        Ok(()) => ViewerCommandResponse::Screenshot {
+            width,
+            height,
+            png: png.into_inner(),
+        },
+        Err(err) => ViewerCommandResponse::error(format!("Failed to encode screenshot: {err}")),
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-#[must_use]
-fn load_file_path(path: &std::path::Path) -> Option<LogDb> {
-    fn load_file_path_impl(path: &std::path::Path) -> anyhow::Result<LogDb> {
-        crate::profile_function!();
-        use anyhow::Context as _;
-        let file = std::fs::File::open(path).context("Failed to open file")?;
-        load_rrd_to_log_db(file)
+fn load_file_path_impl(path: &std::path::Path) -> anyhow::Result<LoadedFile> {
+    crate::profile_function!();
+    use anyhow::Context as _;
+    let file = std::fs::File::open(path).context("Failed to open file")?;
+    let mut loaded_file = load_file_contents_impl(&path.to_string_lossy(), file)?;
+    if let LoadedFile::Recording(new_log_db) = &mut loaded_file {
+        new_log_db.data_source = Some(re_smart_channel::Source::File { path: path.into() });
     }
+    Ok(loaded_file)
+}
 
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+fn load_file_path(path: &std::path::Path) -> Option<LoadedFile> {
     re_log::info!("Loading {path:?}…");
 
     match load_file_path_impl(path) {
-        Ok(mut new_log_db) => {
+        Ok(loaded_file) => {
             re_log::info!("Loaded {path:?}");
-            new_log_db.data_source = Some(re_smart_channel::Source::File { path: path.into() });
-            Some(new_log_db)
+            Some(loaded_file)
         }
         Err(err) => {
             let msg = format!("Failed loading {path:?}: {}", re_error::format(&err));
@@ -1843,12 +2423,14 @@ fn load_file_path(path: &std::path::Path) -> Option<LogDb> {
 }
 
 #[must_use]
-fn load_file_contents(name: &str, read: impl std::io::Read) -> Option<LogDb> {
-    match load_rrd_to_log_db(read) {
-        Ok(mut log_db) => {
+fn load_file_contents(name: &str, read: impl std::io::Read) -> Option<LoadedFile> {
+    match load_file_contents_impl(name, read) {
+        Ok(mut loaded_file) => {
             re_log::info!("Loaded {name:?}");
-            log_db.data_source = Some(re_smart_channel::Source::File { path: name.into() });
-            Some(log_db)
+            if let LoadedFile::Recording(log_db) = &mut loaded_file {
+                log_db.data_source = Some(re_smart_channel::Source::File { path: name.into() });
+            }
+            Some(loaded_file)
         }
         Err(err) => {
             let msg = format!("Failed loading {name:?}: {}", re_error::format(&err));
@@ -1881,7 +2463,7 @@ fn new_recording_confg(
 
     let play_state = match data_source {
         // Play files from the start by default - it feels nice and alive./
-        // RrdHttpStream downloads the whole file before decoding it, so we treat it the same as a file.
+        // RrdHttpStream is a file that is still arriving, so we treat it the same as a file.
         re_smart_channel::Source::File { .. } | re_smart_channel::Source::RrdHttpStream { .. } => {
             PlayState::Playing
         }
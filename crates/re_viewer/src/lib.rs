@@ -12,12 +12,15 @@ pub mod stream_rrd_from_http;
 mod ui;
 mod viewer_analytics;
 
-pub(crate) use misc::{mesh_loader, Item, TimeControl, TimeView, ViewerContext};
+pub(crate) use misc::{mesh_loader, Item, TimeControl, TimeView};
 use re_log_types::PythonVersion;
-pub(crate) use ui::{event_log_view, memory_panel, selection_panel, time_panel, UiVerbosity};
+pub(crate) use ui::{data_profile_view, event_log_view, memory_panel, selection_panel, time_panel};
 
 pub use app::{App, StartupOptions};
+pub use misc::ViewerContext;
 pub use remote_viewer_app::RemoteViewerApp;
+pub use ui::data_ui::{ComponentUiRegistry, UiVerbosity};
+pub use ui::{SceneQuery, SpaceViewClass};
 
 pub mod external {
     pub use eframe;
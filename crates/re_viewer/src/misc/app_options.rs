@@ -25,6 +25,14 @@ pub struct AppOptions {
 
     /// Displays an overlay for debugging picking.
     pub show_picking_debug_overlay: bool,
+
+    /// Render transparent objects with order independent transparency instead of sorting them.
+    pub experimental_order_independent_transparency: bool,
+
+    /// How the times of timelines map onto each other, e.g. `sensor_time` onto `log_time`.
+    ///
+    /// Applies to all recordings with these timelines.
+    pub timeline_relations: Vec<re_log_types::TimelineRelation>,
 }
 
 impl Default for AppOptions {
@@ -44,6 +52,21 @@ impl Default for AppOptions {
             experimental_space_view_screenshots: false,
 
             show_picking_debug_overlay: false,
+
+            experimental_order_independent_transparency: false,
+
+            timeline_relations: Vec::new(),
+        }
+    }
+}
+
+impl AppOptions {
+    /// How space views should render transparent objects.
+    pub fn transparency_mode(&self) -> re_renderer::TransparencyMode {
+        if self.experimental_order_independent_transparency {
+            re_renderer::TransparencyMode::OrderIndependent
+        } else {
+            re_renderer::TransparencyMode::Sorted
         }
     }
 }
@@ -64,6 +64,7 @@ impl<'store, 'cache> ColoredTensorView<'store, 'cache> {
                     format: wgpu::TextureFormat::Rgba8UnormSrgb,
                     width: i.width() as u32,
                     height: i.height() as u32,
+                    generate_mip_maps: true,
                 },
             )
         })
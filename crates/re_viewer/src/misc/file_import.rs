@@ -0,0 +1,548 @@
+//! Turning loose files (images, meshes, point clouds) into log data, so that they can be
+//! dropped into the viewer without first being logged through an SDK.
+
+use anyhow::Context as _;
+
+use re_log_types::{
+    component_types::{ColorRGBA, Point3D, Tensor},
+    DataRow, EncodedMesh3D, EntityPath, EntityPathPart, Mesh3D, MeshFormat, MeshId, MsgId,
+    TimePoint,
+};
+
+/// The kinds of files the viewer knows how to open.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileKind {
+    /// A Rerun data file (`.rrd`).
+    Rrd,
+
+    /// An image, turned into a [`Tensor`].
+    Image,
+
+    /// A mesh, turned into a [`Mesh3D`].
+    Mesh(MeshFormat),
+
+    /// A point cloud (`.ply`), turned into [`Point3D`]s.
+    PointCloud,
+}
+
+impl FileKind {
+    /// All the file extensions we know how to open, in lowercase.
+    pub const EXTENSIONS: &'static [&'static str] =
+        &["rrd", "png", "jpg", "jpeg", "glb", "gltf", "obj", "ply"];
+
+    /// Guess the kind of file from its name, based on its extension.
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let extension = std::path::Path::new(file_name)
+            .extension()?
+            .to_string_lossy()
+            .to_lowercase();
+
+        match extension.as_str() {
+            "rrd" => Some(Self::Rrd),
+            "png" | "jpg" | "jpeg" => Some(Self::Image),
+            "glb" => Some(Self::Mesh(MeshFormat::Glb)),
+            "gltf" => Some(Self::Mesh(MeshFormat::Gltf)),
+            "obj" => Some(Self::Mesh(MeshFormat::Obj)),
+            "ply" => Some(Self::PointCloud),
+            _ => None,
+        }
+    }
+}
+
+/// The name of a file without directories or extension, e.g. `my_image` for `some/dir/my_image.png`.
+pub fn file_stem(file_name: &str) -> String {
+    std::path::Path::new(file_name).file_stem().map_or_else(
+        || file_name.to_owned(),
+        |stem| stem.to_string_lossy().into_owned(),
+    )
+}
+
+/// The entity path we log the contents of a loose file to, e.g. `/my_image` for `some/dir/my_image.png`.
+pub fn entity_path_from_file_name(file_name: &str) -> EntityPath {
+    EntityPath::new(vec![EntityPathPart::Name(
+        file_stem(file_name).as_str().into(),
+    )])
+}
+
+/// Convert the contents of an image, mesh or point cloud file to timeless [`DataRow`]s.
+///
+/// `.rrd` files are not handled here, since they are whole recordings rather than loose data.
+pub fn data_rows_from_file_contents(
+    file_name: &str,
+    bytes: Vec<u8>,
+) -> anyhow::Result<Vec<DataRow>> {
+    crate::profile_function!(file_name);
+
+    let Some(kind) = FileKind::from_file_name(file_name) else {
+        anyhow::bail!(
+            "Unknown file type. Supported extensions: {}",
+            FileKind::EXTENSIONS.join(", ")
+        );
+    };
+
+    let entity_path = entity_path_from_file_name(file_name);
+    let timepoint = TimePoint::timeless();
+
+    let row = match kind {
+        FileKind::Rrd => anyhow::bail!("{file_name:?} is a recording, not loose data"),
+
+        FileKind::Image => {
+            let tensor = tensor_from_image_bytes(bytes)?;
+            DataRow::from_cells1(MsgId::random(), entity_path, timepoint, 1, vec![tensor])
+        }
+
+        FileKind::Mesh(format) => {
+            let mesh = Mesh3D::Encoded(EncodedMesh3D {
+                mesh_id: MeshId::random(),
+                format,
+                bytes: bytes.into(),
+                transform: [
+                    [1.0, 0.0, 0.0],
+                    [0.0, 1.0, 0.0],
+                    [0.0, 0.0, 1.0],
+                    [0.0, 0.0, 0.0],
+                ],
+            });
+            DataRow::from_cells1(MsgId::random(), entity_path, timepoint, 1, vec![mesh])
+        }
+
+        FileKind::PointCloud => {
+            let PointCloud { positions, colors } =
+                load_ply(&bytes).context("Failed to parse .ply file")?;
+            let num_instances = positions.len() as u32;
+            if let Some(colors) = colors {
+                DataRow::from_cells2(
+                    MsgId::random(),
+                    entity_path,
+                    timepoint,
+                    num_instances,
+                    (positions, colors),
+                )
+            } else {
+                DataRow::from_cells1(
+                    MsgId::random(),
+                    entity_path,
+                    timepoint,
+                    num_instances,
+                    positions,
+                )
+            }
+        }
+    };
+
+    Ok(vec![row])
+}
+
+fn tensor_from_image_bytes(bytes: Vec<u8>) -> anyhow::Result<Tensor> {
+    let format = image::guess_format(&bytes).context("Unknown image format")?;
+
+    if format == image::ImageFormat::Jpeg {
+        // Keep RGB jpegs compressed - we decode them lazily in the viewer.
+        if let Ok(tensor) = Tensor::tensor_from_jpeg_bytes(bytes.clone()) {
+            return Ok(tensor);
+        }
+    }
+
+    let image = image::load_from_memory_with_format(&bytes, format)?;
+    Ok(Tensor::from_image(image)?)
+}
+
+// ----------------------------------------------------------------------------
+
+/// The points (and optionally colors) of a point cloud file.
+#[derive(Debug, PartialEq)]
+pub struct PointCloud {
+    pub positions: Vec<Point3D>,
+    pub colors: Option<Vec<ColorRGBA>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PlyScalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl PlyScalar {
+    fn parse(name: &str) -> anyhow::Result<Self> {
+        Ok(match name {
+            "char" | "int8" => Self::I8,
+            "uchar" | "uint8" => Self::U8,
+            "short" | "int16" => Self::I16,
+            "ushort" | "uint16" => Self::U16,
+            "int" | "int32" => Self::I32,
+            "uint" | "uint32" => Self::U32,
+            "float" | "float32" => Self::F32,
+            "double" | "float64" => Self::F64,
+            _ => anyhow::bail!("Unknown property type {name:?}"),
+        })
+    }
+
+    fn size_in_bytes(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    fn is_float(self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+}
+
+#[derive(Debug)]
+enum PlyProperty {
+    Scalar { name: String, scalar: PlyScalar },
+    List { count: PlyScalar, item: PlyScalar },
+}
+
+#[derive(Debug)]
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<PlyProperty>,
+}
+
+/// Reads the scalars in the body of a `.ply` file, one at a time.
+enum PlyReader<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary { bytes: &'a [u8], big_endian: bool },
+}
+
+impl<'a> PlyReader<'a> {
+    fn read(&mut self, scalar: PlyScalar) -> anyhow::Result<f64> {
+        match self {
+            Self::Ascii(tokens) => {
+                let token = tokens.next().context("Unexpected end of file")?;
+                token
+                    .parse()
+                    .with_context(|| format!("Expected a number, got {token:?}"))
+            }
+            Self::Binary { bytes, big_endian } => {
+                let size = scalar.size_in_bytes();
+                anyhow::ensure!(size <= bytes.len(), "Unexpected end of file");
+                let remaining: &'a [u8] = *bytes;
+                let (head, tail) = remaining.split_at(size);
+                *bytes = tail;
+
+                macro_rules! read_as {
+                    ($ty: ty) => {{
+                        let head = head.try_into()?;
+                        (if *big_endian {
+                            <$ty>::from_be_bytes(head)
+                        } else {
+                            <$ty>::from_le_bytes(head)
+                        }) as f64
+                    }};
+                }
+
+                Ok(match scalar {
+                    PlyScalar::I8 => read_as!(i8),
+                    PlyScalar::U8 => read_as!(u8),
+                    PlyScalar::I16 => read_as!(i16),
+                    PlyScalar::U16 => read_as!(u16),
+                    PlyScalar::I32 => read_as!(i32),
+                    PlyScalar::U32 => read_as!(u32),
+                    PlyScalar::F32 => read_as!(f32),
+                    PlyScalar::F64 => read_as!(f64),
+                })
+            }
+        }
+    }
+}
+
+/// Parse the vertices of a [`.ply`](https://en.wikipedia.org/wiki/PLY_(file_format)) file.
+///
+/// Supports both the ascii and binary encodings.
+/// Any other elements (e.g. faces) are skipped over.
+pub fn load_ply(bytes: &[u8]) -> anyhow::Result<PointCloud> {
+    crate::profile_function!();
+
+    const END_HEADER: &[u8] = b"end_header";
+    let end_header = bytes
+        .windows(END_HEADER.len())
+        .position(|window| window == END_HEADER)
+        .context("Missing 'end_header'")?;
+    let body_start = bytes[end_header..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |newline| end_header + newline + 1);
+
+    let header = std::str::from_utf8(&bytes[..end_header]).context("Header is not utf8")?;
+    let mut lines = header.lines().map(str::trim);
+    anyhow::ensure!(lines.next() == Some("ply"), "Missing 'ply' magic number");
+
+    let mut format = None;
+    let mut elements: Vec<PlyElement> = Vec::new();
+
+    for line in lines {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        match words.as_slice() {
+            [] | ["comment" | "obj_info", ..] => {}
+            ["format", encoding, _version] => {
+                format = Some(match *encoding {
+                    "ascii" => PlyFormat::Ascii,
+                    "binary_little_endian" => PlyFormat::BinaryLittleEndian,
+                    "binary_big_endian" => PlyFormat::BinaryBigEndian,
+                    _ => anyhow::bail!("Unknown format {encoding:?}"),
+                });
+            }
+            ["element", name, count] => {
+                elements.push(PlyElement {
+                    name: (*name).to_owned(),
+                    count: count
+                        .parse()
+                        .with_context(|| format!("Bad element count {count:?}"))?,
+                    properties: Vec::new(),
+                });
+            }
+            ["property", "list", count, item, _name] => {
+                let element = elements.last_mut().context("Property outside of element")?;
+                element.properties.push(PlyProperty::List {
+                    count: PlyScalar::parse(count)?,
+                    item: PlyScalar::parse(item)?,
+                });
+            }
+            ["property", scalar, name] => {
+                let element = elements.last_mut().context("Property outside of element")?;
+                element.properties.push(PlyProperty::Scalar {
+                    name: (*name).to_owned(),
+                    scalar: PlyScalar::parse(scalar)?,
+                });
+            }
+            _ => anyhow::bail!("Unexpected header line {line:?}"),
+        }
+    }
+
+    let format = format.context("Missing 'format' in header")?;
+    for element in &elements {
+        // Reading an element without properties consumes nothing, so its count would be unbounded:
+        anyhow::ensure!(
+            element.count == 0 || !element.properties.is_empty(),
+            "Element {:?} has no properties",
+            element.name
+        );
+    }
+    let body = &bytes[body_start..];
+    let mut reader = match format {
+        PlyFormat::Ascii => PlyReader::Ascii(
+            std::str::from_utf8(body)
+                .context("Body is not utf8")?
+                .split_ascii_whitespace(),
+        ),
+        PlyFormat::BinaryLittleEndian => PlyReader::Binary {
+            bytes: body,
+            big_endian: false,
+        },
+        PlyFormat::BinaryBigEndian => PlyReader::Binary {
+            bytes: body,
+            big_endian: true,
+        },
+    };
+
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+
+    for element in &elements {
+        let is_vertex = element.name == "vertex";
+
+        let property_index = |wanted: &str| {
+            element.properties.iter().position(
+                |property| matches!(property, PlyProperty::Scalar { name, .. } if name == wanted),
+            )
+        };
+        let xyz = [
+            property_index("x"),
+            property_index("y"),
+            property_index("z"),
+        ];
+        let rgba = [
+            property_index("red"),
+            property_index("green"),
+            property_index("blue"),
+            property_index("alpha"),
+        ];
+        let has_colors = rgba[..3].iter().all(Option::is_some);
+
+        if is_vertex {
+            anyhow::ensure!(
+                xyz.iter().all(Option::is_some),
+                "Vertices are missing x/y/z properties"
+            );
+            // Don't trust the header: every property takes at least one byte in the body.
+            let max_count = body.len() / element.properties.len().max(1);
+            let capacity = element.count.min(max_count);
+            positions.reserve(capacity);
+            if has_colors {
+                colors.reserve(capacity);
+            }
+        }
+
+        let mut values = vec![0.0; element.properties.len()];
+        for _ in 0..element.count {
+            for (value, property) in values.iter_mut().zip(&element.properties) {
+                match property {
+                    PlyProperty::Scalar { name: _, scalar } => {
+                        *value = reader.read(*scalar)?;
+                    }
+                    PlyProperty::List { count, item } => {
+                        let count = reader.read(*count)? as usize;
+                        for _ in 0..count {
+                            reader.read(*item)?;
+                        }
+                    }
+                }
+            }
+
+            if !is_vertex {
+                continue;
+            }
+
+            let [x, y, z] = xyz.map(|i| i.map_or(0.0, |i| values[i]) as f32);
+            positions.push(Point3D::new(x, y, z));
+
+            if has_colors {
+                let [r, g, b, a] = rgba.map(|i| {
+                    i.map_or(255, |i| {
+                        let is_float = matches!(
+                            element.properties[i],
+                            PlyProperty::Scalar { scalar, .. } if scalar.is_float()
+                        );
+                        let value = if is_float {
+                            values[i] * 255.0
+                        } else {
+                            values[i]
+                        };
+                        value.round().clamp(0.0, 255.0) as u8
+                    })
+                });
+                colors.push(ColorRGBA::from_unmultiplied_rgba(r, g, b, a));
+            }
+        }
+    }
+
+    anyhow::ensure!(
+        elements.iter().any(|element| element.name == "vertex"),
+        "No 'vertex' element found"
+    );
+
+    Ok(PointCloud {
+        positions,
+        colors: (!colors.is_empty()).then_some(colors),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_kind_from_name() {
+        assert_eq!(FileKind::from_file_name("foo/bar.rrd"), Some(FileKind::Rrd));
+        assert_eq!(FileKind::from_file_name("image.PNG"), Some(FileKind::Image));
+        assert_eq!(
+            FileKind::from_file_name("mesh.glb"),
+            Some(FileKind::Mesh(MeshFormat::Glb))
+        );
+        assert_eq!(
+            FileKind::from_file_name("cloud.ply"),
+            Some(FileKind::PointCloud)
+        );
+        assert_eq!(FileKind::from_file_name("notes.txt"), None);
+        assert_eq!(FileKind::from_file_name("no_extension"), None);
+    }
+
+    #[test]
+    fn entity_path_from_name() {
+        assert_eq!(
+            entity_path_from_file_name("some/dir/my_image.png"),
+            EntityPath::from(vec![EntityPathPart::from("my_image")])
+        );
+    }
+
+    #[test]
+    fn ply_ascii() {
+        let ply = "ply
+format ascii 1.0
+comment made by hand
+element vertex 2
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+0 1 2 255 0 0
+3.5 4 5 0 128 255
+3 0 1 1
+";
+        let cloud = load_ply(ply.as_bytes()).unwrap();
+        assert_eq!(
+            cloud,
+            PointCloud {
+                positions: vec![Point3D::new(0.0, 1.0, 2.0), Point3D::new(3.5, 4.0, 5.0)],
+                colors: Some(vec![
+                    ColorRGBA::from_rgb(255, 0, 0),
+                    ColorRGBA::from_rgb(0, 128, 255)
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn ply_binary() {
+        let mut ply = b"ply\nformat binary_little_endian 1.0\nelement vertex 2\nproperty float x\nproperty float y\nproperty double z\nend_header\n".to_vec();
+        for (x, y, z) in [(1.0_f32, 2.0_f32, 3.0_f64), (-1.0, -2.0, -3.0)] {
+            ply.extend_from_slice(&x.to_le_bytes());
+            ply.extend_from_slice(&y.to_le_bytes());
+            ply.extend_from_slice(&z.to_le_bytes());
+        }
+
+        let cloud = load_ply(&ply).unwrap();
+        assert_eq!(
+            cloud,
+            PointCloud {
+                positions: vec![Point3D::new(1.0, 2.0, 3.0), Point3D::new(-1.0, -2.0, -3.0)],
+                colors: None,
+            }
+        );
+    }
+
+    #[test]
+    fn ply_lying_header() {
+        let ply = format!(
+            "ply\nformat ascii 1.0\nelement vertex {}\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nend_header\n0 1 2 255 0 0\n",
+            usize::MAX
+        );
+        assert!(load_ply(ply.as_bytes()).is_err());
+
+        let ply = format!(
+            "ply\nformat ascii 1.0\nelement foo {}\nelement vertex 1\nproperty float x\nproperty float y\nproperty float z\nend_header\n0 1 2\n",
+            usize::MAX
+        );
+        assert!(load_ply(ply.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn ply_truncated() {
+        let ply = "ply\nformat ascii 1.0\nelement vertex 2\nproperty float x\nproperty float y\nproperty float z\nend_header\n0 1 2\n";
+        assert!(load_ply(ply.as_bytes()).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use itertools::Itertools;
 use re_data_store::{InstancePath, LogDb};
 use re_log_types::{ComponentPath, MsgId};
 
-use crate::ui::SpaceViewId;
+use crate::ui::{view_spatial::MeasurementId, SpaceViewId};
 
 /// One "thing" in the UI.
 ///
@@ -16,6 +16,7 @@ pub enum Item {
     SpaceView(SpaceViewId),
     InstancePath(Option<SpaceViewId>, InstancePath),
     DataBlueprintGroup(SpaceViewId, crate::ui::DataBlueprintGroupHandle),
+    Measurement(SpaceViewId, MeasurementId),
 }
 
 impl std::fmt::Debug for Item {
@@ -26,6 +27,7 @@ impl std::fmt::Debug for Item {
             Item::SpaceView(s) => write!(f, "{s:?}"),
             Item::InstancePath(sid, path) => write!(f, "({sid:?}, {path})"),
             Item::DataBlueprintGroup(sid, handle) => write!(f, "({sid:?}, {handle:?})"),
+            Item::Measurement(sid, id) => write!(f, "({sid:?}, {id:?})"),
         }
     }
 }
@@ -52,6 +54,17 @@ impl Item {
                     false
                 }
             }
+            Item::Measurement(space_view_id, measurement_id) => blueprint
+                .viewport
+                .space_view(space_view_id)
+                .map_or(false, |space_view| {
+                    space_view
+                        .view_state
+                        .state_spatial
+                        .measurements
+                        .get(*measurement_id)
+                        .is_some()
+                }),
         }
     }
 
@@ -72,6 +85,7 @@ impl Item {
             Item::ComponentPath(_) => "Entity Component",
             Item::SpaceView(_) => "Space View",
             Item::DataBlueprintGroup(_, _) => "Group",
+            Item::Measurement(_, _) => "Measurement",
         }
     }
 }
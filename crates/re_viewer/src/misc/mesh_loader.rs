@@ -72,11 +72,7 @@ impl LoadedMesh {
 
         let mut slf = Self::load_raw(name, *format, bytes.as_slice(), render_ctx)?;
 
-        // TODO(cmc): Why are we creating the matrix twice here?
-        let (scale, rotation, translation) =
-            glam::Affine3A::from_cols_array_2d(transform).to_scale_rotation_translation();
-        let transform =
-            glam::Affine3A::from_scale_rotation_translation(scale, rotation, translation);
+        let transform = glam::Affine3A::from_cols_array_2d(transform);
         for instance in &mut slf.mesh_instances {
             instance.world_from_mesh = transform * instance.world_from_mesh;
         }
@@ -161,15 +157,47 @@ impl LoadedMesh {
                 albedo_multiplier: albedo_factor.map_or(re_renderer::Rgba::WHITE, |v| {
                     re_renderer::Rgba::from_rgba_unmultiplied(v.x(), v.y(), v.z(), v.w())
                 }),
+                normal_map: None,
+                normal_scale: 1.0,
+                metallic_roughness: None,
+                metallic_factor: re_renderer::mesh::Material::DEFAULT_METALLIC_FACTOR,
+                roughness_factor: re_renderer::mesh::Material::DEFAULT_ROUGHNESS_FACTOR,
             }],
         };
 
+        let gpu_mesh = render_ctx.mesh_manager.write().create(
+            render_ctx,
+            &mesh,
+            ResourceLifeTime::LongLived,
+        )?;
         let mesh_instances = vec![re_renderer::renderer::MeshInstance {
-            gpu_mesh: render_ctx.mesh_manager.write().create(
-                render_ctx,
-                &mesh,
-                ResourceLifeTime::LongLived,
-            )?,
+            gpu_mesh,
+            mesh: Some(std::sync::Arc::new(mesh)),
+            ..Default::default()
+        }];
+
+        Ok(Self {
+            name,
+            bbox,
+            mesh_instances,
+        })
+    }
+
+    /// Uploads a mesh that was built on the fly, e.g. an image bent by the lens distortion of a camera.
+    pub fn from_cpu_mesh(
+        name: String,
+        mesh: re_renderer::mesh::Mesh,
+        lifetime: ResourceLifeTime,
+        render_ctx: &mut RenderContext,
+    ) -> anyhow::Result<Self> {
+        let bbox = macaw::BoundingBox::from_points(mesh.vertex_positions.iter().copied());
+        let gpu_mesh = render_ctx
+            .mesh_manager
+            .write()
+            .create(render_ctx, &mesh, lifetime)?;
+        let mesh_instances = vec![re_renderer::renderer::MeshInstance {
+            gpu_mesh,
+            mesh: Some(std::sync::Arc::new(mesh)),
             ..Default::default()
         }];
 
@@ -1,5 +1,6 @@
 mod app_options;
 pub mod caches;
+pub(crate) mod file_import;
 pub mod format_time;
 mod item;
 pub(crate) mod mesh_loader;
@@ -27,7 +28,7 @@ pub(crate) mod profiler;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod clipboard;
 
-pub use transform_cache::{TransformCache, UnreachableTransform};
+pub use transform_cache::{DistortedTransform, TransformCache, UnreachableTransform};
 pub use {
     app_options::*,
     item::{Item, ItemCollection},
@@ -301,7 +301,8 @@ impl SelectionState {
                 Item::MsgId(_)
                 | Item::ComponentPath(_)
                 | Item::SpaceView(_)
-                | Item::DataBlueprintGroup(_, _) => current == test,
+                | Item::DataBlueprintGroup(_, _)
+                | Item::Measurement(_, _) => current == test,
 
                 Item::InstancePath(current_space_view_id, current_instance_path) => {
                     if let Item::InstancePath(test_space_view_id, test_instance_path) = test {
@@ -356,7 +357,10 @@ impl SelectionState {
 
         for current_selection in self.selection.iter() {
             match current_selection {
-                Item::MsgId(_) | Item::ComponentPath(_) | Item::SpaceView(_) => {}
+                Item::MsgId(_)
+                | Item::ComponentPath(_)
+                | Item::SpaceView(_)
+                | Item::Measurement(_, _) => {}
 
                 Item::DataBlueprintGroup(group_space_view_id, group_handle) => {
                     if *group_space_view_id == space_view_id {
@@ -432,7 +436,10 @@ impl SelectionState {
 
         for current_hover in self.hovered_previous_frame.iter() {
             match current_hover {
-                Item::MsgId(_) | Item::ComponentPath(_) | Item::SpaceView(_) => {}
+                Item::MsgId(_)
+                | Item::ComponentPath(_)
+                | Item::SpaceView(_)
+                | Item::Measurement(_, _) => {}
 
                 Item::DataBlueprintGroup(group_space_view_id, group_handle) => {
                     // Unlike for selected objects/data we are more picky for data blueprints with our hover highlights
@@ -65,7 +65,10 @@ impl SpaceInfo {
                     Transform::Unknown => {
                         continue;
                     }
-                    Transform::Rigid3(_) => false,
+                    Transform::Rigid3(_)
+                    | Transform::Scale(_)
+                    | Transform::Affine3(_)
+                    | Transform::Homography2D(_) => false,
                     Transform::Pinhole(_) => {
                         // Don't allow nested pinhole
                         if encountered_pinhole {
@@ -227,7 +230,10 @@ impl SpaceInfoCollection {
                 // Matches the connectedness requirements in `inverse_transform_at`/`transform_at` in `transform_cache.rs`
                 match transform {
                     Transform::Unknown => Err(UnreachableTransform::UnknownTransform),
-                    Transform::Rigid3(_) => Ok(()),
+                    Transform::Rigid3(_)
+                    | Transform::Scale(_)
+                    | Transform::Affine3(_)
+                    | Transform::Homography2D(_) => Ok(()),
                     Transform::Pinhole(pinhole) => {
                         if encountered_pinhole {
                             Err(UnreachableTransform::NestedPinholeCameras)
@@ -3,7 +3,9 @@ use std::collections::{BTreeMap, BTreeSet};
 use egui::NumExt as _;
 
 use re_data_store::TimesPerTimeline;
-use re_log_types::{Duration, TimeInt, TimeRange, TimeRangeF, TimeReal, TimeType, Timeline};
+use re_log_types::{
+    Duration, TimeInt, TimeRange, TimeRangeF, TimeReal, TimeType, Timeline, TimelineRelation,
+};
 
 /// The time range we are currently zoomed in on.
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
@@ -390,6 +392,27 @@ impl TimeControl {
         self.timeline = timeline;
     }
 
+    /// Switch to another timeline, moving its time marker and view to where we are now if the two
+    /// timelines are related.
+    pub fn set_timeline_related(&mut self, timeline: Timeline, relations: &[TimelineRelation]) {
+        let relation = TimelineRelation::find(relations, self.timeline, timeline);
+        let state = self.states.get(&self.timeline).copied();
+        self.timeline = timeline;
+
+        let (Some(relation), Some(state)) = (relation, state) else {
+            return;
+        };
+        let new_state = self
+            .states
+            .entry(timeline)
+            .or_insert_with(|| TimeState::new(state.time));
+        new_state.time = relation.map(state.time);
+        new_state.view = state.view.map(|view| TimeView {
+            min: relation.map(view.min),
+            time_spanned: view.time_spanned * relation.scale,
+        });
+    }
+
     /// The current time.
     pub fn time(&self) -> Option<TimeReal> {
         self.states.get(&self.timeline).map(|state| state.time)
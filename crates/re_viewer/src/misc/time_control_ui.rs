@@ -1,7 +1,7 @@
 use egui::NumExt as _;
 
 use re_data_store::TimesPerTimeline;
-use re_log_types::TimeType;
+use re_log_types::{TimeType, Timeline, TimelineRelation};
 
 use super::time_control::{Looping, PlayState, TimeControl};
 
@@ -9,6 +9,7 @@ impl TimeControl {
     pub fn timeline_selector_ui(
         &mut self,
         times_per_timeline: &TimesPerTimeline,
+        timeline_relations: &mut Vec<TimelineRelation>,
         ui: &mut egui::Ui,
     ) {
         self.select_a_valid_timeline(times_per_timeline);
@@ -24,10 +25,16 @@ impl TimeControl {
                         .selectable_label(timeline == self.timeline(), timeline.name().as_str())
                         .clicked()
                     {
-                        self.set_timeline(*timeline);
+                        self.set_timeline_related(*timeline, timeline_relations);
                     }
                 }
             });
+
+        ui.menu_button("🔗", |ui| {
+            timeline_relations_ui(ui, times_per_timeline, timeline_relations);
+        })
+        .response
+        .on_hover_text("Relate the times of different timelines");
     }
 
     pub fn fps_ui(&mut self, ui: &mut egui::Ui) {
@@ -190,3 +197,84 @@ fn toggle_playback_text(egui_ctx: &egui::Context) -> String {
         Default::default()
     }
 }
+
+/// Edit how the times of one timeline map onto another.
+fn timeline_relations_ui(
+    ui: &mut egui::Ui,
+    times_per_timeline: &TimesPerTimeline,
+    relations: &mut Vec<TimelineRelation>,
+) {
+    ui.label("Map the times of one timeline onto another, to switch between them at the same instant and to show data logged on only one of them.");
+    ui.weak("to = from × scale + offset");
+
+    let mut remove = None;
+    egui::Grid::new("timeline_relations")
+        .num_columns(6)
+        .show(ui, |ui| {
+            for (i, relation) in relations.iter_mut().enumerate() {
+                timeline_combo_ui(ui, ("from", i), times_per_timeline, &mut relation.from);
+                ui.label("→");
+                timeline_combo_ui(ui, ("to", i), times_per_timeline, &mut relation.to);
+
+                ui.add(
+                    egui::DragValue::new(&mut relation.scale)
+                        .prefix("× ")
+                        .speed(0.0001)
+                        .clamp_range(f64::MIN_POSITIVE..=f64::INFINITY),
+                );
+
+                // The offset is in the unit of the `to` timeline.
+                match relation.to.typ() {
+                    TimeType::Time => {
+                        let mut offset_secs = relation.offset * 1e-9;
+                        ui.add(
+                            egui::DragValue::new(&mut offset_secs)
+                                .prefix("+ ")
+                                .suffix(" s")
+                                .speed(0.001),
+                        );
+                        relation.offset = offset_secs * 1e9;
+                    }
+                    TimeType::Sequence => {
+                        ui.add(egui::DragValue::new(&mut relation.offset).prefix("+ "));
+                    }
+                }
+
+                if ui.small_button("Remove").clicked() {
+                    remove = Some(i);
+                }
+                if !relation.is_valid() {
+                    ui.colored_label(ui.visuals().warn_fg_color, "⚠")
+                        .on_hover_text("A timeline can only be related to another timeline");
+                }
+                ui.end_row();
+            }
+        });
+    if let Some(i) = remove {
+        relations.remove(i);
+    }
+
+    let mut timelines = times_per_timeline.timelines();
+    if let (Some(from), Some(to)) = (timelines.next(), timelines.next()) {
+        if ui.button("Add relation").clicked() {
+            relations.push(TimelineRelation::offset(*from, *to, 0.0));
+        }
+    } else {
+        ui.weak("This recording only has a single timeline.");
+    }
+}
+
+fn timeline_combo_ui(
+    ui: &mut egui::Ui,
+    id_source: impl std::hash::Hash,
+    times_per_timeline: &TimesPerTimeline,
+    selected: &mut Timeline,
+) {
+    egui::ComboBox::from_id_source(id_source)
+        .selected_text(selected.name().as_str())
+        .show_ui(ui, |ui| {
+            for timeline in times_per_timeline.timelines() {
+                ui.selectable_value(selected, *timeline, timeline.name().as_str());
+            }
+        });
+}
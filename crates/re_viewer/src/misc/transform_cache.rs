@@ -3,9 +3,19 @@ use re_arrow_store::LatestAtQuery;
 use re_data_store::{
     log_db::EntityDb, query_latest_single, EntityPath, EntityPropertyMap, EntityTree,
 };
+use re_log_types::{Component as _, Pinhole, TimeInt, Transform};
 
 use crate::misc::TimeControl;
 
+/// Depth offset applied to everything that is projected into an image via a pinhole.
+///
+/// Large offset so this is in front of all 2d that came so far.
+// TODO(andreas): Find better solution
+const INVERSE_PINHOLE_DEPTH_OFFSET: f32 = -100.0;
+
+/// Number of line segments each segment of a line strip is split into when passing through lens distortion.
+const DISTORTED_STRIP_SUBDIVISIONS: usize = 16;
+
 /// Provides transforms from an entity to a chosen reference space for all elements in the scene
 /// for the currently selected time & timeline.
 ///
@@ -22,6 +32,9 @@ pub struct TransformCache {
     /// All reachable entities.
     reference_from_entity_per_entity: IntMap<EntityPath, glam::Mat4>,
 
+    /// All reachable entities that are connected to the reference via a pinhole with lens distortion.
+    distorted_per_entity: IntMap<EntityPath, DistortedTransform>,
+
     /// All unreachable descendant paths of `reference_path`.
     unreachable_descendants: Vec<(EntityPath, UnreachableTransform)>,
 
@@ -44,6 +57,87 @@ pub enum UnreachableTransform {
 
     /// Unknown transform between this and the reference space.
     UnknownTransform,
+
+    /// Exiting out of a space via a transform that can't be inverted, e.g. a scale of zero.
+    NonInvertibleTransform,
+}
+
+/// A transform from an entity to the reference space that passes through a pinhole camera with lens distortion.
+///
+/// Lens distortion can't be expressed as a matrix, so anything using this needs to transform its
+/// positions on the CPU instead of relying on [`TransformCache::reference_from_entity`],
+/// which for these entities only gives the transform of an ideal pinhole camera.
+#[derive(Clone, Copy)]
+pub struct DistortedTransform {
+    pinhole: Pinhole,
+    direction: PinholeDirection,
+
+    /// From the entity to the space on the near side of the pinhole.
+    ///
+    /// That is camera space for [`PinholeDirection::Project`] and image space for [`PinholeDirection::Unproject`].
+    near_from_entity: glam::Mat4,
+
+    /// From the space on the far side of the pinhole to the reference space.
+    reference_from_far: glam::Mat4,
+}
+
+#[derive(Clone, Copy)]
+enum PinholeDirection {
+    /// From camera space into image space, i.e. looking at the camera's image.
+    Project,
+
+    /// From image space into camera space, placing the image at the given distance from the camera.
+    Unproject { image_plane_distance: f32 },
+}
+
+impl DistortedTransform {
+    /// Transforms a point from the entity's space to the reference space.
+    ///
+    /// Returns `None` if the point can't be seen by the camera.
+    pub fn transform_point3(&self, point: glam::Vec3) -> Option<glam::Vec3> {
+        let point = self.near_from_entity.transform_point3(point);
+        let point = match self.direction {
+            PinholeDirection::Project => {
+                // The linear projection in `inverse_transform_at` ends up with a depth of one before the offset.
+                self.pinhole
+                    .project(point)?
+                    .extend(1.0 + INVERSE_PINHOLE_DEPTH_OFFSET)
+            }
+            PinholeDirection::Unproject {
+                image_plane_distance,
+            } => self
+                .pinhole
+                .unproject(point.truncate(), image_plane_distance),
+        };
+        Some(self.reference_from_far.transform_point3(point))
+    }
+
+    /// Transforms a line strip from the entity's space to the reference space.
+    ///
+    /// Straight lines are no longer straight after distortion, so every segment is subdivided.
+    /// Points that can't be seen by the camera are dropped.
+    pub fn transform_strip(&self, strip: impl Iterator<Item = glam::Vec3>) -> Vec<glam::Vec3> {
+        let mut transformed = Vec::new();
+        let mut previous: Option<glam::Vec3> = None;
+        for point in strip {
+            if let Some(previous) = previous {
+                transformed.extend((1..=DISTORTED_STRIP_SUBDIVISIONS).filter_map(|i| {
+                    let t = i as f32 / DISTORTED_STRIP_SUBDIVISIONS as f32;
+                    self.transform_point3(previous.lerp(point, t))
+                }));
+            } else {
+                transformed.extend(self.transform_point3(point));
+            }
+            previous = Some(point);
+        }
+        transformed
+    }
+
+    /// Appends a transform on the entity side, i.e. moves from an entity to one of its neighbors.
+    fn then(mut self, entity_from_neighbor: glam::Mat4) -> Self {
+        self.near_from_entity *= entity_from_neighbor;
+        self
+    }
 }
 
 impl std::fmt::Display for UnreachableTransform {
@@ -57,6 +151,8 @@ impl std::fmt::Display for UnreachableTransform {
                 "Can't display entities that are connected via an unknown transform to this space.",
             Self::InversePinholeCameraWithoutResolution =>
                 "Can't display entities that would require inverting a pinhole camera without a specified resolution.",
+            Self::NonInvertibleTransform =>
+                "Can't display entities that would require inverting a transform that isn't invertible.",
         })
     }
 }
@@ -78,6 +174,7 @@ impl TransformCache {
         let mut transforms = TransformCache {
             reference_path: space_path.clone(),
             reference_from_entity_per_entity: Default::default(),
+            distorted_per_entity: Default::default(),
             unreachable_descendants: Default::default(),
             first_unreachable_parent: None,
         };
@@ -100,11 +197,13 @@ impl TransformCache {
             entity_prop_map,
             glam::Mat4::IDENTITY,
             false,
+            None,
         );
 
         // Walk up from the reference to the highest reachable parent.
         let mut encountered_pinhole = false;
         let mut reference_from_ancestor = glam::Mat4::IDENTITY;
+        let mut distortion = None;
         while let Some(parent_path) = current_tree.path.parent() {
             let Some(parent_tree) = &entity_db.tree.subtree(&parent_path) else {
                 // Unlike not having the space path in the hierarchy, this should be impossible.
@@ -120,6 +219,7 @@ impl TransformCache {
             match inverse_transform_at(
                 &current_tree.path,
                 entity_db,
+                entity_prop_map,
                 &query,
                 &mut encountered_pinhole,
             ) {
@@ -130,6 +230,17 @@ impl TransformCache {
                 }
                 Ok(None) => {}
                 Ok(Some(child_from_parent)) => {
+                    distortion = match distortion {
+                        Some(distortion) => Some(distortion.then(child_from_parent)),
+                        None => distorted_pinhole_at(&current_tree.path, entity_db, &query).map(
+                            |pinhole| DistortedTransform {
+                                pinhole,
+                                direction: PinholeDirection::Project,
+                                near_from_entity: glam::Mat4::IDENTITY,
+                                reference_from_far: reference_from_ancestor,
+                            },
+                        ),
+                    };
                     reference_from_ancestor *= child_from_parent;
                 }
             }
@@ -142,6 +253,7 @@ impl TransformCache {
                 entity_prop_map,
                 reference_from_ancestor,
                 encountered_pinhole,
+                distortion,
             );
 
             current_tree = parent_tree;
@@ -158,6 +270,7 @@ impl TransformCache {
         entity_properties: &EntityPropertyMap,
         reference_from_entity: glam::Mat4,
         encountered_pinhole: bool,
+        distortion: Option<DistortedTransform>,
     ) {
         match self
             .reference_from_entity_per_entity
@@ -170,10 +283,14 @@ impl TransformCache {
                 e.insert(reference_from_entity);
             }
         }
+        if let Some(distortion) = distortion {
+            self.distorted_per_entity
+                .insert(tree.path.clone(), distortion);
+        }
 
         for child_tree in tree.children.values() {
             let mut encountered_pinhole = encountered_pinhole;
-            let reference_from_child = match transform_at(
+            let parent_from_child = match transform_at(
                 &child_tree.path,
                 entity_db,
                 entity_properties,
@@ -185,8 +302,27 @@ impl TransformCache {
                         .push((child_tree.path.clone(), unreachable_reason));
                     continue;
                 }
-                Ok(None) => reference_from_entity,
-                Ok(Some(child_from_parent)) => reference_from_entity * child_from_parent,
+                Ok(parent_from_child) => parent_from_child,
+            };
+            let reference_from_child = parent_from_child
+                .map_or(reference_from_entity, |parent_from_child| {
+                    reference_from_entity * parent_from_child
+                });
+            let child_distortion = match distortion {
+                Some(distortion) => {
+                    Some(distortion.then(parent_from_child.unwrap_or(glam::Mat4::IDENTITY)))
+                }
+                None => distorted_pinhole_at(&child_tree.path, entity_db, query).map(|pinhole| {
+                    let props = entity_properties.get(&child_tree.path);
+                    DistortedTransform {
+                        pinhole,
+                        direction: PinholeDirection::Unproject {
+                            image_plane_distance: *props.pinhole_image_plane_distance.get(),
+                        },
+                        near_from_entity: glam::Mat4::IDENTITY,
+                        reference_from_far: reference_from_entity,
+                    }
+                }),
             };
             self.gather_descendants_transforms(
                 child_tree,
@@ -195,6 +331,7 @@ impl TransformCache {
                 entity_properties,
                 reference_from_child,
                 encountered_pinhole,
+                child_distortion,
             );
         }
     }
@@ -208,6 +345,13 @@ impl TransformCache {
             .cloned()
     }
 
+    /// Retrieves the transform of an entity to the reference space if it passes through a pinhole with lens distortion.
+    ///
+    /// If this returns `Some`, [`Self::reference_from_entity`] is only an approximation ignoring the distortion.
+    pub fn distorted_transform(&self, entity_path: &EntityPath) -> Option<&DistortedTransform> {
+        self.distorted_per_entity.get(entity_path)
+    }
+
     // This method isn't currently implemented, but we might need it in the future.
     // All the necessary data on why a subtree isn't reachable is already stored.
     //
@@ -224,9 +368,18 @@ fn transform_at(
     query: &LatestAtQuery,
     encountered_pinhole: &mut bool,
 ) -> Result<Option<macaw::Mat4>, UnreachableTransform> {
-    if let Some(transform) = query_latest_single(entity_db, entity_path, query) {
+    if let Some(transform) = query_transform(entity_path, entity_db, entity_properties, query) {
         match transform {
             re_log_types::Transform::Rigid3(rigid) => Ok(Some(rigid.parent_from_child().to_mat4())),
+            re_log_types::Transform::Scale(scale) => {
+                Ok(Some(glam::Mat4::from_scale(scale.parent_from_child())))
+            }
+            re_log_types::Transform::Affine3(affine) => {
+                Ok(Some(glam::Mat4::from(affine.parent_from_child())))
+            }
+            re_log_types::Transform::Homography2D(homography) => {
+                Ok(Some(homography.parent_from_child_mat4()))
+            }
             // If we're connected via 'unknown' it's not reachable
             re_log_types::Transform::Unknown => Err(UnreachableTransform::UnknownTransform),
 
@@ -266,12 +419,24 @@ fn transform_at(
 fn inverse_transform_at(
     entity_path: &EntityPath,
     entity_db: &EntityDb,
+    entity_properties: &EntityPropertyMap,
     query: &LatestAtQuery,
     encountered_pinhole: &mut bool,
 ) -> Result<Option<macaw::Mat4>, UnreachableTransform> {
-    if let Some(parent_transform) = query_latest_single(entity_db, entity_path, query) {
+    if let Some(parent_transform) =
+        query_transform(entity_path, entity_db, entity_properties, query)
+    {
         match parent_transform {
             re_log_types::Transform::Rigid3(rigid) => Ok(Some(rigid.child_from_parent().to_mat4())),
+            re_log_types::Transform::Scale(scale) => {
+                inverse_if_invertible(glam::Mat4::from_scale(scale.parent_from_child())).map(Some)
+            }
+            re_log_types::Transform::Affine3(affine) => {
+                inverse_if_invertible(glam::Mat4::from(affine.parent_from_child())).map(Some)
+            }
+            re_log_types::Transform::Homography2D(homography) => {
+                inverse_if_invertible(homography.parent_from_child_mat4()).map(Some)
+            }
             // If we're connected via 'unknown', everything except whats under `parent_tree` is unreachable
             re_log_types::Transform::Unknown => Err(UnreachableTransform::UnknownTransform),
 
@@ -283,7 +448,9 @@ fn inverse_transform_at(
 
                     // TODO(andreas): If we don't have a resolution we don't know the FOV ergo we don't know how to project. Unclear what to do.
                     if let Some(resolution) = pinhole.resolution() {
-                        let translation = pinhole.principal_point().extend(-100.0); // Large Y offset so this is in front of all 2d that came so far. TODO(andreas): Find better solution
+                        let translation = pinhole
+                            .principal_point()
+                            .extend(INVERSE_PINHOLE_DEPTH_OFFSET);
                         Ok(Some(
                             glam::Mat4::from_scale_rotation_translation(
                                 // Scaled with 0.5 since perspective_infinite_lh uses NDC, i.e. [-1; 1] range.
@@ -306,3 +473,100 @@ fn inverse_transform_at(
         Ok(None)
     }
 }
+
+/// Queries the transform at the given entity.
+///
+/// If enabled via [`re_data_store::EntityProperties::interpolate_transforms`], rigid transforms are
+/// interpolated between the latest sample at the query time and the next sample after it.
+fn query_transform(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    entity_properties: &EntityPropertyMap,
+    query: &LatestAtQuery,
+) -> Option<Transform> {
+    let transform = query_latest_single(entity_db, entity_path, query)?;
+
+    let Transform::Rigid3(previous) = transform else {
+        return Some(transform);
+    };
+    if !*entity_properties
+        .get(entity_path)
+        .interpolate_transforms
+        .get()
+    {
+        return Some(transform);
+    }
+    let Some((previous_time, next_time)) = surrounding_sample_times(entity_path, entity_db, query) else {
+        return Some(transform);
+    };
+    let Some(Transform::Rigid3(next)) = query_latest_single(
+        entity_db,
+        entity_path,
+        &LatestAtQuery::new(query.timeline, next_time),
+    ) else {
+        return Some(transform);
+    };
+
+    let t = (query.at.as_i64() - previous_time.as_i64()) as f64
+        / (next_time.as_i64() - previous_time.as_i64()) as f64;
+    Some(Transform::Rigid3(previous.interpolate(&next, t as f32)))
+}
+
+/// Times of the latest transform sample at or before the query time and the first one after it.
+///
+/// Returns `None` if there's no sample on either side, e.g. because the transform is timeless.
+fn surrounding_sample_times(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> Option<(TimeInt, TimeInt)> {
+    let histogram = entity_db
+        .tree
+        .subtree(entity_path)?
+        .components
+        .get(&Transform::name())?
+        .times
+        .get(&query.timeline)?;
+    let at = query.at.as_i64();
+
+    let (next_range, _) = histogram.range(at.saturating_add(1).., 1).next()?;
+
+    // The histogram can only be iterated forwards,
+    // so search backwards for the previous sample with exponentially growing steps.
+    let mut step = 1_i64;
+    let previous = loop {
+        let min = at.saturating_sub(step);
+        if histogram.range_count(min..=at) > 0 {
+            break histogram.range(min..=at, 1).last()?.0.max;
+        }
+        if min == i64::MIN {
+            return None;
+        }
+        step = step.saturating_mul(2);
+    };
+
+    Some((previous.into(), next_range.min.into()))
+}
+
+fn inverse_if_invertible(
+    parent_from_child: glam::Mat4,
+) -> Result<macaw::Mat4, UnreachableTransform> {
+    let child_from_parent = parent_from_child.inverse();
+    if parent_from_child.determinant() != 0.0 && child_from_parent.is_finite() {
+        Ok(child_from_parent)
+    } else {
+        Err(UnreachableTransform::NonInvertibleTransform)
+    }
+}
+
+/// Returns the pinhole at the given entity if it has lens distortion.
+fn distorted_pinhole_at(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> Option<Pinhole> {
+    match query_latest_single(entity_db, entity_path, query)? {
+        re_log_types::Transform::Pinhole(pinhole) if pinhole.has_distortion() => Some(pinhole),
+        _ => None,
+    }
+}
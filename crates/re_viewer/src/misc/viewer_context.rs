@@ -1,9 +1,11 @@
 use re_data_store::{log_db::LogDb, InstancePath};
-use re_log_types::{ComponentPath, EntityPath, MsgId, TimeInt, Timeline};
+use re_log_types::{
+    AnnotationContext, ComponentPath, DataRow, EntityPath, MsgId, TimeInt, Timeline, ViewerEvent,
+};
 
 use crate::ui::{
     data_ui::{ComponentUiRegistry, DataUi},
-    DataBlueprintGroupHandle, SpaceViewId, UiVerbosity,
+    DataBlueprintGroupHandle, ScreenshotMode, SpaceViewClassRegistry, SpaceViewId, UiVerbosity,
 };
 
 use super::{
@@ -22,6 +24,9 @@ pub struct ViewerContext<'a> {
     /// How to display components
     pub component_ui_registry: &'a ComponentUiRegistry,
 
+    /// Custom kinds of space views
+    pub space_view_class_registry: &'a SpaceViewClassRegistry,
+
     /// The current recording
     pub log_db: &'a LogDb,
 
@@ -203,6 +208,22 @@ impl<'a> ViewerContext<'a> {
         self.cursor_interact_with_selectable(response, item)
     }
 
+    pub fn measurement_button(
+        &mut self,
+        ui: &mut egui::Ui,
+        space_view_id: SpaceViewId,
+        measurement: &crate::ui::view_spatial::Measurement,
+    ) -> egui::Response {
+        let item = Item::Measurement(space_view_id, measurement.id);
+        let response = ui
+            .selectable_label(
+                self.selection().contains(&item),
+                format!("{}: {}", measurement.kind, measurement.value_text()),
+            )
+            .on_hover_text("Measurement");
+        self.cursor_interact_with_selectable(response, item)
+    }
+
     pub fn time_button(
         &mut self,
         ui: &mut egui::Ui,
@@ -237,7 +258,9 @@ impl<'a> ViewerContext<'a> {
             .selectable_label(is_selected, text)
             .on_hover_text("Click to switch to this timeline");
         if response.clicked() {
-            self.rec_cfg.time_ctrl.set_timeline(*timeline);
+            self.rec_cfg
+                .time_ctrl
+                .set_timeline_related(*timeline, &self.app_options.timeline_relations);
             self.rec_cfg.time_ctrl.pause();
         }
         response
@@ -324,6 +347,42 @@ impl<'a> ViewerContext<'a> {
     pub fn current_query(&self) -> re_arrow_store::LatestAtQuery {
         self.rec_cfg.time_ctrl.current_query()
     }
+
+    /// Adds a row to the current recording, as if it had been logged by the user.
+    ///
+    /// The row shows up starting with the next frame and is part of the recording when saving it.
+    pub fn log_row(&mut self, row: DataRow) {
+        self.rec_cfg.pending_rows.push(row);
+    }
+
+    /// If `response` was clicked, tells the logging SDK which entity instance is hovered.
+    ///
+    /// `position` is the clicked point in the coordinates of the space view.
+    pub fn send_click_event(&mut self, response: &egui::Response, position: Option<glam::Vec3>) {
+        if !response.clicked() {
+            return;
+        }
+        if let Some(Item::InstancePath(_, instance_path)) = self.hovered().first() {
+            let event = ViewerEvent::EntityClicked {
+                entity_path: instance_path.entity_path.clone(),
+                instance_key: instance_path.instance_key,
+                position: position.map(|position| position.to_array()),
+            };
+            self.rec_cfg.pending_viewer_events.push(event);
+        }
+    }
+
+    /// Takes the request for a screenshot of this space view, if it was asked for with a
+    /// [`re_log_types::ViewerCommand::Screenshot`].
+    pub fn take_screenshot_request(
+        &mut self,
+        space_view_id: SpaceViewId,
+    ) -> Option<ScreenshotMode> {
+        let requests = &mut self.rec_cfg.screenshot_requests;
+        let index = requests.iter().position(|id| *id == space_view_id)?;
+        requests.swap_remove(index);
+        Some(ScreenshotMode::Remote)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -337,4 +396,37 @@ pub struct RecordingConfig {
 
     /// Selection & hovering state.
     pub selection_state: super::SelectionState,
+
+    /// Annotation contexts edited in the viewer, replacing the logged ones at the same entity path.
+    ///
+    /// Not persisted, use the export in the selection panel to keep them.
+    #[serde(skip)]
+    pub annotation_overrides: std::collections::BTreeMap<EntityPath, AnnotationContext>,
+
+    /// Data created in the viewer (e.g. by the annotation tools) waiting to be added to the recording.
+    ///
+    /// Added at the end of the frame, see [`ViewerContext::log_row`].
+    #[serde(skip)]
+    pub pending_rows: Vec<DataRow>,
+
+    /// Interactions to send to the logging SDK at the end of the frame.
+    ///
+    /// Selection and time changes are detected by the app itself.
+    #[serde(skip)]
+    pub pending_viewer_events: Vec<ViewerEvent>,
+
+    /// Space views to take a screenshot of, see [`ViewerContext::take_screenshot_request`].
+    #[serde(skip)]
+    pub screenshot_requests: Vec<SpaceViewId>,
+
+    /// Screenshots taken for [`Self::screenshot_requests`], to send back at the end of the frame.
+    #[serde(skip)]
+    pub taken_screenshots: Vec<TakenScreenshot>,
+}
+
+/// The RGBA pixels of a space view, see [`RecordingConfig::screenshot_requests`].
+pub struct TakenScreenshot {
+    pub space_view_id: SpaceViewId,
+    pub extent: [u32; 2],
+    pub rgba: Vec<u8>,
 }
@@ -1,3 +1,5 @@
+use re_log_types::ViewerEvent;
+
 use crate::App;
 
 /// Connects to a server over WebSockets.
@@ -11,6 +13,9 @@ pub struct RemoteViewerApp {
     url: String,
 
     app: Option<(re_ws_comms::Connection, App)>,
+
+    /// Interactions in the viewer, to send back to the server.
+    viewer_event_rx: Option<std::sync::mpsc::Receiver<ViewerEvent>>,
 }
 
 impl RemoteViewerApp {
@@ -30,6 +35,7 @@ impl RemoteViewerApp {
             re_ui,
             url,
             app: None,
+            viewer_event_rx: None,
         };
         slf.connect(storage);
         slf
@@ -64,7 +70,7 @@ impl RemoteViewerApp {
 
         match re_ws_comms::Connection::viewer_to_server(self.url.clone(), callback) {
             Ok(connection) => {
-                let app = crate::App::from_receiver(
+                let mut app = crate::App::from_receiver(
                     self.build_info,
                     &self.app_env,
                     self.startup_options,
@@ -74,7 +80,13 @@ impl RemoteViewerApp {
                     std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
                 );
 
+                let (viewer_event_tx, viewer_event_rx) = std::sync::mpsc::channel();
+                app.set_viewer_event_callback(Box::new(move |event| {
+                    viewer_event_tx.send(event).ok();
+                }));
+
                 self.app = Some((connection, app));
+                self.viewer_event_rx = Some(viewer_event_rx);
             }
             Err(err) => {
                 re_log::error!("Failed to connect to {:?}: {err}", self.url);
@@ -122,8 +134,14 @@ impl eframe::App for RemoteViewerApp {
             });
         }
 
-        if let Some((_, app)) = &mut self.app {
+        if let Some((connection, app)) = &mut self.app {
             app.update(egui_ctx, frame);
+
+            if let Some(viewer_event_rx) = &self.viewer_event_rx {
+                for event in viewer_event_rx.try_iter() {
+                    connection.send_viewer_event(&event);
+                }
+            }
         } else {
             egui::CentralPanel::default().show(egui_ctx, |ui| {
                 // TODO(emilk): show the error message.
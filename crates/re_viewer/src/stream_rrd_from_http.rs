@@ -1,6 +1,15 @@
-pub fn stream_rrd_from_http_to_channel(
-    url: String,
-) -> re_smart_channel::Receiver<re_log_types::LogMsg> {
+use std::{collections::HashMap, ops::ControlFlow};
+
+use egui::mutex::Mutex;
+use re_log_types::{
+    encoding::{DecodeError, StreamDecoder},
+    LogMsg,
+};
+
+/// How often to try to resume a download after the connection dropped, without making progress.
+const MAX_RESUME_ATTEMPTS: usize = 5;
+
+pub fn stream_rrd_from_http_to_channel(url: String) -> re_smart_channel::Receiver<LogMsg> {
     let (tx, rx) = re_smart_channel::smart_channel(re_smart_channel::Source::RrdHttpStream {
         url: url.clone(),
     });
@@ -13,95 +22,337 @@ pub fn stream_rrd_from_http_to_channel(
     rx
 }
 
-pub fn stream_rrd_from_http(url: String, on_msg: Box<dyn Fn(re_log_types::LogMsg) + Send>) {
-    re_log::debug!("Downloading .rrd file from {url:?}…");
-
-    // TODO(emilk): stream the http request, progressively decoding the .rrd file.
-    ehttp::fetch(ehttp::Request::get(&url), move |result| match result {
-        Ok(response) => {
-            if response.ok {
-                re_log::debug!("Decoding .rrd file from {url:?}…");
-                decode_rrd(response.bytes, on_msg);
-            } else {
-                re_log::error!(
-                    "Failed to fetch .rrd file from {url}: {} {}",
-                    response.status,
-                    response.status_text
+/// Download an `.rrd` file, calling `on_msg` for each message as soon as it has arrived.
+///
+/// If the connection drops, the download is resumed with a HTTP range request.
+/// See [`progress`] for how far along it is.
+pub fn stream_rrd_from_http(url: String, on_msg: Box<dyn Fn(LogMsg) + Send>) {
+    re_log::debug!("Streaming .rrd file from {url:?}…");
+
+    let decoder = match StreamDecoder::new() {
+        Ok(decoder) => decoder,
+        Err(err) => {
+            re_log::error!("Failed to decode .rrd: {err}");
+            return;
+        }
+    };
+
+    PROGRESS
+        .lock()
+        .insert(url.clone(), HttpStreamProgress::default());
+
+    fetch_streaming(HttpStream {
+        url,
+        decoder,
+        on_msg,
+        num_bytes_received: 0,
+        num_bytes_total: None,
+        num_bytes_to_skip: 0,
+        num_failed_attempts: 0,
+    });
+}
+
+// ----------------------------------------------------------------------------
+
+/// How far along the download of an `.rrd` file is.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HttpStreamProgress {
+    pub num_bytes_received: u64,
+
+    /// Size of the whole file, if the server told us.
+    pub num_bytes_total: Option<u64>,
+
+    /// Done, or given up.
+    pub finished: bool,
+}
+
+lazy_static::lazy_static! {
+    static ref PROGRESS: Mutex<HashMap<String, HttpStreamProgress>> = Default::default();
+}
+
+/// The progress of the latest download started with [`stream_rrd_from_http`] of this `url`.
+pub fn progress(url: &str) -> Option<HttpStreamProgress> {
+    PROGRESS.lock().get(url).copied()
+}
+
+fn update_progress(url: &str, update: impl FnOnce(&mut HttpStreamProgress)) {
+    if let Some(progress) = PROGRESS.lock().get_mut(url) {
+        update(progress);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The state of a download, kept across resumed requests.
+struct HttpStream {
+    url: String,
+    decoder: StreamDecoder,
+    on_msg: Box<dyn Fn(LogMsg) + Send>,
+    num_bytes_received: u64,
+
+    /// Size of the whole file, if the server told us.
+    num_bytes_total: Option<u64>,
+
+    /// Bytes at the start of the current response that we already have.
+    num_bytes_to_skip: u64,
+
+    /// Failed attempts since we last received something.
+    num_failed_attempts: usize,
+}
+
+impl HttpStream {
+    /// The HTTP range header to request the rest of the file, if we already have the beginning.
+    fn range_header(&self) -> Option<String> {
+        (self.num_bytes_received > 0).then(|| format!("bytes={}-", self.num_bytes_received))
+    }
+
+    /// Called with the headers of each response.
+    fn on_response(
+        &mut self,
+        status: u16,
+        content_length: Option<u64>,
+        content_range: Option<&str>,
+    ) {
+        let is_partial = status == 206;
+        let num_bytes_total = if is_partial {
+            // E.g. "bytes 1000-1999/2000"
+            content_range
+                .and_then(|range| range.rsplit_once('/'))
+                .and_then(|(_, total)| total.parse().ok())
+        } else {
+            content_length
+        };
+        self.num_bytes_total = num_bytes_total.or(self.num_bytes_total);
+        if num_bytes_total.is_some() {
+            update_progress(&self.url, |progress| {
+                progress.num_bytes_total = num_bytes_total;
+            });
+        }
+
+        self.num_bytes_to_skip = if is_partial {
+            0
+        } else {
+            if self.num_bytes_received > 0 {
+                re_log::debug!(
+                    "{} does not support range requests - skipping to where we were",
+                    self.url
                 );
             }
+            self.num_bytes_received
+        };
+    }
+
+    fn on_chunk(&mut self, chunk: &[u8]) -> ControlFlow<()> {
+        let num_skipped = self.num_bytes_to_skip.min(chunk.len() as u64);
+        self.num_bytes_to_skip -= num_skipped;
+        let chunk = &chunk[num_skipped as usize..];
+        if chunk.is_empty() {
+            return ControlFlow::Continue(());
         }
-        Err(err) => {
-            re_log::error!("Failed to fetch .rrd file from {url}: {err}");
+
+        self.num_bytes_received += chunk.len() as u64;
+        self.num_failed_attempts = 0;
+        update_progress(&self.url, |progress| {
+            progress.num_bytes_received = self.num_bytes_received;
+        });
+
+        if let Err(err) = self.decoder.push_chunk(chunk) {
+            re_log::error!("Failed to decode .rrd from {}: {err}", self.url);
+            return ControlFlow::Break(());
         }
-    });
+        while let Some(msg) = self.decoder.try_read() {
+            match msg {
+                Ok(msg) => (self.on_msg)(msg),
+                Err(err @ DecodeError::MessageTooLarge(_)) => {
+                    re_log::error!("Failed to decode .rrd from {}: {err}", self.url);
+                    return ControlFlow::Break(());
+                }
+                Err(err) => {
+                    re_log::warn_once!("Failed to decode message: {err}");
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Called when a response has ended without errors.
+    ///
+    /// Fails if it ended before the end of the file, so that we resume the download.
+    fn on_response_end(&self) -> Result<(), String> {
+        match self.num_bytes_total {
+            Some(num_bytes_total) if self.num_bytes_received < num_bytes_total => Err(format!(
+                "Response ended after {} of {} bytes",
+                self.num_bytes_received, num_bytes_total
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Should we try again after a failed request?
+    ///
+    /// Only when resuming, as a failure to even start the download is unlikely to go away.
+    fn should_resume(&mut self) -> bool {
+        self.num_failed_attempts += 1;
+        self.num_bytes_received > 0 && self.num_failed_attempts <= MAX_RESUME_ATTEMPTS
+    }
+
+    fn finish(&self) {
+        re_log::debug!(
+            "Finished streaming {} of .rrd from {}",
+            re_format::format_bytes(self.num_bytes_received as _),
+            self.url
+        );
+        update_progress(&self.url, |progress| progress.finished = true);
+    }
 }
 
+// ----------------------------------------------------------------------------
+
 #[cfg(not(target_arch = "wasm32"))]
-#[allow(clippy::needless_pass_by_value)] // must match wasm version
-fn decode_rrd(rrd_bytes: Vec<u8>, on_msg: Box<dyn Fn(re_log_types::LogMsg) + Send>) {
-    match re_log_types::encoding::Decoder::new(rrd_bytes.as_slice()) {
-        Ok(decoder) => {
-            for msg in decoder {
-                match msg {
-                    Ok(msg) => {
-                        on_msg(msg);
+fn fetch_streaming(mut stream: HttpStream) {
+    let spawn_result = std::thread::Builder::new()
+        .name("stream_rrd_from_http".to_owned())
+        .spawn(move || {
+            loop {
+                match fetch_from(&mut stream) {
+                    Ok(()) => break,
+                    Err(err) if stream.should_resume() => {
+                        re_log::warn!(
+                            "Streaming .rrd from {} failed: {err}. Resuming…",
+                            stream.url
+                        );
+                        let delay = stream.num_failed_attempts as u64;
+                        std::thread::sleep(std::time::Duration::from_secs(delay));
                     }
                     Err(err) => {
-                        re_log::warn_once!("Failed to decode message: {err}");
+                        re_log::error!("Failed to stream .rrd from {}: {err}", stream.url);
+                        break;
                     }
                 }
             }
-        }
-        Err(err) => {
-            re_log::error!("Failed to decode .rrd: {err}");
+            stream.finish();
+        });
+    if let Err(err) = spawn_result {
+        re_log::error!("Failed to spawn thread for streaming .rrd: {err}");
+    }
+}
+
+/// Requests the rest of the file and feeds it to the decoder, until it is done or fails.
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_from(stream: &mut HttpStream) -> anyhow::Result<()> {
+    use std::io::Read as _;
+
+    let mut request = ureq::get(&stream.url);
+    if let Some(range) = stream.range_header() {
+        request = request.set("Range", &range);
+    }
+    let response = request.call()?;
+
+    let content_length = response
+        .header("Content-Length")
+        .and_then(|len| len.parse().ok());
+    stream.on_response(
+        response.status(),
+        content_length,
+        response.header("Content-Range"),
+    );
+
+    let mut reader = response.into_reader();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let num_read = match reader.read(&mut buffer) {
+            Ok(0) => return stream.on_response_end().map_err(anyhow::Error::msg),
+            Ok(num_read) => num_read,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+        if stream.on_chunk(&buffer[..num_read]).is_break() {
+            return Ok(());
         }
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-mod web_decode {
-    pub fn decode_rrd(rrd_bytes: Vec<u8>, on_msg: Box<dyn Fn(re_log_types::LogMsg) + Send>) {
-        wasm_bindgen_futures::spawn_local(decode_rrd_async(rrd_bytes, on_msg));
-    }
+mod web_fetch {
+    use wasm_bindgen::{JsCast as _, JsValue};
+    use wasm_bindgen_futures::JsFuture;
 
-    /// Decodes the file in chunks, with an yield between each chunk.
-    ///
-    /// This is cooperative multi-tasking.
-    async fn decode_rrd_async(
-        rrd_bytes: Vec<u8>,
-        on_msg: Box<dyn Fn(re_log_types::LogMsg) + Send>,
-    ) {
-        let mut last_yield = instant::Instant::now();
-
-        match re_log_types::encoding::Decoder::new(rrd_bytes.as_slice()) {
-            Ok(decoder) => {
-                for msg in decoder {
-                    match msg {
-                        Ok(msg) => {
-                            on_msg(msg);
-                        }
-                        Err(err) => {
-                            re_log::warn_once!("Failed to decode message: {err}");
-                        }
-                    }
+    use super::HttpStream;
 
-                    if last_yield.elapsed() > instant::Duration::from_millis(10) {
-                        // yield to the ui task
-                        yield_().await;
-                        last_yield = instant::Instant::now();
+    pub fn fetch_streaming(mut stream: HttpStream) {
+        wasm_bindgen_futures::spawn_local(async move {
+            loop {
+                match fetch_from(&mut stream).await {
+                    Ok(()) => break,
+                    Err(err) if stream.should_resume() => {
+                        re_log::warn!(
+                            "Streaming .rrd from {} failed: {err}. Resuming…",
+                            stream.url
+                        );
+                        sleep_ms(1000 * stream.num_failed_attempts as i32).await;
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to stream .rrd from {}: {err}", stream.url);
+                        break;
                     }
                 }
             }
-            Err(err) => {
-                re_log::error!("Failed to decode .rrd: {err}");
+            stream.finish();
+        });
+    }
+
+    /// Requests the rest of the file and feeds it to the decoder, until it is done or fails.
+    async fn fetch_from(stream: &mut HttpStream) -> Result<(), String> {
+        let headers = web_sys::Headers::new().map_err(js_error)?;
+        if let Some(range) = stream.range_header() {
+            headers.set("Range", &range).map_err(js_error)?;
+        }
+        let mut init = web_sys::RequestInit::new();
+        init.method("GET").headers(&headers);
+        let request =
+            web_sys::Request::new_with_str_and_init(&stream.url, &init).map_err(js_error)?;
+
+        let window = web_sys::window().ok_or("No window")?;
+        let response: web_sys::Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .and_then(|response| response.dyn_into())
+            .map_err(js_error)?;
+        if !response.ok() {
+            return Err(format!("{} {}", response.status(), response.status_text()));
+        }
+
+        let response_headers = response.headers();
+        let content_length = response_headers
+            .get("Content-Length")
+            .ok()
+            .flatten()
+            .and_then(|len| len.parse().ok());
+        let content_range = response_headers.get("Content-Range").ok().flatten();
+        stream.on_response(response.status(), content_length, content_range.as_deref());
+
+        let body = response.body().ok_or("Response without body")?;
+        let reader: web_sys::ReadableStreamDefaultReader =
+            body.get_reader().dyn_into().map_err(js_error)?;
+        loop {
+            let result = JsFuture::from(reader.read()).await.map_err(js_error)?;
+            let done = js_sys::Reflect::get(&result, &"done".into())
+                .map_err(js_error)?
+                .as_bool()
+                .unwrap_or(true);
+            if done {
+                return stream.on_response_end();
+            }
+            let value = js_sys::Reflect::get(&result, &"value".into()).map_err(js_error)?;
+            let chunk = js_sys::Uint8Array::new(&value).to_vec();
+            if stream.on_chunk(&chunk).is_break() {
+                let _ = reader.cancel();
+                return Ok(());
             }
         }
     }
 
-    // Yield to other tasks
-    async fn yield_() {
-        // TODO(emilk): create a better async yield function. See https://github.com/rustwasm/wasm-bindgen/issues/3359
-        sleep_ms(1).await;
+    fn js_error(err: JsValue) -> String {
+        err.as_string().unwrap_or_else(|| format!("{err:?}"))
     }
 
     // Hack to get async sleep on wasm
@@ -118,4 +369,4 @@ mod web_decode {
 }
 
 #[cfg(target_arch = "wasm32")]
-use web_decode::decode_rrd;
+use web_fetch::fetch_streaming;
@@ -117,6 +117,7 @@ impl AnnotationMap {
         let mut visited = IntSet::<EntityPath>::default();
 
         let data_store = &ctx.log_db.entity_db.data_store;
+        let overrides = &ctx.rec_cfg.annotation_overrides;
         let latest_at_query = LatestAtQuery::new(scene_query.timeline, scene_query.latest_at);
 
         // This logic is borrowed from `iter_ancestor_meta_field`, but using the arrow-store instead
@@ -153,6 +154,7 @@ impl AnnotationMap {
                                 entity.iter_primary().ok()?.next()?,
                                 entity.iter_component::<MsgId>().ok()?.next()?,
                             ) {
+                                let context = overrides.get(&parent).cloned().unwrap_or(context);
                                 Some(entry.insert(Arc::new(Annotations { msg_id, context })))
                             } else {
                                 None
@@ -1,6 +1,6 @@
 use crate::misc::{space_info::SpaceInfoCollection, ViewerContext};
 
-use super::viewport::Viewport;
+use super::{time_panel::TimeBookmark, viewport::Viewport};
 
 /// Defines the layout of the whole Viewer (or will, eventually).
 #[derive(Default, serde::Deserialize, serde::Serialize)]
@@ -10,6 +10,9 @@ pub struct Blueprint {
     pub selection_panel_expanded: bool,
     pub time_panel_expanded: bool,
 
+    /// Named points and ranges in time, on any timeline.
+    pub time_bookmarks: Vec<TimeBookmark>,
+
     pub viewport: Viewport,
 }
 
@@ -21,6 +24,7 @@ impl Blueprint {
             blueprint_panel_expanded: screen_size.x > 750.0,
             selection_panel_expanded: screen_size.x > 1000.0,
             time_panel_expanded: screen_size.y > 600.0,
+            time_bookmarks: Default::default(),
             viewport: Default::default(),
         }
     }
@@ -0,0 +1,419 @@
+use instant::Instant;
+
+use re_arrow_store::{TimeInt, TimeType, Timeline};
+use re_data_store::{EntityTree, TimeHistogram};
+use re_format::{format_bytes, format_number};
+use re_log_types::{ComponentName, ComponentPath, Duration, EntityPath, RecordingId};
+
+use crate::ViewerContext;
+
+/// An interval is a gap if it is this many times longer than the average interval.
+const GAP_FACTOR: f64 = 5.0;
+
+/// How often we recompute the profile while new data keeps coming in.
+const REFRESH_INTERVAL_SEC: f32 = 1.0;
+
+/// A table of how much data each component of each entity holds, and how regularly it arrived,
+/// on the selected timeline.
+///
+/// Helps finding the stream that floods the link, or the one that dropped out.
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct DataProfileView {
+    sort_by: SortBy,
+
+    #[serde(skip)]
+    cache: Option<ProfileCache>,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+enum SortBy {
+    Entity,
+    Messages,
+    #[default]
+    Size,
+    Rate,
+    MaxInterval,
+    Gaps,
+}
+
+struct ProfileCache {
+    recording_id: RecordingId,
+    timeline: Timeline,
+    num_log_messages: usize,
+    computed_at: Instant,
+    rows: Vec<ProfileRow>,
+}
+
+struct ProfileRow {
+    ent_path: EntityPath,
+    component: ComponentName,
+    size_bytes: u64,
+    arrivals: ArrivalStats,
+}
+
+impl DataProfileView {
+    pub fn ui(&mut self, ctx: &mut ViewerContext<'_>, ui: &mut egui::Ui) {
+        crate::profile_function!();
+
+        let timeline = *ctx.rec_cfg.time_ctrl.timeline();
+        self.update_cache(ctx, timeline);
+        let Some(cache) = &mut self.cache else { return };
+        sort_rows(&mut cache.rows, self.sort_by);
+
+        egui::Frame {
+            inner_margin: re_ui::ReUi::view_padding().into(),
+            ..egui::Frame::default()
+        }
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} streams on",
+                    format_number(cache.rows.len())
+                ));
+                ctx.timeline_button(ui, &timeline);
+            });
+            ui.weak(format!(
+                "Gaps are intervals more than {GAP_FACTOR} times longer than the average interval of the stream."
+            ));
+            ui.separator();
+
+            egui::ScrollArea::horizontal()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    profile_table(ctx, ui, &cache.rows, timeline.typ(), &mut self.sort_by);
+                });
+        });
+    }
+
+    fn update_cache(&mut self, ctx: &ViewerContext<'_>, timeline: Timeline) {
+        let recording_id = ctx.log_db.recording_id();
+        let num_log_messages = ctx.log_db.len();
+
+        if let Some(cache) = &self.cache {
+            let is_same_stream = cache.recording_id == recording_id && cache.timeline == timeline;
+            let is_same_data = is_same_stream && cache.num_log_messages == num_log_messages;
+            let is_recent = cache.computed_at.elapsed().as_secs_f32() < REFRESH_INTERVAL_SEC;
+            if is_same_data || (is_recent && is_same_stream) {
+                return;
+            }
+        }
+
+        crate::profile_scope!("compute profile");
+
+        let volumes = ctx.log_db.entity_db.data_store.component_volumes(timeline);
+
+        let mut rows = Vec::new();
+        collect_rows(
+            &ctx.log_db.entity_db.tree,
+            &timeline,
+            &mut |tree, component, histogram| {
+                let Some(arrivals) = ArrivalStats::from_histogram(histogram, timeline.typ()) else { return };
+                let size_bytes = volumes
+                    .get(&tree.path)
+                    .and_then(|volumes| volumes.get(component))
+                    .map_or(0, |volume| volume.size_bytes);
+                rows.push(ProfileRow {
+                    ent_path: tree.path.clone(),
+                    component: *component,
+                    size_bytes,
+                    arrivals,
+                });
+            },
+        );
+
+        self.cache = Some(ProfileCache {
+            recording_id,
+            timeline,
+            num_log_messages,
+            computed_at: Instant::now(),
+            rows,
+        });
+    }
+}
+
+fn collect_rows(
+    tree: &EntityTree,
+    timeline: &Timeline,
+    add_row: &mut impl FnMut(&EntityTree, &ComponentName, &TimeHistogram),
+) {
+    for (component, stats) in &tree.components {
+        if let Some(histogram) = stats.times.get(timeline) {
+            add_row(tree, component, histogram);
+        }
+    }
+    for child in tree.children.values() {
+        collect_rows(child, timeline, add_row);
+    }
+}
+
+fn sort_rows(rows: &mut [ProfileRow], sort_by: SortBy) {
+    use std::cmp::Reverse;
+
+    match sort_by {
+        SortBy::Entity => rows.sort_by(|a, b| {
+            (&a.ent_path, a.component.full_name()).cmp(&(&b.ent_path, b.component.full_name()))
+        }),
+        SortBy::Messages => rows.sort_by_key(|row| Reverse(row.arrivals.num_messages)),
+        SortBy::Size => rows.sort_by_key(|row| Reverse(row.size_bytes)),
+        SortBy::Rate => rows.sort_by(|a, b| b.arrivals.rate.total_cmp(&a.arrivals.rate)),
+        SortBy::MaxInterval => rows.sort_by_key(|row| Reverse(row.arrivals.max_interval)),
+        SortBy::Gaps => rows.sort_by_key(|row| Reverse(row.arrivals.num_gaps)),
+    }
+}
+
+fn profile_table(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    rows: &[ProfileRow],
+    time_type: TimeType,
+    sort_by: &mut SortBy,
+) {
+    crate::profile_function!();
+
+    use egui_extras::{Column, TableBuilder};
+
+    let rate_unit = match time_type {
+        TimeType::Time => "Hz",
+        TimeType::Sequence => "/step",
+    };
+
+    TableBuilder::new(ui)
+        .max_scroll_height(f32::INFINITY) // Fill up whole height
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .resizable(true)
+        .column(Column::auto().clip(true).at_least(100.0)) // entity
+        .column(Column::auto().clip(true).at_least(80.0)) // component
+        .columns(Column::auto().at_least(60.0), 5) // messages, size, rate, min & max interval
+        .column(Column::remainder()) // gaps
+        .header(re_ui::ReUi::table_header_height(), |mut header| {
+            re_ui::ReUi::setup_table_header(&mut header);
+            let mut sort_header = |header: &mut egui_extras::TableRow<'_, '_>,
+                                   column: Option<SortBy>,
+                                   text: &str,
+                                   tooltip: &str| {
+                header.col(|ui| {
+                    let response = if let Some(column) = column {
+                        ui.selectable_value(sort_by, column, egui::RichText::new(text).strong())
+                    } else {
+                        ui.strong(text)
+                    };
+                    response.on_hover_text(tooltip);
+                });
+            };
+            sort_header(
+                &mut header,
+                Some(SortBy::Entity),
+                "Entity",
+                "Sort by entity",
+            );
+            sort_header(
+                &mut header,
+                None,
+                "Component",
+                "Click to select the component",
+            );
+            sort_header(
+                &mut header,
+                Some(SortBy::Messages),
+                "Messages",
+                "Number of messages logged on this timeline",
+            );
+            sort_header(
+                &mut header,
+                Some(SortBy::Size),
+                "Size",
+                "Estimated size of the data currently in the store",
+            );
+            sort_header(
+                &mut header,
+                Some(SortBy::Rate),
+                &format!("Rate ({rate_unit})"),
+                "Average number of messages per unit of time",
+            );
+            sort_header(
+                &mut header,
+                None,
+                "Min Δt",
+                "Shortest interval between two messages",
+            );
+            sort_header(
+                &mut header,
+                Some(SortBy::MaxInterval),
+                "Max Δt",
+                "Longest interval between two messages",
+            );
+            sort_header(
+                &mut header,
+                Some(SortBy::Gaps),
+                "Gaps",
+                "Number of unusually long intervals, and the longest one. Click to go there.",
+            );
+        })
+        .body(|mut body| {
+            re_ui::ReUi::setup_table_body(&mut body);
+
+            let row_height = re_ui::ReUi::table_line_height();
+            body.rows(row_height, rows.len(), |index, mut table_row| {
+                let row = &rows[index];
+                let arrivals = &row.arrivals;
+
+                table_row.col(|ui| {
+                    ctx.entity_path_button(ui, None, &row.ent_path);
+                });
+                table_row.col(|ui| {
+                    let component_path = ComponentPath::new(row.ent_path.clone(), row.component);
+                    ctx.component_path_button_to(ui, row.component.short_name(), &component_path);
+                });
+                table_row.col(|ui| {
+                    ui.label(format_number(arrivals.num_messages as _));
+                });
+                table_row.col(|ui| {
+                    ui.label(format_bytes(row.size_bytes as _));
+                });
+                table_row.col(|ui| {
+                    ui.label(format!("{:.2}", arrivals.rate));
+                });
+                table_row.col(|ui| {
+                    if let Some(interval) = arrivals.min_interval {
+                        ui.label(format_interval(time_type, interval));
+                    }
+                });
+                table_row.col(|ui| {
+                    if let Some(interval) = arrivals.max_interval {
+                        ui.label(format_interval(time_type, interval));
+                    }
+                });
+                table_row.col(|ui| {
+                    if let Some((gap_start, gap_end)) = arrivals.longest_gap {
+                        let text = format!(
+                            "{} (longest: {})",
+                            format_number(arrivals.num_gaps),
+                            format_interval(time_type, gap_end - gap_start)
+                        );
+                        if ui
+                            .link(text)
+                            .on_hover_text(format!(
+                                "From {} to {}",
+                                time_type.format(TimeInt::from(gap_start)),
+                                time_type.format(TimeInt::from(gap_end))
+                            ))
+                            .clicked()
+                        {
+                            ctx.rec_cfg.time_ctrl.set_time(gap_start);
+                            ctx.rec_cfg.time_ctrl.pause();
+                        }
+                    }
+                });
+            });
+        });
+}
+
+fn format_interval(time_type: TimeType, interval: i64) -> String {
+    match time_type {
+        TimeType::Time => Duration::from_nanos(interval).to_string(),
+        TimeType::Sequence => interval.to_string(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// When the messages of a single stream arrived, on a single timeline.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct ArrivalStats {
+    num_messages: u64,
+
+    /// Messages per second on time timelines, per step on sequence timelines.
+    rate: f64,
+
+    /// Shortest interval between two distinct times.
+    min_interval: Option<i64>,
+
+    /// Longest interval between two distinct times.
+    max_interval: Option<i64>,
+
+    /// Number of intervals that are [`GAP_FACTOR`] times longer than the average one.
+    num_gaps: usize,
+
+    /// Start and end of the longest gap, if any.
+    longest_gap: Option<(i64, i64)>,
+}
+
+impl ArrivalStats {
+    fn from_histogram(histogram: &TimeHistogram, time_type: TimeType) -> Option<Self> {
+        let first = histogram.min_key()?;
+        let last = histogram.max_key()?;
+        let num_messages = histogram.total_count();
+
+        let span = (last - first) as f64;
+        let rate = if span > 0.0 {
+            let span = match time_type {
+                TimeType::Time => span * 1e-9,
+                TimeType::Sequence => span,
+            };
+            (num_messages - 1) as f64 / span
+        } else {
+            0.0
+        };
+
+        // With a cutoff of 1, every returned range is a single time.
+        let times = histogram
+            .range(.., 1)
+            .map(|(range, _)| range.min)
+            .collect::<Vec<_>>();
+        let average_interval = if times.len() > 1 {
+            span / (times.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        let mut stats = Self {
+            num_messages,
+            rate,
+            ..Default::default()
+        };
+        let mut add_interval = |from: i64, to: i64| {
+            let interval = to - from;
+            stats.min_interval = Some(stats.min_interval.map_or(interval, |i| i.min(interval)));
+            stats.max_interval = Some(stats.max_interval.map_or(interval, |i| i.max(interval)));
+            if interval as f64 > GAP_FACTOR * average_interval {
+                stats.num_gaps += 1;
+                if stats
+                    .longest_gap
+                    .map_or(true, |(start, end)| end - start < interval)
+                {
+                    stats.longest_gap = Some((from, to));
+                }
+            }
+        };
+        for pair in times.windows(2) {
+            add_interval(pair[0], pair[1]);
+        }
+
+        Some(stats)
+    }
+}
+
+#[test]
+fn test_arrival_stats() {
+    let mut histogram = TimeHistogram::default();
+    for time in [0, 10, 20, 30, 40, 100, 110] {
+        histogram.increment(time, 1);
+    }
+
+    let stats = ArrivalStats::from_histogram(&histogram, TimeType::Sequence).unwrap();
+    assert_eq!(stats.num_messages, 7);
+    assert_eq!(stats.min_interval, Some(10));
+    assert_eq!(stats.max_interval, Some(60));
+    assert_eq!(stats.num_gaps, 0); // 60 is less than 5 times the average of ~18
+
+    for time in 111..=200 {
+        histogram.increment(time, 1);
+    }
+    let stats = ArrivalStats::from_histogram(&histogram, TimeType::Sequence).unwrap();
+    assert_eq!(stats.min_interval, Some(1));
+    assert_eq!(stats.num_gaps, 1); // only 60 is more than 5 times the average of ~2
+    assert_eq!(stats.longest_gap, Some((40, 100)));
+
+    assert!(ArrivalStats::from_histogram(&TimeHistogram::default(), TimeType::Time).is_none());
+}
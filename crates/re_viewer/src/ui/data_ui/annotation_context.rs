@@ -1,13 +1,25 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use egui::{color_picker, Vec2};
 use itertools::Itertools;
-use re_log_types::{context::AnnotationInfo, AnnotationContext};
+use re_data_store::{query_latest_single, EntityPath};
+use re_log_types::{
+    component_types::{ColorRGBA, KeypointId, Label},
+    context::{AnnotationInfo, ClassDescription},
+    AnnotationContext,
+};
 
-use crate::ui::{annotations::auto_color, UiVerbosity};
+use crate::{
+    misc::ViewerContext,
+    ui::{annotations::auto_color, UiVerbosity},
+};
 
 use super::DataUi;
 
 const TABLE_SCROLL_AREA_HEIGHT: f32 = 500.0; // add scroll-bars when we get to this height
 
+const SKELETON_GRAPH_SIZE: f32 = 200.0;
+
 impl DataUi for AnnotationContext {
     fn data_ui(
         &self,
@@ -103,6 +115,9 @@ impl DataUi for AnnotationContext {
                                         }
                                     });
                             });
+
+                            ui.add_space(8.0);
+                            skeleton_graph_ui(ui, class);
                         }
                     }
                 });
@@ -111,6 +126,262 @@ impl DataUi for AnnotationContext {
     }
 }
 
+/// Shows the keypoint connections of a class as a graph, with all keypoints laid out on a circle.
+pub fn skeleton_graph_ui(ui: &mut egui::Ui, class: &ClassDescription) {
+    let keypoint_ids = class
+        .keypoint_map
+        .keys()
+        .copied()
+        .chain(
+            class
+                .keypoint_connections
+                .iter()
+                .flat_map(|(from, to)| [*from, *to]),
+        )
+        .collect::<BTreeSet<_>>();
+    if keypoint_ids.is_empty() {
+        return;
+    }
+
+    let (response, painter) =
+        ui.allocate_painter(Vec2::splat(SKELETON_GRAPH_SIZE), egui::Sense::hover());
+    let center = response.rect.center();
+    let font_id = egui::TextStyle::Small.resolve(ui.style());
+    let radius = 0.5 * SKELETON_GRAPH_SIZE - 3.0 * font_id.size; // leave room for the labels
+
+    let positions: BTreeMap<KeypointId, egui::Pos2> = keypoint_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let angle = std::f32::consts::TAU * i as f32 / keypoint_ids.len() as f32
+                - std::f32::consts::FRAC_PI_2;
+            (*id, center + radius * Vec2::angled(angle))
+        })
+        .collect();
+
+    let class_color = class
+        .info
+        .color
+        .map_or_else(|| auto_color(class.info.id), |color| color.into());
+
+    for (from, to) in &class.keypoint_connections {
+        if let (Some(from), Some(to)) = (positions.get(from), positions.get(to)) {
+            painter.line_segment([*from, *to], (2.0, class_color));
+        }
+    }
+
+    for (id, position) in &positions {
+        let info = class.keypoint_map.get(id);
+        let color = info
+            .and_then(|info| info.color)
+            .map_or(class_color, |color| color.into());
+        let label = info
+            .and_then(|info| info.label.as_ref())
+            .map_or_else(|| id.0.to_string(), |label| label.0.clone());
+
+        painter.circle_filled(*position, 4.0, color);
+        painter.text(
+            *position + (*position - center).normalized() * 1.5 * font_id.size,
+            egui::Align2::CENTER_CENTER,
+            label,
+            font_id.clone(),
+            ui.visuals().text_color(),
+        );
+    }
+}
+
+/// Lets the user edit the labels & colors of the annotation context logged at `entity_path`.
+///
+/// Edits are stored in [`crate::misc::RecordingConfig::annotation_overrides`] and show up in all views right away.
+pub fn annotation_context_editor_ui(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+) {
+    let query = ctx.current_query();
+    let Some(logged) = query_latest_single::<AnnotationContext>(&ctx.log_db.entity_db, entity_path, &query) else {
+        return;
+    };
+    let is_edited = ctx.rec_cfg.annotation_overrides.contains_key(entity_path);
+    let mut context = ctx
+        .rec_cfg
+        .annotation_overrides
+        .get(entity_path)
+        .cloned()
+        .unwrap_or(logged);
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(is_edited, egui::Button::new("Reset"))
+            .on_hover_text("Discard all edits and go back to the logged annotation context")
+            .clicked()
+        {
+            ctx.rec_cfg.annotation_overrides.remove(entity_path);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if ui
+            .button("Export…")
+            .on_hover_text("Save the annotation context, including all edits, to an .rrd file")
+            .clicked()
+        {
+            export_annotation_context(ctx, entity_path, &context);
+        }
+    });
+
+    let mut changed = false;
+    for (class_id, class) in context
+        .class_map
+        .iter_mut()
+        .sorted_by_key(|(class_id, _)| **class_id)
+    {
+        let class_label = class
+            .info
+            .label
+            .as_ref()
+            .map_or_else(String::new, |label| format!(" – {}", label.0));
+        egui::CollapsingHeader::new(format!("Class {}{class_label}", class_id.0))
+            .id_source(class_id.0)
+            .show(ui, |ui| {
+                let class_color = class
+                    .info
+                    .color
+                    .map_or_else(|| auto_color(class.info.id), |color| color.into());
+
+                egui::Grid::new("class")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Id");
+                        ui.strong("Label");
+                        ui.strong("Color");
+                        ui.end_row();
+
+                        ui.label("Class");
+                        changed |=
+                            annotation_info_edit_ui(ui, &mut class.info, auto_color(class_id.0));
+                        ui.end_row();
+
+                        for (keypoint_id, info) in class
+                            .keypoint_map
+                            .iter_mut()
+                            .sorted_by_key(|(keypoint_id, _)| **keypoint_id)
+                        {
+                            ui.label(format!("Keypoint {}", keypoint_id.0));
+                            changed |= annotation_info_edit_ui(ui, info, class_color);
+                            ui.end_row();
+                        }
+                    });
+
+                if !class.keypoint_connections.is_empty() {
+                    ui.add_space(8.0);
+                    skeleton_graph_ui(ui, class);
+                }
+            });
+    }
+
+    if changed {
+        ctx.rec_cfg
+            .annotation_overrides
+            .insert(entity_path.clone(), context);
+    }
+}
+
+/// Label & color of an annotation as two grid cells, returns true if anything changed.
+fn annotation_info_edit_ui(
+    ui: &mut egui::Ui,
+    info: &mut AnnotationInfo,
+    fallback_color: egui::Color32,
+) -> bool {
+    let mut changed = false;
+
+    let mut label = info
+        .label
+        .as_ref()
+        .map_or_else(String::new, |label| label.0.clone());
+    if ui.text_edit_singleline(&mut label).changed() {
+        info.label = (!label.is_empty()).then(|| Label(label));
+        changed = true;
+    }
+
+    let mut color = info.color.map_or(fallback_color, |color| color.into());
+    if color_picker::color_edit_button_srgba(ui, &mut color, color_picker::Alpha::OnlyBlend)
+        .changed()
+    {
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        info.color = Some(ColorRGBA::from_unmultiplied_rgba(r, g, b, a));
+        changed = true;
+    }
+
+    changed
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn export_annotation_context(
+    ctx: &ViewerContext<'_>,
+    entity_path: &EntityPath,
+    context: &AnnotationContext,
+) {
+    let Some(path) = rfd::FileDialog::new()
+        .set_file_name("annotation_context.rrd")
+        .set_title("Export annotation context")
+        .save_file() else {
+        return;
+    };
+
+    let application_id = ctx
+        .log_db
+        .recording_info()
+        .map_or_else(re_log_types::ApplicationId::unknown, |info| {
+            info.application_id.clone()
+        });
+
+    match write_annotation_context(application_id, entity_path, context, &path) {
+        Ok(()) => re_log::info!("Exported annotation context to {path:?}"),
+        Err(err) => re_log::error!("Failed to export annotation context to {path:?}: {err}"),
+    }
+}
+
+/// Writes a recording that contains nothing but the given annotation context, logged as timeless.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_annotation_context(
+    application_id: re_log_types::ApplicationId,
+    entity_path: &EntityPath,
+    context: &AnnotationContext,
+    path: &std::path::Path,
+) -> anyhow::Result<()> {
+    use anyhow::Context as _;
+    use re_log_types::{
+        BeginRecordingMsg, DataRow, LogMsg, MsgId, RecordingId, RecordingInfo, RecordingSource,
+        Time, TimePoint,
+    };
+
+    let begin_recording = LogMsg::BeginRecordingMsg(BeginRecordingMsg {
+        msg_id: MsgId::random(),
+        info: RecordingInfo {
+            application_id,
+            recording_id: RecordingId::random(),
+            is_official_example: false,
+            started: Time::now(),
+            recording_source: RecordingSource::Other("rerun viewer".to_owned()),
+        },
+    });
+
+    let row = DataRow::from_cells1(
+        MsgId::random(),
+        entity_path.clone(),
+        TimePoint::timeless(),
+        1,
+        &[context.clone()][..],
+    );
+    let annotation_context = LogMsg::ArrowMsg((&row.into_table()).try_into()?);
+
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create file at {path:?}"))?;
+    re_log_types::encoding::encode([begin_recording, annotation_context].iter(), file)
+        .context("Message encode")
+}
+
 fn annotation_info_table_ui<'a>(
     ui: &mut egui::Ui,
     annotation_infos: impl Iterator<Item = &'a AnnotationInfo>,
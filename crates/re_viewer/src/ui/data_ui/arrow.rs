@@ -0,0 +1,469 @@
+//! A generic ui for arbitrary arrow data, driven by its schema.
+//!
+//! Used for all components that have no dedicated ui, e.g. custom components logged by users.
+
+use re_log_types::external::arrow2::{
+    array::{
+        Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray, ListArray,
+        MapArray, PrimitiveArray, StructArray, UnionArray, Utf8Array,
+    },
+    datatypes::{DataType, Field, PhysicalType, PrimitiveType},
+    types::NativeType,
+};
+
+use crate::ui::UiVerbosity;
+
+/// How many elements of a list to show in one-line previews.
+const MAX_PREVIEW_ELEMENTS: usize = 8;
+
+/// How many elements of a list to show when expanded.
+const MAX_LIST_ELEMENTS: usize = 100;
+
+/// Longest text we format with the generic arrow formatting, in bytes.
+const MAX_FALLBACK_TEXT_LEN: usize = 256;
+
+/// Longest string we show, in bytes.
+const MAX_STRING_LEN: usize = 256;
+
+/// Show the value at `index` of `array`, whatever its datatype.
+pub(crate) fn arrow_ui(ui: &mut egui::Ui, verbosity: UiVerbosity, array: &dyn Array, index: usize) {
+    match verbosity {
+        UiVerbosity::Small | UiVerbosity::MaxHeight(_) => {
+            let text = value_text(array, index, MAX_PREVIEW_ELEMENTS);
+            copyable_label(ui, egui::Label::new(text).wrap(false), || {
+                text_to_copy(array, index)
+            });
+        }
+        UiVerbosity::Reduced | UiVerbosity::All => value_ui(ui, array, index),
+    }
+}
+
+fn value_ui(ui: &mut egui::Ui, array: &dyn Array, index: usize) {
+    if array.is_null(index) {
+        ui.weak("null");
+        return;
+    }
+
+    match array.data_type().to_logical_type() {
+        DataType::Struct(fields) => {
+            if let Some(array) = downcast::<StructArray>(array) {
+                ui.vertical(|ui| {
+                    for (position, (field, values)) in fields.iter().zip(array.values()).enumerate()
+                    {
+                        field_ui(ui, position, &field.name, values.as_ref(), index);
+                    }
+                });
+                return;
+            }
+        }
+        DataType::Union(fields, _, _) => {
+            if let Some((field, values, index)) = union_value(array, fields, index) {
+                field_ui(ui, 0, &field.name, values, index);
+                return;
+            }
+        }
+        DataType::Map(_, _) => {
+            if let Some(entries) = map_entries(array, index) {
+                map_ui(ui, &entries);
+                return;
+            }
+        }
+        _ => {
+            if let Some(elements) = list_elements(array, index) {
+                list_ui(ui, elements.as_ref());
+                return;
+            }
+        }
+    }
+
+    copyable_label(
+        ui,
+        egui::Label::new(value_text(array, index, MAX_PREVIEW_ELEMENTS)),
+        || text_to_copy(array, index),
+    );
+}
+
+/// A named value, e.g. the field of a struct.
+///
+/// `position` is the index among its siblings, as the names of e.g. map keys need not be unique.
+/// Nested values are collapsible, with a preview in the header.
+fn field_ui(ui: &mut egui::Ui, position: usize, name: &str, array: &dyn Array, index: usize) {
+    if is_nested(array.data_type()) && !array.is_null(index) {
+        let preview = value_text(array, index, MAX_PREVIEW_ELEMENTS);
+        let response = egui::CollapsingHeader::new(format!("{name}: {preview}"))
+            .id_source((position, name))
+            .show(ui, |ui| value_ui(ui, array, index));
+        copy_context_menu(response.header_response, || text_to_copy(array, index));
+    } else {
+        ui.horizontal(|ui| {
+            ui.label(format!("{name}:"));
+            value_ui(ui, array, index);
+        });
+    }
+}
+
+fn list_ui(ui: &mut egui::Ui, elements: &dyn Array) {
+    let num_elements = elements.len();
+    if num_elements == 0 {
+        ui.weak("(empty)");
+    } else if is_nested(elements.data_type()) {
+        ui.vertical(|ui| {
+            for index in 0..num_elements.min(MAX_LIST_ELEMENTS) {
+                field_ui(ui, index, &format!("[{index}]"), elements, index);
+            }
+            if num_elements > MAX_LIST_ELEMENTS {
+                ui.weak(format!(
+                    "… {} more",
+                    re_format::format_number(num_elements - MAX_LIST_ELEMENTS)
+                ));
+            }
+        });
+    } else {
+        copyable_label(
+            ui,
+            egui::Label::new(elements_text(elements, MAX_LIST_ELEMENTS)),
+            || elements_text(elements, usize::MAX),
+        );
+    }
+}
+
+fn map_ui(ui: &mut egui::Ui, entries: &StructArray) {
+    let [keys, values] = entries.values() else {
+        return;
+    };
+    let num_entries = entries.len();
+    if num_entries == 0 {
+        ui.weak("(empty)");
+        return;
+    }
+    ui.vertical(|ui| {
+        for index in 0..num_entries.min(MAX_LIST_ELEMENTS) {
+            let key = value_text(keys.as_ref(), index, MAX_PREVIEW_ELEMENTS);
+            field_ui(ui, index, &key, values.as_ref(), index);
+        }
+        if num_entries > MAX_LIST_ELEMENTS {
+            ui.weak(format!(
+                "… {} more",
+                re_format::format_number(num_entries - MAX_LIST_ELEMENTS)
+            ));
+        }
+    });
+}
+
+/// A label that can be copied to the clipboard with a right-click.
+fn copyable_label(ui: &mut egui::Ui, label: egui::Label, text_to_copy: impl FnOnce() -> String) {
+    let response = ui.add(label.sense(egui::Sense::click()));
+    copy_context_menu(response, text_to_copy);
+}
+
+/// The text is only computed when copying, as it can be large.
+fn copy_context_menu(response: egui::Response, text_to_copy: impl FnOnce() -> String) {
+    response.context_menu(|ui| {
+        if ui.button("Copy").clicked() {
+            let text = text_to_copy();
+            ui.output_mut(|o| o.copied_text = text);
+            ui.close_menu();
+        }
+    });
+}
+
+/// The full value, without any elements left out.
+fn text_to_copy(array: &dyn Array, index: usize) -> String {
+    value_text(array, index, usize::MAX)
+}
+
+// ----------------------------------------------------------------------------
+
+/// Format the value at `index` of `array` on a single line,
+/// showing at most `max_elements` elements of each list.
+///
+/// Long strings are cut short, unless `max_elements` is `usize::MAX`, i.e. when copying the value.
+fn value_text(array: &dyn Array, index: usize, max_elements: usize) -> String {
+    if array.is_null(index) {
+        return "null".to_owned();
+    }
+    try_value_text(array, index, max_elements).unwrap_or_else(|| fallback_text(array, index))
+}
+
+/// Returns `None` for datatypes we don't have a nicer formatting for.
+fn try_value_text(array: &dyn Array, index: usize, max_elements: usize) -> Option<String> {
+    Some(match array.data_type().to_physical_type() {
+        PhysicalType::Boolean => downcast::<BooleanArray>(array)?.value(index).to_string(),
+        PhysicalType::Primitive(primitive) => primitive_text(array, primitive, index)?,
+        PhysicalType::Utf8 => string_text(
+            downcast::<Utf8Array<i32>>(array)?.value(index),
+            max_elements,
+        ),
+        PhysicalType::LargeUtf8 => string_text(
+            downcast::<Utf8Array<i64>>(array)?.value(index),
+            max_elements,
+        ),
+        PhysicalType::Binary => bytes_text(downcast::<BinaryArray<i32>>(array)?.value(index)),
+        PhysicalType::LargeBinary => bytes_text(downcast::<BinaryArray<i64>>(array)?.value(index)),
+        PhysicalType::FixedSizeBinary => {
+            bytes_text(downcast::<FixedSizeBinaryArray>(array)?.value(index))
+        }
+        PhysicalType::List | PhysicalType::LargeList | PhysicalType::FixedSizeList => {
+            elements_text(list_elements(array, index)?.as_ref(), max_elements)
+        }
+        PhysicalType::Struct => {
+            let DataType::Struct(fields) = array.data_type().to_logical_type() else {
+                return None;
+            };
+            let array = downcast::<StructArray>(array)?;
+            let fields = fields
+                .iter()
+                .zip(array.values())
+                .map(|(field, values)| {
+                    let value = value_text(values.as_ref(), index, max_elements);
+                    format!("{}: {value}", field.name)
+                })
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(", "))
+        }
+        PhysicalType::Map => {
+            let entries = map_entries(array, index)?;
+            let [keys, values] = entries.values() else {
+                return None;
+            };
+            let num_entries = entries.len();
+            let mut parts = (0..num_entries.min(max_elements))
+                .map(|index| {
+                    format!(
+                        "{}: {}",
+                        value_text(keys.as_ref(), index, max_elements),
+                        value_text(values.as_ref(), index, max_elements)
+                    )
+                })
+                .collect::<Vec<_>>();
+            if num_entries > max_elements {
+                parts.push(format!(
+                    "… {} more",
+                    re_format::format_number(num_entries - max_elements)
+                ));
+            }
+            format!("{{{}}}", parts.join(", "))
+        }
+        PhysicalType::Union => {
+            let DataType::Union(fields, _, _) = array.data_type().to_logical_type() else {
+                return None;
+            };
+            let (field, values, index) = union_value(array, fields, index)?;
+            format!(
+                "{}({})",
+                field.name,
+                value_text(values, index, max_elements)
+            )
+        }
+        _ => return None,
+    })
+}
+
+fn primitive_text(array: &dyn Array, primitive: PrimitiveType, index: usize) -> Option<String> {
+    Some(match primitive {
+        PrimitiveType::Int8 => int_text(primitive_value::<i8>(array, index)?.into()),
+        PrimitiveType::Int16 => int_text(primitive_value::<i16>(array, index)?.into()),
+        PrimitiveType::Int32 => int_text(primitive_value::<i32>(array, index)?.into()),
+        PrimitiveType::Int64 => int_text(primitive_value::<i64>(array, index)?),
+        PrimitiveType::UInt8 => uint_text(primitive_value::<u8>(array, index)?.into()),
+        PrimitiveType::UInt16 => uint_text(primitive_value::<u16>(array, index)?.into()),
+        PrimitiveType::UInt32 => uint_text(primitive_value::<u32>(array, index)?.into()),
+        PrimitiveType::UInt64 => uint_text(primitive_value::<u64>(array, index)?),
+        PrimitiveType::Float32 => re_format::format_f32(primitive_value::<f32>(array, index)?),
+        PrimitiveType::Float64 => re_format::format_f64(primitive_value::<f64>(array, index)?),
+        _ => return None,
+    })
+}
+
+fn primitive_value<T: NativeType>(array: &dyn Array, index: usize) -> Option<T> {
+    Some(downcast::<PrimitiveArray<T>>(array)?.value(index))
+}
+
+fn int_text(value: i64) -> String {
+    if value < 0 {
+        format!("-{}", uint_text(value.unsigned_abs()))
+    } else {
+        uint_text(value as u64)
+    }
+}
+
+fn uint_text(value: u64) -> String {
+    // `usize` is only 32 bits on wasm.
+    usize::try_from(value).map_or_else(|_| value.to_string(), re_format::format_number)
+}
+
+/// Debug formatted to get quotes and escapes, and cut short after [`MAX_STRING_LEN`] unless copying.
+fn string_text(string: &str, max_elements: usize) -> String {
+    if max_elements == usize::MAX || string.len() <= MAX_STRING_LEN {
+        return format!("{string:?}");
+    }
+    let mut end = MAX_STRING_LEN;
+    while !string.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{:?}…", &string[..end])
+}
+
+fn bytes_text(bytes: &[u8]) -> String {
+    re_format::format_bytes(bytes.len() as _)
+}
+
+/// Format the elements of a list, showing at most `max_elements` of them.
+fn elements_text(elements: &dyn Array, max_elements: usize) -> String {
+    let num_elements = elements.len();
+    let mut parts = (0..num_elements.min(max_elements))
+        .map(|index| value_text(elements, index, max_elements))
+        .collect::<Vec<_>>();
+    if num_elements > max_elements {
+        parts.push(format!(
+            "… {} more",
+            re_format::format_number(num_elements - max_elements)
+        ));
+    }
+    format!("[{}]", parts.join(", "))
+}
+
+/// The arrow formatting, for everything we don't have a nicer formatting for.
+///
+/// Stops formatting after [`MAX_FALLBACK_TEXT_LEN`], as the value may be arbitrarily large.
+fn fallback_text(array: &dyn Array, index: usize) -> String {
+    let mut text = BoundedText::default();
+    let display = re_log_types::external::arrow2::array::get_display(array, "null");
+    if display(&mut text, index).is_ok() {
+        text.text
+    } else if text.truncated {
+        text.text + "…"
+    } else {
+        format!("<{:?}>", array.data_type())
+    }
+}
+
+/// A [`std::fmt::Write`] that fails once more than [`MAX_FALLBACK_TEXT_LEN`] bytes have been written.
+#[derive(Default)]
+struct BoundedText {
+    text: String,
+    truncated: bool,
+}
+
+impl std::fmt::Write for BoundedText {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let remaining = MAX_FALLBACK_TEXT_LEN - self.text.len();
+        if s.len() <= remaining {
+            self.text.push_str(s);
+            Ok(())
+        } else {
+            let mut end = remaining;
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.text.push_str(&s[..end]);
+            self.truncated = true;
+            Err(std::fmt::Error)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn downcast<T: 'static>(array: &dyn Array) -> Option<&T> {
+    array.as_any().downcast_ref::<T>()
+}
+
+/// Can the value contain other values, i.e. is it worth collapsing?
+fn is_nested(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::FixedSizeList
+            | PhysicalType::Struct
+            | PhysicalType::Union
+            | PhysicalType::Map
+    )
+}
+
+/// The elements of the list at `index`, for all kinds of lists.
+fn list_elements(array: &dyn Array, index: usize) -> Option<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::List => Some(downcast::<ListArray<i32>>(array)?.value(index)),
+        PhysicalType::LargeList => Some(downcast::<ListArray<i64>>(array)?.value(index)),
+        PhysicalType::FixedSizeList => Some(downcast::<FixedSizeListArray>(array)?.value(index)),
+        _ => None,
+    }
+}
+
+/// The `{key, value}` entries of the map at `index`.
+fn map_entries(array: &dyn Array, index: usize) -> Option<StructArray> {
+    downcast::<MapArray>(array)?
+        .value(index)
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .cloned()
+}
+
+/// The variant of the union at `index`, and where to find its value.
+fn union_value<'a>(
+    array: &'a dyn Array,
+    fields: &'a [Field],
+    index: usize,
+) -> Option<(&'a Field, &'a dyn Array, usize)> {
+    let array = downcast::<UnionArray>(array)?;
+    let (field_index, value_index) = array.index(index);
+    Some((
+        fields.get(field_index)?,
+        array.fields().get(field_index)?.as_ref(),
+        value_index,
+    ))
+}
+
+#[test]
+fn test_value_text() {
+    use re_log_types::external::arrow2::array::{
+        Float32Array, Int32Array, MutableListArray, MutablePrimitiveArray, TryExtend as _,
+    };
+
+    let ints = Int32Array::from_slice([-1_234_567, 42]);
+    assert_eq!(value_text(&ints, 0, 8), "-1\u{2009}234\u{2009}567");
+    assert_eq!(value_text(&ints, 1, 8), "42");
+
+    let floats = Float32Array::from_slice([1.5, 2.0]);
+    let names = Utf8Array::<i32>::from_slice(["left", "right"]);
+    let wheels = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("velocity", DataType::Float32, false),
+        ]),
+        vec![names.boxed(), floats.boxed()],
+        None,
+    );
+    assert_eq!(
+        value_text(&wheels, 0, 8),
+        r#"{name: "left", velocity: 1.5}"#
+    );
+
+    let long = "ä".repeat(MAX_STRING_LEN);
+    let strings = Utf8Array::<i32>::from_slice([long.as_str()]);
+    assert!(value_text(&strings, 0, 8).len() <= MAX_STRING_LEN + r#""…"#.len());
+    assert_eq!(value_text(&strings, 0, usize::MAX), format!("{long:?}"));
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([Some([Some(1), Some(2), Some(3)])])
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+    assert_eq!(value_text(&list, 0, 8), "[1, 2, 3]");
+    assert_eq!(value_text(&list, 0, 2), "[1, 2, … 1 more]");
+}
+
+#[test]
+fn test_fallback_text_is_bounded() {
+    use re_log_types::external::arrow2::array::{DictionaryArray, Int32Array};
+
+    let long = "x".repeat(10 * MAX_FALLBACK_TEXT_LEN);
+    let values = Utf8Array::<i32>::from_slice([long.as_str()]);
+    let dictionary =
+        DictionaryArray::try_from_keys(Int32Array::from_slice([0]), values.boxed()).unwrap();
+
+    let text = value_text(&dictionary, 0, MAX_PREVIEW_ELEMENTS);
+    assert!(text.len() <= MAX_FALLBACK_TEXT_LEN + "…".len());
+    assert!(text.ends_with('…'));
+}
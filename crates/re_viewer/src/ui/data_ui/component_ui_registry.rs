@@ -2,14 +2,14 @@ use std::collections::BTreeMap;
 
 use re_arrow_store::LatestAtQuery;
 use re_log_types::{
-    component_types::InstanceKey, external::arrow2, Component, ComponentName,
+    component_types::InstanceKey, external::arrow2::array::Array, Component, ComponentName,
     DeserializableComponent,
 };
 use re_query::ComponentWithInstances;
 
 use crate::{misc::ViewerContext, ui::UiVerbosity};
 
-use super::DataUi;
+use super::{arrow::arrow_ui, DataUi};
 
 type ComponentUiCallback = Box<
     dyn Fn(
@@ -23,6 +23,9 @@ type ComponentUiCallback = Box<
 >;
 
 /// How to display components in a Ui
+///
+/// Components without a registered ui are shown with a generic ui, based on their arrow datatype.
+/// Use [`Self::add_ui`] or [`Self::add_arrow_ui`] to register a ui for your own components.
 pub struct ComponentUiRegistry {
     components: BTreeMap<ComponentName, ComponentUiCallback>,
 }
@@ -58,6 +61,7 @@ impl Default for ComponentUiRegistry {
         // registry.add::<re_log_types::component_types::Size3D>();
         registry.add::<re_log_types::component_types::Tensor>();
         registry.add::<re_log_types::component_types::TextEntry>();
+        registry.add::<re_log_types::component_types::TextEntryFields>();
         registry.add::<re_log_types::component_types::Transform>();
         registry.add::<re_log_types::component_types::Vec2D>();
         registry.add::<re_log_types::component_types::Vec3D>();
@@ -71,12 +75,61 @@ impl ComponentUiRegistry {
     fn add<C: DeserializableComponent + DataUi>(&mut self)
     where
         for<'a> &'a C::ArrayType: IntoIterator,
+    {
+        self.add_callback::<C>(|ctx, ui, verbosity, query, component| {
+            component.data_ui(ctx, ui, verbosity, query);
+        });
+    }
+
+    /// Show all instances of the component `C` with the given `ui`, instead of the generic ui.
+    ///
+    /// Replaces any ui previously registered for `C`, including the built-in ones.
+    pub fn add_ui<C: DeserializableComponent>(
+        &mut self,
+        ui: impl Fn(&mut egui::Ui, UiVerbosity, &C) + 'static,
+    ) where
+        for<'a> &'a C::ArrayType: IntoIterator,
+    {
+        self.add_callback::<C>(move |_ctx, egui_ui, verbosity, _query, component| {
+            ui(egui_ui, verbosity, &component);
+        });
+    }
+
+    /// Show all instances of the component `name` with the given `ui`, instead of the generic ui.
+    ///
+    /// The ui is given an array containing only the instance to show.
+    /// Useful for components that have no Rust type in the viewer.
+    pub fn add_arrow_ui(
+        &mut self,
+        name: ComponentName,
+        ui: impl Fn(&mut egui::Ui, UiVerbosity, &dyn Array) + 'static,
+    ) {
+        self.components.insert(
+            name,
+            Box::new(
+                move |_ctx, egui_ui, verbosity, _query, component, instance| {
+                    if let Some(value) = component.lookup_arrow(instance) {
+                        ui(egui_ui, verbosity, value.as_ref());
+                    } else {
+                        egui_ui.weak("(null)");
+                    }
+                },
+            ),
+        );
+    }
+
+    fn add_callback<C: DeserializableComponent>(
+        &mut self,
+        callback: impl Fn(&mut ViewerContext<'_>, &mut egui::Ui, UiVerbosity, &LatestAtQuery, C)
+            + 'static,
+    ) where
+        for<'a> &'a C::ArrayType: IntoIterator,
     {
         self.components.insert(
             C::name(),
-            Box::new(|ctx, ui, verbosity, query, component, instance| {
+            Box::new(move |ctx, ui, verbosity, query, component, instance| {
                 match component.lookup::<C>(instance) {
-                    Ok(component) => component.data_ui(ctx, ui, verbosity, query),
+                    Ok(component) => callback(ctx, ui, verbosity, query, component),
                     Err(re_query::QueryError::ComponentNotFound) => {
                         ui.weak("(not found)");
                     }
@@ -109,18 +162,9 @@ impl ComponentUiRegistry {
         if let Some(ui_callback) = self.components.get(&component.name()) {
             (*ui_callback)(ctx, ui, verbosity, query, component, instance_key);
         } else {
-            // No special ui implementation - use a generic one:
+            // No special ui implementation - use a generic one, based on the datatype:
             if let Some(value) = component.lookup_arrow(instance_key) {
-                let bytes = arrow2::compute::aggregate::estimated_bytes_size(value.as_ref());
-                if bytes < 256 {
-                    // For small items, print them
-                    let mut repr = String::new();
-                    let display = arrow2::array::get_display(value.as_ref(), "null");
-                    display(&mut repr, 0).unwrap();
-                    ui.label(repr);
-                } else {
-                    ui.label(format!("{bytes} bytes"));
-                }
+                arrow_ui(ui, verbosity, value.as_ref(), 0);
             } else {
                 ui.weak("(null)");
             }
@@ -168,6 +212,33 @@ impl DataUi for re_log_types::component_types::TextEntry {
     }
 }
 
+impl DataUi for re_log_types::component_types::TextEntryFields {
+    fn data_ui(
+        &self,
+        _ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match verbosity {
+            UiVerbosity::Small | UiVerbosity::MaxHeight(_) => {
+                ui.label(format!("{} fields", self.0.len()));
+            }
+            UiVerbosity::All | UiVerbosity::Reduced => {
+                egui::Grid::new("text_entry_fields")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for field in &self.0 {
+                            ui.label(format!("{}:", field.key));
+                            ui.label(&field.value);
+                            ui.end_row();
+                        }
+                    });
+            }
+        }
+    }
+}
+
 impl DataUi for re_log_types::component_types::Mesh3D {
     fn data_ui(
         &self,
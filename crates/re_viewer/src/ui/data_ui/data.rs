@@ -3,7 +3,10 @@ use egui::Vec2;
 use re_format::format_f32;
 use re_log_types::{
     component_types::ColorRGBA,
-    component_types::{LineStrip2D, LineStrip3D, Mat3x3, Rect2D, Vec2D, Vec3D, Vec4D},
+    component_types::{
+        Affine3, BrownConrady, Homography2D, KannalaBrandt, LensDistortion, LineStrip2D,
+        LineStrip3D, Mat3x3, Rect2D, Scale3D, Vec2D, Vec3D, Vec4D,
+    },
     Pinhole, Rigid3, Transform, ViewCoordinates,
 };
 
@@ -68,6 +71,9 @@ impl DataUi for Transform {
             }
             Transform::Rigid3(rigid3) => rigid3.data_ui(ctx, ui, verbosity, query),
             Transform::Pinhole(pinhole) => pinhole.data_ui(ctx, ui, verbosity, query),
+            Transform::Scale(scale) => scale.data_ui(ctx, ui, verbosity, query),
+            Transform::Affine3(affine) => affine.data_ui(ctx, ui, verbosity, query),
+            Transform::Homography2D(homography) => homography.data_ui(ctx, ui, verbosity, query),
         }
     }
 }
@@ -131,6 +137,91 @@ impl DataUi for Rigid3 {
     }
 }
 
+impl DataUi for Scale3D {
+    fn data_ui(
+        &self,
+        _ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _verbosity: UiVerbosity,
+        _query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match self {
+            Scale3D::Uniform(scale) => {
+                ui.label(format!("Uniform scale {}", format_f32(*scale)));
+            }
+            Scale3D::ThreeD(scale) => {
+                ui.label(format!("Scale {scale}"));
+            }
+        }
+    }
+}
+
+impl DataUi for Affine3 {
+    fn data_ui(
+        &self,
+        ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match verbosity {
+            UiVerbosity::Small | UiVerbosity::MaxHeight(_) => {
+                ui.label("Affine 3D transform").on_hover_ui(|ui| {
+                    self.data_ui(ctx, ui, UiVerbosity::All, query);
+                });
+            }
+
+            UiVerbosity::All | UiVerbosity::Reduced => {
+                let Affine3 {
+                    linear,
+                    translation,
+                } = self;
+
+                ui.vertical(|ui| {
+                    ui.label("Affine 3D transform:");
+                    ui.indent("affine3", |ui| {
+                        ui.label("linear:");
+                        ui.indent("linear", |ui| {
+                            linear.data_ui(ctx, ui, verbosity, query);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("translation:");
+                            ui.monospace(translation.to_string());
+                        });
+                    });
+                });
+            }
+        }
+    }
+}
+
+impl DataUi for Homography2D {
+    fn data_ui(
+        &self,
+        ctx: &mut crate::misc::ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        verbosity: UiVerbosity,
+        query: &re_arrow_store::LatestAtQuery,
+    ) {
+        match verbosity {
+            UiVerbosity::Small | UiVerbosity::MaxHeight(_) => {
+                ui.label("2D homography").on_hover_ui(|ui| {
+                    self.data_ui(ctx, ui, UiVerbosity::All, query);
+                });
+            }
+
+            UiVerbosity::All | UiVerbosity::Reduced => {
+                ui.vertical(|ui| {
+                    ui.label("2D homography:");
+                    ui.indent("homography2d", |ui| {
+                        self.parent_from_child.data_ui(ctx, ui, verbosity, query);
+                    });
+                });
+            }
+        }
+    }
+}
+
 impl DataUi for Pinhole {
     fn data_ui(
         &self,
@@ -150,6 +241,7 @@ impl DataUi for Pinhole {
                 let Pinhole {
                     image_from_cam: image_from_view,
                     resolution,
+                    distortion,
                 } = self;
 
                 ui.vertical(|ui| {
@@ -168,6 +260,25 @@ impl DataUi for Pinhole {
                         ui.indent("image_from_view", |ui| {
                             image_from_view.data_ui(ctx, ui, verbosity, query);
                         });
+
+                        ui.horizontal(|ui| {
+                            ui.label("distortion:");
+                            ui.monospace(distortion.to_string());
+                        });
+                        match distortion {
+                            LensDistortion::None | LensDistortion::Equirectangular => {}
+                            LensDistortion::BrownConrady(BrownConrady { k1, k2, p1, p2, k3 }) => {
+                                ui.indent("distortion", |ui| {
+                                    ui.monospace(format!("k1: {k1}, k2: {k2}, k3: {k3}"));
+                                    ui.monospace(format!("p1: {p1}, p2: {p2}"));
+                                });
+                            }
+                            LensDistortion::KannalaBrandt(KannalaBrandt { k1, k2, k3, k4 }) => {
+                                ui.indent("distortion", |ui| {
+                                    ui.monospace(format!("k1: {k1}, k2: {k2}, k3: {k3}, k4: {k4}"));
+                                });
+                            }
+                        }
                     });
                 });
             }
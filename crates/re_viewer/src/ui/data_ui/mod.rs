@@ -6,6 +6,7 @@ use re_log_types::{DataCell, PathOp, TimePoint};
 use crate::misc::ViewerContext;
 
 mod annotation_context;
+mod arrow;
 mod component;
 mod component_path;
 mod component_ui_registry;
@@ -16,7 +17,8 @@ mod instance_path;
 mod log_msg;
 mod msg_id;
 
-pub(crate) use component_ui_registry::ComponentUiRegistry;
+pub(crate) use annotation_context::annotation_context_editor_ui;
+pub use component_ui_registry::ComponentUiRegistry;
 
 /// Controls how mich space we use to show the data in [`DataUi`].
 #[derive(Clone, Copy, Debug)]
@@ -120,6 +122,32 @@ impl DataUi for PathOp {
             PathOp::ClearRecursive(entity_path) => {
                 ui.label(format!("ClearRecursive: {entity_path}"))
             }
+            PathOp::ClearSomeComponents(entity_path, components) => ui.label(format!(
+                "ClearSomeComponents: {entity_path} {}",
+                components.iter().map(|c| c.short_name()).join(", ")
+            )),
+            PathOp::ClearTimeRange {
+                entity_path,
+                timeline,
+                range,
+                components,
+                recursive,
+            } => {
+                let components = components.as_ref().map_or_else(
+                    || "all components".to_owned(),
+                    |components| components.iter().map(|c| c.short_name()).join(", "),
+                );
+                ui.label(format!(
+                    "ClearTimeRange: {entity_path} {} on {}: {components}{}",
+                    timeline.typ().format_range(*range),
+                    timeline.name(),
+                    if *recursive { ", recursive" } else { "" }
+                ))
+            }
+            PathOp::TombstoneInstances(entity_path, instance_keys) => ui.label(format!(
+                "TombstoneInstances: {entity_path} {}",
+                instance_keys.iter().map(|key| key.to_string()).join(", ")
+            )),
         };
     }
 }
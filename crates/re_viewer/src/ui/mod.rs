@@ -6,6 +6,7 @@ mod scene;
 mod selection_history;
 mod selection_history_ui;
 mod space_view;
+mod space_view_class;
 mod space_view_entity_picker;
 mod space_view_heuristics;
 mod view_bar_chart;
@@ -15,6 +16,7 @@ mod view_text;
 mod view_time_series;
 mod viewport;
 
+pub(crate) mod data_profile_view;
 pub(crate) mod data_ui;
 pub(crate) mod event_log_view;
 pub(crate) mod memory_panel;
@@ -25,14 +27,14 @@ pub mod view_spatial;
 
 // ----
 
-use self::scene::SceneQuery;
-
 pub(crate) use self::blueprint::Blueprint;
-pub(crate) use self::space_view::{SpaceView, SpaceViewId};
+pub(crate) use self::space_view::{ScreenshotMode, SpaceView, SpaceViewId};
 
 pub use self::annotations::{Annotations, DefaultColor, MISSING_ANNOTATIONS};
 pub use self::data_blueprint::DataBlueprintGroupHandle;
+pub use self::scene::SceneQuery;
 pub use self::selection_history::{HistoricalSelection, SelectionHistory};
+pub use self::space_view_class::{SpaceViewClass, SpaceViewClassRegistry};
 pub use self::view_category::ViewCategory;
 pub use self::viewport::Viewport;
 
@@ -15,7 +15,7 @@ impl<'s> SceneQuery<'s> {
     /// Iter over all of the currently visible [`EntityPath`]s in the [`SceneQuery`].
     ///
     /// Also includes the corresponding [`EntityProperties`].
-    pub(crate) fn iter_entities(&self) -> impl Iterator<Item = (&EntityPath, EntityProperties)> {
+    pub fn iter_entities(&self) -> impl Iterator<Item = (&EntityPath, EntityProperties)> {
         self.entity_paths
             .iter()
             .map(|entity_path| (entity_path, self.entity_props_map.get(entity_path)))
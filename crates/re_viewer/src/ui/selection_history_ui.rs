@@ -201,6 +201,17 @@ fn item_to_string(blueprint: &Blueprint, item: &Item) -> String {
                 "<group in removed space view>".to_owned()
             }
         }
+        Item::Measurement(sid, id) => {
+            let measurement = blueprint
+                .viewport
+                .space_view(sid)
+                .and_then(|space_view| space_view.view_state.state_spatial.measurements.get(*id));
+            if let Some(measurement) = measurement {
+                format!("{}: {}", measurement.kind, measurement.value_text())
+            } else {
+                "<removed measurement>".to_owned()
+            }
+        }
         Item::MsgId(msg_id) => msg_id.short_string(),
         Item::ComponentPath(path) => {
             format!("{} {}", path.entity_path, path.component_name.short_name(),)
@@ -1,18 +1,26 @@
 use egui::NumExt as _;
 use re_data_store::{
     query_latest_single, ColorMap, ColorMapper, EditableAutoValue, EntityPath, EntityProperties,
+    MeshShading,
 };
 use re_log_types::{
     component_types::{Tensor, TensorDataMeaning},
-    TimeType, Transform,
+    AnnotationContext, Component as _, Mesh3D, TimeType, Transform,
 };
 
 use crate::{
-    ui::{view_spatial::SpatialNavigationMode, Blueprint},
+    misc::space_info::query_view_coordinates,
+    ui::{
+        view_spatial::{MeasurementKind, SpatialNavigationMode},
+        Blueprint,
+    },
     Item, UiVerbosity, ViewerContext,
 };
 
-use super::{data_ui::DataUi, space_view::ViewState};
+use super::{
+    data_ui::{annotation_context_editor_ui, DataUi},
+    space_view::ViewState,
+};
 
 // ---
 
@@ -103,6 +111,13 @@ impl SelectionPanel {
                     });
                 }
 
+                if let Some(entity_path) = annotation_context_path(ctx, selection, &query) {
+                    ctx.re_ui
+                        .large_collapsing_header(ui, "Annotation Context", true, |ui| {
+                            annotation_context_editor_ui(ctx, ui, &entity_path);
+                        });
+                }
+
                 ctx.re_ui
                     .large_collapsing_header(ui, "Blueprint", true, |ui| {
                         blueprint_ui(ui, ctx, blueprint, selection);
@@ -121,10 +136,31 @@ fn has_data_section(item: &Item) -> bool {
     match item {
         Item::MsgId(_) | Item::ComponentPath(_) | Item::InstancePath(_, _) => true,
         // Skip data ui since we don't know yet what to show for these.
-        Item::SpaceView(_) | Item::DataBlueprintGroup(_, _) => false,
+        Item::SpaceView(_) | Item::DataBlueprintGroup(_, _) | Item::Measurement(_, _) => false,
     }
 }
 
+/// The entity path of the selection, if an [`AnnotationContext`] was logged there.
+fn annotation_context_path(
+    ctx: &ViewerContext<'_>,
+    item: &Item,
+    query: &re_arrow_store::LatestAtQuery,
+) -> Option<EntityPath> {
+    let entity_path = match item {
+        Item::ComponentPath(component_path) => component_path.entity_path(),
+        Item::InstancePath(_, instance_path) if !instance_path.instance_key.is_specific() => {
+            &instance_path.entity_path
+        }
+        Item::MsgId(_)
+        | Item::InstancePath(_, _)
+        | Item::SpaceView(_)
+        | Item::DataBlueprintGroup(_, _)
+        | Item::Measurement(_, _) => return None,
+    };
+    query_latest_single::<AnnotationContext>(&ctx.log_db.entity_db, entity_path, query)
+        .map(|_| entity_path.clone())
+}
+
 /// What is selected? Not the contents, just the short id of it.
 pub fn what_is_selected_ui(
     ui: &mut egui::Ui,
@@ -213,6 +249,43 @@ pub fn what_is_selected_ui(
                 }
             }
         }
+        Item::Measurement(space_view_id, measurement_id) => {
+            if let Some(space_view) = blueprint.viewport.space_view(space_view_id) {
+                if let Some(measurement) = space_view
+                    .view_state
+                    .state_spatial
+                    .measurements
+                    .get(*measurement_id)
+                {
+                    let coordinates = query_view_coordinates(
+                        &ctx.log_db.entity_db,
+                        &space_view.space_path,
+                        &ctx.current_query(),
+                    );
+                    egui::Grid::new("measurement")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(format!("{}:", measurement.kind));
+                            ui.monospace(measurement.value_text());
+                            ui.end_row();
+
+                            if measurement.kind == MeasurementKind::Distance {
+                                if let Some(axis_deltas) = measurement.axis_deltas(coordinates) {
+                                    for (axis_name, delta) in axis_deltas {
+                                        ui.label(format!("{axis_name}:"));
+                                        ui.monospace(re_format::format_f32(delta));
+                                        ui.end_row();
+                                    }
+                                }
+                            }
+
+                            ui.label("in Space View:");
+                            ctx.space_view_button(ui, space_view);
+                            ui.end_row();
+                        });
+                }
+            }
+        }
     }
 }
 
@@ -225,7 +298,7 @@ impl DataUi for Item {
         query: &re_arrow_store::LatestAtQuery,
     ) {
         match self {
-            Item::SpaceView(_) | Item::DataBlueprintGroup(_, _) => {
+            Item::SpaceView(_) | Item::DataBlueprintGroup(_, _) | Item::Measurement(_, _) => {
                 // Shouldn't be reachable since SelectionPanel::contents doesn't show data ui for these.
                 // If you add something in here make sure to adjust SelectionPanel::contents accordingly.
                 debug_assert!(!has_data_section(self));
@@ -339,6 +412,23 @@ fn blueprint_ui(
                 }
             }
         }
+
+        Item::Measurement(space_view_id, measurement_id) => {
+            if ui
+                .button("Remove")
+                .on_hover_text("Remove this measurement from the Space View.")
+                .clicked()
+            {
+                if let Some(space_view) = blueprint.viewport.space_view_mut(space_view_id) {
+                    space_view
+                        .view_state
+                        .state_spatial
+                        .measurements
+                        .remove(*measurement_id);
+                }
+                ctx.selection_state_mut().clear_current();
+            }
+        }
     }
 }
 
@@ -414,10 +504,15 @@ fn entity_props_ui(
             }
             ui.end_row();
 
+            if let Some(entity_path) = entity_path {
+                transform_props_ui(ctx, ui, entity_path, entity_props);
+            }
+
             if *view_state.state_spatial.nav_mode.get() == SpatialNavigationMode::ThreeD {
                 if let Some(entity_path) = entity_path {
                     pinhole_props_ui(ctx, ui, entity_path, entity_props);
                     depth_props_ui(ctx, ui, entity_path, entity_props);
+                    mesh_props_ui(ctx, ui, entity_path, entity_props);
                 }
             }
         });
@@ -454,6 +549,31 @@ fn colormap_props_ui(ui: &mut egui::Ui, entity_props: &mut EntityProperties) {
     ui.end_row();
 }
 
+fn transform_props_ui(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    entity_props: &mut EntityProperties,
+) {
+    let query = ctx.current_query();
+    if let Some(re_log_types::Transform::Rigid3(_)) =
+        query_latest_single::<Transform>(&ctx.log_db.entity_db, entity_path, &query)
+    {
+        let mut interpolate = *entity_props.interpolate_transforms.get();
+        if ui
+            .checkbox(&mut interpolate, "Interpolate transform")
+            .on_hover_text(
+                "Smoothly interpolate the transform between the samples logged \
+                before and after the current time.",
+            )
+            .changed()
+        {
+            entity_props.interpolate_transforms = EditableAutoValue::UserEdited(interpolate);
+        }
+        ui.end_row();
+    }
+}
+
 fn pinhole_props_ui(
     ctx: &mut ViewerContext<'_>,
     ui: &mut egui::Ui,
@@ -532,6 +652,58 @@ fn depth_props_ui(
     Some(())
 }
 
+fn mesh_props_ui(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    entity_props: &mut EntityProperties,
+) {
+    let timeline = ctx.current_query().timeline;
+    let has_mesh = ctx
+        .log_db
+        .entity_db
+        .data_store
+        .all_components(&timeline, entity_path)
+        .map_or(false, |components| components.contains(&Mesh3D::name()));
+    if !has_mesh {
+        return;
+    }
+
+    let current = *entity_props.mesh_shading.get();
+    ui.label("Shading");
+    egui::ComboBox::from_id_source("mesh_shading")
+        .selected_text(current.to_string())
+        .show_ui(ui, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.set_min_width(64.0);
+
+            for proposed in [MeshShading::Smooth, MeshShading::Flat, MeshShading::Unlit] {
+                if ui
+                    .selectable_label(current == proposed, proposed.to_string())
+                    .clicked()
+                {
+                    entity_props.mesh_shading = EditableAutoValue::UserEdited(proposed);
+                }
+            }
+        })
+        .response
+        .on_hover_text(
+            "Smooth uses the mesh's vertex normals, \
+            flat uses a single normal per triangle and unlit shows the plain colors.",
+        );
+    ui.end_row();
+
+    let mut wireframe = *entity_props.mesh_wireframe.get();
+    if ui
+        .checkbox(&mut wireframe, "Wireframe")
+        .on_hover_text("Draw the edges of all triangles on top of the mesh.")
+        .changed()
+    {
+        entity_props.mesh_wireframe = EditableAutoValue::UserEdited(wireframe);
+    }
+    ui.end_row();
+}
+
 fn depth_from_world_scale_ui(ui: &mut egui::Ui, property: &mut EditableAutoValue<f32>) {
     ui.label("Backproject meter");
     let mut value = *property.get();
@@ -1,14 +1,18 @@
 use re_arrow_store::Timeline;
-use re_data_store::{EntityPath, EntityTree, InstancePath, TimeInt};
+use re_data_store::{EntityPath, EntityTree, InstancePath, LogDb, TimeInt};
 use re_renderer::{GpuReadbackIdentifier, ScreenshotProcessor};
 
 use crate::{
-    misc::{space_info::SpaceInfoCollection, SpaceViewHighlights, TransformCache, ViewerContext},
+    misc::{
+        space_info::SpaceInfoCollection, SpaceViewHighlights, TakenScreenshot, TransformCache,
+        ViewerContext,
+    },
     ui::view_category::categorize_entity_path,
 };
 
 use super::{
     data_blueprint::DataBlueprintTree,
+    space_view_class::{SpaceViewClassRegistry, SpaceViewClassState},
     space_view_heuristics::default_queried_entities,
     view_bar_chart,
     view_category::ViewCategory,
@@ -44,6 +48,10 @@ pub enum ScreenshotMode {
 
     /// The screenshot will be copied to the clipboard.
     CopyToClipboard,
+
+    /// The screenshot will be sent back to whoever asked for it with a
+    /// [`re_log_types::ViewerCommand::Screenshot`].
+    Remote,
 }
 
 /// A view of a space.
@@ -67,6 +75,10 @@ pub struct SpaceView {
     /// We only show data that match this category.
     pub category: ViewCategory,
 
+    /// The name of the [`super::SpaceViewClass`] for [`ViewCategory::Custom`].
+    #[serde(default)]
+    pub custom_class: Option<String>,
+
     /// True if the user is expected to add entities themselves. False otherwise.
     pub entities_determined_by_user: bool,
 }
@@ -99,10 +111,47 @@ impl SpaceView {
             data_blueprint: data_blueprint_tree,
             view_state: ViewState::default(),
             category,
+            custom_class: None,
             entities_determined_by_user: false,
         }
     }
 
+    /// A space view of a [`super::SpaceViewClass`] registered by the app.
+    pub fn new_custom(
+        class_name: &str,
+        space_path: &EntityPath,
+        queries_entities: &[EntityPath],
+    ) -> Self {
+        let mut space_view = Self::new(ViewCategory::Custom, space_path, queries_entities);
+        if space_path.is_root() {
+            space_view.display_name = format!("/ ({class_name})");
+        }
+        space_view.custom_class = Some(class_name.to_owned());
+        space_view
+    }
+
+    /// Can this space view show the given entity, judging by its components?
+    pub fn can_show_entity(
+        &self,
+        log_db: &LogDb,
+        space_view_class_registry: &SpaceViewClassRegistry,
+        entity_path: &EntityPath,
+    ) -> bool {
+        if let Some(class) = &self.custom_class {
+            space_view_class_registry.can_show_entity(class, log_db, entity_path)
+        } else {
+            categorize_entity_path(Timeline::log_time(), log_db, entity_path)
+                .contains(self.category)
+        }
+    }
+
+    /// The kind of space view, for showing to the user.
+    pub fn kind_name(&self) -> String {
+        self.custom_class
+            .clone()
+            .unwrap_or_else(|| self.category.to_string())
+    }
+
     pub fn on_frame_start(
         &mut self,
         ctx: &mut ViewerContext<'_>,
@@ -112,8 +161,7 @@ impl SpaceView {
 
         if !self.entities_determined_by_user {
             // Add entities that have been logged since we were created
-            let queries_entities =
-                default_queried_entities(ctx, &self.space_path, spaces_info, self.category);
+            let queries_entities = default_queried_entities(ctx, self, spaces_info);
             self.data_blueprint
                 .insert_entities_according_to_hierarchy(queries_entities.iter(), &self.space_path);
         }
@@ -121,7 +169,17 @@ impl SpaceView {
         while ScreenshotProcessor::next_readback_result(
             ctx.render_ctx,
             self.id.gpu_readback_id(),
-            |data, extent, mode| self.handle_pending_screenshots(data, extent, mode),
+            |data, extent, mode| {
+                if mode == ScreenshotMode::Remote {
+                    ctx.rec_cfg.taken_screenshots.push(TakenScreenshot {
+                        space_view_id: self.id,
+                        extent: [extent.x, extent.y],
+                        rgba: data.to_vec(),
+                    });
+                } else {
+                    self.handle_pending_screenshots(data, extent, mode);
+                }
+            },
         )
         .is_some()
         {}
@@ -194,6 +252,16 @@ impl SpaceView {
                     }
                 }
             }
+            ViewCategory::Custom => {
+                if let Some(class) = &self.custom_class {
+                    ctx.space_view_class_registry.selection_ui(
+                        class,
+                        ctx,
+                        ui,
+                        &mut self.view_state.state_custom,
+                    );
+                }
+            }
         }
     }
 
@@ -249,8 +317,15 @@ impl SpaceView {
                 self.view_state
                     .state_spatial
                     .update_object_property_heuristics(ctx, &mut self.data_blueprint);
-                self.view_state
-                    .ui_spatial(ctx, ui, &self.space_path, scene, self.id, highlights);
+                self.view_state.ui_spatial(
+                    ctx,
+                    ui,
+                    &self.space_path,
+                    scene,
+                    &transforms,
+                    self.id,
+                    highlights,
+                );
             }
 
             ViewCategory::Tensor => {
@@ -258,6 +333,18 @@ impl SpaceView {
                 scene.load(ctx, &query);
                 self.view_state.ui_tensor(ctx, ui, &scene);
             }
+
+            ViewCategory::Custom => {
+                if let Some(class) = &self.custom_class {
+                    ctx.space_view_class_registry.scene_ui(
+                        class,
+                        ctx,
+                        ui,
+                        &mut self.view_state.state_custom,
+                        &query,
+                    );
+                }
+            }
         };
     }
 
@@ -280,16 +367,14 @@ impl SpaceView {
         &mut self,
         tree: &EntityTree,
         spaces_info: &SpaceInfoCollection,
-        log_db: &re_data_store::LogDb,
+        log_db: &LogDb,
+        space_view_class_registry: &SpaceViewClassRegistry,
     ) {
         crate::profile_function!();
 
         let mut entities = Vec::new();
         tree.visit_children_recursively(&mut |entity_path: &EntityPath| {
-            let entity_categories =
-                categorize_entity_path(Timeline::log_time(), log_db, entity_path);
-
-            if entity_categories.contains(self.category)
+            if self.can_show_entity(log_db, space_view_class_registry, entity_path)
                 && !self.data_blueprint.contains_entity(entity_path)
                 && spaces_info
                     .is_reachable_by_transform(entity_path, &self.space_path)
@@ -320,6 +405,9 @@ pub struct ViewState {
     state_bar_chart: view_bar_chart::BarChartState,
     pub state_spatial: view_spatial::ViewSpatialState,
     state_tensors: ahash::HashMap<InstancePath, view_tensor::ViewTensorState>,
+
+    #[serde(skip)]
+    state_custom: SpaceViewClassState,
 }
 
 impl ViewState {
@@ -331,12 +419,20 @@ impl ViewState {
         ui: &mut egui::Ui,
         space: &EntityPath,
         scene: view_spatial::SceneSpatial,
+        transforms: &TransformCache,
         space_view_id: SpaceViewId,
         highlights: &SpaceViewHighlights,
     ) {
         ui.vertical(|ui| {
-            self.state_spatial
-                .view_spatial(ctx, ui, space, scene, space_view_id, highlights);
+            self.state_spatial.view_spatial(
+                ctx,
+                ui,
+                space,
+                scene,
+                transforms,
+                space_view_id,
+                highlights,
+            );
         });
     }
 
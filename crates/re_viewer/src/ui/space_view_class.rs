@@ -0,0 +1,228 @@
+//! Custom kinds of space views, registered by the app embedding the viewer.
+
+use std::{any::Any, collections::BTreeMap};
+
+use re_arrow_store::Timeline;
+use re_data_store::{ComponentName, EntityPath, LogDb};
+
+use crate::misc::ViewerContext;
+
+use super::scene::SceneQuery;
+
+/// A custom kind of space view, e.g. an occupancy grid or a Gantt chart of task states.
+///
+/// Register it with [`crate::App::add_space_view_class`].
+/// It is then offered next to the built-in space views, for all entities that
+/// have one of its [`Self::indicator_components`].
+pub trait SpaceViewClass {
+    /// State of a single space view of this class, e.g. zoom level or filters.
+    ///
+    /// It lives as long as the space view, but is not saved with the blueprint.
+    type State: Any + Clone + Default;
+
+    /// The data shown by a space view of this class, loaded each frame.
+    type Scene;
+
+    /// Unique name of the class, shown in the ui.
+    fn name(&self) -> &str;
+
+    /// Shown when hovering the help button of a space view of this class.
+    fn help_text(&self) -> Option<&str> {
+        None
+    }
+
+    /// Entities with any of these components are shown in space views of this class.
+    fn indicator_components(&self) -> Vec<ComponentName>;
+
+    /// Load the data of the visible entities, e.g. with the queries of `re_query`.
+    ///
+    /// The data store is at `ctx.log_db.entity_db.data_store`.
+    fn load_scene(&self, ctx: &mut ViewerContext<'_>, query: &SceneQuery<'_>) -> Self::Scene;
+
+    /// Show the scene, with egui or with `re_renderer` via `ctx.render_ctx`.
+    fn ui(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut Self::State,
+        scene: Self::Scene,
+    );
+
+    /// Settings of a space view of this class, shown in the selection panel.
+    fn selection_ui(
+        &self,
+        _ctx: &mut ViewerContext<'_>,
+        _ui: &mut egui::Ui,
+        _state: &mut Self::State,
+    ) {
+    }
+}
+
+/// Object safe version of [`SpaceViewClass`], so we can store classes of different types.
+trait DynSpaceViewClass {
+    fn help_text(&self) -> Option<&str>;
+
+    fn indicator_components(&self) -> Vec<ComponentName>;
+
+    fn scene_ui(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+        query: &SceneQuery<'_>,
+    );
+
+    fn selection_ui(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+    );
+}
+
+impl<T: SpaceViewClass> DynSpaceViewClass for T {
+    fn help_text(&self) -> Option<&str> {
+        SpaceViewClass::help_text(self)
+    }
+
+    fn indicator_components(&self) -> Vec<ComponentName> {
+        SpaceViewClass::indicator_components(self)
+    }
+
+    fn scene_ui(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+        query: &SceneQuery<'_>,
+    ) {
+        let scene = self.load_scene(ctx, query);
+        SpaceViewClass::ui(self, ctx, ui, state.get_or_default::<T::State>(), scene);
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+    ) {
+        SpaceViewClass::selection_ui(self, ctx, ui, state.get_or_default::<T::State>());
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+trait AnyState: Any {
+    fn clone_box(&self) -> Box<dyn AnyState>;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any + Clone> AnyState for T {
+    fn clone_box(&self) -> Box<dyn AnyState> {
+        Box::new(self.clone())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// The [`SpaceViewClass::State`] of a space view, created on first use.
+#[derive(Default)]
+pub struct SpaceViewClassState(Option<Box<dyn AnyState>>);
+
+impl Clone for SpaceViewClassState {
+    fn clone(&self) -> Self {
+        Self(self.0.as_ref().map(|state| state.clone_box()))
+    }
+}
+
+impl SpaceViewClassState {
+    fn get_or_default<T: Any + Clone + Default>(&mut self) -> &mut T {
+        // Reset the state if it has a different type, e.g. because the class was replaced.
+        let is_of_type = self
+            .0
+            .as_mut()
+            .map_or(false, |state| state.as_any_mut().is::<T>());
+        if !is_of_type {
+            self.0 = Some(Box::new(T::default()));
+        }
+        self.0
+            .as_mut()
+            .and_then(|state| state.as_any_mut().downcast_mut::<T>())
+            .unwrap()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// All registered [`SpaceViewClass`]es, by name.
+#[derive(Default)]
+pub struct SpaceViewClassRegistry {
+    classes: BTreeMap<String, Box<dyn DynSpaceViewClass>>,
+}
+
+impl SpaceViewClassRegistry {
+    /// Replaces any class of the same name.
+    pub fn add<T: SpaceViewClass + 'static>(&mut self, class: T) {
+        let name = SpaceViewClass::name(&class).to_owned();
+        if self.classes.insert(name.clone(), Box::new(class)).is_some() {
+            re_log::warn!("Replacing the space view class {name:?}");
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.classes.keys().map(|name| name.as_str())
+    }
+
+    pub fn help_text(&self, name: &str) -> Option<&str> {
+        self.classes.get(name)?.help_text()
+    }
+
+    /// Can space views of the class `name` show the entity?
+    pub fn can_show_entity(&self, name: &str, log_db: &LogDb, entity_path: &EntityPath) -> bool {
+        let Some(class) = self.classes.get(name) else {
+            return false;
+        };
+        let indicator_components = class.indicator_components();
+        log_db
+            .entity_db
+            .data_store
+            .all_components(&Timeline::log_time(), entity_path)
+            .map_or(false, |components| {
+                components
+                    .iter()
+                    .any(|component| indicator_components.contains(component))
+            })
+    }
+
+    pub fn scene_ui(
+        &self,
+        name: &str,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+        query: &SceneQuery<'_>,
+    ) {
+        if let Some(class) = self.classes.get(name) {
+            class.scene_ui(ctx, ui, state, query);
+        } else {
+            ui.centered_and_justified(|ui| {
+                ui.label(format!("Unknown space view class {name:?}"));
+            });
+        }
+    }
+
+    pub fn selection_ui(
+        &self,
+        name: &str,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut SpaceViewClassState,
+    ) {
+        if let Some(class) = self.classes.get(name) {
+            class.selection_ui(ctx, ui, state);
+        }
+    }
+}
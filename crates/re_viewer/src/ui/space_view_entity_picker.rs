@@ -208,7 +208,12 @@ fn add_entities_line_ui(
                     |ui| {
                         let response = ctx.re_ui.small_icon_button(ui, &re_ui::icons::ADD);
                         if response.clicked() {
-                            space_view.add_entity_subtree(entity_tree, spaces_info, ctx.log_db);
+                            space_view.add_entity_subtree(
+                                entity_tree,
+                                spaces_info,
+                                ctx.log_db,
+                                ctx.space_view_class_registry,
+                            );
                         }
 
                         if add_info
@@ -301,7 +306,9 @@ fn create_entity_add_info(
 
     tree.visit_children_recursively(&mut |entity_path| {
         let categories = categorize_entity_path(Timeline::log_time(), ctx.log_db, entity_path);
-        let can_add: CanAddToSpaceView = if categories.contains(space_view.category) {
+        let can_show =
+            space_view.can_show_entity(ctx.log_db, ctx.space_view_class_registry, entity_path);
+        let can_add: CanAddToSpaceView = if can_show {
             match spaces_info.is_reachable_by_transform(entity_path, &space_view.space_path) {
                 Ok(()) => CanAddToSpaceView::Compatible {
                     already_added: space_view.data_blueprint.contains_entity(entity_path),
@@ -318,7 +325,7 @@ fn create_entity_add_info(
             CanAddToSpaceView::No {
                 reason: format!(
                     "Entity can't be displayed by this type of Space View ({})",
-                    space_view.category
+                    space_view.kind_name()
                 ),
             }
         };
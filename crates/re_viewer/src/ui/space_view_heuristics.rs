@@ -33,15 +33,36 @@ pub fn all_possible_space_views(
         .chain(root_children.values().map(|sub_tree| &sub_tree.path))
         .unique();
 
-    // For each candidate, create space views for all possible categories.
+    // For each candidate, create space views for all possible categories and custom classes.
     candidate_space_paths
         .flat_map(|candidate_space_path| {
-            default_queried_entities_by_category(ctx, candidate_space_path, spaces_info)
-                .iter()
-                .map(|(category, entity_paths)| {
-                    SpaceView::new(*category, candidate_space_path, entity_paths)
+            let builtin_space_views =
+                default_queried_entities_by_category(ctx, candidate_space_path, spaces_info)
+                    .iter()
+                    .map(|(category, entity_paths)| {
+                        SpaceView::new(*category, candidate_space_path, entity_paths)
+                    })
+                    .collect::<Vec<_>>();
+            let custom_space_views = ctx
+                .space_view_class_registry
+                .names()
+                .filter_map(|class_name| {
+                    let mut space_view =
+                        SpaceView::new_custom(class_name, candidate_space_path, &[]);
+                    let entity_paths = default_queried_entities(ctx, &space_view, spaces_info);
+                    if entity_paths.is_empty() {
+                        return None;
+                    }
+                    space_view
+                        .data_blueprint
+                        .insert_entities_according_to_hierarchy(
+                            entity_paths.iter(),
+                            candidate_space_path,
+                        );
+                    Some(space_view)
                 })
-                .collect::<Vec<_>>()
+                .collect::<Vec<_>>();
+            builtin_space_views.into_iter().chain(custom_space_views)
         })
         .collect()
 }
@@ -99,11 +120,16 @@ fn is_interesting_space_view_not_at_root(
     //    If however there is ..
     //       .. an unknown transform, the children can't be shown otherwise
     //       .. an pinhole transform, we'd like to see the world from this camera's pov as well!
+    //       .. a homography, we'd like to see the image both warped and unwarped.
     if candidate.category == ViewCategory::Spatial {
         if let Some(transform) = query_latest_single(entity_db, &candidate.space_path, query) {
             match transform {
-                re_log_types::Transform::Rigid3(_) => {}
-                re_log_types::Transform::Pinhole(_) | re_log_types::Transform::Unknown => {
+                re_log_types::Transform::Rigid3(_)
+                | re_log_types::Transform::Scale(_)
+                | re_log_types::Transform::Affine3(_) => {}
+                re_log_types::Transform::Pinhole(_)
+                | re_log_types::Transform::Homography2D(_)
+                | re_log_types::Transform::Unknown => {
                     return true;
                 }
             }
@@ -119,8 +145,40 @@ pub fn default_created_space_views(
     ctx: &ViewerContext<'_>,
     spaces_info: &SpaceInfoCollection,
 ) -> Vec<SpaceView> {
-    let candidates = all_possible_space_views(ctx, spaces_info);
-    default_created_space_views_from_candidates(&ctx.log_db.entity_db, candidates)
+    let (custom_candidates, candidates) = all_possible_space_views(ctx, spaces_info)
+        .into_iter()
+        .partition(|candidate| candidate.custom_class.is_some());
+    let mut space_views =
+        default_created_space_views_from_candidates(&ctx.log_db.entity_db, candidates);
+    space_views.extend(default_created_custom_space_views(custom_candidates));
+    space_views
+}
+
+/// For each custom class: a space view at the root if it has any direct entities,
+/// otherwise one for each child of the root.
+fn default_created_custom_space_views(candidates: Vec<SpaceView>) -> Vec<SpaceView> {
+    let classes_with_interesting_roots = candidates
+        .iter()
+        .filter(|candidate| {
+            candidate.space_path.is_root()
+                && !candidate.data_blueprint.root_group().entities.is_empty()
+        })
+        .filter_map(|candidate| candidate.custom_class.clone())
+        .collect::<std::collections::BTreeSet<_>>();
+
+    candidates
+        .into_iter()
+        .filter(|candidate| {
+            let has_interesting_root = candidate.custom_class.as_ref().map_or(false, |class| {
+                classes_with_interesting_roots.contains(class)
+            });
+            if has_interesting_root {
+                candidate.space_path.is_root()
+            } else {
+                candidate.space_path.len() == 1
+            }
+        })
+        .collect()
 }
 
 fn default_created_space_views_from_candidates(
@@ -267,14 +325,13 @@ fn is_default_added_to_space_view(
             && has_any_component_except(entity_path, data_store, timeline, &ignored_components))
 }
 
-/// List of entities a space view queries by default for a given category.
+/// List of entities a space view queries by default.
 ///
-/// These are all entities in the given space which have the requested category and are reachable by a transform.
+/// These are all entities in the space of the space view which it can show and are reachable by a transform.
 pub fn default_queried_entities(
     ctx: &ViewerContext<'_>,
-    space_path: &EntityPath,
+    space_view: &SpaceView,
     spaces_info: &SpaceInfoCollection,
-    category: ViewCategory,
 ) -> Vec<EntityPath> {
     crate::profile_function!();
 
@@ -282,6 +339,7 @@ pub fn default_queried_entities(
     let log_db = &ctx.log_db;
     let data_store = &log_db.entity_db.data_store;
 
+    let space_path = &space_view.space_path;
     let mut entities = Vec::new();
     let space_info = spaces_info.get_first_parent_with_info(space_path);
 
@@ -292,7 +350,11 @@ pub fn default_queried_entities(
                 .iter()
                 .filter(|entity_path| {
                     is_default_added_to_space_view(entity_path, space_path, data_store, timeline)
-                        && categorize_entity_path(timeline, log_db, entity_path).contains(category)
+                        && space_view.can_show_entity(
+                            log_db,
+                            ctx.space_view_class_registry,
+                            entity_path,
+                        )
                 })
                 .cloned(),
         );
@@ -0,0 +1,298 @@
+use egui::{pos2, Color32, CursorIcon, Rect};
+
+use re_arrow_store::RangeQuery;
+use re_data_store::{EntityTree, LogDb};
+use re_log_types::{
+    component_types::TimeMarker, Component as _, EntityPath, TimeInt, TimeRange, TimeReal, Timeline,
+};
+
+use crate::TimeControl;
+
+use super::time_ranges_ui::TimeRangesUi;
+
+/// A named point or range in time, created in the viewer and saved with the blueprint.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TimeBookmark {
+    pub label: String,
+    pub timeline: Timeline,
+
+    /// `min == max` for a single point in time.
+    pub range: TimeRange,
+}
+
+/// Where a [`Marker`] comes from.
+#[derive(Clone, Debug)]
+pub enum MarkerSource {
+    /// Index into the bookmarks of the blueprint.
+    Bookmark(usize),
+
+    /// A [`TimeMarker`] logged to this entity.
+    Logged(EntityPath),
+}
+
+/// A bookmark or a logged [`TimeMarker`] on the current timeline.
+#[derive(Clone, Debug)]
+pub struct Marker {
+    pub label: String,
+
+    /// `min == max` for a single point in time.
+    pub range: TimeRange,
+
+    pub source: MarkerSource,
+}
+
+const MARKER_COLOR: Color32 = Color32::from_rgb(255, 170, 40);
+
+/// All bookmarks and logged markers on `timeline`, sorted by time.
+pub fn markers_on_timeline(
+    log_db: &LogDb,
+    bookmarks: &[TimeBookmark],
+    timeline: &Timeline,
+) -> Vec<Marker> {
+    crate::profile_function!();
+
+    let mut markers = bookmarks
+        .iter()
+        .enumerate()
+        .filter(|(_, bookmark)| bookmark.timeline == *timeline)
+        .map(|(i, bookmark)| Marker {
+            label: bookmark.label.clone(),
+            range: bookmark.range,
+            source: MarkerSource::Bookmark(i),
+        })
+        .collect::<Vec<_>>();
+
+    let query = RangeQuery::new(*timeline, TimeRange::new(TimeInt::MIN, TimeInt::MAX));
+    add_logged_markers(log_db, &query, &log_db.entity_db.tree, &mut markers);
+
+    markers.sort_by_key(|marker| marker.range.min);
+    markers
+}
+
+fn add_logged_markers(
+    log_db: &LogDb,
+    query: &RangeQuery,
+    tree: &EntityTree,
+    markers: &mut Vec<Marker>,
+) {
+    if !tree.prefix_times.has_timeline(&query.timeline) {
+        return;
+    }
+
+    let has_markers = tree
+        .components
+        .get(&TimeMarker::name())
+        .map_or(false, |stats| stats.times.has_timeline(&query.timeline));
+    if has_markers {
+        let store = &log_db.entity_db.data_store;
+        let ent_views = re_query::range_entity_with_primary::<TimeMarker, 1>(
+            store,
+            query,
+            &tree.path,
+            [TimeMarker::name()],
+        );
+        for (time, ent_view) in ent_views {
+            let Some(time) = time else { continue }; // Markers are meaningless without a time.
+            for marker in ent_view.iter_primary_flattened() {
+                let max = TimeInt::from(time.as_i64().saturating_add(marker.duration.unwrap_or(0)));
+                markers.push(Marker {
+                    label: marker.label,
+                    range: TimeRange::new(time, max.max(time)),
+                    source: MarkerSource::Logged(tree.path.clone()),
+                });
+            }
+        }
+    }
+
+    for child in tree.children.values() {
+        add_logged_markers(log_db, query, child, markers);
+    }
+}
+
+/// Moves the time to the next (or previous) marker, if any.
+pub fn jump_to_marker(time_ctrl: &mut TimeControl, markers: &[Marker], forward: bool) {
+    let Some(time) = time_ctrl.time() else { return };
+
+    let target = if forward {
+        markers
+            .iter()
+            .find(|marker| TimeReal::from(marker.range.min) > time)
+    } else {
+        markers
+            .iter()
+            .rev()
+            .find(|marker| TimeReal::from(marker.range.min) < time)
+    };
+
+    if let Some(marker) = target {
+        time_ctrl.set_time(marker.range.min);
+        time_ctrl.pause();
+    }
+}
+
+/// Paints the markers as flags on the time axis. Click a flag to move the time there.
+pub fn markers_ui(
+    markers: &[Marker],
+    time_ctrl: &mut TimeControl,
+    time_ranges_ui: &TimeRangesUi,
+    ui: &mut egui::Ui,
+    time_area_painter: &egui::Painter,
+    timeline_rect: &Rect,
+) {
+    let full_y_range = timeline_rect.top()..=time_area_painter.clip_rect().bottom();
+    let flag_size = 8.0;
+
+    for (i, marker) in markers.iter().enumerate() {
+        let Some(min_x) = time_ranges_ui.x_from_time_f32(marker.range.min.into()) else { continue };
+        let max_x = time_ranges_ui
+            .x_from_time_f32(marker.range.max.into())
+            .unwrap_or(min_x);
+
+        if marker.range.min < marker.range.max {
+            let band = Rect::from_x_y_ranges(
+                min_x..=max_x,
+                (timeline_rect.bottom() - 4.0)..=timeline_rect.bottom(),
+            );
+            time_area_painter.rect_filled(band, 0.0, MARKER_COLOR.gamma_multiply(0.5));
+        }
+
+        time_area_painter.vline(
+            min_x,
+            full_y_range.clone(),
+            (1.0, MARKER_COLOR.gamma_multiply(0.3)),
+        );
+
+        let top = timeline_rect.top();
+        time_area_painter.add(egui::Shape::convex_polygon(
+            vec![
+                pos2(min_x, top),
+                pos2(min_x + flag_size, top + 0.5 * flag_size),
+                pos2(min_x, top + flag_size),
+            ],
+            MARKER_COLOR,
+            egui::Stroke::NONE,
+        ));
+
+        let flag_rect = Rect::from_min_max(
+            pos2(min_x - 2.0, top),
+            pos2(min_x + flag_size, top + flag_size),
+        );
+        let response = ui
+            .interact(
+                flag_rect,
+                ui.id().with(("time_marker_flag", i)),
+                egui::Sense::click(),
+            )
+            .on_hover_ui(|ui| marker_tooltip_ui(ui, time_ctrl, marker));
+        if response.hovered() {
+            // Also stops the time panel from moving the time to wherever the pointer is.
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        }
+        if response.clicked() {
+            time_ctrl.set_time(marker.range.min);
+            time_ctrl.pause();
+        }
+    }
+}
+
+fn marker_tooltip_ui(ui: &mut egui::Ui, time_ctrl: &TimeControl, marker: &Marker) {
+    ui.strong(&marker.label);
+    ui.label(format_marker_time(time_ctrl, marker));
+    if let MarkerSource::Logged(ent_path) = &marker.source {
+        ui.weak(format!("Logged to {ent_path}"));
+    }
+}
+
+fn format_marker_time(time_ctrl: &TimeControl, marker: &Marker) -> String {
+    if marker.range.min == marker.range.max {
+        time_ctrl.time_type().format(marker.range.min)
+    } else {
+        time_ctrl.time_type().format_range(marker.range)
+    }
+}
+
+/// A menu listing all the markers on the current timeline, to jump to them and edit bookmarks.
+pub fn bookmarks_button_ui(
+    log_db: &LogDb,
+    bookmarks: &mut Vec<TimeBookmark>,
+    time_ctrl: &mut TimeControl,
+    ui: &mut egui::Ui,
+) {
+    ui.menu_button("🔖", |ui| {
+        let markers = markers_on_timeline(log_db, bookmarks, time_ctrl.timeline());
+
+        if markers.is_empty() {
+            ui.weak("No bookmarks or logged markers on this timeline");
+        }
+
+        let mut remove = None;
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                egui::Grid::new("time_markers")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        for marker in &markers {
+                            let is_current = time_ctrl
+                                .time()
+                                .map_or(false, |time| time == TimeReal::from(marker.range.min));
+                            if ui
+                                .selectable_label(is_current, format_marker_time(time_ctrl, marker))
+                                .clicked()
+                            {
+                                time_ctrl.set_time(marker.range.min);
+                                time_ctrl.pause();
+                            }
+
+                            match &marker.source {
+                                MarkerSource::Bookmark(i) => {
+                                    ui.text_edit_singleline(&mut bookmarks[*i].label);
+                                    if ui.small_button("Remove").clicked() {
+                                        remove = Some(*i);
+                                    }
+                                }
+                                MarkerSource::Logged(ent_path) => {
+                                    ui.label(&marker.label);
+                                    ui.weak(ent_path.to_string());
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        if let Some(i) = remove {
+            bookmarks.remove(i);
+        }
+
+        ui.separator();
+
+        let timeline = *time_ctrl.timeline();
+        if let Some(time) = time_ctrl.time_int() {
+            if ui.button("Bookmark current time").clicked() {
+                bookmarks.push(TimeBookmark {
+                    label: format!("Bookmark {}", bookmarks.len() + 1),
+                    timeline,
+                    range: TimeRange::point(time),
+                });
+            }
+        }
+        if let Some(selection) = time_ctrl.loop_selection() {
+            if ui.button("Bookmark loop selection").clicked() {
+                bookmarks.push(TimeBookmark {
+                    label: format!("Bookmark {}", bookmarks.len() + 1),
+                    timeline,
+                    range: TimeRange::new(selection.min.round(), selection.max.round()),
+                });
+            }
+        }
+    })
+    .response
+    .on_hover_text(format!(
+        "Bookmarks and logged markers.\n\
+        \n\
+        Previous marker{}\n\
+        Next marker{}",
+        re_ui::Command::PlaybackPreviousMarker.format_shortcut_tooltip_suffix(ui.ctx()),
+        re_ui::Command::PlaybackNextMarker.format_shortcut_tooltip_suffix(ui.ctx()),
+    ));
+}
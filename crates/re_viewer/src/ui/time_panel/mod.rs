@@ -1,5 +1,7 @@
+mod bookmarks;
 mod data_density_graph;
 mod paint_ticks;
+mod signal_graph;
 mod time_axis;
 mod time_ranges_ui;
 mod time_selection_ui;
@@ -15,9 +17,12 @@ use crate::{Item, TimeControl, TimeView, ViewerContext};
 
 use super::{data_ui::DataUi, selection_panel::what_is_selected_ui, Blueprint};
 
+use signal_graph::SignalKind;
 use time_axis::TimelineAxis;
 use time_ranges_ui::TimeRangesUi;
 
+pub(crate) use bookmarks::{jump_to_marker, markers_on_timeline, TimeBookmark};
+
 /// A panel that shows entity names to the left, time on the top.
 ///
 /// This includes the timeline controls and streams view.
@@ -26,9 +31,15 @@ use time_ranges_ui::TimeRangesUi;
 pub(crate) struct TimePanel {
     data_dentity_graph_painter: data_density_graph::DataDensityGraphPainter,
 
+    #[serde(skip)]
+    signal_graph_cache: signal_graph::SignalGraphCache,
+
     /// Width of the entity name columns previous frame.
     prev_col_width: f32,
 
+    /// Show the values of scalar and text streams instead of their data density.
+    show_signals: bool,
+
     /// The right side of the entity name column; updated during its painting.
     #[serde(skip)]
     next_col_right: f32,
@@ -42,7 +53,9 @@ impl Default for TimePanel {
     fn default() -> Self {
         Self {
             data_dentity_graph_painter: Default::default(),
+            signal_graph_cache: Default::default(),
             prev_col_width: 400.0,
+            show_signals: false,
             next_col_right: 0.0,
             time_ranges_ui: Default::default(),
         }
@@ -109,7 +122,7 @@ impl TimePanel {
                                 ui.horizontal(|ui| {
                                     ui.spacing_mut().interact_size = Vec2::splat(top_bar_height);
                                     ui.visuals_mut().button_frame = true;
-                                    top_row_ui(ctx, ui);
+                                    top_row_ui(ctx, blueprint, ui);
                                 });
                             })
                             .response
@@ -153,7 +166,11 @@ impl TimePanel {
                 });
                 ui.horizontal(|ui| {
                     let time_ctrl = &mut ctx.rec_cfg.time_ctrl;
-                    time_ctrl.timeline_selector_ui(ctx.log_db.times_per_timeline(), ui);
+                    time_ctrl.timeline_selector_ui(
+                        ctx.log_db.times_per_timeline(),
+                        &mut ctx.app_options.timeline_relations,
+                        ui,
+                    );
                     collapsed_time_marker_and_time(ui, ctx);
                 });
             });
@@ -163,7 +180,11 @@ impl TimePanel {
             let time_ctrl = &mut ctx.rec_cfg.time_ctrl;
             let times_per_timeline = ctx.log_db.times_per_timeline();
             time_ctrl.play_pause_ui(re_ui, times_per_timeline, ui);
-            time_ctrl.timeline_selector_ui(times_per_timeline, ui);
+            time_ctrl.timeline_selector_ui(
+                times_per_timeline,
+                &mut ctx.app_options.timeline_relations,
+                ui,
+            );
             time_ctrl.playback_speed_ui(ui);
             time_ctrl.fps_ui(ui);
 
@@ -180,6 +201,7 @@ impl TimePanel {
         crate::profile_function!();
 
         self.data_dentity_graph_painter.begin_frame(ui.ctx());
+        self.signal_graph_cache.begin_frame();
 
         //               |timeline            |
         // ------------------------------------
@@ -218,14 +240,17 @@ impl TimePanel {
                 ui.set_min_size(size);
                 ui.style_mut().wrap = Some(false);
                 ui.add_space(4.0); // hack to vertically center the text
-                ui.strong("Streams");
-            })
-            .response
-            .on_hover_text(
-                "A hierarchial view of the paths used during logging.\n\
+                ui.horizontal(|ui| {
+                    ui.strong("Streams").on_hover_text(
+                        "A hierarchial view of the paths used during logging.\n\
                         \n\
                         On the right you can see when there was a log event for a stream.",
-            );
+                    );
+                    ui.checkbox(&mut self.show_signals, "Values").on_hover_text(
+                        "Show scalars as a min/max envelope, and text entries as ticks colored by their level, instead of the data density",
+                    );
+                });
+            });
 
             let bottom = ui.min_rect().bottom();
             Rect::from_x_y_ranges(time_fg_x_range.clone(), top..=bottom)
@@ -271,6 +296,19 @@ impl TimePanel {
             &time_bg_area_painter,
             &timeline_rect,
         );
+        let markers = bookmarks::markers_on_timeline(
+            ctx.log_db,
+            &blueprint.time_bookmarks,
+            ctx.rec_cfg.time_ctrl.timeline(),
+        );
+        bookmarks::markers_ui(
+            &markers,
+            &mut ctx.rec_cfg.time_ctrl,
+            &self.time_ranges_ui,
+            ui,
+            &time_area_painter,
+            &timeline_rect,
+        );
         let time_area_response = interact_with_streams_rect(
             &self.time_ranges_ui,
             &mut ctx.rec_cfg.time_ctrl,
@@ -442,6 +480,31 @@ impl TimePanel {
             let row_rect =
                 Rect::from_x_y_ranges(time_area_response.rect.x_range(), response_rect.y_range());
 
+            let signal_kind = SignalKind::for_components(tree.components.keys())
+                .filter(|_| self.show_signals)
+                .filter(|_| {
+                    signal_graph::is_within_budget(
+                        num_messages_at_time,
+                        &self.time_ranges_ui,
+                        row_rect,
+                    )
+                });
+            if let Some(kind) = signal_kind {
+                signal_graph::signal_graph_ui(
+                    &mut self.signal_graph_cache,
+                    ctx,
+                    time_area_response,
+                    time_area_painter,
+                    ui,
+                    kind,
+                    &tree.path,
+                    row_rect,
+                    &self.time_ranges_ui,
+                    item,
+                );
+                return;
+            }
+
             data_density_graph::data_density_graph_ui(
                 &mut self.data_dentity_graph_painter,
                 ctx,
@@ -546,6 +609,31 @@ impl TimePanel {
                         response_rect.y_range(),
                     );
 
+                    let signal_kind = SignalKind::for_component(component_name)
+                        .filter(|_| self.show_signals)
+                        .filter(|_| {
+                            signal_graph::is_within_budget(
+                                messages_over_time,
+                                &self.time_ranges_ui,
+                                row_rect,
+                            )
+                        });
+                    if let Some(kind) = signal_kind {
+                        signal_graph::signal_graph_ui(
+                            &mut self.signal_graph_cache,
+                            ctx,
+                            time_area_response,
+                            time_area_painter,
+                            ui,
+                            kind,
+                            &tree.path,
+                            row_rect,
+                            &self.time_ranges_ui,
+                            item,
+                        );
+                        continue;
+                    }
+
                     data_density_graph::data_density_graph_ui(
                         &mut self.data_dentity_graph_painter,
                         ctx,
@@ -625,7 +713,7 @@ fn paint_streams_guide_line(
     );
 }
 
-fn top_row_ui(ctx: &mut ViewerContext<'_>, ui: &mut egui::Ui) {
+fn top_row_ui(ctx: &mut ViewerContext<'_>, blueprint: &mut Blueprint, ui: &mut egui::Ui) {
     ui.spacing_mut().item_spacing.x = 18.0; // from figma
 
     if ui.max_rect().width() < 600.0 {
@@ -641,7 +729,17 @@ fn top_row_ui(ctx: &mut ViewerContext<'_>, ui: &mut egui::Ui) {
             });
             ui.horizontal(|ui| {
                 let time_ctrl = &mut ctx.rec_cfg.time_ctrl;
-                time_ctrl.timeline_selector_ui(ctx.log_db.times_per_timeline(), ui);
+                time_ctrl.timeline_selector_ui(
+                    ctx.log_db.times_per_timeline(),
+                    &mut ctx.app_options.timeline_relations,
+                    ui,
+                );
+                bookmarks::bookmarks_button_ui(
+                    ctx.log_db,
+                    &mut blueprint.time_bookmarks,
+                    &mut ctx.rec_cfg.time_ctrl,
+                    ui,
+                );
 
                 current_time_ui(ctx, ui);
 
@@ -657,7 +755,12 @@ fn top_row_ui(ctx: &mut ViewerContext<'_>, ui: &mut egui::Ui) {
         let times_per_timeline = ctx.log_db.times_per_timeline();
 
         time_ctrl.play_pause_ui(re_ui, times_per_timeline, ui);
-        time_ctrl.timeline_selector_ui(times_per_timeline, ui);
+        time_ctrl.timeline_selector_ui(
+            times_per_timeline,
+            &mut ctx.app_options.timeline_relations,
+            ui,
+        );
+        bookmarks::bookmarks_button_ui(ctx.log_db, &mut blueprint.time_bookmarks, time_ctrl, ui);
         time_ctrl.playback_speed_ui(ui);
         time_ctrl.fps_ui(ui);
         current_time_ui(ctx, ui);
@@ -0,0 +1,485 @@
+//! Show the actual values of a data stream over time, instead of just its data density.
+//!
+//! Scalars are shown as a min/max envelope, with one bucket per ui point.
+//! Text entries are shown as ticks, colored by their log level.
+//!
+//! The values are cached in a [`SignalGraphCache`], so they are only queried again when
+//! the data or the view changes.
+
+use egui::{epaint::Vertex, pos2, remap, Color32, NumExt as _, Rect, Shape};
+
+use re_arrow_store::RangeQuery;
+use re_data_store::{EntityPath, TimeHistogram};
+use re_log_types::{
+    component_types::{Scalar, TextEntry},
+    Component as _, ComponentName, EntityPathHash, TimeInt, TimeReal, Timeline,
+};
+use re_query::range_entity_with_primary;
+
+use crate::{
+    misc::{Item, ViewerContext},
+    ui::view_text::{level_color, level_to_rich_text},
+};
+
+use super::time_ranges_ui::TimeRangesUi;
+
+/// How many text entries we show at most in a tooltip.
+const MAX_TOOLTIP_TEXT_ENTRIES: usize = 8;
+
+/// Above this many messages in view we show the data density instead of the values,
+/// as all the values need to be queried again whenever the view changes.
+const MAX_MESSAGES_IN_VIEW: u64 = 100_000;
+
+/// The kinds of data streams we can show the values of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignalKind {
+    Scalar,
+    TextEntry,
+}
+
+impl SignalKind {
+    pub fn for_component(component: &ComponentName) -> Option<Self> {
+        if *component == Scalar::name() {
+            Some(Self::Scalar)
+        } else if *component == TextEntry::name() {
+            Some(Self::TextEntry)
+        } else {
+            None
+        }
+    }
+
+    /// The signal to show for an entity with these components, if any.
+    pub fn for_components<'a>(components: impl Iterator<Item = &'a ComponentName>) -> Option<Self> {
+        components.filter_map(Self::for_component).min()
+    }
+}
+
+/// Can we afford to show the values of a row with these messages, or should we show the data density?
+pub fn is_within_budget(
+    messages_over_time: &TimeHistogram,
+    time_ranges_ui: &TimeRangesUi,
+    row_rect: Rect,
+) -> bool {
+    let time_range = time_ranges_ui.time_range_from_x_range(row_rect.x_range());
+    messages_over_time.range_count(time_range.min.as_i64()..=time_range.max.as_i64())
+        <= MAX_MESSAGES_IN_VIEW
+}
+
+// ----------------------------------------------------------------------------
+
+/// Everything the cached values of a signal depend on.
+#[derive(Clone, Copy, PartialEq)]
+struct SignalKey {
+    store_generation: u64,
+    timeline: Timeline,
+
+    /// `None` when viewing all of the time.
+    time_view: Option<(TimeReal, f64)>,
+
+    x_range: (f32, f32),
+}
+
+enum SignalValues {
+    Scalar(Vec<Option<Bucket>>),
+    TextEntry(Vec<Tick>),
+}
+
+struct CachedSignal {
+    key: Option<SignalKey>,
+    values: SignalValues,
+    used_this_frame: bool,
+}
+
+/// The values of the signals shown in the previous frames.
+#[derive(Default)]
+pub struct SignalGraphCache {
+    signals: ahash::HashMap<(EntityPathHash, SignalKind), CachedSignal>,
+}
+
+impl SignalGraphCache {
+    /// Forgets the signals that weren't shown last frame.
+    pub fn begin_frame(&mut self) {
+        self.signals
+            .retain(|_, signal| std::mem::take(&mut signal.used_this_frame));
+    }
+
+    fn values(
+        &mut self,
+        ent_path: &EntityPath,
+        kind: SignalKind,
+        key: SignalKey,
+        compute: impl FnOnce() -> SignalValues,
+    ) -> &SignalValues {
+        let signal = self
+            .signals
+            .entry((ent_path.hash(), kind))
+            .or_insert_with(|| CachedSignal {
+                key: None,
+                values: SignalValues::TextEntry(Vec::new()),
+                used_this_frame: false,
+            });
+        if signal.key != Some(key) {
+            signal.values = compute();
+            signal.key = Some(key);
+        }
+        signal.used_this_frame = true;
+        &signal.values
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paints the values of the given entity over the visible time range of the row.
+///
+/// Hovering shows the values, clicking moves the time there.
+#[allow(clippy::too_many_arguments)]
+pub fn signal_graph_ui(
+    cache: &mut SignalGraphCache,
+    ctx: &mut ViewerContext<'_>,
+    time_area_response: &egui::Response,
+    time_area_painter: &egui::Painter,
+    ui: &mut egui::Ui,
+    kind: SignalKind,
+    ent_path: &EntityPath,
+    row_rect: Rect,
+    time_ranges_ui: &TimeRangesUi,
+    item: Item,
+) {
+    crate::profile_function!();
+
+    let timeline = *ctx.rec_cfg.time_ctrl.timeline();
+    let query = RangeQuery::new(
+        timeline,
+        time_ranges_ui.time_range_from_x_range(row_rect.x_range()),
+    );
+    let color = super::data_density_graph::graph_color(ctx, &item, ui);
+
+    let store = &ctx.log_db.entity_db.data_store;
+    let key = SignalKey {
+        store_generation: store.generation(),
+        timeline,
+        time_view: ctx
+            .rec_cfg
+            .time_ctrl
+            .time_view()
+            .map(|time_view| (time_view.min, time_view.time_spanned)),
+        x_range: (row_rect.left(), row_rect.right()),
+    };
+    let values = cache.values(ent_path, kind, key, || match kind {
+        SignalKind::Scalar => SignalValues::Scalar(scalar_buckets(
+            store,
+            &query,
+            ent_path,
+            row_rect,
+            time_ranges_ui,
+        )),
+        SignalKind::TextEntry => SignalValues::TextEntry(text_entry_ticks(
+            store,
+            &query,
+            ent_path,
+            row_rect,
+            time_ranges_ui,
+        )),
+    });
+
+    let hovered_time = match values {
+        SignalValues::Scalar(buckets) => {
+            scalar_envelope_ui(time_area_painter, ui, &query, buckets, row_rect, color)
+        }
+        SignalValues::TextEntry(ticks) => {
+            text_entry_ticks_ui(time_area_painter, ui, &query, ticks, row_rect, color)
+        }
+    };
+
+    if let Some(time) = hovered_time {
+        ctx.rec_cfg
+            .selection_state
+            .set_hovered(std::iter::once(item.clone()));
+
+        if time_area_response.clicked_by(egui::PointerButton::Primary) {
+            ctx.set_single_selection(item);
+            ctx.rec_cfg.time_ctrl.set_time(time);
+            ctx.rec_cfg.time_ctrl.pause();
+        }
+    }
+}
+
+/// Where the pointer is within the row, unless something else is going on.
+fn hover_pos(ui: &egui::Ui, row_rect: Rect) -> Option<egui::Pos2> {
+    if ui.ctx().memory(|mem| mem.is_anything_being_dragged()) {
+        return None;
+    }
+    ui.input(|i| i.pointer.hover_pos())
+        .filter(|pos| row_rect.contains(*pos))
+}
+
+// ----------------------------------------------------------------------------
+
+/// The values that fell within a single ui point.
+#[derive(Clone, Copy)]
+struct Bucket {
+    min: f64,
+    max: f64,
+    count: usize,
+
+    /// The time of the first value.
+    time: TimeInt,
+}
+
+/// The scalars within the row, bucketed per ui point.
+fn scalar_buckets(
+    store: &re_arrow_store::DataStore,
+    query: &RangeQuery,
+    ent_path: &EntityPath,
+    row_rect: Rect,
+    time_ranges_ui: &TimeRangesUi,
+) -> Vec<Option<Bucket>> {
+    crate::profile_function!();
+
+    let num_buckets = row_rect.width().ceil().at_least(1.0) as usize;
+    let mut buckets: Vec<Option<Bucket>> = vec![None; num_buckets];
+
+    for (time, ent_view) in
+        range_entity_with_primary::<Scalar, 1>(store, query, ent_path, [Scalar::name()])
+    {
+        let Some(time) = time else { continue }; // Timeless scalars are not a signal over time.
+        let Some(x) = time_ranges_ui.x_from_time_f32(time.into()) else { continue };
+        let Some(bucket) = usize::try_from((x - row_rect.left()).floor() as i64)
+            .ok()
+            .and_then(|i| buckets.get_mut(i)) else { continue };
+
+        for Scalar(value) in ent_view.iter_primary_flattened() {
+            if !value.is_finite() {
+                continue;
+            }
+            let bucket = bucket.get_or_insert(Bucket {
+                min: value,
+                max: value,
+                count: 0,
+                time,
+            });
+            bucket.min = bucket.min.min(value);
+            bucket.max = bucket.max.max(value);
+            bucket.count += 1;
+        }
+    }
+
+    buckets
+}
+
+/// Returns the hovered time, if any.
+fn scalar_envelope_ui(
+    painter: &egui::Painter,
+    ui: &egui::Ui,
+    query: &RangeQuery,
+    buckets: &[Option<Bucket>],
+    row_rect: Rect,
+    color: Color32,
+) -> Option<TimeInt> {
+    crate::profile_function!();
+
+    // Normalize to the values that are visible:
+    let (min_value, max_value) = buckets
+        .iter()
+        .flatten()
+        .fold((f64::INFINITY, f64::NEGATIVE_INFINITY), |(min, max), b| {
+            (min.min(b.min), max.max(b.max))
+        });
+    if min_value > max_value {
+        return None; // Nothing visible.
+    }
+
+    let padding = 2.0;
+    let y_range = (row_rect.bottom() - padding)..=(row_rect.top() + padding);
+    let y_from_value = |value: f64| {
+        if min_value == max_value {
+            row_rect.center().y
+        } else {
+            remap(
+                value as f32,
+                min_value as f32..=max_value as f32,
+                y_range.clone(),
+            )
+        }
+    };
+
+    // One quad per bucket, connected to the previous bucket if there is one:
+    let uv = egui::Pos2::ZERO;
+    let min_thickness = 1.0;
+    let mut mesh = egui::Mesh::default();
+    let mut prev_right_vidx = None;
+    for (i, bucket) in buckets.iter().enumerate() {
+        let Some(bucket) = bucket else {
+            prev_right_vidx = None;
+            continue;
+        };
+
+        let left = row_rect.left() + i as f32;
+        let mut top = y_from_value(bucket.max);
+        let mut bottom = y_from_value(bucket.min);
+        if bottom - top < min_thickness {
+            let center = 0.5 * (top + bottom);
+            top = center - 0.5 * min_thickness;
+            bottom = center + 0.5 * min_thickness;
+        }
+
+        let vidx = mesh.vertices.len() as u32;
+        for pos in [
+            pos2(left, top),
+            pos2(left, bottom),
+            pos2(left + 1.0, top),
+            pos2(left + 1.0, bottom),
+        ] {
+            mesh.vertices.push(Vertex { pos, uv, color });
+        }
+        mesh.add_triangle(vidx, vidx + 1, vidx + 2);
+        mesh.add_triangle(vidx + 1, vidx + 2, vidx + 3);
+        if let Some(prev) = prev_right_vidx {
+            mesh.add_triangle(prev, prev + 1, vidx);
+            mesh.add_triangle(prev + 1, vidx, vidx + 1);
+        }
+        prev_right_vidx = Some(vidx + 2);
+    }
+    painter.add(Shape::Mesh(mesh));
+
+    // Hover the closest bucket near the pointer:
+    let pointer_pos = hover_pos(ui, row_rect)?;
+    let interact_radius = ui.style().interaction.resize_grab_radius_side;
+    let pointer_i = (pointer_pos.x - row_rect.left()).floor() as i64;
+    let (i, bucket) = (-(interact_radius as i64)..=(interact_radius as i64))
+        .filter_map(|offset| {
+            let i = usize::try_from(pointer_i + offset).ok()?;
+            let bucket = (*buckets.get(i)?)?;
+            Some((i, bucket))
+        })
+        .min_by_key(|(i, _)| (*i as i64 - pointer_i).abs())?;
+
+    let x = row_rect.left() + i as f32 + 0.5;
+    painter.vline(
+        x,
+        row_rect.y_range(),
+        ui.visuals().widgets.hovered.fg_stroke,
+    );
+
+    let time_type = query.timeline.typ();
+    egui::show_tooltip_at_pointer(ui.ctx(), egui::Id::new("signal_tooltip"), |ui| {
+        ui.label(time_type.format(bucket.time));
+        if bucket.min == bucket.max {
+            ui.monospace(re_format::format_f64(bucket.min));
+        } else {
+            ui.monospace(format!(
+                "{} … {}",
+                re_format::format_f64(bucket.min),
+                re_format::format_f64(bucket.max)
+            ));
+        }
+        if bucket.count > 1 {
+            ui.weak(format!("{} values", re_format::format_number(bucket.count)));
+        }
+    });
+
+    Some(bucket.time)
+}
+
+// ----------------------------------------------------------------------------
+
+struct Tick {
+    x: f32,
+    time: TimeInt,
+    level: Option<String>,
+    body: String,
+}
+
+/// The text entries within the row, with the most severe ones last.
+fn text_entry_ticks(
+    store: &re_arrow_store::DataStore,
+    query: &RangeQuery,
+    ent_path: &EntityPath,
+    row_rect: Rect,
+    time_ranges_ui: &TimeRangesUi,
+) -> Vec<Tick> {
+    crate::profile_function!();
+
+    let mut ticks = Vec::new();
+    for (time, ent_view) in
+        range_entity_with_primary::<TextEntry, 1>(store, query, ent_path, [TextEntry::name()])
+    {
+        let Some(time) = time else { continue };
+        let Some(x) = time_ranges_ui.x_from_time_f32(time.into()) else { continue };
+        if !row_rect.x_range().contains(&x) {
+            continue;
+        }
+        for entry in ent_view.iter_primary_flattened() {
+            ticks.push(Tick {
+                x,
+                time,
+                level: entry.level,
+                body: entry.body,
+            });
+        }
+    }
+
+    // Paint the most severe entries last, so they are on top:
+    ticks.sort_by_key(|tick| tick.level.as_deref().and_then(TextEntry::severity));
+    ticks
+}
+
+/// Returns the hovered time, if any.
+fn text_entry_ticks_ui(
+    painter: &egui::Painter,
+    ui: &egui::Ui,
+    query: &RangeQuery,
+    ticks: &[Tick],
+    row_rect: Rect,
+    color: Color32,
+) -> Option<TimeInt> {
+    crate::profile_function!();
+
+    let half_height = 0.35 * row_rect.height();
+    let y_range = (row_rect.center().y - half_height)..=(row_rect.center().y + half_height);
+    for tick in &ticks {
+        let color = tick
+            .level
+            .as_deref()
+            .map_or(color, |level| level_color(ui, level));
+        painter.vline(tick.x, y_range.clone(), (1.5, color));
+    }
+
+    // Hover all the ticks near the pointer:
+    let pointer_pos = hover_pos(ui, row_rect)?;
+    let interact_radius = ui.style().interaction.resize_grab_radius_side;
+    let mut hovered = ticks
+        .iter()
+        .filter(|tick| (tick.x - pointer_pos.x).abs() <= interact_radius)
+        .collect::<Vec<_>>();
+    if hovered.is_empty() {
+        return None;
+    }
+    hovered.sort_by_key(|tick| tick.time);
+
+    egui::show_tooltip_at_pointer(ui.ctx(), egui::Id::new("signal_tooltip"), |ui| {
+        let time_type = query.timeline.typ();
+        egui::Grid::new("text_entry_ticks")
+            .num_columns(3)
+            .show(ui, |ui| {
+                for tick in hovered.iter().take(MAX_TOOLTIP_TEXT_ENTRIES) {
+                    ui.label(time_type.format(tick.time));
+                    if let Some(level) = &tick.level {
+                        ui.label(level_to_rich_text(ui, level));
+                    } else {
+                        ui.label("");
+                    }
+                    ui.label(&tick.body);
+                    ui.end_row();
+                }
+            });
+        if hovered.len() > MAX_TOOLTIP_TEXT_ENTRIES {
+            ui.weak(format!(
+                "…and {} more",
+                hovered.len() - MAX_TOOLTIP_TEXT_ENTRIES
+            ));
+        }
+    });
+
+    hovered.first().map(|tick| tick.time)
+}
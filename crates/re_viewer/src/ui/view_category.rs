@@ -30,6 +30,9 @@ pub enum ViewCategory {
 
     /// High-dimensional tensor view
     Tensor,
+
+    /// A [`crate::ui::SpaceViewClass`] registered by the app, see [`crate::ui::SpaceView::custom_class`].
+    Custom,
 }
 
 impl ViewCategory {
@@ -40,6 +43,7 @@ impl ViewCategory {
             ViewCategory::BarChart => &re_ui::icons::SPACE_VIEW_HISTOGRAM,
             ViewCategory::Spatial => &re_ui::icons::SPACE_VIEW_3D,
             ViewCategory::Tensor => &re_ui::icons::SPACE_VIEW_TENSOR,
+            ViewCategory::Custom => &re_ui::icons::SPACE_VIEW_RAW,
         }
     }
 }
@@ -52,6 +56,7 @@ impl std::fmt::Display for ViewCategory {
             ViewCategory::BarChart => "Bar Chart",
             ViewCategory::Spatial => "Spatial",
             ViewCategory::Tensor => "Tensor",
+            ViewCategory::Custom => "Custom",
         })
     }
 }
@@ -60,6 +65,8 @@ pub type ViewCategorySet = enumset::EnumSet<ViewCategory>;
 
 // TODO(cmc): these `categorize_*` functions below are pretty dangerous: make sure you've covered
 // all possible `ViewCategory` values, or you're in for a bad time..!
+// `ViewCategory::Custom` is the exception: which entities custom space views show is up to
+// their `SpaceViewClass`, see `SpaceViewClassRegistry::can_show_entity`.
 
 pub fn categorize_entity_path(
     timeline: Timeline,
@@ -0,0 +1,434 @@
+//! Tools for drawing new points, boxes and polygons in the 2D view, e.g. to label or correct images.
+//!
+//! Each finished shape is logged into the current recording as a new child of a target entity,
+//! together with the selected [`ClassId`], so it shows up like any other logged data and
+//! is part of the recording when saving it.
+//! Shapes are drawn in the space of the view and moved into the frame of the target entity before logging.
+
+use egui::{Color32, Pos2, Rect, Shape, Stroke};
+use re_data_store::{query_latest_single, EntityPath, EntityPropertyMap};
+use re_log_types::{
+    component_types::{ClassId, LineStrip2D, Point2D, Rect2D},
+    AnnotationContext, DataRow, EntityPathPart, Index, MsgId, TimePoint,
+};
+
+use crate::{
+    misc::{TransformCache, ViewerContext},
+    ui::annotations::{DefaultColor, ResolvedClassDescription},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum AnnotationTool {
+    Point,
+    Box,
+    Polygon,
+}
+
+impl std::fmt::Display for AnnotationTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Point => "Point".fmt(f),
+            Self::Box => "Box".fmt(f),
+            Self::Polygon => "Polygon".fmt(f),
+        }
+    }
+}
+
+/// A shape that is currently being drawn, in space coordinates.
+#[derive(Clone, Debug)]
+enum ShapeInProgress {
+    Box { start: Pos2, end: Pos2 },
+    Polygon { vertices: Vec<Pos2> },
+}
+
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AnnotationToolsState {
+    /// The active tool. If `None`, the view is navigated as usual.
+    tool: Option<AnnotationTool>,
+
+    /// New shapes are logged as children of this entity.
+    ///
+    /// `None` means `annotations` below the space root.
+    target_path: Option<EntityPath>,
+
+    /// Class the new shapes are assigned to.
+    class_id: u16,
+
+    #[serde(skip)]
+    in_progress: Option<ShapeInProgress>,
+}
+
+impl AnnotationToolsState {
+    pub fn is_active(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    pub fn deactivate(&mut self) {
+        self.tool = None;
+        self.in_progress = None;
+    }
+
+    fn target_path(&self, space: &EntityPath) -> EntityPath {
+        self.target_path.clone().unwrap_or_else(|| {
+            let mut parts = space.as_slice().to_vec();
+            parts.push(EntityPathPart::from("annotations"));
+            parts.into()
+        })
+    }
+
+    /// Tool selection & settings, shown in the selection panel of the space view.
+    pub fn settings_ui(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        space: &EntityPath,
+    ) {
+        ui.horizontal(|ui| {
+            let mut tool = self.tool;
+            ui.selectable_value(&mut tool, None, "Off")
+                .on_hover_text("Navigate the view as usual.");
+            ui.selectable_value(&mut tool, Some(AnnotationTool::Point), "Point")
+                .on_hover_text("Click to add a point.");
+            ui.selectable_value(&mut tool, Some(AnnotationTool::Box), "Box")
+                .on_hover_text("Drag to add a box.");
+            ui.selectable_value(&mut tool, Some(AnnotationTool::Polygon), "Polygon")
+                .on_hover_text(
+                    "Click to add vertices, double-click or press Enter to close the polygon.",
+                );
+            if tool != self.tool {
+                self.tool = tool;
+                self.in_progress = None;
+            }
+        });
+
+        if !self.is_active() {
+            return;
+        }
+
+        let target_path = self.target_path(space);
+        ui.horizontal(|ui| {
+            ui.label("Log to");
+            let mut path_string = target_path.to_string();
+            if ui
+                .text_edit_singleline(&mut path_string)
+                .on_hover_text("Every new shape is logged as a new child of this entity.")
+                .changed()
+            {
+                self.target_path = Some(EntityPath::from(path_string.trim()));
+            }
+        });
+
+        let context = annotation_context(ctx, &target_path);
+        ui.horizontal(|ui| {
+            ui.label("Class");
+            let selected_text = class_label(context.as_ref(), self.class_id);
+            egui::ComboBox::from_id_source("annotation_class")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.style_mut().wrap = Some(false);
+                    let mut class_ids = context
+                        .iter()
+                        .flat_map(|context| context.class_map.keys())
+                        .map(|class_id| class_id.0)
+                        .collect::<Vec<_>>();
+                    class_ids.sort_unstable();
+                    for class_id in class_ids {
+                        ui.selectable_value(
+                            &mut self.class_id,
+                            class_id,
+                            class_label(context.as_ref(), class_id),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text("The classes of the annotation context of the target entity.");
+            ui.add(egui::DragValue::new(&mut self.class_id))
+                .on_hover_text("Class id of new shapes.");
+        });
+    }
+
+    /// Handles the input of the active tool and paints the shape that is currently being drawn.
+    ///
+    /// Returns the shapes to paint on top of the view.
+    pub fn interact(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        response: &egui::Response,
+        space: &EntityPath,
+        ui_from_space: egui::emath::RectTransform,
+    ) -> Vec<Shape> {
+        let Some(tool) = self.tool else {
+            return Vec::new();
+        };
+        let space_from_ui = ui_from_space.inverse();
+        let pointer_in_space = response
+            .interact_pointer_pos()
+            .or_else(|| response.hover_pos())
+            .map(|pos| space_from_ui.transform_pos(pos));
+
+        if response.hovered() {
+            response.ctx.set_cursor_icon(egui::CursorIcon::Crosshair);
+        }
+        if response.ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.in_progress = None;
+        }
+
+        match tool {
+            AnnotationTool::Point => {
+                if let (true, Some(pos)) = (response.clicked(), pointer_in_space) {
+                    self.log_shape(ctx, space, NewShape::Point(pos));
+                }
+            }
+            AnnotationTool::Box => {
+                if response.drag_started_by(egui::PointerButton::Primary) {
+                    // The drag only starts once the pointer moved a bit, start where it was pressed.
+                    let press_origin = response.ctx.input(|i| i.pointer.press_origin());
+                    if let Some(start) = press_origin.map(|pos| space_from_ui.transform_pos(pos)) {
+                        self.in_progress = Some(ShapeInProgress::Box { start, end: start });
+                    }
+                }
+                if let (Some(ShapeInProgress::Box { end, .. }), Some(pos)) =
+                    (&mut self.in_progress, pointer_in_space)
+                {
+                    *end = pos;
+                }
+                if response.drag_released() {
+                    if let Some(ShapeInProgress::Box { start, end }) = self.in_progress.take() {
+                        let rect = Rect::from_two_pos(start, end);
+                        if rect.is_positive() {
+                            self.log_shape(ctx, space, NewShape::Box(rect));
+                        }
+                    }
+                }
+            }
+            AnnotationTool::Polygon => {
+                let double_clicked = response.double_clicked();
+                let close =
+                    double_clicked || response.ctx.input(|i| i.key_pressed(egui::Key::Enter));
+                if close {
+                    if let Some(ShapeInProgress::Polygon { mut vertices }) = self.in_progress.take()
+                    {
+                        if double_clicked {
+                            // The first click of the double-click already added a vertex.
+                            vertices.pop();
+                        }
+                        if vertices.len() >= 3 {
+                            self.log_shape(ctx, space, NewShape::Polygon(vertices));
+                        }
+                    }
+                } else if let (true, Some(pos)) = (response.clicked(), pointer_in_space) {
+                    match &mut self.in_progress {
+                        Some(ShapeInProgress::Polygon { vertices }) => vertices.push(pos),
+                        _ => {
+                            self.in_progress = Some(ShapeInProgress::Polygon {
+                                vertices: vec![pos],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let Some(in_progress) = &self.in_progress else {
+            return Vec::new();
+        };
+        let target_path = self.target_path(space);
+        let context = annotation_context(ctx, &target_path);
+        let class_description = ResolvedClassDescription(
+            context
+                .as_ref()
+                .and_then(|context| context.class_map.get(&ClassId(self.class_id))),
+        );
+        let color = class_description
+            .annotation_info()
+            .color(None, DefaultColor::EntityPath(&target_path));
+        let stroke = Stroke::new(2.0, color);
+
+        match in_progress {
+            ShapeInProgress::Box { start, end } => vec![Shape::rect_stroke(
+                ui_from_space.transform_rect(Rect::from_two_pos(*start, *end)),
+                0.0,
+                stroke,
+            )],
+            ShapeInProgress::Polygon { vertices } => {
+                let mut points = vertices
+                    .iter()
+                    .map(|vertex| ui_from_space.transform_pos(*vertex))
+                    .collect::<Vec<_>>();
+                let mut shapes = points
+                    .iter()
+                    .map(|point| Shape::circle_filled(*point, 3.0, color))
+                    .collect::<Vec<_>>();
+                if let Some(hover_pos) = response.hover_pos() {
+                    points.push(hover_pos);
+                }
+                shapes.push(Shape::line(points, stroke));
+                shapes.push(Shape::circle_stroke(
+                    ui_from_space.transform_pos(vertices[0]),
+                    5.0,
+                    Stroke::new(1.0, Color32::WHITE),
+                ));
+                shapes
+            }
+        }
+    }
+
+    /// Logs a finished shape as a new child of the target entity, at the current time.
+    fn log_shape(&mut self, ctx: &mut ViewerContext<'_>, space: &EntityPath, shape: NewShape) {
+        let target_path = self.target_path(space);
+        let Some(target_from_space) = entity_from_space(ctx, space, &target_path) else {
+            re_log::warn!("Can't add an annotation to {target_path}: it can't be reached from {space}");
+            return;
+        };
+        let to_target = |pos: Pos2| {
+            let pos = target_from_space.project_point3(glam::vec3(pos.x, pos.y, 0.0));
+            egui::pos2(pos.x, pos.y)
+        };
+
+        let tree = &ctx.log_db.entity_db.tree;
+        let mut index = 0;
+        let entity_path = loop {
+            let mut parts = target_path.as_slice().to_vec();
+            parts.push(EntityPathPart::Index(Index::Sequence(index)));
+            let entity_path = EntityPath::from(parts);
+            if tree.subtree(&entity_path).is_none() {
+                break entity_path;
+            }
+            index += 1;
+        };
+
+        let time_ctrl = &ctx.rec_cfg.time_ctrl;
+        let timepoint = time_ctrl
+            .time_int()
+            .map_or_else(TimePoint::timeless, |time| {
+                TimePoint::from([(*time_ctrl.timeline(), time)])
+            });
+        let class_id = [ClassId(self.class_id)];
+
+        re_log::debug!("Adding annotation at {entity_path}");
+        let row = match shape {
+            NewShape::Point(pos) => {
+                let pos = to_target(pos);
+                DataRow::from_cells2(
+                    MsgId::random(),
+                    entity_path,
+                    timepoint,
+                    1,
+                    (&[Point2D::from([pos.x, pos.y])][..], &class_id[..]),
+                )
+            }
+            NewShape::Box(rect) => {
+                // Rects are axis aligned, so a rotated target gets the bounding rect of the drawn box.
+                let rect = Rect::from_points(&[
+                    to_target(rect.left_top()),
+                    to_target(rect.right_top()),
+                    to_target(rect.left_bottom()),
+                    to_target(rect.right_bottom()),
+                ]);
+                DataRow::from_cells2(
+                    MsgId::random(),
+                    entity_path,
+                    timepoint,
+                    1,
+                    (
+                        &[Rect2D::from_xywh(
+                            rect.min.x,
+                            rect.min.y,
+                            rect.width(),
+                            rect.height(),
+                        )][..],
+                        &class_id[..],
+                    ),
+                )
+            }
+            NewShape::Polygon(vertices) => {
+                // Repeat the first vertex to close the line strip.
+                let line_strip = LineStrip2D::from(
+                    vertices
+                        .iter()
+                        .chain(vertices.first())
+                        .map(|vertex| {
+                            let vertex = to_target(*vertex);
+                            [vertex.x, vertex.y]
+                        })
+                        .collect::<Vec<_>>(),
+                );
+                DataRow::from_cells2(
+                    MsgId::random(),
+                    entity_path,
+                    timepoint,
+                    1,
+                    (&[line_strip][..], &class_id[..]),
+                )
+            }
+        };
+        ctx.log_row(row);
+    }
+}
+
+enum NewShape {
+    Point(Pos2),
+    Box(Rect),
+    Polygon(Vec<Pos2>),
+}
+
+/// Transform from the space of the view into the frame of the given entity.
+///
+/// The entity doesn't need to exist yet, in which case it shares the frame of its closest existing ancestor.
+/// Returns `None` if the entity can't be reached from the space.
+fn entity_from_space(
+    ctx: &ViewerContext<'_>,
+    space: &EntityPath,
+    entity_path: &EntityPath,
+) -> Option<glam::Mat4> {
+    let transforms = TransformCache::determine_transforms(
+        &ctx.log_db.entity_db,
+        &ctx.rec_cfg.time_ctrl,
+        space,
+        &EntityPropertyMap::default(),
+    );
+
+    let tree = &ctx.log_db.entity_db.tree;
+    let mut path = entity_path.clone();
+    loop {
+        if let Some(space_from_entity) = transforms.reference_from_entity(&path) {
+            return Some(space_from_entity.inverse());
+        }
+        if tree.subtree(&path).is_some() {
+            return None; // Exists, but is unreachable, e.g. behind a pinhole.
+        }
+        path = path.parent()?;
+    }
+}
+
+/// The annotation context that applies to the given entity, including the ones edited in the viewer.
+fn annotation_context(
+    ctx: &ViewerContext<'_>,
+    entity_path: &EntityPath,
+) -> Option<AnnotationContext> {
+    let query = ctx.current_query();
+    let mut next_parent = Some(entity_path.clone());
+    while let Some(parent) = next_parent {
+        if let Some(context) = ctx.rec_cfg.annotation_overrides.get(&parent) {
+            return Some(context.clone());
+        }
+        if let Some(context) =
+            query_latest_single::<AnnotationContext>(&ctx.log_db.entity_db, &parent, &query)
+        {
+            return Some(context);
+        }
+        next_parent = parent.parent();
+    }
+    None
+}
+
+fn class_label(context: Option<&AnnotationContext>, class_id: u16) -> String {
+    context
+        .and_then(|context| context.class_map.get(&ClassId(class_id)))
+        .and_then(|class| class.info.label.as_ref())
+        .map_or_else(
+            || class_id.to_string(),
+            |label| format!("{class_id}: {}", label.0),
+        )
+}
@@ -0,0 +1,424 @@
+//! Measuring distances, angles and areas in the spatial views.
+//!
+//! Measurements are made by clicking points in the view: in 2D at the cursor, in 3D at the picked surface.
+//! Finished measurements stay in the view and are selectable via [`Item::Measurement`].
+
+use egui::{Align2, Color32, Pos2, Rect, Shape, Stroke};
+use re_log_types::ViewCoordinates;
+
+use crate::{
+    misc::{HoverHighlight, Item, ViewerContext},
+    ui::SpaceViewId,
+};
+
+/// Identifies a measurement within its space view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct MeasurementId(u64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum MeasurementKind {
+    /// Distance between two points.
+    Distance,
+
+    /// Angle at the second of three points.
+    Angle,
+
+    /// Area of a polygon with three or more points.
+    Area,
+}
+
+impl MeasurementKind {
+    /// Number of points after which a measurement is complete, `None` if closed explicitly.
+    fn num_points(&self) -> Option<usize> {
+        match self {
+            Self::Distance => Some(2),
+            Self::Angle => Some(3),
+            Self::Area => None,
+        }
+    }
+}
+
+impl std::fmt::Display for MeasurementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Distance => "Distance".fmt(f),
+            Self::Angle => "Angle".fmt(f),
+            Self::Area => "Area".fmt(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub id: MeasurementId,
+    pub kind: MeasurementKind,
+
+    /// Points in the coordinates of the space, z is zero in 2D views.
+    pub points: Vec<glam::Vec3>,
+}
+
+impl Measurement {
+    /// Distance in scene units, angle in radians or area in square scene units.
+    pub fn value(&self) -> f32 {
+        match (self.kind, self.points.as_slice()) {
+            (MeasurementKind::Distance, [a, b]) => a.distance(*b),
+            (MeasurementKind::Angle, [a, vertex, b]) => (*a - *vertex).angle_between(*b - *vertex),
+            (MeasurementKind::Area, points) => {
+                // Shoelace formula, generalized to planar polygons in 3D.
+                let twice_area = points
+                    .iter()
+                    .zip(points.iter().cycle().skip(1))
+                    .map(|(a, b)| a.cross(*b))
+                    .sum::<glam::Vec3>();
+                twice_area.length() * 0.5
+            }
+            _ => f32::NAN,
+        }
+    }
+
+    pub fn value_text(&self) -> String {
+        let value = self.value();
+        match self.kind {
+            MeasurementKind::Distance => re_format::format_f32(value),
+            MeasurementKind::Angle => format!("{:.1}°", value.to_degrees()),
+            MeasurementKind::Area => format!("{}²", re_format::format_f32(value)),
+        }
+    }
+
+    /// The vector from the first to the last point, split up along the axes of the space.
+    ///
+    /// Uses the names of the [`ViewCoordinates`] if known.
+    pub fn axis_deltas(
+        &self,
+        coordinates: Option<ViewCoordinates>,
+    ) -> Option<[(&'static str, f32); 3]> {
+        let (first, last) = (self.points.first()?, self.points.last()?);
+        let delta = *last - *first;
+        Some(match coordinates {
+            Some(coordinates) => {
+                let [x, y, z] = coordinates.0;
+                [
+                    (x.long(), delta.x),
+                    (y.long(), delta.y),
+                    (z.long(), delta.z),
+                ]
+            }
+            None => [("X", delta.x), ("Y", delta.y), ("Z", delta.z)],
+        })
+    }
+}
+
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct MeasurementsState {
+    /// The active tool. If `None`, the view is navigated as usual.
+    tool: Option<MeasurementKind>,
+
+    /// Show the values of all images under the cursor (2D only).
+    pub show_pixel_probe: bool,
+
+    #[serde(skip)]
+    measurements: Vec<Measurement>,
+
+    /// Points of the measurement that is currently being made.
+    #[serde(skip)]
+    in_progress: Vec<glam::Vec3>,
+
+    #[serde(skip)]
+    next_id: u64,
+}
+
+impl MeasurementsState {
+    pub fn is_active(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    pub fn deactivate(&mut self) {
+        self.tool = None;
+        self.in_progress.clear();
+    }
+
+    pub fn get(&self, id: MeasurementId) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.id == id)
+    }
+
+    pub fn remove(&mut self, id: MeasurementId) {
+        self.measurements.retain(|measurement| measurement.id != id);
+    }
+
+    /// Tool selection & the list of measurements, shown in the selection panel of the space view.
+    pub fn settings_ui(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        space_view_id: SpaceViewId,
+    ) {
+        ui.horizontal(|ui| {
+            let mut tool = self.tool;
+            ui.selectable_value(&mut tool, None, "Off")
+                .on_hover_text("Navigate the view as usual.");
+            ui.selectable_value(&mut tool, Some(MeasurementKind::Distance), "Distance")
+                .on_hover_text("Click two points to measure their distance.");
+            ui.selectable_value(&mut tool, Some(MeasurementKind::Angle), "Angle")
+                .on_hover_text("Click three points to measure the angle at the second one.");
+            ui.selectable_value(&mut tool, Some(MeasurementKind::Area), "Area")
+                .on_hover_text(
+                    "Click the corners of a polygon, double-click or press Enter to close it.",
+                );
+            if tool != self.tool {
+                self.tool = tool;
+                self.in_progress.clear();
+            }
+        });
+
+        let mut removed = None;
+        for measurement in &self.measurements {
+            ui.horizontal(|ui| {
+                ctx.measurement_button(ui, space_view_id, measurement);
+                if ctx
+                    .re_ui
+                    .small_icon_button(ui, &re_ui::icons::REMOVE)
+                    .on_hover_text("Remove this measurement.")
+                    .clicked()
+                {
+                    removed = Some(measurement.id);
+                }
+            });
+        }
+        if let Some(id) = removed {
+            self.remove(id);
+        }
+        if !self.measurements.is_empty() && ui.button("Clear all").clicked() {
+            self.measurements.clear();
+        }
+    }
+
+    /// Handles the input of the active tool.
+    ///
+    /// `pointer_in_space` is the point that would be added by a click, if any.
+    pub fn interact(&mut self, response: &egui::Response, pointer_in_space: Option<glam::Vec3>) {
+        let Some(kind) = self.tool else {
+            return;
+        };
+
+        if response.hovered() {
+            response.ctx.set_cursor_icon(egui::CursorIcon::Crosshair);
+        }
+        if response.ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.in_progress.clear();
+        }
+
+        let close = kind.num_points().is_none()
+            && (response.double_clicked()
+                || response.ctx.input(|i| i.key_pressed(egui::Key::Enter)));
+        if close {
+            if self.in_progress.len() >= 3 {
+                self.finish(kind);
+            }
+            self.in_progress.clear();
+        } else if let (true, Some(pos)) = (response.clicked(), pointer_in_space) {
+            self.in_progress.push(pos);
+            if kind.num_points() == Some(self.in_progress.len()) {
+                self.finish(kind);
+            }
+        }
+    }
+
+    fn finish(&mut self, kind: MeasurementKind) {
+        let id = MeasurementId(self.next_id);
+        self.next_id += 1;
+        self.measurements.push(Measurement {
+            id,
+            kind,
+            points: std::mem::take(&mut self.in_progress),
+        });
+    }
+
+    /// Shapes for all measurements, to be painted on top of the view.
+    ///
+    /// `ui_from_space` projects a point of the space into the ui, `None` if it isn't visible.
+    /// Hovering the label of a measurement hovers its [`Item::Measurement`].
+    pub fn shapes(
+        &self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &egui::Ui,
+        response: &egui::Response,
+        space_view_id: SpaceViewId,
+        ui_from_space: impl Fn(glam::Vec3) -> Option<Pos2>,
+    ) -> Vec<Shape> {
+        let mut shapes = Vec::new();
+        let mut hovered_measurement = None;
+
+        for measurement in &self.measurements {
+            let item = Item::Measurement(space_view_id, measurement.id);
+            let is_selected = ctx.selection().contains(&item);
+            let is_hovered =
+                ctx.selection_state().highlight_for_ui_element(&item) == HoverHighlight::Hovered;
+            let color = if is_selected {
+                ui.visuals().selection.bg_fill
+            } else {
+                Color32::from_rgb(255, 200, 0)
+            };
+            let stroke = Stroke::new(if is_hovered { 3.0 } else { 1.5 }, color);
+
+            let points = measurement
+                .points
+                .iter()
+                .filter_map(|point| ui_from_space(*point))
+                .collect::<Vec<_>>();
+            if points.len() != measurement.points.len() {
+                continue;
+            }
+            add_polyline_shapes(
+                &mut shapes,
+                &points,
+                measurement.kind == MeasurementKind::Area,
+                stroke,
+            );
+
+            let label_rect = add_label_shapes(
+                &mut shapes,
+                ui,
+                label_position(measurement.kind, &points),
+                measurement.value_text(),
+                color,
+            );
+            if response
+                .hover_pos()
+                .map_or(false, |pos| label_rect.contains(pos))
+            {
+                hovered_measurement = Some(item);
+            }
+        }
+
+        if let Some(item) = hovered_measurement {
+            ctx.set_hovered(std::iter::once(item));
+        }
+
+        // The measurement that is currently being made, with a rubber band to the cursor.
+        if !self.in_progress.is_empty() {
+            let mut points = self
+                .in_progress
+                .iter()
+                .filter_map(|point| ui_from_space(*point))
+                .collect::<Vec<_>>();
+            if let Some(hover_pos) = response.hover_pos() {
+                points.push(hover_pos);
+            }
+            add_polyline_shapes(
+                &mut shapes,
+                &points,
+                false,
+                Stroke::new(1.5, Color32::WHITE),
+            );
+        }
+
+        shapes
+    }
+}
+
+fn add_polyline_shapes(shapes: &mut Vec<Shape>, points: &[Pos2], closed: bool, stroke: Stroke) {
+    if closed && points.len() > 2 {
+        shapes.push(Shape::closed_line(points.to_vec(), stroke));
+    } else {
+        shapes.push(Shape::line(points.to_vec(), stroke));
+    }
+    shapes.extend(
+        points
+            .iter()
+            .map(|point| Shape::circle_filled(*point, stroke.width + 1.5, stroke.color)),
+    );
+}
+
+fn label_position(kind: MeasurementKind, points: &[Pos2]) -> Pos2 {
+    match (kind, points) {
+        (MeasurementKind::Distance, [a, b]) => a.lerp(*b, 0.5),
+        (MeasurementKind::Angle, [_, vertex, _]) => *vertex,
+        _ => {
+            let sum = points
+                .iter()
+                .fold(egui::Vec2::ZERO, |sum, p| sum + p.to_vec2());
+            (sum / points.len().max(1) as f32).to_pos2()
+        }
+    }
+}
+
+/// Adds a label with a background, returns its rectangle.
+fn add_label_shapes(
+    shapes: &mut Vec<Shape>,
+    ui: &egui::Ui,
+    position: Pos2,
+    text: String,
+    color: Color32,
+) -> Rect {
+    let galley = ui.fonts(|fonts| {
+        fonts.layout_no_wrap(
+            text,
+            egui::TextStyle::Body.resolve(ui.style()),
+            Color32::BLACK,
+        )
+    });
+    let text_rect = Align2::CENTER_BOTTOM.anchor_rect(Rect::from_min_size(
+        position - egui::vec2(0.0, 6.0),
+        galley.size(),
+    ));
+    let bg_rect = text_rect.expand2(egui::vec2(4.0, 2.0));
+    shapes.push(Shape::rect_filled(bg_rect, 3.0, color));
+    shapes.push(Shape::galley(text_rect.min, galley));
+    bg_rect
+}
+
+// ----------------------------------------------------------------------------
+
+/// Values of all images under the given position (in space coordinates), for showing them in a tooltip.
+///
+/// Returns `(entity, pixel, value)` for every image that covers the position,
+/// where `pixel` is the `[x, y]` coordinate within that image.
+pub fn pixel_probe_values(
+    ctx: &ViewerContext<'_>,
+    images: &[super::Image],
+    space_pos: Pos2,
+) -> Vec<(String, [u64; 2], String)> {
+    use re_log_types::component_types::TensorTrait as _;
+
+    images
+        .iter()
+        .filter_map(|image| {
+            let entity_path = image
+                .instance_path_hash
+                .resolve(&ctx.log_db.entity_db)?
+                .entity_path;
+
+            let pos_in_image = image.world_from_obj.inverse().project_point3(glam::vec3(
+                space_pos.x,
+                space_pos.y,
+                0.0,
+            ));
+            if !pos_in_image.is_finite() || pos_in_image.x < 0.0 || pos_in_image.y < 0.0 {
+                return None;
+            }
+            let (x, y) = (pos_in_image.x.floor() as u64, pos_in_image.y.floor() as u64);
+
+            let tensor = &image.tensor;
+            let values = match tensor.shape() {
+                [_, _] => vec![tensor.get(&[y, x])?],
+                [_, _, channels] => (0..channels.size)
+                    .map(|channel| tensor.get(&[y, x, channel]))
+                    .collect::<Option<Vec<_>>>()?,
+                _ => return None,
+            };
+
+            let mut text = values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            if let (Some(meter), [value]) = (image.meter, values.as_slice()) {
+                text += &format!(" ({:.3} m)", value.as_f64() / meter as f64);
+            }
+            Some((entity_path.to_string(), [x, y], text))
+        })
+        .collect()
+}
@@ -1,4 +1,6 @@
+mod annotation_tools;
 mod eye;
+mod measurements;
 mod scene;
 mod space_camera_3d;
 
@@ -7,6 +9,7 @@ mod ui_2d;
 mod ui_3d;
 mod ui_renderer_bridge;
 
+pub use self::measurements::{Measurement, MeasurementId, MeasurementKind};
 pub use self::scene::{Image, MeshSource, MeshSourceData, SceneSpatial, UiLabel, UiLabelTarget};
 pub use self::space_camera_3d::SpaceCamera3D;
 pub use ui::{SpatialNavigationMode, ViewSpatialState};
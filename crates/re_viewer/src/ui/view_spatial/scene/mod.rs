@@ -3,7 +3,7 @@ use std::sync::Arc;
 use ahash::HashMap;
 use re_data_store::{EntityPath, InstancePathHash};
 use re_log_types::{
-    component_types::{ClassId, KeypointId, Tensor},
+    component_types::{ClassId, KeypointId, KeypointVisibility, Tensor},
     MeshId,
 };
 use re_renderer::{Color32, OutlineMaskPreference, Size};
@@ -53,6 +53,7 @@ pub struct MeshSource {
     pub world_from_mesh: macaw::Affine3A,
     pub mesh: Arc<LoadedMesh>,
     pub outline_mask_ids: OutlineMaskPreference,
+    pub shading: re_renderer::renderer::MeshShading,
 }
 
 pub struct Image {
@@ -60,6 +61,9 @@ pub struct Image {
 
     pub tensor: Tensor,
 
+    /// Transform from the image's pixel coordinates to the space the scene lives in.
+    pub world_from_obj: glam::Mat4,
+
     /// If this is a depth map, how long is a meter?
     ///
     /// For example, with a `u16` dtype one might have
@@ -133,7 +137,35 @@ fn instance_path_hash_if_interactive(
     }
 }
 
-pub type Keypoints = HashMap<(ClassId, i64), HashMap<KeypointId, glam::Vec3>>;
+/// A single keypoint, as needed for drawing the connections between keypoints.
+#[derive(Clone, Copy)]
+pub struct Keypoint {
+    pub position: glam::Vec3,
+    pub is_occluded: bool,
+}
+
+pub type Keypoints = HashMap<(ClassId, i64), HashMap<KeypointId, Keypoint>>;
+
+/// Occluded keypoints and their connections are drawn with this fraction of their opacity.
+const OCCLUDED_KEYPOINT_OPACITY: f32 = 0.4;
+
+/// Marks the label of occluded keypoints as such.
+fn occluded_keypoint_label(label: String, visibility: Option<KeypointVisibility>) -> String {
+    if visibility.map_or(false, |visibility| visibility.is_occluded()) {
+        format!("{label} (occluded)")
+    } else {
+        label
+    }
+}
+
+/// Color of a keypoint with the given visibility.
+fn keypoint_color(color: Color32, visibility: Option<KeypointVisibility>) -> Color32 {
+    if visibility.map_or(false, |visibility| visibility.is_occluded()) {
+        color.linear_multiply(OCCLUDED_KEYPOINT_OPACITY)
+    } else {
+        color
+    }
+}
 
 impl SceneSpatial {
     pub fn new(re_ctx: &mut re_renderer::RenderContext) -> Self {
@@ -160,7 +192,10 @@ impl SceneSpatial {
         self.annotation_map.load(ctx, query);
 
         let parts: Vec<&dyn ScenePart> = vec![
-            &scene_part::Points3DPart { max_labels: 10 },
+            &scene_part::Points3DPart {
+                max_labels: 10,
+                max_keypoint_labels: 64,
+            },
             // --
             &scene_part::Boxes3DPart,
             &scene_part::Lines3DPart,
@@ -217,8 +252,13 @@ impl SceneSpatial {
                     );
                     continue;
                 };
+                let color = if a.is_occluded || b.is_occluded {
+                    color.linear_multiply(OCCLUDED_KEYPOINT_OPACITY)
+                } else {
+                    color
+                };
                 line_batch
-                    .add_segment(*a, *b)
+                    .add_segment(a.position, b.position)
                     .radius(Size::AUTO)
                     .color(color)
                     .user_data(instance_path_hash);
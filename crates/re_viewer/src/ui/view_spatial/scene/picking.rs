@@ -370,9 +370,12 @@ fn picking_textured_rects(
             continue;
         }
 
+        let [top_left, top_right, bottom_left, _] = rect.corners();
         let rect_plane = macaw::Plane3::from_normal_point(
-            rect.extent_u.cross(rect.extent_v).normalize(),
-            rect.top_left_corner_position,
+            (top_right - top_left)
+                .cross(bottom_left - top_left)
+                .normalize(),
+            top_left,
         );
 
         // TODO(andreas): Interaction radius is currently ignored for rects.
@@ -382,9 +385,9 @@ fn picking_textured_rects(
             continue;
         }
         let intersection_world = context.ray_in_world.origin + context.ray_in_world.dir * t;
-        let dir_from_rect_top_left = intersection_world - rect.top_left_corner_position;
-        let u = dir_from_rect_top_left.dot(rect.extent_u) / rect.extent_u.length_squared();
-        let v = dir_from_rect_top_left.dot(rect.extent_v) / rect.extent_v.length_squared();
+        let Some(glam::Vec2 { x: u, y: v }) = rect_texcoord(rect, intersection_world) else {
+            continue;
+        };
 
         if (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v) {
             let picking_hit = PickingRayHit {
@@ -422,3 +425,27 @@ fn picking_ui_rects(
         );
     }
 }
+
+/// Texture coordinate of a point on the plane of a (possibly projectively warped) rectangle.
+///
+/// Solves `point * (w_tl + u * w_u + v * w_v) = top_left + u * extent_u + v * extent_v` for `u` & `v`
+/// in the least squares sense.
+fn rect_texcoord(
+    rect: &re_renderer::renderer::TexturedRect,
+    point: glam::Vec3,
+) -> Option<glam::Vec2> {
+    let w = rect.homogeneous_w;
+    let a = rect.extent_u - point * w.y;
+    let b = rect.extent_v - point * w.z;
+    let r = point * w.x - rect.top_left_corner_position;
+
+    let normal_matrix = glam::Mat2::from_cols(
+        glam::vec2(a.dot(a), a.dot(b)),
+        glam::vec2(a.dot(b), b.dot(b)),
+    );
+    let determinant = normal_matrix.determinant();
+    if determinant == 0.0 || !determinant.is_finite() {
+        return None;
+    }
+    Some(normal_matrix.inverse() * glam::vec2(a.dot(r), b.dot(r)))
+}
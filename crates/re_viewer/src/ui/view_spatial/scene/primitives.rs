@@ -100,10 +100,9 @@ impl SceneSpatialPrimitives {
         *bounding_box = macaw::BoundingBox::nothing();
 
         for rect in textured_rectangles {
-            bounding_box.extend(rect.top_left_corner_position);
-            bounding_box.extend(rect.top_left_corner_position + rect.extent_u);
-            bounding_box.extend(rect.top_left_corner_position + rect.extent_v);
-            bounding_box.extend(rect.top_left_corner_position + rect.extent_v + rect.extent_u);
+            for corner in rect.corners() {
+                bounding_box.extend(corner);
+            }
         }
 
         // We don't need a very accurate bounding box, so in order to save some time,
@@ -159,6 +158,7 @@ impl SceneSpatialPrimitives {
                         picking_layer_id: picking_layer_id_from_instance_path_hash(
                             mesh.picking_instance_hash,
                         ),
+                        shading: mesh.shading,
                         ..Default::default()
                     })
             })
@@ -5,10 +5,13 @@ use re_log_types::{
     Component,
 };
 use re_query::{query_primary_with_history, QueryError};
-use re_renderer::Size;
+use re_renderer::{renderer::LineStripFlags, Size};
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{
         scene::SceneQuery,
         view_spatial::{
@@ -29,6 +32,7 @@ impl Boxes2DPart {
         scene: &mut SceneSpatial,
         entity_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         instance_path_hash: InstancePathHash,
         rect: &Rect2D,
         color: Option<ColorRGBA>,
@@ -52,15 +56,35 @@ impl Boxes2DPart {
             .primitives
             .line_strips
             .batch("2d box")
-            .world_from_obj(world_from_obj)
+            .world_from_obj(if distortion.is_some() {
+                Mat4::IDENTITY
+            } else {
+                world_from_obj
+            })
             .outline_mask_ids(entity_highlight.overall);
 
-        let rectangle = line_batch
-            .add_rectangle_outline_2d(
+        let rectangle = if let Some(distortion) = distortion {
+            let top_left = glam::Vec2::from(rect.top_left_corner()).extend(0.0);
+            let extent_u = glam::vec3(rect.width(), 0.0, 0.0);
+            let extent_v = glam::vec3(0.0, rect.height(), 0.0);
+            let outline = [
+                top_left,
+                top_left + extent_u,
+                top_left + extent_u + extent_v,
+                top_left + extent_v,
+                top_left,
+            ];
+            line_batch
+                .add_strip(distortion.transform_strip(outline.into_iter()).into_iter())
+                .flags(LineStripFlags::NO_COLOR_GRADIENT)
+        } else {
+            line_batch.add_rectangle_outline_2d(
                 rect.top_left_corner().into(),
                 glam::vec2(rect.width(), 0.0),
                 glam::vec2(0.0, rect.height()),
             )
+        };
+        let rectangle = rectangle
             .color(color)
             .radius(radius)
             .user_data(instance_path_hash);
@@ -102,6 +126,7 @@ impl ScenePart for Boxes2DPart {
                 continue;
             };
 
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
             match query_primary_with_history::<Rect2D, 6>(
@@ -133,6 +158,7 @@ impl ScenePart for Boxes2DPart {
                             scene,
                             ent_path,
                             world_from_obj,
+                            distortion,
                             instance_hash,
                             &rect,
                             color,
@@ -6,10 +6,13 @@ use re_log_types::{
     Component,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
-use re_renderer::Size;
+use re_renderer::{renderer::LineStripFlags, Size};
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{
         scene::SceneQuery,
         view_spatial::{SceneSpatial, UiLabel, UiLabelTarget},
@@ -21,13 +24,30 @@ use super::{instance_path_hash_for_picking, ScenePart};
 
 pub struct Boxes3DPart;
 
+/// The outline of a unit box as a single strip, passing over some edges twice.
+///
+/// Needed where the edges are no longer straight and can't be drawn as segments, i.e. under lens distortion.
+fn box_outline_strip(transform: glam::Affine3A) -> impl Iterator<Item = glam::Vec3> {
+    [
+        0b000, 0b001, 0b011, 0b010, 0b000, 0b100, 0b101, 0b111, 0b110, 0b100, 0b101, 0b001, 0b011,
+        0b111, 0b110, 0b010,
+    ]
+    .into_iter()
+    .map(move |corner: u32| {
+        let offset = |bit: u32| if corner & bit == 0 { -0.5 } else { 0.5 };
+        transform.transform_point3(glam::vec3(offset(0b100), offset(0b010), offset(0b001)))
+    })
+}
+
 impl Boxes3DPart {
+    #[allow(clippy::too_many_arguments)]
     fn process_entity_view(
         scene: &mut SceneSpatial,
         props: &EntityProperties,
         entity_view: &EntityView<Box3D>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
     ) -> Result<(), QueryError> {
         scene.num_logged_3d_objects += 1;
@@ -38,7 +58,11 @@ impl Boxes3DPart {
             .primitives
             .line_strips
             .batch("box 3d")
-            .world_from_obj(world_from_obj)
+            .world_from_obj(if distortion.is_some() {
+                Mat4::IDENTITY
+            } else {
+                world_from_obj
+            })
             .outline_mask_ids(entity_highlight.overall);
 
         let visitor = |instance_key: InstanceKey,
@@ -69,8 +93,18 @@ impl Boxes3DPart {
             let tran = position.map_or(glam::Vec3::ZERO, glam::Vec3::from);
             let transform = glam::Affine3A::from_scale_rotation_translation(scale, rot, tran);
 
-            let box_lines = line_batch
-                .add_box_outline(transform)
+            let box_lines = if let Some(distortion) = distortion {
+                line_batch
+                    .add_strip(
+                        distortion
+                            .transform_strip(box_outline_strip(transform))
+                            .into_iter(),
+                    )
+                    .flags(LineStripFlags::NO_COLOR_GRADIENT)
+            } else {
+                line_batch.add_box_outline(transform)
+            };
+            let box_lines = box_lines
                 .radius(radius)
                 .color(color)
                 .user_data(instance_hash);
@@ -78,10 +112,18 @@ impl Boxes3DPart {
                 box_lines.outline_mask_ids(*outline_mask_ids);
             }
 
-            if let Some(label) = annotation_info.label(label.as_ref().map(|s| &s.0)) {
+            let label_position = if let Some(distortion) = distortion {
+                distortion.transform_point3(tran)
+            } else {
+                Some(world_from_obj.transform_point3(tran))
+            };
+            if let (Some(label), Some(label_position)) = (
+                annotation_info.label(label.as_ref().map(|s| &s.0)),
+                label_position,
+            ) {
                 scene.ui.labels.push(UiLabel {
                     text: label,
-                    target: UiLabelTarget::Position3D(world_from_obj.transform_point3(tran)),
+                    target: UiLabelTarget::Position3D(label_position),
                     color,
                     labeled_instance: instance_hash,
                 });
@@ -107,6 +149,7 @@ impl ScenePart for Boxes3DPart {
             let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
                 continue;
             };
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
             match query_primary_with_history::<Box3D, 8>(
@@ -134,6 +177,7 @@ impl ScenePart for Boxes3DPart {
                         &entity,
                         ent_path,
                         world_from_obj,
+                        distortion,
                         entity_highlight,
                     )?;
                 }
@@ -21,6 +21,9 @@ use crate::{
 
 use super::ScenePart;
 
+/// Number of line segments each edge of the image border is split into for cameras with lens distortion.
+const DISTORTED_FRUSTUM_SUBDIVISIONS: usize = 16;
+
 /// Determine the view coordinates (i.e.) the axis semantics.
 ///
 /// The recommended way to log this is on the object holding the extrinsic camera properties
@@ -123,22 +126,28 @@ impl CamerasPart {
             (offset + glam::vec2(fx * 0.25, -triangle_frustum_offset)).extend(frustum_length),
         ];
 
-        let segments = [
-            // Frustum corners
-            (glam::Vec3::ZERO, corners[0]),
-            (glam::Vec3::ZERO, corners[1]),
-            (glam::Vec3::ZERO, corners[2]),
-            (glam::Vec3::ZERO, corners[3]),
-            // rectangle around "far plane"
-            (corners[0], corners[1]),
-            (corners[1], corners[2]),
-            (corners[2], corners[3]),
-            (corners[3], corners[0]),
-            // triangle indicating up direction
-            (up_triangle[0], up_triangle[1]),
-            (up_triangle[1], up_triangle[2]),
-            (up_triangle[2], up_triangle[0]),
-        ];
+        let segments = match pinhole.resolution() {
+            Some(resolution) if pinhole.has_distortion() => {
+                distorted_frustum_segments(&pinhole, resolution, frustum_length)
+            }
+            _ => [
+                // Frustum corners
+                (glam::Vec3::ZERO, corners[0]),
+                (glam::Vec3::ZERO, corners[1]),
+                (glam::Vec3::ZERO, corners[2]),
+                (glam::Vec3::ZERO, corners[3]),
+                // rectangle around "far plane"
+                (corners[0], corners[1]),
+                (corners[1], corners[2]),
+                (corners[2], corners[3]),
+                (corners[3], corners[0]),
+                // triangle indicating up direction
+                (up_triangle[0], up_triangle[1]),
+                (up_triangle[1], up_triangle[2]),
+                (up_triangle[2], up_triangle[0]),
+            ]
+            .to_vec(),
+        };
 
         let radius = re_renderer::Size::new_points(1.0);
         let color = SceneSpatial::CAMERA_COLOR;
@@ -161,6 +170,54 @@ impl CamerasPart {
     }
 }
 
+/// Frustum of a camera with lens distortion.
+///
+/// The image border is no longer a rectangle in camera space, so instead of connecting the corners,
+/// points along the border are unprojected individually.
+fn distorted_frustum_segments(
+    pinhole: &Pinhole,
+    resolution: glam::Vec2,
+    frustum_length: f32,
+) -> Vec<(glam::Vec3, glam::Vec3)> {
+    let unproject = |pixel: glam::Vec2| pinhole.unproject(pixel, frustum_length);
+
+    let corners = [
+        glam::vec2(resolution.x, 0.0),
+        resolution,
+        glam::vec2(0.0, resolution.y),
+        glam::Vec2::ZERO,
+    ];
+    // Same proportions as the triangle of an undistorted camera.
+    let triangle_offset = resolution.y * 0.025;
+    let triangle_size = resolution.x * 0.125;
+    let up_triangle = [
+        glam::vec2(resolution.x * 0.5 - triangle_size, -triangle_offset),
+        glam::vec2(resolution.x * 0.5, -triangle_offset - triangle_size),
+        glam::vec2(resolution.x * 0.5 + triangle_size, -triangle_offset),
+    ];
+
+    let mut segments = corners
+        .iter()
+        .map(|corner| (glam::Vec3::ZERO, unproject(*corner)))
+        .collect::<Vec<_>>();
+
+    for polygon in [&corners[..], &up_triangle[..]] {
+        for (i, start) in polygon.iter().enumerate() {
+            let end = polygon[(i + 1) % polygon.len()];
+            segments.extend((0..DISTORTED_FRUSTUM_SUBDIVISIONS).map(|j| {
+                let t0 = j as f32 / DISTORTED_FRUSTUM_SUBDIVISIONS as f32;
+                let t1 = (j + 1) as f32 / DISTORTED_FRUSTUM_SUBDIVISIONS as f32;
+                (
+                    unproject(start.lerp(end, t0)),
+                    unproject(start.lerp(end, t1)),
+                )
+            }));
+        }
+    }
+
+    segments
+}
+
 impl ScenePart for CamerasPart {
     fn load(
         &self,
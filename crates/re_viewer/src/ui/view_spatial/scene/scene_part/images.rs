@@ -6,20 +6,30 @@ use itertools::Itertools;
 
 use re_data_store::{query_latest_single, EntityPath, EntityProperties, InstancePathHash};
 use re_log_types::{
-    component_types::{ColorRGBA, InstanceKey, Tensor, TensorData, TensorDataMeaning, TensorTrait},
+    component_types::{
+        BrownConrady, ColorRGBA, InstanceKey, KannalaBrandt, LensDistortion, Tensor, TensorData,
+        TensorDataMeaning, TensorTrait,
+    },
     Component, Transform,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::{
-    renderer::{DepthCloud, DepthCloudDepthData},
-    ColorMap, OutlineMaskPreference,
+    renderer::{DepthCloud, DepthCloudDepthData, DepthCloudDistortion, MeshShading},
+    resource_managers::ResourceLifeTime,
+    ColorMap, OutlineMaskPreference, Rgba32Unmul,
 };
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    mesh_loader::LoadedMesh,
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{
         scene::SceneQuery,
-        view_spatial::{scene::scene_part::instance_path_hash_for_picking, Image, SceneSpatial},
+        view_spatial::{
+            scene::scene_part::instance_path_hash_for_picking, Image, MeshSource, SceneSpatial,
+        },
         Annotations, DefaultColor,
     },
 };
@@ -43,16 +53,26 @@ fn push_tensor_texture(
 
     if let Some(texture_handle) = tensor_view.texture_handle(ctx.render_ctx) {
         let (h, w) = (tensor.shape()[0].size as f32, tensor.shape()[1].size as f32);
+
+        // `world_from_obj` may be projective (e.g. a homography), so transform in homogeneous coordinates.
+        // Normalize such that the top left corner itself is a regular point.
+        let top_left_corner_position = world_from_obj * glam::Vec4::W;
+        let extent_u = world_from_obj * (glam::Vec4::X * w) / top_left_corner_position.w;
+        let extent_v = world_from_obj * (glam::Vec4::Y * h) / top_left_corner_position.w;
+        let top_left_corner_position = top_left_corner_position / top_left_corner_position.w;
+
         scene
             .primitives
             .textured_rectangles
             .push(re_renderer::renderer::TexturedRect {
-                top_left_corner_position: world_from_obj.transform_point3(glam::Vec3::ZERO),
-                extent_u: world_from_obj.transform_vector3(glam::Vec3::X * w),
-                extent_v: world_from_obj.transform_vector3(glam::Vec3::Y * h),
+                top_left_corner_position: top_left_corner_position.truncate(),
+                extent_u: extent_u.truncate(),
+                extent_v: extent_v.truncate(),
+                homogeneous_w: glam::vec3(1.0, extent_u.w, extent_v.w),
                 texture: texture_handle,
                 texture_filter_magnification: re_renderer::renderer::TextureFilterMag::Nearest,
-                texture_filter_minification: re_renderer::renderer::TextureFilterMin::Linear,
+                texture_filter_minification:
+                    re_renderer::renderer::TextureFilterMin::LinearMipmapLinear,
                 multiplicative_tint: tint,
                 // Push to background. Mostly important for mouse picking order!
                 depth_offset: -1,
@@ -65,6 +85,104 @@ fn push_tensor_texture(
     }
 }
 
+/// Number of quads along each edge of an image that is bent by the lens distortion of a parent pinhole.
+const DISTORTED_IMAGE_SUBDIVISIONS: u32 = 32;
+
+/// Like [`push_tensor_texture`], but for images under a pinhole with lens distortion.
+///
+/// Straight lines are no longer straight after distortion, so instead of a textured rectangle
+/// the image becomes a grid mesh whose vertices are each moved through the distortion.
+#[allow(clippy::too_many_arguments)]
+fn push_distorted_tensor_texture(
+    scene: &mut SceneSpatial,
+    ctx: &mut ViewerContext<'_>,
+    annotations: &Arc<Annotations>,
+    distortion: &DistortedTransform,
+    ent_path: &EntityPath,
+    instance_path_hash: InstancePathHash,
+    tensor: &Tensor,
+    tint: egui::Rgba,
+    outline_mask: OutlineMaskPreference,
+) {
+    crate::profile_function!();
+
+    let tensor_view = ctx.cache.image.get_colormapped_view(tensor, annotations);
+    let Some(texture_handle) = tensor_view.texture_handle(ctx.render_ctx) else {
+        return;
+    };
+    let (h, w) = (tensor.shape()[0].size as f32, tensor.shape()[1].size as f32);
+
+    let n = DISTORTED_IMAGE_SUBDIVISIONS;
+    let num_vertices = ((n + 1) * (n + 1)) as usize;
+    let mut vertex_positions = Vec::with_capacity(num_vertices);
+    let mut vertex_texcoords = Vec::with_capacity(num_vertices);
+    let mut visible = Vec::with_capacity(num_vertices);
+    for y in 0..=n {
+        for x in 0..=n {
+            let texcoord = glam::vec2(x as f32, y as f32) / n as f32;
+            let position = distortion.transform_point3((texcoord * glam::vec2(w, h)).extend(0.0));
+            visible.push(position.is_some());
+            vertex_positions.push(position.unwrap_or(Vec3::ZERO));
+            vertex_texcoords.push(texcoord);
+        }
+    }
+
+    let vertex_index = |x: u32, y: u32| y * (n + 1) + x;
+    let mut indices = Vec::new();
+    for y in 0..n {
+        for x in 0..n {
+            let quad = [
+                vertex_index(x, y),
+                vertex_index(x + 1, y),
+                vertex_index(x, y + 1),
+                vertex_index(x + 1, y + 1),
+            ];
+            // Drop quads that are (partially) outside of what the camera can see.
+            if quad.iter().all(|&i| visible[i as usize]) {
+                indices.extend([quad[0], quad[1], quad[2], quad[2], quad[1], quad[3]]);
+            }
+        }
+    }
+    if indices.is_empty() {
+        return;
+    }
+
+    let label = ent_path.to_string();
+    let num_indices = indices.len() as u32;
+    let mesh = re_renderer::mesh::Mesh {
+        label: label.clone().into(),
+        indices,
+        vertex_positions,
+        vertex_colors: vec![Rgba32Unmul::WHITE; num_vertices],
+        vertex_normals: vec![Vec3::ZERO; num_vertices],
+        vertex_texcoords,
+        materials: smallvec::smallvec![re_renderer::mesh::Material {
+            label: label.clone().into(),
+            index_range: 0..num_indices,
+            albedo: texture_handle,
+            albedo_multiplier: tint,
+            normal_map: None,
+            normal_scale: 1.0,
+            metallic_roughness: None,
+            metallic_factor: re_renderer::mesh::Material::DEFAULT_METALLIC_FACTOR,
+            roughness_factor: re_renderer::mesh::Material::DEFAULT_ROUGHNESS_FACTOR,
+        }],
+    };
+
+    match LoadedMesh::from_cpu_mesh(label, mesh, ResourceLifeTime::SingleFrame, ctx.render_ctx) {
+        Ok(mesh) => scene.primitives.meshes.push(MeshSource {
+            picking_instance_hash: instance_path_hash,
+            world_from_mesh: macaw::Affine3A::IDENTITY,
+            mesh: Arc::new(mesh),
+            outline_mask_ids: outline_mask,
+            shading: MeshShading::Unlit,
+        }),
+        Err(err) => {
+            re_log::warn_once!("Failed to upload distorted image at {ent_path}: {err}");
+        }
+    }
+}
+
 fn handle_image_layering(scene: &mut SceneSpatial) {
     crate::profile_function!();
 
@@ -186,6 +304,7 @@ impl ImagesPart {
                     properties,
                     ent_path,
                     world_from_obj,
+                    transforms.distorted_transform(ent_path),
                     entity_highlight,
                     instance_key,
                     tensor,
@@ -205,6 +324,7 @@ impl ImagesPart {
         properties: &EntityProperties,
         ent_path: &EntityPath,
         world_from_obj: glam::Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
         instance_key: InstanceKey,
         tensor: Tensor,
@@ -231,16 +351,30 @@ impl ImagesPart {
 
         match ctx.cache.decode.try_decode_tensor_if_necessary(tensor) {
             Ok(tensor) => {
-                push_tensor_texture(
-                    scene,
-                    ctx,
-                    &annotations,
-                    world_from_obj,
-                    instance_path_hash,
-                    &tensor,
-                    color.into(),
-                    outline_mask,
-                );
+                if let Some(distortion) = distortion {
+                    push_distorted_tensor_texture(
+                        scene,
+                        ctx,
+                        &annotations,
+                        distortion,
+                        ent_path,
+                        instance_path_hash,
+                        &tensor,
+                        color.into(),
+                        outline_mask,
+                    );
+                } else {
+                    push_tensor_texture(
+                        scene,
+                        ctx,
+                        &annotations,
+                        world_from_obj,
+                        instance_path_hash,
+                        &tensor,
+                        color.into(),
+                        outline_mask,
+                    );
+                }
 
                 // TODO(jleibs): Meter should really be its own component
                 let meter = tensor.meter;
@@ -248,6 +382,7 @@ impl ImagesPart {
                 scene.ui.images.push(Image {
                     instance_path_hash,
                     tensor,
+                    world_from_obj,
                     meter,
                     annotations,
                 });
@@ -344,6 +479,16 @@ impl ImagesPart {
         scene.primitives.depth_clouds.clouds.push(DepthCloud {
             world_from_obj,
             depth_camera_intrinsics: intrinsics.image_from_cam.into(),
+            depth_camera_distortion: match intrinsics.distortion {
+                LensDistortion::None => DepthCloudDistortion::None,
+                LensDistortion::BrownConrady(BrownConrady { k1, k2, p1, p2, k3 }) => {
+                    DepthCloudDistortion::BrownConrady { k1, k2, p1, p2, k3 }
+                }
+                LensDistortion::KannalaBrandt(KannalaBrandt { k1, k2, k3, k4 }) => {
+                    DepthCloudDistortion::KannalaBrandt { k1, k2, k3, k4 }
+                }
+                LensDistortion::Equirectangular => DepthCloudDistortion::Equirectangular,
+            },
             world_depth_from_data_depth,
             point_radius_from_world_depth,
             max_depth_in_world: world_depth_from_data_depth * max_data_value,
@@ -9,7 +9,10 @@ use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::{renderer::LineStripFlags, Size};
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{scene::SceneQuery, view_spatial::SceneSpatial, DefaultColor},
 };
 
@@ -26,6 +29,7 @@ impl Lines2DPart {
         entity_view: &EntityView<LineStrip2D>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
     ) -> Result<(), QueryError> {
         scene.num_logged_2d_objects += 1;
@@ -37,7 +41,11 @@ impl Lines2DPart {
             .primitives
             .line_strips
             .batch("lines 2d")
-            .world_from_obj(world_from_obj)
+            .world_from_obj(if distortion.is_some() {
+                Mat4::IDENTITY
+            } else {
+                world_from_obj
+            })
             .outline_mask_ids(entity_highlight.overall);
 
         let visitor = |instance_key: InstanceKey,
@@ -58,8 +66,17 @@ impl Lines2DPart {
             let color =
                 annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
 
-            let lines = line_batch
-                .add_strip_2d(strip.0.into_iter().map(|v| v.into()))
+            let strip = strip.0.into_iter().map(glam::Vec2::from);
+            let lines = if let Some(distortion) = distortion {
+                line_batch.add_strip(
+                    distortion
+                        .transform_strip(strip.map(|p| p.extend(0.0)))
+                        .into_iter(),
+                )
+            } else {
+                line_batch.add_strip_2d(strip)
+            };
+            let lines = lines
                 .color(color)
                 .radius(radius)
                 .flags(LineStripFlags::NO_COLOR_GRADIENT)
@@ -90,6 +107,7 @@ impl ScenePart for Lines2DPart {
             let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
                 continue;
             };
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
             match query_primary_with_history::<LineStrip2D, 4>(
@@ -114,6 +132,7 @@ impl ScenePart for Lines2DPart {
                         &entity,
                         ent_path,
                         world_from_obj,
+                        distortion,
                         entity_highlight,
                     )?;
                 }
@@ -9,7 +9,10 @@ use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::Size;
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{scene::SceneQuery, view_spatial::SceneSpatial, DefaultColor},
 };
 
@@ -26,6 +29,7 @@ impl Lines3DPart {
         entity_view: &EntityView<LineStrip3D>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
     ) -> Result<(), QueryError> {
         scene.num_logged_3d_objects += 1;
@@ -36,7 +40,11 @@ impl Lines3DPart {
             .primitives
             .line_strips
             .batch("lines 3d")
-            .world_from_obj(world_from_obj)
+            .world_from_obj(if distortion.is_some() {
+                Mat4::IDENTITY
+            } else {
+                world_from_obj
+            })
             .outline_mask_ids(entity_highlight.overall);
 
         let visitor = |instance_key: InstanceKey,
@@ -58,8 +66,13 @@ impl Lines3DPart {
             let color =
                 annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
 
-            let lines = line_batch
-                .add_strip(strip.0.into_iter().map(|v| v.into()))
+            let strip = strip.0.into_iter().map(glam::Vec3::from);
+            let lines = if let Some(distortion) = distortion {
+                line_batch.add_strip(distortion.transform_strip(strip).into_iter())
+            } else {
+                line_batch.add_strip(strip)
+            };
+            let lines = lines
                 .radius(radius)
                 .color(color)
                 .user_data(picking_instance_hash);
@@ -89,6 +102,7 @@ impl ScenePart for Lines3DPart {
             let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
                 continue;
             };
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
             match query_primary_with_history::<LineStrip3D, 4>(
@@ -113,6 +127,7 @@ impl ScenePart for Lines3DPart {
                         &entity,
                         ent_path,
                         world_from_obj,
+                        distortion,
                         entity_highlight,
                     )?;
                 }
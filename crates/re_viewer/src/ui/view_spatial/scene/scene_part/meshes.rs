@@ -1,11 +1,12 @@
 use glam::Mat4;
 
-use re_data_store::{EntityPath, EntityProperties};
+use re_data_store::{EntityPath, EntityProperties, InstancePathHash, MeshShading};
 use re_log_types::{
     component_types::{ColorRGBA, InstanceKey},
     Component, Mesh3D,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
+use re_renderer::{Color32, LineStripSeriesBuilder, Size};
 
 use crate::{
     misc::{SpaceViewHighlights, TransformCache, ViewerContext},
@@ -18,6 +19,8 @@ use crate::{
 
 use super::{instance_path_hash_for_picking, ScenePart};
 
+const WIREFRAME_COLOR: Color32 = Color32::from_gray(220);
+
 pub struct MeshPart;
 
 impl MeshPart {
@@ -37,6 +40,12 @@ impl MeshPart {
         let _default_color = DefaultColor::EntityPath(ent_path);
         let world_from_obj_affine = glam::Affine3A::from_mat4(world_from_obj);
         let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
+        let shading = match props.mesh_shading.get() {
+            MeshShading::Smooth => re_renderer::renderer::MeshShading::Smooth,
+            MeshShading::Flat => re_renderer::renderer::MeshShading::Flat,
+            MeshShading::Unlit => re_renderer::renderer::MeshShading::Unlit,
+        };
+        let wireframe = *props.mesh_wireframe.get();
 
         let visitor =
             |instance_key: InstanceKey, mesh: re_log_types::Mesh3D, _color: Option<ColorRGBA>| {
@@ -63,8 +72,12 @@ impl MeshPart {
                         world_from_mesh: world_from_obj_affine,
                         mesh: cpu_mesh,
                         outline_mask_ids,
+                        shading,
                     })
                 {
+                    if wireframe {
+                        Self::add_wireframe(&mut scene.primitives.line_strips, &mesh);
+                    }
                     scene.primitives.meshes.push(mesh);
                 };
             };
@@ -73,6 +86,52 @@ impl MeshPart {
 
         Ok(())
     }
+
+    /// Adds every triangle edge of the mesh as a line segment.
+    ///
+    /// Only possible for meshes whose cpu side data is still around.
+    fn add_wireframe(
+        line_strips: &mut LineStripSeriesBuilder<InstancePathHash>,
+        mesh: &MeshSource,
+    ) {
+        crate::profile_function!();
+
+        for mesh_instance in &mesh.mesh.mesh_instances {
+            let Some(cpu_mesh) = &mesh_instance.mesh else {
+                continue;
+            };
+
+            // Triangles share most of their edges with their neighbors, so deduplicate them.
+            let mut edges = cpu_mesh
+                .indices
+                .chunks_exact(3)
+                .flat_map(|triangle| {
+                    [
+                        (triangle[0], triangle[1]),
+                        (triangle[1], triangle[2]),
+                        (triangle[2], triangle[0]),
+                    ]
+                })
+                .map(|(a, b)| (a.min(b), a.max(b)))
+                .collect::<Vec<_>>();
+            edges.sort_unstable();
+            edges.dedup();
+
+            let positions = &cpu_mesh.vertex_positions;
+            line_strips
+                .batch("mesh wireframe")
+                .world_from_obj(Mat4::from(
+                    mesh.world_from_mesh * mesh_instance.world_from_mesh,
+                ))
+                .outline_mask_ids(mesh.outline_mask_ids)
+                .add_segments(edges.into_iter().filter_map(|(a, b)| {
+                    Some((*positions.get(a as usize)?, *positions.get(b as usize)?))
+                }))
+                .radius(Size::new_points(0.5))
+                .color(WIREFRAME_COLOR)
+                .user_data(mesh.picking_instance_hash);
+        }
+    }
 }
 
 impl ScenePart for MeshPart {
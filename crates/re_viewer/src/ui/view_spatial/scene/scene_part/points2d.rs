@@ -2,17 +2,25 @@ use glam::Mat4;
 
 use re_data_store::{EntityPath, EntityProperties};
 use re_log_types::{
-    component_types::{ClassId, ColorRGBA, InstanceKey, KeypointId, Label, Point2D, Radius},
+    component_types::{
+        ClassId, ColorRGBA, InstanceKey, KeypointId, KeypointVisibility, Label, Point2D, Radius,
+    },
     Component,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::Size;
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{
         scene::SceneQuery,
-        view_spatial::{scene::Keypoints, SceneSpatial, UiLabel, UiLabelTarget},
+        view_spatial::{
+            scene::{keypoint_color, occluded_keypoint_label, Keypoint, Keypoints},
+            SceneSpatial, UiLabel, UiLabelTarget,
+        },
         DefaultColor,
     },
 };
@@ -30,12 +38,18 @@ impl Points2DPart {
         entity_view: &EntityView<Point2D>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
     ) -> Result<(), QueryError> {
         scene.num_logged_2d_objects += 1;
 
         let mut label_batch = Vec::new();
-        let max_num_labels = 10;
+        // Skeletons easily have more than a handful of keypoints, all of which we want to label.
+        let max_num_labels = if entity_view.has_component::<KeypointId>() {
+            64
+        } else {
+            10
+        };
 
         let annotations = scene.annotation_map.find(ent_path);
         let default_color = DefaultColor::EntityPath(ent_path);
@@ -47,7 +61,11 @@ impl Points2DPart {
             .primitives
             .points
             .batch("2d points")
-            .world_from_obj(world_from_obj)
+            .world_from_obj(if distortion.is_some() {
+                Mat4::IDENTITY
+            } else {
+                world_from_obj
+            })
             .outline_mask_ids(entity_highlight.overall);
 
         // TODO(andreas): This should follow the same batch processing as points3d.
@@ -57,7 +75,12 @@ impl Points2DPart {
                        radius: Option<Radius>,
                        label: Option<Label>,
                        class_id: Option<ClassId>,
-                       keypoint_id: Option<KeypointId>| {
+                       keypoint_id: Option<KeypointId>,
+                       visibility: Option<KeypointVisibility>| {
+            if visibility.map_or(false, |visibility| !visibility.is_labeled()) {
+                return;
+            }
+
             let picking_instance_hash = instance_path_hash_for_picking(
                 ent_path,
                 instance_key,
@@ -77,19 +100,39 @@ impl Points2DPart {
                         keypoints
                             .entry((class_id, 0))
                             .or_insert_with(Default::default)
-                            .insert(keypoint_id, pos.extend(0.0));
+                            .insert(
+                                keypoint_id,
+                                Keypoint {
+                                    position: pos.extend(0.0),
+                                    is_occluded: visibility
+                                        .map_or(false, |visibility| visibility.is_occluded()),
+                                },
+                            );
                     }
                     class_description.annotation_info_with_keypoint(keypoint_id)
                 },
             );
 
-            let color =
-                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color);
+            let color = keypoint_color(
+                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color),
+                visibility,
+            );
             let radius = radius.map_or(Size::AUTO, |r| Size::new_scene(r.0));
-            let label = annotation_info.label(label.map(|l| l.0).as_ref());
-
-            let point_range_builder = point_batch
-                .add_point_2d(pos)
+            let label = annotation_info
+                .label(label.map(|l| l.0).as_ref())
+                .map(|label| occluded_keypoint_label(label, visibility));
+
+            let point_range_builder = if let Some(distortion) = distortion {
+                // Points the camera can't see are moved to NaN, so they don't show up.
+                point_batch.add_point(
+                    distortion
+                        .transform_point3(pos.extend(0.0))
+                        .unwrap_or(glam::Vec3::NAN),
+                )
+            } else {
+                point_batch.add_point_2d(pos)
+            };
+            let point_range_builder = point_range_builder
                 .color(color)
                 .radius(radius)
                 .user_data(picking_instance_hash);
@@ -111,7 +154,7 @@ impl Points2DPart {
             }
         };
 
-        entity_view.visit6(visitor)?;
+        entity_view.visit7(visitor)?;
         drop(point_batch); // Drop batch so we have access to the scene again (batches need to be dropped before starting new ones).
 
         if label_batch.len() < max_num_labels {
@@ -140,9 +183,10 @@ impl ScenePart for Points2DPart {
             let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
                 continue;
             };
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
-            match query_primary_with_history::<Point2D, 7>(
+            match query_primary_with_history::<Point2D, 8>(
                 &ctx.log_db.entity_db.data_store,
                 &query.timeline,
                 &query.latest_at,
@@ -156,6 +200,7 @@ impl ScenePart for Points2DPart {
                     Label::name(),
                     ClassId::name(),
                     KeypointId::name(),
+                    KeypointVisibility::name(),
                 ],
             )
             .and_then(|entities| {
@@ -167,6 +212,7 @@ impl ScenePart for Points2DPart {
                         &entity,
                         ent_path,
                         world_from_obj,
+                        distortion,
                         entity_highlight,
                     )?;
                 }
@@ -5,19 +5,27 @@ use glam::Mat4;
 
 use re_data_store::{EntityPath, EntityProperties, InstancePathHash};
 use re_log_types::{
-    component_types::{ClassId, ColorRGBA, InstanceKey, KeypointId, Label, Point3D, Radius},
+    component_types::{
+        ClassId, ColorRGBA, InstanceKey, KeypointId, KeypointVisibility, Label, Point3D, Radius,
+    },
     Component,
 };
 use re_query::{query_primary_with_history, EntityView, QueryError};
 use re_renderer::Size;
 
 use crate::{
-    misc::{SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache, ViewerContext},
+    misc::{
+        DistortedTransform, SpaceViewHighlights, SpaceViewOutlineMasks, TransformCache,
+        ViewerContext,
+    },
     ui::{
         annotations::ResolvedAnnotationInfo,
         scene::SceneQuery,
         view_spatial::{
-            scene::{scene_part::instance_path_hash_for_picking, Keypoints},
+            scene::{
+                keypoint_color, occluded_keypoint_label,
+                scene_part::instance_path_hash_for_picking, Keypoint, Keypoints,
+            },
             SceneSpatial, UiLabel, UiLabelTarget,
         },
         Annotations, DefaultColor,
@@ -29,6 +37,9 @@ use super::ScenePart;
 pub struct Points3DPart {
     /// If the number of points in the batch is > max_labels, don't render point labels.
     pub(crate) max_labels: usize,
+
+    /// Same as [`Self::max_labels`], but for batches of keypoints, which typically all have a label.
+    pub(crate) max_keypoint_labels: usize,
 }
 
 impl Points3DPart {
@@ -36,6 +47,7 @@ impl Points3DPart {
         query: &SceneQuery<'_>,
         entity_view: &EntityView<Point3D>,
         annotations: &Arc<Annotations>,
+        visibilities: &[Option<KeypointVisibility>],
     ) -> Result<(Vec<ResolvedAnnotationInfo>, Keypoints), QueryError> {
         crate::profile_function!();
 
@@ -54,17 +66,27 @@ impl Points3DPart {
             entity_view.iter_primary()?,
             entity_view.iter_component::<KeypointId>()?,
             entity_view.iter_component::<ClassId>()?,
+            visibilities,
         )
-        .map(|(position, keypoint_id, class_id)| {
+        .map(|(position, keypoint_id, class_id, visibility)| {
             let class_description = annotations.class_description(class_id);
 
             if let (Some(keypoint_id), Some(class_id), Some(position)) =
                 (keypoint_id, class_id, position)
             {
-                keypoints
-                    .entry((class_id, query.latest_at.as_i64()))
-                    .or_insert_with(Default::default)
-                    .insert(keypoint_id, position.into());
+                if visibility.map_or(true, |visibility| visibility.is_labeled()) {
+                    keypoints
+                        .entry((class_id, query.latest_at.as_i64()))
+                        .or_insert_with(Default::default)
+                        .insert(
+                            keypoint_id,
+                            Keypoint {
+                                position: position.into(),
+                                is_occluded: visibility
+                                    .map_or(false, |visibility| visibility.is_occluded()),
+                            },
+                        );
+                }
                 class_description.annotation_info_with_keypoint(keypoint_id)
             } else {
                 class_description.annotation_info()
@@ -79,6 +101,7 @@ impl Points3DPart {
         entity_view: &'a EntityView<Point3D>,
         ent_path: &'a EntityPath,
         annotation_infos: &'a [ResolvedAnnotationInfo],
+        visibilities: &'a [Option<KeypointVisibility>],
     ) -> Result<impl Iterator<Item = egui::Color32> + 'a, QueryError> {
         crate::profile_function!();
         let default_color = DefaultColor::EntityPath(ent_path);
@@ -86,9 +109,13 @@ impl Points3DPart {
         let colors = itertools::izip!(
             annotation_infos.iter(),
             entity_view.iter_component::<ColorRGBA>()?,
+            visibilities,
         )
-        .map(move |(annotation_info, color)| {
-            annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color)
+        .map(move |(annotation_info, color, visibility)| {
+            keypoint_color(
+                annotation_info.color(color.map(move |c| c.to_array()).as_ref(), default_color),
+                *visibility,
+            )
         });
         Ok(colors)
     }
@@ -121,7 +148,9 @@ impl Points3DPart {
         instance_path_hashes: &'a [InstancePathHash],
         colors: &'a [egui::Color32],
         annotation_infos: &'a [ResolvedAnnotationInfo],
+        visibilities: &'a [Option<KeypointVisibility>],
         world_from_obj: Mat4,
+        distortion: Option<&'a DistortedTransform>,
     ) -> Result<impl Iterator<Item = UiLabel> + 'a, QueryError> {
         let labels = itertools::izip!(
             annotation_infos.iter(),
@@ -129,19 +158,30 @@ impl Points3DPart {
             entity_view.iter_component::<Label>()?,
             colors,
             instance_path_hashes,
+            visibilities,
         )
         .filter_map(
-            move |(annotation_info, point, label, color, labeled_instance)| {
-                let label = annotation_info.label(label.map(|l| l.0).as_ref());
+            move |(annotation_info, point, label, color, labeled_instance, visibility)| {
+                if visibility.map_or(false, |visibility| !visibility.is_labeled()) {
+                    return None;
+                }
+                let label = annotation_info
+                    .label(label.map(|l| l.0).as_ref())
+                    .map(|label| occluded_keypoint_label(label, *visibility));
                 match (point, label) {
-                    (Some(point), Some(label)) => Some(UiLabel {
-                        text: label,
-                        color: *color,
-                        target: UiLabelTarget::Position3D(
-                            world_from_obj.transform_point3(point.into()),
-                        ),
-                        labeled_instance: *labeled_instance,
-                    }),
+                    (Some(point), Some(label)) => {
+                        let position = if let Some(distortion) = distortion {
+                            distortion.transform_point3(point.into())?
+                        } else {
+                            world_from_obj.transform_point3(point.into())
+                        };
+                        Some(UiLabel {
+                            text: label,
+                            color: *color,
+                            target: UiLabelTarget::Position3D(position),
+                            labeled_instance: *labeled_instance,
+                        })
+                    }
                     _ => None,
                 }
             },
@@ -158,6 +198,7 @@ impl Points3DPart {
         entity_view: &EntityView<Point3D>,
         ent_path: &EntityPath,
         world_from_obj: Mat4,
+        distortion: Option<&DistortedTransform>,
         entity_highlight: &SpaceViewOutlineMasks,
     ) -> Result<(), QueryError> {
         crate::profile_function!();
@@ -167,15 +208,36 @@ impl Points3DPart {
         let annotations = scene.annotation_map.find(ent_path);
         let show_labels = true;
 
+        let visibilities = entity_view
+            .iter_component::<KeypointVisibility>()?
+            .collect::<Vec<_>>();
+
         let point_positions = {
             crate::profile_scope!("collect_points");
             entity_view
                 .iter_primary()?
-                .filter_map(|pt| pt.map(glam::Vec3::from))
+                .zip(visibilities.iter())
+                .filter_map(|(pt, visibility)| {
+                    let pt = glam::Vec3::from(pt?);
+                    // Keypoints that were not labeled are moved to NaN, so they don't show up.
+                    Some(
+                        if visibility.map_or(false, |visibility| !visibility.is_labeled()) {
+                            glam::Vec3::NAN
+                        } else {
+                            pt
+                        },
+                    )
+                })
+                .map(move |pt| match distortion {
+                    // Points the camera can't see are moved to NaN, so they don't show up
+                    // while still lining up with the other components.
+                    Some(distortion) => distortion.transform_point3(pt).unwrap_or(glam::Vec3::NAN),
+                    None => pt,
+                })
         };
 
         let (annotation_infos, keypoints) =
-            Self::process_annotations(query, entity_view, &annotations)?;
+            Self::process_annotations(query, entity_view, &annotations, &visibilities)?;
         let instance_path_hashes_for_picking = {
             crate::profile_scope!("instance_hashes");
             entity_view
@@ -192,20 +254,28 @@ impl Points3DPart {
                 .collect::<Vec<_>>()
         };
 
-        let colors = Self::process_colors(entity_view, ent_path, &annotation_infos)?;
+        let colors = Self::process_colors(entity_view, ent_path, &annotation_infos, &visibilities)?;
         let radii = Self::process_radii(ent_path, entity_view)?;
 
-        if show_labels && instance_path_hashes_for_picking.len() <= self.max_labels {
+        let max_labels = if entity_view.has_component::<KeypointId>() {
+            self.max_keypoint_labels
+        } else {
+            self.max_labels
+        };
+        if show_labels && instance_path_hashes_for_picking.len() <= max_labels {
             // Max labels is small enough that we can afford iterating on the colors again.
             let colors =
-                Self::process_colors(entity_view, ent_path, &annotation_infos)?.collect::<Vec<_>>();
+                Self::process_colors(entity_view, ent_path, &annotation_infos, &visibilities)?
+                    .collect::<Vec<_>>();
 
             scene.ui.labels.extend(Self::process_labels(
                 entity_view,
                 &instance_path_hashes_for_picking,
                 &colors,
                 &annotation_infos,
+                &visibilities,
                 world_from_obj,
+                distortion,
             )?);
         }
 
@@ -214,7 +284,11 @@ impl Points3DPart {
                 .primitives
                 .points
                 .batch("3d points")
-                .world_from_obj(world_from_obj)
+                .world_from_obj(if distortion.is_some() {
+                    Mat4::IDENTITY
+                } else {
+                    world_from_obj
+                })
                 .outline_mask_ids(entity_highlight.overall);
             let mut point_range_builder = point_batch
                 .add_points(entity_view.num_instances(), point_positions)
@@ -262,9 +336,10 @@ impl ScenePart for Points3DPart {
             let Some(world_from_obj) = transforms.reference_from_entity(ent_path) else {
                 continue;
             };
+            let distortion = transforms.distorted_transform(ent_path);
             let entity_highlight = highlights.entity_outline_mask(ent_path.hash());
 
-            match query_primary_with_history::<Point3D, 7>(
+            match query_primary_with_history::<Point3D, 8>(
                 &ctx.log_db.entity_db.data_store,
                 &query.timeline,
                 &query.latest_at,
@@ -278,6 +353,7 @@ impl ScenePart for Points3DPart {
                     Label::name(),
                     ClassId::name(),
                     KeypointId::name(),
+                    KeypointVisibility::name(),
                 ],
             )
             .and_then(|entities| {
@@ -289,6 +365,7 @@ impl ScenePart for Points3DPart {
                         &entity,
                         ent_path,
                         world_from_obj,
+                        distortion,
                         entity_highlight,
                     )?;
                 }
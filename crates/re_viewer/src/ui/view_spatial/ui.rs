@@ -9,7 +9,8 @@ use re_renderer::OutlineConfig;
 
 use crate::{
     misc::{
-        space_info::query_view_coordinates, SelectionHighlight, SpaceViewHighlights, ViewerContext,
+        space_info::query_view_coordinates, SelectionHighlight, SpaceViewHighlights,
+        TransformCache, ViewerContext,
     },
     ui::{
         data_blueprint::DataBlueprintTree, space_view::ScreenshotMode, view_spatial::UiLabelTarget,
@@ -19,6 +20,7 @@ use crate::{
 
 use super::{
     eye::Eye,
+    measurements::MeasurementsState,
     scene::{PickingResult, SceneSpatialUiData},
     ui_2d::View2DState,
     ui_3d::View3DState,
@@ -87,6 +89,9 @@ pub struct ViewSpatialState {
     pub(super) state_2d: View2DState,
     pub(super) state_3d: View3DState,
 
+    /// Distances, angles & areas measured in this view.
+    pub measurements: MeasurementsState,
+
     /// Size of automatically sized objects. None if it wasn't configured.
     auto_size_config: re_renderer::AutoSizeConfig,
 }
@@ -100,6 +105,7 @@ impl Default for ViewSpatialState {
             scene_num_primitives: 0,
             state_2d: Default::default(),
             state_3d: Default::default(),
+            measurements: Default::default(),
             auto_size_config: re_renderer::AutoSizeConfig {
                 point_radius: re_renderer::Size::AUTO, // let re_renderer decide
                 line_radius: re_renderer::Size::AUTO,  // let re_renderer decide
@@ -350,6 +356,51 @@ impl ViewSpatialState {
                     ui.checkbox(&mut self.state_3d.show_bbox, "Show bounding box").on_hover_text("Show the current scene bounding box");
                 });
                 ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Follow")
+                    .on_hover_text("Keep the camera moving along with an entity or a logged camera.");
+                ui.vertical(|ui| {
+                    self.state_3d.follow_ui(ctx, ui, data_blueprint);
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Lighting")
+                    .on_hover_text("The directional light used for shading meshes.");
+                ui.vertical(|ui| {
+                    ui.checkbox(&mut self.state_3d.light_follows_camera, "Light follows camera")
+                        .on_hover_text("If enabled, the light always shines from behind the camera.\nOtherwise it shines from above.");
+                    ui.checkbox(&mut self.state_3d.show_shadows, "Shadows")
+                        .on_hover_text("Meshes cast shadows onto each other");
+                });
+                ui.end_row();
+            }
+
+            ctx.re_ui.grid_left_hand_label(ui, "Measure")
+                .on_hover_text("Measure distances, angles and areas by clicking points in the view.");
+            ui.vertical(|ui| {
+                let was_measuring = self.measurements.is_active();
+                self.measurements.settings_ui(ctx, ui, space_view_id);
+                if self.measurements.is_active() && !was_measuring {
+                    self.state_2d.annotation_tools.deactivate();
+                }
+                if *self.nav_mode.get() == SpatialNavigationMode::TwoD {
+                    ui.checkbox(&mut self.measurements.show_pixel_probe, "Pixel probe")
+                        .on_hover_text("Show the values of all images under the cursor.");
+                }
+            });
+            ui.end_row();
+
+            if *self.nav_mode.get() == SpatialNavigationMode::TwoD {
+                ctx.re_ui.grid_left_hand_label(ui, "Annotate")
+                    .on_hover_text("Draw new points, boxes and polygons, which are logged into the recording.");
+                ui.vertical(|ui| {
+                    let was_annotating = self.state_2d.annotation_tools.is_active();
+                    self.state_2d.annotation_tools.settings_ui(ctx, ui, space_path);
+                    if self.state_2d.annotation_tools.is_active() && !was_annotating {
+                        self.measurements.deactivate();
+                    }
+                });
+                ui.end_row();
             }
 
             ctx.re_ui.grid_left_hand_label(ui, "Bounding box")
@@ -386,6 +437,7 @@ impl ViewSpatialState {
         ui: &mut egui::Ui,
         space: &EntityPath,
         scene: SceneSpatial,
+        transforms: &TransformCache,
         space_view_id: SpaceViewId,
         highlights: &SpaceViewHighlights,
     ) {
@@ -406,6 +458,7 @@ impl ViewSpatialState {
                 let coordinates =
                     query_view_coordinates(&ctx.log_db.entity_db, space, &ctx.current_query());
                 self.state_3d.space_specs = SpaceSpecs::from_view_coordinates(coordinates);
+                self.state_3d.update_followed_entity(transforms);
                 super::view_3d(ctx, ui, self, space, space_view_id, scene, highlights);
             }
             SpatialNavigationMode::TwoD => {
@@ -8,7 +8,9 @@ use re_log_types::component_types::TensorTrait;
 use re_renderer::view_builder::{TargetConfiguration, ViewBuilder};
 
 use super::{
+    annotation_tools::AnnotationToolsState,
     eye::Eye,
+    measurements::pixel_probe_values,
     scene::AdditionalPickingInfo,
     ui::{create_labels, screenshot_context_menu, PICKING_RECT_SIZE},
     SpatialNavigationMode, ViewSpatialState,
@@ -37,6 +39,9 @@ pub struct View2DState {
     /// The zoom and pan state, which is either a zoom/center or `Auto` which will fill the screen
     #[serde(skip)]
     zoom: ZoomState2D,
+
+    /// Tools for drawing new shapes on top of the view.
+    pub annotation_tools: AnnotationToolsState,
 }
 
 #[derive(Clone, Copy, Default)]
@@ -103,12 +108,15 @@ impl View2DState {
 
     /// Update our zoom state based on response
     /// If nothing else happens this will reset `accepting_scroll` to true when appropriate
+    ///
+    /// While `is_drawing`, dragging & double-clicking is left to the drawing tools.
     fn update(
         &mut self,
         response: &egui::Response,
         ui_to_space: egui::emath::RectTransform,
         scene_rect_accum: Rect,
         available_size: Vec2,
+        is_drawing: bool,
     ) {
         // Determine if we are zooming
         let zoom_delta = response.ctx.input(|i| i.zoom_delta());
@@ -130,7 +138,13 @@ impl View2DState {
                             accepting_scroll: false,
                         };
                         // Recursively update now that we have initialized `ZoomState` to `Scaled`
-                        self.update(response, ui_to_space, scene_rect_accum, available_size);
+                        self.update(
+                            response,
+                            ui_to_space,
+                            scene_rect_accum,
+                            available_size,
+                            is_drawing,
+                        );
                     }
                 }
             }
@@ -164,7 +178,7 @@ impl View2DState {
                 }
 
                 // If we are dragging, adjust the center accordingly
-                if response.dragged_by(egui::PointerButton::Primary) {
+                if response.dragged_by(egui::PointerButton::Primary) && !is_drawing {
                     // Adjust center based on drag
                     center -= response.drag_delta() / scale;
                     accepting_scroll = false;
@@ -182,7 +196,7 @@ impl View2DState {
         // Process things that might reset ZoomState to Auto
         if let ZoomState2D::Scaled { scale, .. } = self.zoom {
             // If the user double-clicks
-            if response.double_clicked() {
+            if response.double_clicked() && !is_drawing {
                 self.zoom = ZoomState2D::Auto;
             }
 
@@ -218,7 +232,10 @@ impl View2DState {
 
 pub const HELP_TEXT_2D: &str = "Ctrl-scroll  to zoom (⌘-scroll or Mac).\n\
     Drag to pan.\n\
-    Double-click to reset the view.";
+    Double-click to reset the view.\n\
+    \n\
+    Select a measurement or annotation tool in the selection panel to draw in the view.\n\
+    Press Escape to discard what is being drawn.";
 
 /// Create the outer 2D view, which consists of a scrollable region
 /// TODO(andreas): Split into smaller parts, more re-use with `ui_3d`
@@ -302,9 +319,18 @@ fn view_2d_scrollable(
     let points_from_pixels = 1.0 / painter.ctx().pixels_per_point();
     let space_from_pixel = space_from_points * points_from_pixels;
 
-    state
-        .state_2d
-        .update(&response, space_from_ui, scene_rect_accum, available_size);
+    // A click may only drive one tool, the annotation tools take precedence over measuring.
+    if state.state_2d.annotation_tools.is_active() {
+        state.measurements.deactivate();
+    }
+    let is_drawing = state.state_2d.annotation_tools.is_active() || state.measurements.is_active();
+    state.state_2d.update(
+        &response,
+        space_from_ui,
+        scene_rect_accum,
+        available_size,
+        is_drawing,
+    );
 
     let eye = Eye {
         world_from_view: IsoTransform::IDENTITY,
@@ -320,6 +346,7 @@ fn view_2d_scrollable(
         scene
             .primitives
             .any_outlines,
+        ctx.app_options.transparency_mode(),
     ) else {
         return response;
     };
@@ -468,13 +495,57 @@ fn view_2d_scrollable(
         state.previous_picking_result = None;
     }
 
-    ctx.select_hovered_on_click(&response);
+    if let (true, Some(pointer_pos_ui)) =
+        (state.measurements.show_pixel_probe, response.hover_pos())
+    {
+        let space_pos = space_from_ui.transform_pos(pointer_pos_ui);
+        let values = pixel_probe_values(ctx, &scene.ui.images, space_pos);
+        if !values.is_empty() {
+            response = response.on_hover_ui_at_pointer(|ui| {
+                egui::Grid::new("pixel_probe")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        for (entity_path, [x, y], value) in values {
+                            ui.label(entity_path);
+                            ui.monospace(format!("[{x}, {y}]"));
+                            ui.monospace(value);
+                            ui.end_row();
+                        }
+                    });
+            });
+        }
+    }
+
+    // While drawing, clicks are used by the tools instead of for selection.
+    let annotation_shapes =
+        state
+            .state_2d
+            .annotation_tools
+            .interact(ctx, &response, space, ui_from_space);
+    let pointer_in_space = response
+        .interact_pointer_pos()
+        .or_else(|| response.hover_pos())
+        .map(|pos| {
+            let pos = space_from_ui.transform_pos(pos);
+            glam::vec3(pos.x, pos.y, 0.0)
+        });
+    state.measurements.interact(&response, pointer_in_space);
+    let measurement_shapes =
+        state
+            .measurements
+            .shapes(ctx, parent_ui, &response, space_view_id, |pos| {
+                Some(ui_from_space.transform_pos(pos2(pos.x, pos.y)))
+            });
+    if !is_drawing {
+        ctx.send_click_event(&response, pointer_in_space);
+        ctx.select_hovered_on_click(&response);
+    }
 
     // ------------------------------------------------------------------------
 
     // Screenshot context menu.
     let (response, screenshot_mode) = screenshot_context_menu(ctx, response);
-    if let Some(mode) = screenshot_mode {
+    if let Some(mode) = screenshot_mode.or_else(|| ctx.take_screenshot_request(space_view_id)) {
         let _ =
             view_builder.schedule_screenshot(ctx.render_ctx, space_view_id.gpu_readback_id(), mode);
     }
@@ -513,6 +584,8 @@ fn view_2d_scrollable(
 
     // Add egui driven labels on top of re_renderer content.
     painter.extend(label_shapes);
+    painter.extend(annotation_shapes);
+    painter.extend(measurement_shapes);
 
     response
 }
@@ -524,6 +597,7 @@ fn setup_target_config(
     space_name: &str,
     auto_size_config: re_renderer::AutoSizeConfig,
     any_outlines: bool,
+    transparency_mode: re_renderer::TransparencyMode,
 ) -> anyhow::Result<TargetConfiguration> {
     let pixels_from_points = painter.ctx().pixels_per_point();
     let resolution_in_pixel = get_viewport(painter.clip_rect(), pixels_from_points);
@@ -547,6 +621,9 @@ fn setup_target_config(
             pixels_from_point: pixels_from_points,
             auto_size_config,
             outline_config: any_outlines.then(|| outline_config(painter.ctx())),
+            transparency_mode,
+            light: Default::default(),
+            shadow_map_config: None,
         }
     })
 }
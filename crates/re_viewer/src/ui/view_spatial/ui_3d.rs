@@ -1,18 +1,20 @@
 use eframe::emath::RectTransform;
 use egui::NumExt as _;
 use glam::Affine3A;
-use macaw::{vec3, BoundingBox, Quat, Vec3};
+use itertools::Itertools as _;
+use macaw::{vec3, BoundingBox, IsoTransform, Quat, Vec3};
 
 use re_data_store::{InstancePath, InstancePathHash};
 use re_log_types::{EntityPath, ViewCoordinates};
 use re_renderer::{
     view_builder::{Projection, TargetConfiguration, ViewBuilder},
-    Size,
+    DirectionalLight, ShadowMapConfig, Size,
 };
 
 use crate::{
-    misc::{HoveredSpace, Item, SpaceViewHighlights},
+    misc::{HoveredSpace, Item, SpaceViewHighlights, TransformCache},
     ui::{
+        data_blueprint::DataBlueprintTree,
         data_ui::{self, DataUi},
         view_spatial::{
             scene::AdditionalPickingInfo,
@@ -46,6 +48,9 @@ pub struct View3DState {
     /// Camera pose just before we took over another camera via [Self::tracked_camera].
     camera_before_tracked_camera: Option<Eye>,
 
+    /// Entity the eye moves along with.
+    followed_entity: Option<FollowedEntity>,
+
     #[serde(skip)]
     eye_interpolation: Option<EyeInterpolation>,
 
@@ -57,6 +62,8 @@ pub struct View3DState {
     pub spin: bool,
     pub show_axes: bool,
     pub show_bbox: bool,
+    pub light_follows_camera: bool,
+    pub show_shadows: bool,
 
     #[serde(skip)]
     last_eye_interact_time: f64,
@@ -74,11 +81,14 @@ impl Default for View3DState {
             orbit_eye: Default::default(),
             tracked_camera: None,
             camera_before_tracked_camera: None,
+            followed_entity: None,
             eye_interpolation: Default::default(),
             hovered_point: Default::default(),
             spin: false,
             show_axes: false,
             show_bbox: false,
+            light_follows_camera: true,
+            show_shadows: false,
             last_eye_interact_time: f64::NEG_INFINITY,
             space_specs: Default::default(),
             space_camera: Default::default(),
@@ -87,10 +97,202 @@ impl Default for View3DState {
 }
 
 impl View3DState {
+    fn light(&self) -> DirectionalLight {
+        if self.light_follows_camera {
+            DirectionalLight::camera_headlight()
+        } else {
+            // Light from above, slightly tilted so that vertical surfaces aren't all lit the same.
+            let up = self.space_specs.up.unwrap_or(Vec3::Z);
+            let sideways = self
+                .space_specs
+                .right
+                .unwrap_or_else(|| up.any_orthonormal_vector());
+            DirectionalLight {
+                direction: up * 2.0 + sideways,
+                ..Default::default()
+            }
+        }
+    }
+
     pub fn reset_camera(&mut self, scene_bbox_accum: &BoundingBox) {
         self.interpolate_to_orbit_eye(default_eye(scene_bbox_accum, &self.space_specs));
         self.tracked_camera = None;
         self.camera_before_tracked_camera = None;
+        self.followed_entity = None;
+    }
+
+    /// Takes over the view of a logged camera and stays locked to it as it moves.
+    fn track_camera(&mut self, instance_path: InstancePath) {
+        self.tracked_camera = None;
+        self.camera_before_tracked_camera = None;
+
+        if let Some(camera) = find_camera(&self.space_camera, &instance_path.hash()) {
+            self.camera_before_tracked_camera = self.orbit_eye.map(|eye| eye.to_eye());
+            self.interpolate_to_eye(camera);
+            self.tracked_camera = Some(instance_path);
+            self.followed_entity = None;
+        }
+    }
+
+    /// Starts (or stops with `None`) moving the eye along with an entity.
+    pub fn follow_entity(&mut self, entity_path: Option<EntityPath>) {
+        self.followed_entity = entity_path.map(|entity_path| FollowedEntity {
+            entity_path,
+            mode: FollowMode::default(),
+            offset: Vec3::ZERO,
+            world_from_follow_frame: None,
+        });
+        if self.followed_entity.is_some() {
+            self.tracked_camera = None;
+            self.camera_before_tracked_camera = None;
+            self.eye_interpolation = None;
+        }
+    }
+
+    /// Moves the eye along with the followed entity, if any.
+    ///
+    /// Needs to be called every frame before the eye is updated.
+    pub fn update_followed_entity(&mut self, transforms: &TransformCache) {
+        let up = self
+            .space_specs
+            .up
+            .and_then(|up| up.try_normalize())
+            .unwrap_or(Vec3::Z);
+        let (Some(followed), Some(orbit_eye)) = (&mut self.followed_entity, &mut self.orbit_eye) else {
+            return;
+        };
+        let Some(world_from_entity) = transforms.reference_from_entity(&followed.entity_path) else {
+            return;
+        };
+        let world_from_follow_frame = followed.mode.world_from_follow_frame(world_from_entity, up);
+
+        if let Some(previous) = followed.world_from_follow_frame {
+            // Whatever the user did with the eye since the last frame is relative to where the entity was back then.
+            followed.offset = previous.inverse().transform_point3(orbit_eye.orbit_center);
+            let rotation_delta = world_from_follow_frame.rotation() * previous.rotation().inverse();
+            orbit_eye.world_from_view_rot = rotation_delta * orbit_eye.world_from_view_rot;
+        }
+        orbit_eye.orbit_center = world_from_follow_frame.transform_point3(followed.offset);
+        followed.world_from_follow_frame = Some(world_from_follow_frame);
+    }
+
+    /// Ui for choosing an entity to follow or a camera to take over.
+    pub fn follow_ui(
+        &mut self,
+        ctx: &mut ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        data_blueprint: &DataBlueprintTree,
+    ) {
+        let current_path = self
+            .followed_entity
+            .as_ref()
+            .map(|followed| followed.entity_path.clone());
+        let mut followed_path = current_path.clone();
+        egui::ComboBox::from_id_source("followed_entity")
+            .selected_text(
+                followed_path
+                    .as_ref()
+                    .map_or_else(|| "Nothing".to_owned(), |path| path.to_string()),
+            )
+            .show_ui(ui, |ui| {
+                ui.style_mut().wrap = Some(false);
+                ui.selectable_value(&mut followed_path, None, "Nothing");
+                for entity_path in data_blueprint.entity_paths().iter().sorted() {
+                    ui.selectable_value(
+                        &mut followed_path,
+                        Some(entity_path.clone()),
+                        entity_path.to_string(),
+                    );
+                }
+            })
+            .response
+            .on_hover_text("Moves the camera along with this entity as it moves over time.");
+        if followed_path != current_path {
+            self.follow_entity(followed_path);
+        }
+
+        if let Some(followed) = &mut self.followed_entity {
+            let mut mode = followed.mode;
+            egui::ComboBox::from_id_source("follow_mode")
+                .selected_text(mode.to_string())
+                .show_ui(ui, |ui| {
+                    ui.style_mut().wrap = Some(false);
+                    for proposed in [FollowMode::Position, FollowMode::PositionAndHeading] {
+                        ui.selectable_value(&mut mode, proposed, proposed.to_string());
+                    }
+                });
+            if mode != followed.mode {
+                followed.mode = mode;
+                // The offset is relative to a different frame now, start over at the entity.
+                followed.offset = Vec3::ZERO;
+                followed.world_from_follow_frame = None;
+            }
+
+            let mut offset = followed.offset;
+            let speed = (offset.length() * 0.01).at_least(0.01);
+            let changed = ui
+                .horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut offset.x)
+                            .speed(speed)
+                            .prefix("x: "),
+                    )
+                    .changed()
+                        | ui.add(
+                            egui::DragValue::new(&mut offset.y)
+                                .speed(speed)
+                                .prefix("y: "),
+                        )
+                        .changed()
+                        | ui.add(
+                            egui::DragValue::new(&mut offset.z)
+                                .speed(speed)
+                                .prefix("z: "),
+                        )
+                        .changed()
+                })
+                .inner;
+            if changed {
+                followed.offset = offset;
+                if let (Some(world_from_follow_frame), Some(orbit_eye)) =
+                    (followed.world_from_follow_frame, &mut self.orbit_eye)
+                {
+                    orbit_eye.orbit_center = world_from_follow_frame.transform_point3(offset);
+                }
+            }
+        }
+
+        let cameras = self
+            .space_camera
+            .iter()
+            .filter_map(|camera| camera.instance_path_hash.resolve(&ctx.log_db.entity_db))
+            .collect::<Vec<_>>();
+        if !cameras.is_empty() {
+            let mut tracked_camera = self.tracked_camera.clone();
+            egui::ComboBox::from_id_source("tracked_camera")
+                .selected_text(
+                    tracked_camera
+                        .as_ref()
+                        .map_or_else(|| "Jump to camera".to_owned(), |path| path.to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    ui.style_mut().wrap = Some(false);
+                    for camera in cameras {
+                        let label = camera.to_string();
+                        ui.selectable_value(&mut tracked_camera, Some(camera), label);
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Takes over the view of a logged camera and stays with it as it moves.\n\
+                    You can restore the previous view with Escape.",
+                );
+            if tracked_camera != self.tracked_camera {
+                if let Some(camera) = tracked_camera {
+                    self.track_camera(camera);
+                }
+            }
+        }
     }
 
     fn update_eye(
@@ -191,6 +393,61 @@ impl View3DState {
     }
 }
 
+/// How the eye moves along with a followed entity, see [`View3DState::follow_entity`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum FollowMode {
+    /// Follow only the position of the entity, keeping the orientation of the eye.
+    #[default]
+    Position,
+
+    /// Follow the position of the entity and turn along with its heading around the up axis.
+    PositionAndHeading,
+}
+
+impl std::fmt::Display for FollowMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FollowMode::Position => "Position",
+            FollowMode::PositionAndHeading => "Position & heading",
+        })
+    }
+}
+
+impl FollowMode {
+    /// The frame the eye is kept fixed in.
+    ///
+    /// For [`FollowMode::PositionAndHeading`] its x axis is the entity's heading and its z axis is `up`.
+    fn world_from_follow_frame(self, world_from_entity: glam::Mat4, up: Vec3) -> IsoTransform {
+        let position = world_from_entity.project_point3(Vec3::ZERO);
+        let rotation = match self {
+            FollowMode::Position => Quat::IDENTITY,
+            FollowMode::PositionAndHeading => {
+                // Heading is wherever the entity's x axis points, unless it points straight up.
+                let heading = [Vec3::X, Vec3::Y]
+                    .into_iter()
+                    .map(|axis| world_from_entity.transform_vector3(axis))
+                    .find_map(|direction| (direction - up * direction.dot(up)).try_normalize())
+                    .unwrap_or_else(|| up.any_orthonormal_vector());
+                Quat::from_mat3(&glam::Mat3::from_cols(heading, up.cross(heading), up))
+            }
+        };
+        IsoTransform::from_rotation_translation(rotation, position)
+    }
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+struct FollowedEntity {
+    entity_path: EntityPath,
+    mode: FollowMode,
+
+    /// Orbit center relative to the followed entity, in the frame given by [`FollowMode::world_from_follow_frame`].
+    offset: Vec3,
+
+    /// Frame of the followed entity in the previous frame.
+    #[serde(skip)]
+    world_from_follow_frame: Option<IsoTransform>,
+}
+
 #[derive(Clone)]
 struct EyeInterpolation {
     elapsed_time: f32,
@@ -256,6 +513,10 @@ pub const HELP_TEXT_3D: &str = "Drag to rotate.\n\
     Double-click an object to focus the view on it.\n\
     For cameras, you can restore the view again with Escape.\n\
     \n\
+    To keep a moving entity in view, follow it from the space view's selection panel.\n\
+    \n\
+    To measure, select a measurement tool in the selection panel and click on objects in the view.\n\
+    \n\
     Double-click on empty space to reset the view.";
 
 /// TODO(andreas): Split into smaller parts, more re-use with `ui_2d`
@@ -338,6 +599,12 @@ pub fn view_3d(
             .primitives
             .any_outlines
             .then(|| outline_config(ui.ctx())),
+        transparency_mode: ctx.app_options.transparency_mode(),
+        light: state.state_3d.light(),
+        shadow_map_config: state
+            .state_3d
+            .show_shadows
+            .then(|| ShadowMapConfig::new(state.scene_bbox)),
     };
 
     let mut view_builder = ViewBuilder::default();
@@ -362,6 +629,7 @@ pub fn view_3d(
 
     // TODO(andreas): We're very close making the hover reaction of ui2d and ui3d the same. Finish the job!
     // Check if we're hovering any hover primitive.
+    let mut pointer_in_space = None;
     if let (true, Some(pointer_pos)) = (should_do_hovering, response.hover_pos()) {
         // Schedule GPU picking.
         let pointer_in_pixel =
@@ -463,6 +731,7 @@ pub fn view_3d(
             .as_ref()
             .or_else(|| picking_result.transparent_hits.last())
             .map(|hit| picking_result.space_position(hit));
+        pointer_in_space = hovered_point;
 
         ctx.selection_state_mut()
             .set_hovered_space(HoveredSpace::ThreeD {
@@ -484,20 +753,30 @@ pub fn view_3d(
         state.previous_picking_result = None;
     }
 
-    ctx.select_hovered_on_click(&response);
+    // While measuring, clicks are used for placing points instead of for selection.
+    let is_drawing = state.measurements.is_active();
+    state.measurements.interact(&response, pointer_in_space);
+    let ui_from_world = eye.ui_from_world(&rect);
+    let measurement_shapes = state
+        .measurements
+        .shapes(ctx, ui, &response, space_view_id, |pos| {
+            let pos = ui_from_world * pos.extend(1.0);
+            (pos.w > 0.0).then(|| egui::pos2(pos.x / pos.w, pos.y / pos.w))
+        });
+    if !is_drawing {
+        ctx.send_click_event(&response, pointer_in_space);
+        ctx.select_hovered_on_click(&response);
+    }
 
     // Double click changes camera
-    if response.double_clicked() {
+    if response.double_clicked() && !is_drawing {
         state.state_3d.tracked_camera = None;
         state.state_3d.camera_before_tracked_camera = None;
 
         // While hovering an entity, focuses the camera on it.
         if let Some(Item::InstancePath(_, instance_path)) = ctx.hovered().first() {
-            if let Some(camera) = find_camera(&scene.space_cameras, &instance_path.hash()) {
-                state.state_3d.camera_before_tracked_camera =
-                    state.state_3d.orbit_eye.map(|eye| eye.to_eye());
-                state.state_3d.interpolate_to_eye(camera);
-                state.state_3d.tracked_camera = Some(instance_path.clone());
+            if find_camera(&scene.space_cameras, &instance_path.hash()).is_some() {
+                state.state_3d.track_camera(instance_path.clone());
             } else if let Some(clicked_point) = state.state_3d.hovered_point {
                 if let Some(mut new_orbit_eye) = state.state_3d.orbit_eye {
                     // TODO(andreas): It would be nice if we could focus on the center of the entity rather than the clicked point.
@@ -530,7 +809,7 @@ pub fn view_3d(
 
     // Screenshot context menu.
     let (_, screenshot_mode) = screenshot_context_menu(ctx, response);
-    if let Some(mode) = screenshot_mode {
+    if let Some(mode) = screenshot_mode.or_else(|| ctx.take_screenshot_request(space_view_id)) {
         let _ =
             view_builder.schedule_screenshot(ctx.render_ctx, space_view_id.gpu_readback_id(), mode);
     }
@@ -630,6 +909,7 @@ pub fn view_3d(
     // Add egui driven labels on top of re_renderer content.
     let painter = ui.painter().with_clip_rect(ui.max_rect());
     painter.extend(label_shapes);
+    painter.extend(measurement_shapes);
 }
 
 fn show_projections_from_2d_space(
@@ -2,4 +2,4 @@ mod scene;
 pub(crate) use self::scene::{SceneText, TextEntry};
 
 mod ui;
-pub(crate) use self::ui::{level_to_rich_text, view_text, ViewTextState};
+pub(crate) use self::ui::{level_color, level_to_rich_text, view_text, ViewTextState};
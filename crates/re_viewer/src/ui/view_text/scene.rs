@@ -27,6 +27,9 @@ pub struct TextEntry {
     pub level: Option<String>,
 
     pub body: String,
+
+    /// Structured key/value fields, in the order they were logged.
+    pub fields: Vec<(String, String)>,
 }
 
 /// A text scene, with everything needed to render it.
@@ -66,17 +69,19 @@ impl SceneText {
                 MsgId::name(),
                 component_types::TextEntry::name(),
                 component_types::ColorRGBA::name(),
+                component_types::TextEntryFields::name(),
             ];
-            let ent_views = range_entity_with_primary::<component_types::TextEntry, 4>(
+            let ent_views = range_entity_with_primary::<component_types::TextEntry, 5>(
                 store, &query, ent_path, components,
             );
 
             for (time, ent_view) in ent_views {
-                match ent_view.visit3(
+                match ent_view.visit4(
                     |_instance,
                      text_entry: component_types::TextEntry,
                      msg_id: Option<MsgId>,
-                     color: Option<component_types::ColorRGBA>| {
+                     color: Option<component_types::ColorRGBA>,
+                     fields: Option<component_types::TextEntryFields>| {
                         let component_types::TextEntry { body, level } = text_entry;
 
                         // Early filtering once more, see above.
@@ -92,6 +97,12 @@ impl SceneText {
                                 color: color.map(|c| c.to_array()),
                                 level,
                                 body,
+                                fields: fields
+                                    .unwrap_or_default()
+                                    .0
+                                    .into_iter()
+                                    .map(|field| (field.key, field.value))
+                                    .collect(),
                             });
                         }
                     },
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use egui::{Color32, RichText};
 
 use re_data_store::{EntityPath, Timeline};
-use re_log_types::TimePoint;
+use re_log_types::{component_types, TimeInt, TimePoint};
 
 use crate::ViewerContext;
 
@@ -22,9 +22,54 @@ pub struct ViewTextState {
 
     pub filters: ViewTextFilters,
 
+    search: TextSearch,
+
     monospace: bool,
 }
 
+/// Full-text search over the bodies and structured field values of the text entries.
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+struct TextSearch {
+    query: String,
+
+    /// Interpret [`Self::query`] as a regular expression rather than a plain, case-insensitive, substring.
+    is_regex: bool,
+
+    /// The compiled [`Self::query`], together with the query & mode it was compiled from.
+    #[serde(skip)]
+    compiled: Option<(String, bool, Result<regex::Regex, regex::Error>)>,
+}
+
+impl TextSearch {
+    /// The matcher for the current query, `None` if there is nothing to search for.
+    ///
+    /// Recompiles the query only if it changed since the last call.
+    fn matcher(&mut self) -> Option<&Result<regex::Regex, regex::Error>> {
+        if self.query.is_empty() {
+            return None;
+        }
+
+        let is_stale = self.compiled.as_ref().map_or(true, |(query, is_regex, _)| {
+            *query != self.query || *is_regex != self.is_regex
+        });
+        if is_stale {
+            let pattern = if self.is_regex {
+                self.query.clone()
+            } else {
+                format!("(?i){}", regex::escape(&self.query))
+            };
+            self.compiled = Some((
+                self.query.clone(),
+                self.is_regex,
+                regex::Regex::new(&pattern),
+            ));
+        }
+
+        self.compiled.as_ref().map(|(_, _, matcher)| matcher)
+    }
+}
+
 impl ViewTextState {
     pub fn selection_ui(&mut self, re_ui: &re_ui::ReUi, ui: &mut egui::Ui) {
         crate::profile_function!();
@@ -33,8 +78,10 @@ impl ViewTextState {
             col_timelines,
             col_entity_path,
             col_log_level,
+            col_fields,
             row_entity_paths,
             row_log_levels,
+            min_log_level,
         } = &mut self.filters;
 
         re_ui.selection_grid(ui, "log_config").show(ui, |ui| {
@@ -45,6 +92,9 @@ impl ViewTextState {
                 }
                 ui.checkbox(col_entity_path, "Entity path");
                 ui.checkbox(col_log_level, "Log level");
+                for (key, visible) in col_fields {
+                    ui.checkbox(visible, key.as_str());
+                }
             });
             ui.end_row();
 
@@ -56,6 +106,26 @@ impl ViewTextState {
             });
             ui.end_row();
 
+            re_ui.grid_left_hand_label(ui, "Min. Level");
+            egui::ComboBox::from_id_source("min_log_level")
+                .selected_text(min_log_level.as_deref().unwrap_or("All"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(min_log_level, None, "All");
+                    for level in component_types::TextEntry::RECOMMENDED_LEVELS {
+                        ui.selectable_value(
+                            min_log_level,
+                            Some(level.to_owned()),
+                            level_to_rich_text(ui, level),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Only show entries at least this severe.\n\
+                    Entries with no or a non-standard level are always shown.",
+                );
+            ui.end_row();
+
             re_ui.grid_left_hand_label(ui, "Level Filter");
             ui.vertical(|ui| {
                 for (log_level, visible) in row_log_levels {
@@ -95,29 +165,101 @@ pub(crate) fn view_text(
     // - If it did, autoscroll to the text log to reveal the current time.
     // - Otherwise, let the user scroll around freely!
     let time_cursor_moved = state.latest_time != time;
-    let scroll_to_row = time_cursor_moved.then(|| {
-        crate::profile_scope!("TextEntryState - search scroll time");
-        scene
-            .text_entries
-            .partition_point(|te| te.time.unwrap_or(i64::MIN) < time)
-    });
-
     state.latest_time = time;
 
     ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+        let text_entries = search_ui(ctx, ui, &mut state.search, &scene.text_entries, time);
+
+        let scroll_to_row = time_cursor_moved.then(|| {
+            crate::profile_scope!("TextEntryState - search scroll time");
+            text_entries.partition_point(|te| te.time.unwrap_or(i64::MIN) < time)
+        });
+
         egui::ScrollArea::horizontal().show(ui, |ui| {
             crate::profile_scope!("render table");
-            table_ui(ctx, ui, state, &scene.text_entries, scroll_to_row);
-        })
+            table_ui(ctx, ui, state, &text_entries, scroll_to_row);
+        });
     })
     .response
 }
 
+/// Shows the search bar and returns the entries matching the search.
+///
+/// The previous/next buttons move the time cursor to the closest matching entry before/after `time`.
+fn search_ui<'a>(
+    ctx: &mut ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    search: &mut TextSearch,
+    text_entries: &'a [TextEntry],
+    time: i64,
+) -> Vec<&'a TextEntry> {
+    crate::profile_function!();
+
+    ui.horizontal(|ui| {
+        ui.label("Search:");
+        ui.text_edit_singleline(&mut search.query);
+        ui.checkbox(&mut search.is_regex, "Regex").on_hover_text(
+            "Interpret the search as a regular expression.\n\
+                Otherwise the search is a case-insensitive substring match.",
+        );
+
+        let matches = match search.matcher() {
+            None => text_entries.iter().collect::<Vec<_>>(),
+            Some(Ok(matcher)) => text_entries
+                .iter()
+                .filter(|te| {
+                    matcher.is_match(&te.body)
+                        || te.fields.iter().any(|(_, value)| matcher.is_match(value))
+                })
+                .collect(),
+            Some(Err(err)) => {
+                ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+                text_entries.iter().collect()
+            }
+        };
+
+        if search.matcher().is_some() {
+            let match_times = || matches.iter().filter_map(|te| te.time);
+            let prev_time = match_times().filter(|t| *t < time).max();
+            let next_time = match_times().filter(|t| *t > time).min();
+
+            ui.label(format!("{} matches", matches.len()));
+
+            let prev_clicked = ui
+                .add_enabled(prev_time.is_some(), egui::Button::new("Previous"))
+                .on_hover_text("Move the time cursor to the previous match")
+                .clicked();
+            let next_clicked = ui
+                .add_enabled(next_time.is_some(), egui::Button::new("Next"))
+                .on_hover_text("Move the time cursor to the next match")
+                .clicked();
+
+            let jump_to = if prev_clicked {
+                prev_time
+            } else if next_clicked {
+                next_time
+            } else {
+                None
+            };
+            if let Some(jump_to) = jump_to {
+                let timeline = *ctx.rec_cfg.time_ctrl.timeline();
+                ctx.rec_cfg
+                    .time_ctrl
+                    .set_timeline_and_time(timeline, TimeInt::from(jump_to));
+                ctx.rec_cfg.time_ctrl.pause();
+            }
+        }
+
+        matches
+    })
+    .inner
+}
+
 // --- Filters ---
 
-// TODO(cmc): implement "body contains <value>" filter.
 // TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
 pub struct ViewTextFilters {
     // Column filters: which columns should be visible?
     // Timelines are special: each one has a dedicated column.
@@ -125,9 +267,16 @@ pub struct ViewTextFilters {
     pub col_entity_path: bool,
     pub col_log_level: bool,
 
+    /// Structured fields are special too: each key has a dedicated column.
+    pub col_fields: BTreeMap<String, bool>,
+
     // Row filters: which rows should be visible?
     pub row_entity_paths: BTreeMap<EntityPath, bool>,
     pub row_log_levels: BTreeMap<String, bool>,
+
+    /// Only show entries whose level is at least this severe,
+    /// see [`component_types::TextEntry::RECOMMENDED_LEVELS`].
+    pub min_log_level: Option<String>,
 }
 
 impl Default for ViewTextFilters {
@@ -136,8 +285,10 @@ impl Default for ViewTextFilters {
             col_entity_path: true,
             col_log_level: true,
             col_timelines: Default::default(),
+            col_fields: Default::default(),
             row_entity_paths: Default::default(),
             row_log_levels: Default::default(),
+            min_log_level: None,
         }
     }
 }
@@ -151,7 +302,17 @@ impl ViewTextFilters {
     }
 
     pub fn is_log_level_visible(&self, level: &str) -> bool {
-        self.row_log_levels.get(level).copied().unwrap_or(true)
+        let is_severe_enough = match (
+            self.min_log_level
+                .as_deref()
+                .and_then(component_types::TextEntry::severity),
+            component_types::TextEntry::severity(level),
+        ) {
+            (Some(min_severity), Some(severity)) => min_severity <= severity,
+            _ => true,
+        };
+
+        is_severe_enough && self.row_log_levels.get(level).copied().unwrap_or(true)
     }
 
     // Checks whether new values are available for any of the filters, and updates everything
@@ -163,8 +324,10 @@ impl ViewTextFilters {
             col_timelines,
             col_entity_path: _,
             col_log_level: _,
+            col_fields,
             row_entity_paths,
             row_log_levels,
+            min_log_level: _,
         } = self;
 
         for timeline in ctx.log_db.timelines() {
@@ -178,6 +341,10 @@ impl ViewTextFilters {
         for level in text_entries.iter().filter_map(|te| te.level.as_ref()) {
             row_log_levels.entry(level.clone()).or_insert(true);
         }
+
+        for (key, _) in text_entries.iter().flat_map(|te| &te.fields) {
+            col_fields.entry(key.clone()).or_insert(true);
+        }
     }
 }
 
@@ -204,7 +371,7 @@ fn table_ui(
     ctx: &mut ViewerContext<'_>,
     ui: &mut egui::Ui,
     state: &mut ViewTextState,
-    text_entries: &[TextEntry],
+    text_entries: &[&TextEntry],
     scroll_to_row: Option<usize>,
 ) {
     let timelines = state
@@ -214,6 +381,13 @@ fn table_ui(
         .filter_map(|(timeline, visible)| visible.then_some(timeline))
         .collect::<Vec<_>>();
 
+    let field_keys = state
+        .filters
+        .col_fields
+        .iter()
+        .filter_map(|(key, visible)| visible.then_some(key))
+        .collect::<Vec<_>>();
+
     use egui_extras::Column;
 
     let global_timeline = *ctx.rec_cfg.time_ctrl.timeline();
@@ -247,6 +421,9 @@ fn table_ui(
         if state.filters.col_log_level {
             table_builder = table_builder.column(Column::auto().at_least(30.0));
         }
+        // fields
+        table_builder =
+            table_builder.columns(Column::auto().clip(true).at_least(32.0), field_keys.len());
         // body
         table_builder = table_builder.column(Column::remainder().at_least(100.0));
     }
@@ -268,6 +445,11 @@ fn table_ui(
                     ui.strong("Level");
                 });
             }
+            for key in &field_keys {
+                header.col(|ui| {
+                    ui.strong(key.as_str());
+                });
+            }
             header.col(|ui| {
                 ui.strong("Body");
             });
@@ -277,9 +459,9 @@ fn table_ui(
 
             body_clip_rect = Some(body.max_rect());
 
-            let row_heights = text_entries.iter().map(calc_row_height);
+            let row_heights = text_entries.iter().map(|te| calc_row_height(te));
             body.heterogeneous_rows(row_heights, |index, mut row| {
-                let text_entry = &text_entries[index];
+                let text_entry = text_entries[index];
 
                 // NOTE: `try_from_props` is where we actually fetch data from the underlying
                 // store, which is a costly operation.
@@ -338,6 +520,18 @@ fn table_ui(
                     });
                 }
 
+                // fields
+                for key in &field_keys {
+                    row.col(|ui| {
+                        if let Some((_, value)) = text_entry.fields.iter().find(|(k, _)| k == *key)
+                        {
+                            ui.label(value);
+                        } else {
+                            ui.label("-");
+                        }
+                    });
+                }
+
                 // body
                 row.col(|ui| {
                     let mut text = egui::RichText::new(&text_entry.body);
@@ -376,12 +570,19 @@ pub fn level_to_rich_text(ui: &egui::Ui, lvl: &str) -> RichText {
     match lvl {
         "CRITICAL" => RichText::new(lvl)
             .color(Color32::WHITE)
-            .background_color(ui.visuals().error_fg_color),
-        "ERROR" => RichText::new(lvl).color(ui.visuals().error_fg_color),
-        "WARN" => RichText::new(lvl).color(ui.visuals().warn_fg_color),
-        "INFO" => RichText::new(lvl).color(Color32::LIGHT_GREEN),
-        "DEBUG" => RichText::new(lvl).color(Color32::LIGHT_BLUE),
-        "TRACE" => RichText::new(lvl).color(Color32::LIGHT_GRAY),
-        _ => RichText::new(lvl).color(ui.visuals().text_color()),
+            .background_color(level_color(ui, lvl)),
+        _ => RichText::new(lvl).color(level_color(ui, lvl)),
+    }
+}
+
+/// The color we show the given log level in.
+pub fn level_color(ui: &egui::Ui, lvl: &str) -> Color32 {
+    match lvl {
+        "CRITICAL" | "ERROR" => ui.visuals().error_fg_color,
+        "WARN" => ui.visuals().warn_fg_color,
+        "INFO" => Color32::LIGHT_GREEN,
+        "DEBUG" => Color32::LIGHT_BLUE,
+        "TRACE" => Color32::LIGHT_GRAY,
+        _ => ui.visuals().text_color(),
     }
 }
@@ -17,7 +17,7 @@ use super::{
     space_view_entity_picker::SpaceViewEntityPicker,
     space_view_heuristics::all_possible_space_views,
     view_category::ViewCategory,
-    SpaceView, SpaceViewId,
+    SpaceView, SpaceViewClassRegistry, SpaceViewId,
 };
 
 // ----------------------------------------------------------------------------
@@ -70,6 +70,19 @@ impl Viewport {
         self.space_views.get(space_view)
     }
 
+    /// The space view with this display name, if any.
+    pub(crate) fn space_view_by_name(&self, name: &str) -> Option<SpaceViewId> {
+        self.space_views
+            .values()
+            .find(|space_view| space_view.display_name == name)
+            .map(|space_view| space_view.id)
+    }
+
+    /// Show only this space view, or all the visible ones if `None`.
+    pub(crate) fn set_maximized(&mut self, space_view: Option<SpaceViewId>) {
+        self.maximized = space_view;
+    }
+
     pub(crate) fn space_view_mut(&mut self, space_view_id: &SpaceViewId) -> Option<&mut SpaceView> {
         self.space_views.get_mut(space_view_id)
     }
@@ -519,6 +532,8 @@ impl Viewport {
                         space_view.category.icon(),
                         if space_view.space_path.is_root() {
                             space_view.display_name.clone()
+                        } else if let Some(class) = &space_view.custom_class {
+                            format!("{} ({class})", space_view.space_path)
                         } else {
                             space_view.space_path.to_string()
                         },
@@ -700,12 +715,20 @@ impl<'a, 'b> egui_dock::TabViewer for TabViewer<'a, 'b> {
     }
 }
 
-fn help_text_ui(ui: &mut egui::Ui, space_view: &SpaceView) {
+fn help_text_ui(
+    ui: &mut egui::Ui,
+    space_view: &SpaceView,
+    space_view_class_registry: &SpaceViewClassRegistry,
+) {
     let help_text = match space_view.category {
         ViewCategory::TimeSeries => Some(crate::ui::view_time_series::HELP_TEXT),
         ViewCategory::BarChart => Some(crate::ui::view_bar_chart::HELP_TEXT),
         ViewCategory::Spatial => Some(space_view.view_state.state_spatial.help_text()),
         ViewCategory::Text | ViewCategory::Tensor => None,
+        ViewCategory::Custom => space_view
+            .custom_class
+            .as_deref()
+            .and_then(|class| space_view_class_registry.help_text(class)),
     };
 
     if let Some(help_text) = help_text {
@@ -756,7 +779,7 @@ fn space_view_options_ui(
             }
 
             // Show help last, since not all space views have help text
-            help_text_ui(ui, space_view);
+            help_text_ui(ui, space_view, ctx.space_view_class_registry);
 
             // Put a frame so that the buttons cover any labels they intersect with:
             let rect = ui.min_rect().expand2(egui::vec2(1.0, -2.0));
@@ -2,6 +2,8 @@ use std::ops::ControlFlow;
 
 use ewebsock::{WsEvent, WsMessage, WsSender};
 
+use re_log_types::ViewerEvent;
+
 use crate::Result;
 
 /// Represents a connection to the server.
@@ -56,4 +58,10 @@ impl Connection {
 
         Ok(Self(sender))
     }
+
+    /// Send an interaction in the viewer back to the server.
+    pub fn send_viewer_event(&mut self, event: &ViewerEvent) {
+        self.0
+            .send(WsMessage::Binary(re_log_types::encode_viewer_event(event)));
+    }
 }
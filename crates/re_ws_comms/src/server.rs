@@ -3,6 +3,8 @@
 //! Each incoming log message is stored, and sent to any connected client.
 //! Each connecting client is first sent the history of stored log messages.
 //!
+//! The clients can send back [`ViewerEvent`]s, see [`Server::listen_with_viewer_events`].
+//!
 //! In the future thing will be changed to a protocol where the clients can query
 //! for specific data based on e.g. time.
 
@@ -13,7 +15,7 @@ use parking_lot::Mutex;
 use tokio::net::{TcpListener, TcpStream};
 use tokio_tungstenite::{accept_async, tungstenite::Error};
 
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
 use re_smart_channel::Receiver;
 
 // ----------------------------------------------------------------------------
@@ -44,6 +46,28 @@ impl Server {
     pub async fn listen(
         self,
         rx: Receiver<LogMsg>,
+        shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+    ) -> anyhow::Result<()> {
+        self.listen_impl(rx, None, shutdown_rx).await
+    }
+
+    /// Like [`Self::listen`], but also puts the [`ViewerEvent`]s sent by the viewers into `viewer_event_tx`.
+    ///
+    /// Events are dropped while the channel is full.
+    pub async fn listen_with_viewer_events(
+        self,
+        rx: Receiver<LogMsg>,
+        viewer_event_tx: crossbeam::channel::Sender<ViewerEvent>,
+        shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+    ) -> anyhow::Result<()> {
+        self.listen_impl(rx, Some(viewer_event_tx), shutdown_rx)
+            .await
+    }
+
+    async fn listen_impl(
+        self,
+        rx: Receiver<LogMsg>,
+        viewer_event_tx: Option<crossbeam::channel::Sender<ViewerEvent>>,
         mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
     ) -> anyhow::Result<()> {
         use anyhow::Context as _;
@@ -68,6 +92,7 @@ impl Server {
                 peer,
                 tcp_stream,
                 history.clone(),
+                viewer_event_tx.clone(),
             ));
         }
     }
@@ -98,6 +123,7 @@ async fn accept_connection(
     _peer: SocketAddr,
     tcp_stream: TcpStream,
     history: Arc<Mutex<Vec<Arc<[u8]>>>>,
+    viewer_event_tx: Option<crossbeam::channel::Sender<ViewerEvent>>,
 ) {
     // let span = re_log::span!(
     //     re_log::Level::INFO,
@@ -108,7 +134,7 @@ async fn accept_connection(
 
     re_log::debug!("New WebSocket connection");
 
-    if let Err(err) = handle_connection(log_stream, tcp_stream, history).await {
+    if let Err(err) = handle_connection(log_stream, tcp_stream, history, viewer_event_tx).await {
         match err {
             Error::ConnectionClosed | Error::Protocol(_) | Error::Utf8 => (),
             err => re_log::error!("Error processing connection: {err}"),
@@ -120,6 +146,7 @@ async fn handle_connection(
     log_stream: tokio::sync::broadcast::Sender<Arc<[u8]>>,
     tcp_stream: TcpStream,
     history: Arc<Mutex<Vec<Arc<[u8]>>>>,
+    viewer_event_tx: Option<crossbeam::channel::Sender<ViewerEvent>>,
 ) -> tungstenite::Result<()> {
     let ws_stream = accept_async(tcp_stream).await?;
     let (mut ws_sender, mut ws_receiver) = ws_stream.split();
@@ -139,6 +166,9 @@ async fn handle_connection(
         tokio::select! {
             ws_msg = ws_receiver.next() => {
                 match ws_msg {
+                    Some(Ok(tungstenite::Message::Binary(binary))) => {
+                        receive_viewer_event(&binary, viewer_event_tx.as_ref());
+                    }
                     Some(Ok(msg)) => {
                         re_log::debug!("Received message: {:?}", msg);
                     }
@@ -161,3 +191,25 @@ async fn handle_connection(
 
     Ok(())
 }
+
+fn receive_viewer_event(
+    binary: &[u8],
+    viewer_event_tx: Option<&crossbeam::channel::Sender<ViewerEvent>>,
+) {
+    let Some(viewer_event_tx) = viewer_event_tx else {
+        re_log::debug_once!("Ignoring viewer events - nobody is listening for them.");
+        return;
+    };
+
+    match re_log_types::decode_viewer_event(binary) {
+        Ok(event) => {
+            if let Err(crossbeam::channel::TrySendError::Full(_)) = viewer_event_tx.try_send(event)
+            {
+                re_log::debug_once!("Dropping viewer events because nobody is receiving them.");
+            }
+        }
+        Err(err) => {
+            re_log::warn!("Failed to decode viewer event: {err}");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use re_log_types::LogMsg;
 use re_log_types::RecordingInfo;
+use re_log_types::ViewerEvent;
 use re_sdk::Session;
 
 /// Starts a Rerun viewer on the current thread and migrates the given callback, along with
@@ -19,7 +20,11 @@ where
     F: FnOnce(Session) + Send + 'static,
 {
     let (tx, rx) = re_smart_channel::smart_channel(re_smart_channel::Source::Sdk);
-    let sink = Box::new(NativeViewerSink(tx));
+    let (viewer_event_tx, viewer_event_rx) = crossbeam::channel::bounded(1024);
+    let sink = Box::new(NativeViewerSink {
+        tx,
+        viewer_event_rx,
+    });
     let app_env =
         re_viewer::AppEnvironment::from_recording_source(&recording_info.recording_source);
 
@@ -37,7 +42,7 @@ where
         // TODO(cmc): it'd be nice to centralize all the UI wake up logic somewhere.
         let rx = re_viewer::wake_up_ui_thread_on_each_msg(rx, cc.egui_ctx.clone());
         let startup_options = re_viewer::StartupOptions::default();
-        Box::new(re_viewer::App::from_receiver(
+        let mut app = re_viewer::App::from_receiver(
             re_build_info::build_info!(),
             &app_env,
             startup_options,
@@ -45,7 +50,12 @@ where
             cc.storage,
             rx,
             std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
-        ))
+        );
+        app.set_viewer_event_callback(Box::new(move |event| {
+            // Drop the event if the logging thread doesn't keep up.
+            viewer_event_tx.try_send(event).ok();
+        }));
+        Box::new(app)
     }))
 }
 
@@ -82,13 +92,20 @@ pub fn show(session: &Session) -> re_viewer::external::eframe::Result<()> {
 
 /// Stream log messages to a native viewer on the main thread.
 #[cfg(feature = "native_viewer")]
-struct NativeViewerSink(pub re_smart_channel::Sender<LogMsg>);
+struct NativeViewerSink {
+    tx: re_smart_channel::Sender<LogMsg>,
+    viewer_event_rx: crossbeam::channel::Receiver<ViewerEvent>,
+}
 
 #[cfg(feature = "native_viewer")]
 impl re_sdk::sink::LogSink for NativeViewerSink {
     fn send(&self, msg: LogMsg) {
-        if let Err(err) = self.0.send(msg) {
+        if let Err(err) = self.tx.send(msg) {
             re_log::error_once!("Failed to send log message to viewer: {err}");
         }
     }
+
+    fn viewer_events(&self) -> Option<crossbeam::channel::Receiver<ViewerEvent>> {
+        Some(self.viewer_event_rx.clone())
+    }
 }
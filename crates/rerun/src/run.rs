@@ -9,6 +9,10 @@ use clap::Subcommand;
 #[cfg(feature = "web_viewer")]
 use crate::web_viewer::host_web_viewer;
 
+/// How many viewer events to buffer for each connected SDK before dropping them.
+#[cfg(feature = "server")]
+const VIEWER_EVENT_BUFFER_SIZE: usize = 1024;
+
 // Note the extra blank lines between the point-lists below: it is required by `clap`.
 
 /// The Rerun Viewer and Server
@@ -51,6 +55,14 @@ struct Args {
     #[clap(long, default_value_t = re_sdk_comms::DEFAULT_SERVER_PORT)]
     port: u16,
 
+    /// Let test harnesses, notebooks etc. remote-control the native viewer over this TCP port.
+    ///
+    /// Only listens on localhost. Send one JSON `ViewerCommand` per line, e.g.
+    /// `{"RunCommand":{"command":"ToggleTimePanel"}}`, and get one JSON response per line back.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    control_port: Option<u16>,
+
     /// Start the viewer in the browser (instead of locally).
     /// Requires Rerun to have been compiled with the 'web_viewer' feature.
     #[clap(long)]
@@ -259,6 +271,10 @@ async fn run_impl(
 
     let (shutdown_rx, shutdown_bool) = setup_ctrl_c_handler();
 
+    // Interactions in the viewer, sent back to the SDKs connected to our server.
+    #[cfg(feature = "server")]
+    let (viewer_event_tx, _) = tokio::sync::broadcast::channel(VIEWER_EVENT_BUFFER_SIZE);
+
     // Where do we get the data from?
     let rx = if let Some(url_or_path) = args.url_or_path.clone() {
         match categorize_argument(url_or_path) {
@@ -310,7 +326,13 @@ async fn run_impl(
                 // `rerun.spawn()` doesn't need to log that a connection has been made
                 quiet: call_source.is_python(),
             };
-            re_sdk_comms::serve(args.port, server_options, shutdown_rx.resubscribe()).await?
+            re_sdk_comms::serve_with_viewer_events(
+                args.port,
+                server_options,
+                shutdown_rx.resubscribe(),
+                viewer_event_tx.clone(),
+            )
+            .await?
         }
 
         #[cfg(not(feature = "server"))]
@@ -339,6 +361,25 @@ async fn run_impl(
 
             // This is the server which the web viewer will talk to:
             let ws_server = re_ws_comms::Server::new(re_ws_comms::DEFAULT_WS_SERVER_PORT).await?;
+            #[cfg(feature = "server")]
+            let ws_server_handle = {
+                let (ws_viewer_event_tx, ws_viewer_event_rx) =
+                    crossbeam::channel::bounded(VIEWER_EVENT_BUFFER_SIZE);
+                std::thread::Builder::new()
+                    .name("viewer_event_forwarder".into())
+                    .spawn(move || {
+                        for event in ws_viewer_event_rx {
+                            viewer_event_tx.send(event).ok();
+                        }
+                    })
+                    .expect("Failed to spawn thread");
+                tokio::spawn(ws_server.listen_with_viewer_events(
+                    rx,
+                    ws_viewer_event_tx,
+                    shutdown_ws_server,
+                ))
+            };
+            #[cfg(not(feature = "server"))]
             let ws_server_handle = tokio::spawn(ws_server.listen(rx, shutdown_ws_server));
             let ws_server_url = re_ws_comms::default_server_url("127.0.0.1");
 
@@ -359,6 +400,13 @@ async fn run_impl(
             );
         }
     } else {
+        #[cfg(all(feature = "native_viewer", feature = "server"))]
+        let control_rx = if let Some(port) = args.control_port {
+            Some(re_sdk_comms::serve_control(port, shutdown_rx.resubscribe()).await?)
+        } else {
+            None
+        };
+
         #[cfg(feature = "native_viewer")]
         return re_viewer::run_native_app(Box::new(move |cc, re_ui| {
             // We need to wake up the ui thread in order to process shutdown signals.
@@ -380,6 +428,16 @@ async fn run_impl(
                 shutdown_bool,
             );
             app.set_profiler(profiler);
+            #[cfg(feature = "server")]
+            app.set_viewer_event_callback(Box::new(move |event| {
+                viewer_event_tx.send(event).ok();
+            }));
+            #[cfg(feature = "server")]
+            if let Some(control_rx) = control_rx {
+                let control_rx =
+                    re_viewer::wake_up_ui_thread_on_each_msg(control_rx, cc.egui_ctx.clone());
+                app.set_viewer_command_receiver(control_rx);
+            }
             Box::new(app)
         }))
         .map_err(|e| e.into());
@@ -1,10 +1,11 @@
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
 
 /// Hosts two servers:
 /// * A web-server, serving the web-viewer
 /// * A `WebSocket` server, server [`LogMsg`]es to remote viewer(s).
 struct RemoteViewerServer {
     sender: re_smart_channel::Sender<LogMsg>,
+    viewer_event_rx: crossbeam::channel::Receiver<ViewerEvent>,
     shutdown_tx: tokio::sync::broadcast::Sender<()>,
 }
 
@@ -20,13 +21,18 @@ impl RemoteViewerServer {
         let (rerun_tx, rerun_rx) = re_smart_channel::smart_channel(re_smart_channel::Source::Sdk);
         let (shutdown_tx, shutdown_rx_ws_server) = tokio::sync::broadcast::channel(1);
         let shutdown_rx_web_server = shutdown_tx.subscribe();
+        let (viewer_event_tx, viewer_event_rx) = crossbeam::channel::bounded(1024);
 
         tokio::spawn(async move {
             // This is the server which the web viewer will talk to:
             let ws_server = re_ws_comms::Server::new(re_ws_comms::DEFAULT_WS_SERVER_PORT)
                 .await
                 .unwrap();
-            let ws_server_handle = tokio::spawn(ws_server.listen(rerun_rx, shutdown_rx_ws_server));
+            let ws_server_handle = tokio::spawn(ws_server.listen_with_viewer_events(
+                rerun_rx,
+                viewer_event_tx,
+                shutdown_rx_ws_server,
+            ));
             let ws_server_url = re_ws_comms::default_server_url("127.0.0.1");
 
             // This is the server that serves the Wasm+HTML:
@@ -42,6 +48,7 @@ impl RemoteViewerServer {
 
         Self {
             sender: rerun_tx,
+            viewer_event_rx,
             shutdown_tx,
         }
     }
@@ -80,6 +87,10 @@ impl crate::sink::LogSink for RemoteViewerServer {
             re_log::error_once!("Failed to send log message to web server: {err}");
         }
     }
+
+    fn viewer_events(&self) -> Option<crossbeam::channel::Receiver<ViewerEvent>> {
+        Some(self.viewer_event_rx.clone())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -115,6 +115,11 @@ SECTION_TABLE: Final[List[Section]] = [
         # "LogLevel", "LoggingHandler"
         func_list=["log_text_entry"],
     ),
+    Section(
+        title="Markers",
+        module_summary=None,
+        func_list=["log_marker"],
+    ),
     Section(
         title="Helpers",
         module_summary="script_helpers",
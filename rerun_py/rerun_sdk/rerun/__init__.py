@@ -14,13 +14,21 @@ from rerun.log.extension_components import log_extension_components
 from rerun.log.file import ImageFormat, MeshFormat, log_image_file, log_mesh_file
 from rerun.log.image import log_depth_image, log_image, log_segmentation_image
 from rerun.log.lines import log_line_segments, log_line_strip, log_path
+from rerun.log.marker import log_marker
 from rerun.log.mesh import log_mesh, log_meshes
 from rerun.log.points import log_point, log_points
 from rerun.log.rects import RectFormat, log_rect, log_rects
 from rerun.log.scalar import log_scalar
 from rerun.log.tensor import log_tensor
 from rerun.log.text import LoggingHandler, LogLevel, log_text_entry
-from rerun.log.transform import log_rigid3, log_unknown_transform, log_view_coordinates
+from rerun.log.transform import (
+    log_affine3,
+    log_homography,
+    log_rigid3,
+    log_scale,
+    log_unknown_transform,
+    log_view_coordinates,
+)
 from rerun.script_helpers import script_add_args, script_setup, script_teardown
 
 __all__ = [
@@ -30,15 +38,18 @@ __all__ = [
     "bindings",
     "components",
     "ImageFormat",
+    "log_affine3",
     "log_annotation_context",
     "log_arrow",
     "log_cleared",
     "log_depth_image",
     "log_extension_components",
+    "log_homography",
     "log_image_file",
     "log_image",
     "log_line_segments",
     "log_line_strip",
+    "log_marker",
     "log_mesh_file",
     "log_mesh",
     "log_meshes",
@@ -50,6 +61,7 @@ __all__ = [
     "log_rect",
     "log_rects",
     "log_rigid3",
+    "log_scale",
     "log_scalar",
     "log_segmentation_image",
     "log_tensor",
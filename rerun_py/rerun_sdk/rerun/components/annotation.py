@@ -11,6 +11,8 @@ __all__ = [
     "ClassIdType",
     "KeypointIdArray",
     "KeypointIdType",
+    "KeypointVisibilityArray",
+    "KeypointVisibilityType",
 ]
 
 
@@ -41,4 +43,25 @@ class KeypointIdArray(pa.ExtensionArray):  # type: ignore[misc]
 
 KeypointIdType = ComponentTypeFactory("KeypointIdType", ClassIdArray, REGISTERED_COMPONENT_NAMES["rerun.keypoint_id"])
 
+
+
+class KeypointVisibilityArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_numpy(array: npt.NDArray[np.uint8]) -> KeypointVisibilityArray:
+        """
+        Build a `KeypointVisibilityArray` from an Nx1 numpy array.
+
+        Follows the COCO convention: 0 = not labeled, 1 = labeled but occluded, 2 = labeled and visible.
+        """
+        assert len(array.shape) == 1
+
+        storage = pa.array(array, type=KeypointVisibilityType.storage_type)
+        # TODO(john) enable extension type wrapper
+        # return cast(KeypointVisibilityArray, pa.ExtensionArray.from_storage(KeypointVisibilityArray(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+KeypointVisibilityType = ComponentTypeFactory(
+    "KeypointVisibilityType", KeypointVisibilityArray, REGISTERED_COMPONENT_NAMES["rerun.keypoint_visibility"]
+)
+
 pa.register_extension_type(ClassIdType())
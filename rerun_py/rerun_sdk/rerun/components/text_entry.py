@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Sequence
+from typing import Any, Mapping, Sequence
 
 import pyarrow as pa
 
@@ -9,6 +9,8 @@ from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
 __all__ = [
     "TextEntryArray",
     "TextEntryType",
+    "TextEntryFieldsArray",
+    "TextEntryFieldsType",
 ]
 
 
@@ -35,3 +37,26 @@ class TextEntryArray(pa.ExtensionArray):  # type: ignore[misc]
 TextEntryType = ComponentTypeFactory("TextEntryType", TextEntryArray, REGISTERED_COMPONENT_NAMES["rerun.text_entry"])
 
 pa.register_extension_type(TextEntryType())
+
+
+class TextEntryFieldsArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_mappings(fields: Sequence[Mapping[str, Any]]) -> TextEntryFieldsArray:
+        """
+        Build a `TextEntryFieldsArray` from a sequence of structured key/value pairs, one mapping per text entry.
+
+        Values are converted to strings.
+        """
+
+        entries = [[{"key": str(key), "value": str(value)} for key, value in mapping.items()] for mapping in fields]
+
+        storage = pa.array(entries, type=TextEntryFieldsType.storage_type)
+        # TODO(john) enable extension type wrapper
+        # return cast(TextEntryFieldsArray, pa.ExtensionArray.from_storage(TextEntryFieldsType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+TextEntryFieldsType = ComponentTypeFactory(
+    "TextEntryFieldsType", TextEntryFieldsArray, REGISTERED_COMPONENT_NAMES["rerun.text_entry_fields"]
+)
+
+pa.register_extension_type(TextEntryFieldsType())
@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+from typing import Sequence
+
+import pyarrow as pa
+
+from rerun.components import REGISTERED_COMPONENT_NAMES, ComponentTypeFactory
+
+__all__ = [
+    "TimeMarkerArray",
+    "TimeMarkerType",
+]
+
+
+class TimeMarkerArray(pa.ExtensionArray):  # type: ignore[misc]
+    def from_labels_and_durations(markers: Sequence[tuple[str, int | None]]) -> TimeMarkerArray:
+        """Build a `TimeMarkerArray` from a sequence of labels and optional durations."""
+        storage = pa.array(
+            [{"label": label, "duration": duration} for label, duration in markers],
+            type=TimeMarkerType.storage_type,
+        )
+        # TODO(john) enable extension type wrapper
+        # return cast(TimeMarkerArray, pa.ExtensionArray.from_storage(TimeMarkerType(), storage))
+        return storage  # type: ignore[no-any-return]
+
+
+TimeMarkerType = ComponentTypeFactory(
+    "TimeMarkerType", TimeMarkerArray, REGISTERED_COMPONENT_NAMES["rerun.time_marker"]
+)
+
+pa.register_extension_type(TimeMarkerType())
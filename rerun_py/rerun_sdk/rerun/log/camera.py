@@ -1,3 +1,5 @@
+from typing import Optional
+
 import numpy as np
 import numpy.typing as npt
 
@@ -16,6 +18,8 @@ def log_pinhole(
     child_from_parent: npt.ArrayLike,
     width: int,
     height: int,
+    distortion_model: Optional[str] = None,
+    distortion_coefficients: Optional[npt.ArrayLike] = None,
     timeless: bool = False,
 ) -> None:
     """
@@ -29,6 +33,16 @@ def log_pinhole(
 
     Where `point_image_hom` is the projected point in the image space expressed in homogeneous coordinates.
 
+    Optionally, lens distortion can be applied to the normalized coordinates `point_cam[:2] / point_cam[2]`
+    before multiplying with `child_from_parent`. The following models are supported:
+
+    * `"brown_conrady"`: radial & tangential distortion as used by OpenCV.
+      Takes the coefficients `[k1, k2, p1, p2]` or `[k1, k2, p1, p2, k3]`.
+    * `"kannala_brandt"`: fisheye distortion as used by OpenCV's `fisheye` module.
+      Takes the coefficients `[k1, k2, k3, k4]`.
+    * `"equirectangular"`: a spherical projection where the normalized coordinates are longitude & latitude in
+      radians. Takes no coefficients.
+
     Example
     -------
     ```
@@ -56,6 +70,10 @@ def log_pinhole(
         Width of the image in pixels.
     height:
         Height of the image in pixels.
+    distortion_model:
+        Name of the lens distortion model, see above. No distortion if `None`.
+    distortion_coefficients:
+        Coefficients of the lens distortion model.
     timeless:
         If true, the camera will be timeless (default: False).
 
@@ -66,5 +84,9 @@ def log_pinhole(
         entity_path,
         resolution=[width, height],
         child_from_parent=np.asarray(child_from_parent).T.tolist(),
+        distortion_model=distortion_model,
+        distortion_coefficients=None
+        if distortion_coefficients is None
+        else np.asarray(distortion_coefficients, dtype=np.float32).flatten().tolist(),
         timeless=timeless,
     )
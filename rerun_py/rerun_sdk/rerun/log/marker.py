@@ -0,0 +1,58 @@
+from typing import Any, Dict, Optional
+
+from rerun import bindings
+from rerun.components.instance import InstanceArray
+from rerun.components.time_marker import TimeMarkerArray
+from rerun.log.extension_components import _add_extension_components
+from rerun.log.log_decorator import log_decorator
+
+__all__ = [
+    "log_marker",
+]
+
+
+@log_decorator
+def log_marker(
+    entity_path: str,
+    label: str,
+    *,
+    duration: Optional[int] = None,
+    ext: Optional[Dict[str, Any]] = None,
+) -> None:
+    """
+    Log a named marker at the current time, e.g. to flag an incident.
+
+    The viewer shows markers as flags on the time axis of the time panel, and lists them
+    in its bookmarks menu so you can jump straight to them.
+
+    Markers cannot be timeless.
+
+    Parameters
+    ----------
+    entity_path:
+        Path to the marker in the space hierarchy.
+    label:
+        The name of the marker.
+    duration:
+        If set, the marker spans a range of this length, starting at the current time.
+        In the units of each timeline: nanoseconds for time timelines, steps for sequence timelines.
+    ext:
+        Optional dictionary of extension components. See [rerun.log_extension_components][]
+
+    """
+
+    instanced: Dict[str, Any] = {}
+    splats: Dict[str, Any] = {}
+
+    instanced["rerun.time_marker"] = TimeMarkerArray.from_labels_and_durations([(label, duration)])
+
+    if ext:
+        _add_extension_components(instanced, splats, ext, None)
+
+    if splats:
+        splats["rerun.instance_key"] = InstanceArray.splat()
+        bindings.log_arrow_msg(entity_path, components=splats, timeless=False)
+
+    # Always the primary component last so range-based queries will include the other data. See(#1215)
+    if instanced:
+        bindings.log_arrow_msg(entity_path, components=instanced, timeless=False)
@@ -4,7 +4,7 @@ import numpy as np
 import numpy.typing as npt
 
 from rerun import bindings
-from rerun.components.annotation import ClassIdArray
+from rerun.components.annotation import ClassIdArray, KeypointVisibilityArray
 from rerun.components.color import ColorRGBAArray
 from rerun.components.instance import InstanceArray
 from rerun.components.label import LabelArray
@@ -140,6 +140,7 @@ def log_points(
     labels: Optional[Sequence[str]] = None,
     class_ids: OptionalClassIds = None,
     keypoint_ids: OptionalKeyPointIds = None,
+    keypoint_visibilities: Optional[npt.ArrayLike] = None,
     ext: Optional[Dict[str, Any]] = None,
     timeless: bool = False,
 ) -> None:
@@ -185,6 +186,9 @@ def log_points(
         This is useful to identify points within a single classification (which is identified with class_id).
         E.g. the classification might be 'Person' and the keypoints refer to joints on a detected skeleton.
         See [rerun.log_annotation_context][]
+    keypoint_visibilities:
+        Optional visibility of the key points, following the COCO convention:
+        0 = not labeled (not shown), 1 = labeled but occluded, 2 = labeled and visible.
     ext:
         Optional dictionary of extension components. See [rerun.log_extension_components][]
     timeless:
@@ -204,6 +208,10 @@ def log_points(
     labels = _normalize_labels(labels)
     class_ids = _normalize_ids(class_ids)
     keypoint_ids = _normalize_ids(keypoint_ids)
+    if keypoint_visibilities is None:
+        keypoint_visibilities = np.array((), dtype="uint8")
+    else:
+        keypoint_visibilities = np.require(keypoint_visibilities, dtype="uint8").flatten()
 
     identifiers_np = np.array((), dtype="uint64")
     if identifiers is not None:
@@ -248,6 +256,10 @@ def log_points(
         is_splat = len(keypoint_ids) == 1
         comps[is_splat]["rerun.keypoint_id"] = ClassIdArray.from_numpy(keypoint_ids)
 
+    if len(keypoint_visibilities):
+        is_splat = len(keypoint_visibilities) == 1
+        comps[is_splat]["rerun.keypoint_visibility"] = KeypointVisibilityArray.from_numpy(keypoint_visibilities)
+
     if ext:
         _add_extension_components(comps[0], comps[1], ext, identifiers_np)
 
@@ -6,7 +6,7 @@ from typing import Any, Dict, Final, Optional
 from rerun import bindings
 from rerun.components.color import ColorRGBAArray
 from rerun.components.instance import InstanceArray
-from rerun.components.text_entry import TextEntryArray
+from rerun.components.text_entry import TextEntryArray, TextEntryFieldsArray
 from rerun.log import Color, _normalize_colors
 
 __all__ = [
@@ -49,6 +49,7 @@ def log_text_entry_internal(
     *,
     level: Optional[str] = LogLevel.INFO,
     color: Optional[Color] = None,
+    fields: Optional[Dict[str, Any]] = None,
     timeless: bool = False,
 ) -> None:
     """
@@ -69,6 +70,9 @@ def log_text_entry_internal(
         from [LogLevel][rerun.log.text.LogLevel]
     color:
         Optional RGB or RGBA in sRGB gamma-space as either 0-1 floats or 0-255 integers, with separate alpha.
+    fields:
+        Optional structured key/value pairs attached to the text entry, e.g. `{"speed": 4.2, "gear": 3}`.
+        Values are converted to strings. The viewer shows a column for each key.
     timeless:
         Whether the text entry should be timeless.
 
@@ -79,6 +83,8 @@ def log_text_entry_internal(
 
     if text:
         instanced["rerun.text_entry"] = TextEntryArray.from_bodies_and_levels([(text, level)])
+        if fields:
+            instanced["rerun.text_entry_fields"] = TextEntryFieldsArray.from_mappings([fields])
     else:
         logging.warning(f"Null  text entry in log_text_entry('{entity_path}') will be dropped.")
 
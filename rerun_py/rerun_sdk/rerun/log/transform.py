@@ -3,8 +3,9 @@ Methods for logging transforms on entity paths.
 
 Learn more about transforms [in the manual](https://www.rerun.io/docs/concepts/spaces-and-transforms)
 """
-from typing import Optional, Tuple
+from typing import Optional, Sequence, Tuple, Union
 
+import numpy as np
 import numpy.typing as npt
 
 from rerun import bindings
@@ -16,6 +17,9 @@ __all__ = [
     "log_view_coordinates",
     "log_unknown_transform",
     "log_rigid3",
+    "log_scale",
+    "log_affine3",
+    "log_homography",
 ]
 
 
@@ -175,3 +179,140 @@ def log_rigid3(
 
     if xyz != "":
         log_view_coordinates(entity_path, xyz=xyz, timeless=timeless)
+
+
+@log_decorator
+def log_scale(
+    entity_path: str,
+    scale: Union[float, Sequence[float], npt.ArrayLike],
+    *,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a scale between this entity and the parent.
+
+    Example
+    -------
+    ```
+    rerun.log_scale("world/model", 2.0)
+    rerun.log_scale("world/stretched", [1.0, 2.0, 0.5])
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path of the *child* space in the space hierarchy.
+    scale:
+        Either a single uniform scale factor or one factor per axis, mapping the child space to the parent space.
+    timeless:
+        If true, the transform will be timeless (default: False).
+
+    """
+
+    scale = np.asarray(scale, dtype=np.float32).flatten()
+    if len(scale) not in (1, 3):
+        _send_warning(f"Expected scale to be a single number or of length 3, got {len(scale)} elements.", 1)
+        return
+
+    bindings.log_scale(entity_path, scale=scale.tolist(), timeless=timeless)
+
+
+@log_decorator
+def log_affine3(
+    entity_path: str,
+    *,
+    parent_from_child: Optional[npt.ArrayLike] = None,
+    child_from_parent: Optional[npt.ArrayLike] = None,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a general affine 3D transform between this entity and the parent.
+
+    Set either `parent_from_child` or `child_from_parent` to a 3x4 (or 4x4) row-major matrix.
+    For a 4x4 matrix, the last row has to be `[0, 0, 0, 1]`.
+
+    Example
+    -------
+    ```
+    shear = np.array([[1.0, 0.5, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]])
+    rerun.log_affine3("world/sheared", parent_from_child=shear)
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path of the *child* space in the space hierarchy.
+    parent_from_child:
+        Matrix mapping points in the child space to the parent space.
+    child_from_parent:
+        The inverse of `parent_from_child`.
+    timeless:
+        If true, the transform will be timeless (default: False).
+
+    """
+
+    if parent_from_child is not None and child_from_parent is not None:
+        raise TypeError("Set either parent_from_child or child_from_parent, but not both.")
+
+    if parent_from_child is not None:
+        matrix = np.asarray(parent_from_child, dtype=np.float32)
+    elif child_from_parent is not None:
+        matrix = np.asarray(child_from_parent, dtype=np.float32)
+    else:
+        raise TypeError("Set either parent_from_child or child_from_parent.")
+
+    if matrix.shape == (4, 4):
+        if not np.allclose(matrix[3], [0.0, 0.0, 0.0, 1.0]):
+            _send_warning("The last row of an affine 4x4 matrix has to be [0, 0, 0, 1]. Ignoring log.", 1)
+            return
+        matrix = matrix[:3]
+    if matrix.shape != (3, 4):
+        _send_warning(f"Expected a 3x4 or 4x4 matrix, got shape {matrix.shape}. Ignoring log.", 1)
+        return
+
+    bindings.log_affine3(
+        entity_path,
+        parent_from_child=parent_from_child is not None,
+        linear=matrix[:, :3].T.tolist(),
+        translation=matrix[:, 3].tolist(),
+        timeless=timeless,
+    )
+
+
+@log_decorator
+def log_homography(
+    entity_path: str,
+    parent_from_child: npt.ArrayLike,
+    *,
+    timeless: bool = False,
+) -> None:
+    """
+    Log a 2D homography between this entity and the parent.
+
+    Both this entity and its parent have to be 2D spaces.
+    Images logged under this entity are shown warped into the parent space.
+
+    Example
+    -------
+    ```
+    homography, _ = cv2.findHomography(points_in_image, points_in_reference)
+    rerun.log_homography("reference/image", homography)
+    ```
+
+    Parameters
+    ----------
+    entity_path:
+        Path of the *child* space in the space hierarchy.
+    parent_from_child:
+        A 3x3 row-major matrix mapping homogeneous 2D points in the child space to the parent space.
+    timeless:
+        If true, the transform will be timeless (default: False).
+
+    """
+
+    matrix = np.asarray(parent_from_child, dtype=np.float32)
+    if matrix.shape != (3, 3):
+        _send_warning(f"Expected a 3x3 matrix, got shape {matrix.shape}. Ignoring log.", 1)
+        return
+
+    bindings.log_homography(entity_path, parent_from_child=matrix.T.tolist(), timeless=timeless)
@@ -147,6 +147,9 @@ fn rerun_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(log_unknown_transform, m)?)?;
     m.add_function(wrap_pyfunction!(log_rigid3, m)?)?;
+    m.add_function(wrap_pyfunction!(log_scale, m)?)?;
+    m.add_function(wrap_pyfunction!(log_affine3, m)?)?;
+    m.add_function(wrap_pyfunction!(log_homography, m)?)?;
     m.add_function(wrap_pyfunction!(log_pinhole, m)?)?;
 
     m.add_function(wrap_pyfunction!(log_meshes, m)?)?;
@@ -440,21 +443,126 @@ fn log_rigid3(
     log_transform(entity_path, transform, timeless)
 }
 
+#[pyfunction]
+fn log_scale(entity_path: &str, scale: Vec<f32>, timeless: bool) -> PyResult<()> {
+    let scale = match scale[..] {
+        [uniform] => re_log_types::Scale3D::Uniform(uniform),
+        [x, y, z] => re_log_types::Scale3D::ThreeD([x, y, z].into()),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Expected scale to be a single number or of length 3, got {scale:?}"
+            )));
+        }
+    };
+    let transform = re_log_types::Transform::Scale(scale);
+
+    log_transform(entity_path, transform, timeless)
+}
+
+/// `linear` is expected to be column major.
+#[pyfunction]
+fn log_affine3(
+    entity_path: &str,
+    parent_from_child: bool,
+    linear: [[f32; 3]; 3],
+    translation: [f32; 3],
+    timeless: bool,
+) -> PyResult<()> {
+    let transform = glam::Affine3A::from_mat3_translation(
+        glam::Mat3::from_cols_array_2d(&linear),
+        glam::Vec3::from_slice(&translation),
+    );
+
+    let transform = if parent_from_child {
+        transform
+    } else {
+        if transform.matrix3.determinant() == 0.0 {
+            return Err(PyValueError::new_err(
+                "child_from_parent transform is not invertible",
+            ));
+        }
+        transform.inverse()
+    };
+
+    let transform =
+        re_log_types::Transform::Affine3(re_log_types::Affine3::new_parent_from_child(transform));
+
+    log_transform(entity_path, transform, timeless)
+}
+
+/// `parent_from_child` is expected to be column major.
+#[pyfunction]
+fn log_homography(
+    entity_path: &str,
+    parent_from_child: [[f32; 3]; 3],
+    timeless: bool,
+) -> PyResult<()> {
+    let transform = re_log_types::Transform::Homography2D(re_log_types::Homography2D {
+        parent_from_child: parent_from_child.into(),
+    });
+
+    log_transform(entity_path, transform, timeless)
+}
+
 #[pyfunction]
 fn log_pinhole(
     entity_path: &str,
     resolution: [f32; 2],
     child_from_parent: [[f32; 3]; 3],
+    distortion_model: Option<&str>,
+    distortion_coefficients: Option<Vec<f32>>,
     timeless: bool,
 ) -> PyResult<()> {
+    let distortion = convert_lens_distortion(distortion_model, distortion_coefficients)?;
     let transform = re_log_types::Transform::Pinhole(re_log_types::Pinhole {
         image_from_cam: child_from_parent.into(),
         resolution: Some(resolution.into()),
+        distortion,
     });
 
     log_transform(entity_path, transform, timeless)
 }
 
+fn convert_lens_distortion(
+    model: Option<&str>,
+    coefficients: Option<Vec<f32>>,
+) -> PyResult<re_log_types::component_types::LensDistortion> {
+    use re_log_types::component_types::{BrownConrady, KannalaBrandt, LensDistortion};
+
+    let coefficients = coefficients.unwrap_or_default();
+    match (model, &coefficients[..]) {
+        (None, []) => Ok(LensDistortion::None),
+        (Some("brown_conrady"), &[k1, k2, p1, p2]) => {
+            Ok(LensDistortion::BrownConrady(BrownConrady {
+                k1,
+                k2,
+                p1,
+                p2,
+                k3: 0.0,
+            }))
+        }
+        (Some("brown_conrady"), &[k1, k2, p1, p2, k3]) => {
+            Ok(LensDistortion::BrownConrady(BrownConrady { k1, k2, p1, p2, k3 }))
+        }
+        (Some("kannala_brandt"), &[k1, k2, k3, k4]) => {
+            Ok(LensDistortion::KannalaBrandt(KannalaBrandt { k1, k2, k3, k4 }))
+        }
+        (Some("equirectangular"), []) => Ok(LensDistortion::Equirectangular),
+        (Some("brown_conrady"), _) => Err(PyValueError::new_err(format!(
+            "Expected 4 or 5 Brown-Conrady coefficients (k1, k2, p1, p2[, k3]), got {coefficients:?}"
+        ))),
+        (Some("kannala_brandt"), _) => Err(PyValueError::new_err(format!(
+            "Expected 4 Kannala-Brandt coefficients (k1, k2, k3, k4), got {coefficients:?}"
+        ))),
+        (None | Some("equirectangular"), _) => Err(PyValueError::new_err(format!(
+            "Distortion model {model:?} doesn't take any coefficients, got {coefficients:?}"
+        ))),
+        (Some(model), _) => Err(PyValueError::new_err(format!(
+            "Unknown distortion model {model:?}. Expected one of 'brown_conrady', 'kannala_brandt' or 'equirectangular'"
+        ))),
+    }
+}
+
 fn log_transform(
     entity_path: &str,
     transform: re_log_types::Transform,